@@ -28,7 +28,8 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use secp256k1::{Message, PublicKey};
+use secp256k1::util::SIGNATURE_SIZE;
+use secp256k1::{Message, PublicKey, RecoveryId, Signature};
 use sp_core::H160;
 use sp_io::hashing::keccak_256;
 
@@ -46,3 +47,51 @@ pub fn prepare_message(msg: &[u8]) -> Message {
     let hash = keccak_256(&prefix);
     Message::parse_slice(&hash).expect("hash size == 256 bits; qed")
 }
+
+/// Error of the Ethereum signature recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The signature is malformed.
+    Invalid,
+    /// The signer can't be recovered from the signature.
+    VerificationFailed,
+}
+
+/// Recovers the Ethereum address which signed `msg` with `personal_sign`. The signature is
+/// `r || s || v`, where `v` is either the recovery id or the recovery id plus 27.
+pub fn recover_signer(msg: &[u8], signature: &[u8]) -> Result<EthereumAddress, SignatureError> {
+    if signature.len() != SIGNATURE_SIZE + 1 {
+        return Err(SignatureError::Invalid);
+    }
+    let recovery_id = if signature[SIGNATURE_SIZE] >= 27 {
+        signature[SIGNATURE_SIZE] - 27
+    } else {
+        signature[SIGNATURE_SIZE]
+    };
+    let recovery_id =
+        RecoveryId::parse(recovery_id).map_err(|_| SignatureError::VerificationFailed)?;
+    let signature = Signature::parse_slice(&signature[..SIGNATURE_SIZE])
+        .map_err(|_| SignatureError::Invalid)?;
+    let public_key = secp256k1::recover(&prepare_message(msg), &signature, &recovery_id)
+        .map_err(|_| SignatureError::VerificationFailed)?;
+    Ok(public_key_to_eth_address(&public_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recover_signer_rejects_malformed_signatures() {
+        assert_eq!(
+            recover_signer(b"message", &[0u8; SIGNATURE_SIZE]),
+            Err(SignatureError::Invalid)
+        );
+        let mut signature = [1u8; SIGNATURE_SIZE + 1];
+        signature[SIGNATURE_SIZE] = 31;
+        assert_eq!(
+            recover_signer(b"message", &signature),
+            Err(SignatureError::VerificationFailed)
+        );
+    }
+}
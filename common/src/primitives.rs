@@ -114,6 +114,37 @@ impl<AssetId: Eq> TradingPair<AssetId> {
     }
 }
 
+impl<AssetId: Eq + Encode + Clone> TradingPair<AssetId> {
+    /// Builds the trading pair of two assets on a DEX quoted in `dex_base_asset_id`.
+    ///
+    /// If one of the assets is the DEX base asset, it becomes the base of the pair. Otherwise
+    /// the order is derived from the assets themselves, so both `(a, b)` and `(b, a)` result in
+    /// the same pair.
+    pub fn canonical(dex_base_asset_id: &AssetId, asset_a: AssetId, asset_b: AssetId) -> Self {
+        if &asset_b == dex_base_asset_id {
+            return TradingPair {
+                base_asset_id: asset_b,
+                target_asset_id: asset_a,
+            };
+        }
+        if &asset_a == dex_base_asset_id {
+            return TradingPair {
+                base_asset_id: asset_a,
+                target_asset_id: asset_b,
+            };
+        }
+        let ((base_asset_id, _), (target_asset_id, _)) = crate::sort_with_hash_key(
+            crate::comm_merkle_op(&asset_a, &asset_b),
+            (&asset_a, &()),
+            (&asset_b, &()),
+        );
+        TradingPair {
+            base_asset_id: base_asset_id.clone(),
+            target_asset_id: target_asset_id.clone(),
+        }
+    }
+}
+
 /// Asset identifier.
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, PartialOrd, Ord, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Hash))]
@@ -766,6 +797,22 @@ pub struct PswapRemintInfo {
     pub vesting: Balance,
 }
 
+/// Source of reference prices used by primary market liquidity sources, e.g. XST and TBC.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PriceSource {
+    /// Average prices calculated from secondary market spot prices.
+    PriceTools,
+    /// Median of price feeds submitted by permissioned relayers.
+    Oracle,
+}
+
+impl Default for PriceSource {
+    fn default() -> Self {
+        Self::PriceTools
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -808,4 +855,16 @@ mod tests {
         // should not panic
         serde_json::to_value(&BalanceWrapper(balance)).unwrap();
     }
+
+    #[test]
+    fn canonical_trading_pair_should_not_depend_on_assets_order() {
+        let pair = TradingPair::canonical(&XOR, DOT, XOR);
+        assert_eq!(pair.base_asset_id, XOR);
+        assert_eq!(pair.target_asset_id, DOT);
+        assert_eq!(TradingPair::canonical(&XOR, XOR, DOT), pair);
+
+        let pair = TradingPair::canonical(&XOR, DAI, USDT);
+        assert_eq!(TradingPair::canonical(&XOR, USDT, DAI), pair);
+        assert!(pair.consists_of(&DAI) && pair.consists_of(&USDT));
+    }
 }
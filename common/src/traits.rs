@@ -29,7 +29,10 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::prelude::{ManagementMode, QuoteAmount, SwapAmount, SwapOutcome};
-use crate::{Fixed, LiquiditySourceFilter, LiquiditySourceId, PswapRemintInfo, RewardReason};
+use crate::{
+    Fixed, LiquiditySourceFilter, LiquiditySourceId, LiquiditySourceType, PswapRemintInfo,
+    RewardReason,
+};
 use frame_support::dispatch::DispatchResult;
 use frame_support::pallet_prelude::MaybeSerializeDeserialize;
 use frame_support::sp_runtime::traits::BadOrigin;
@@ -90,6 +93,28 @@ impl<DEXId, AssetId> EnsureTradingPairExists<DEXId, AssetId, DispatchError> for
     }
 }
 
+/// Checks if the liquidity source can be used to exchange the assets on the DEX.
+pub trait IsSourceEnabled<DEXId, AssetId> {
+    fn is_source_enabled(
+        dex_id: &DEXId,
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        source_type: LiquiditySourceType,
+    ) -> bool;
+}
+
+/// Treats all sources as enabled, e.g. for mock sources which have no trading pairs registered.
+impl<DEXId, AssetId> IsSourceEnabled<DEXId, AssetId> for () {
+    fn is_source_enabled(
+        _dex_id: &DEXId,
+        _input_asset_id: &AssetId,
+        _output_asset_id: &AssetId,
+        _source_type: LiquiditySourceType,
+    ) -> bool {
+        true
+    }
+}
+
 /// Indicates that particular object can be used to perform exchanges.
 pub trait LiquiditySource<TargetId, AccountId, AssetId, Amount, Error> {
     /// Check if liquidity source provides an exchange from given input asset to output asset.
@@ -490,6 +515,18 @@ impl OnPswapBurned for () {
     }
 }
 
+/// General trait for passing information about fees paid for swaps to required pallets.
+pub trait OnSwapFeePaid<AccountId> {
+    /// Report that `account_id` has paid `xor_fee` for a swap, the fee is nominated in XOR.
+    fn on_swap_fee_paid(account_id: &AccountId, xor_fee: Balance);
+}
+
+impl<AccountId> OnSwapFeePaid<AccountId> for () {
+    fn on_swap_fee_paid(_account_id: &AccountId, _xor_fee: Balance) {
+        // do nothing
+    }
+}
+
 /// Trait to abstract interface of VestedRewards pallet, in order for pallets with rewards sources avoid having dependency issues.
 pub trait VestedRewardsPallet<AccountId> {
     /// Report that swaps with xor were performed.
@@ -505,6 +542,10 @@ pub trait VestedRewardsPallet<AccountId> {
     /// Report that account has received pswap reward for buying from tbc.
     fn add_tbc_reward(account_id: &AccountId, pswap_amount: Balance) -> DispatchResult;
 
+    /// Report that account has received already vested pswap reward for buying from tbc,
+    /// it's claimable immediately regardless of vesting schedule.
+    fn add_vested_tbc_reward(account_id: &AccountId, pswap_amount: Balance) -> DispatchResult;
+
     /// Report that account has received farmed pswap reward for providing liquidity on secondary market.
     fn add_farming_reward(account_id: &AccountId, pswap_amount: Balance) -> DispatchResult;
 
@@ -563,6 +604,56 @@ impl<AssetId> PriceToolsPallet<AssetId> for () {
     }
 }
 
+/// Trait to abstract interface of external price oracle, which aggregates price feeds submitted by relayers.
+pub trait PriceOracle<AssetId> {
+    /// Get amount of `output_asset_id` corresponding to a unit (1) of `input_asset_id`.
+    /// Fails if there is not enough fresh price feeds for any of the assets.
+    fn get_price(
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+    ) -> Result<Balance, DispatchError>;
+}
+
+impl<AssetId> PriceOracle<AssetId> for () {
+    fn get_price(_: &AssetId, _: &AssetId) -> Result<Balance, DispatchError> {
+        Err(DispatchError::CannotLookup)
+    }
+}
+
+/// Trait to abstract interface of circuit breaker, which halts trading on primary markets when their
+/// spot prices deviate too much from average prices.
+pub trait CircuitBreaker<AssetId> {
+    /// Check deviation of `average_price` from spot prices band [`sell_price`, `buy_price`] of `asset_id`
+    /// on liquidity `source`. If deviation exceeds configured bound, trading is halted for a cool-down period.
+    /// Fails if trading is halted or deviation is exceeded.
+    fn check_price_deviation(
+        source: LiquiditySourceType,
+        asset_id: &AssetId,
+        buy_price: Fixed,
+        sell_price: Fixed,
+        average_price: Fixed,
+    ) -> DispatchResult;
+
+    /// Check if trading of `asset_id` on liquidity `source` is halted.
+    fn is_trading_halted(source: LiquiditySourceType, asset_id: &AssetId) -> bool;
+}
+
+impl<AssetId> CircuitBreaker<AssetId> for () {
+    fn check_price_deviation(
+        _source: LiquiditySourceType,
+        _asset_id: &AssetId,
+        _buy_price: Fixed,
+        _sell_price: Fixed,
+        _average_price: Fixed,
+    ) -> DispatchResult {
+        Ok(())
+    }
+
+    fn is_trading_halted(_source: LiquiditySourceType, _asset_id: &AssetId) -> bool {
+        false
+    }
+}
+
 impl<AccountId, DEXId, A, B> OnPoolCreated for (A, B)
 where
     AccountId: Clone,
@@ -594,6 +685,30 @@ impl<AssetId> OnPoolReservesChanged<AssetId> for () {
     }
 }
 
+pub trait OnPoolTokensChanged<AccountId> {
+    /// Pool tokens of `account_id` in the pool of `pool_account` have been either minted or burned.
+    /// `old_pool_tokens` and `old_total_issuance` are the amounts before the change.
+    fn on_pool_tokens_changed(
+        pool_account: &AccountId,
+        account_id: &AccountId,
+        old_pool_tokens: Balance,
+        old_total_issuance: Balance,
+    );
+
+    /// Maximal weight of `on_pool_tokens_changed`, which is charged by the calls changing pool tokens.
+    fn on_pool_tokens_changed_weight() -> Weight;
+}
+
+impl<AccountId> OnPoolTokensChanged<AccountId> for () {
+    fn on_pool_tokens_changed(_: &AccountId, _: &AccountId, _: Balance, _: Balance) {
+        // do nothing
+    }
+
+    fn on_pool_tokens_changed_weight() -> Weight {
+        0
+    }
+}
+
 /// General trait for passing on the amount of burned VAL.
 pub trait OnValBurned {
     /// Report amount and fractions of burned pswap at the moment of invokation.
@@ -52,9 +52,9 @@ use framenode_runtime::{
     DEXManagerConfig, DemocracyConfig, EthBridgeConfig, GetBaseAssetId, GetParliamentAccountId,
     GetPswapAssetId, GetValAssetId, GetXorAssetId, GrandpaConfig, ImOnlineId, IrohaMigrationConfig,
     LiquiditySourceType, MulticollateralBondingCurvePoolConfig, PermissionsConfig,
-    PswapDistributionConfig, RewardsConfig, Runtime, SessionConfig, StakerStatus, StakingConfig,
-    SystemConfig, TechAccountId, TechnicalConfig, TokensConfig, TradingPairConfig, XSTPoolConfig,
-    WASM_BINARY,
+    PriceOracleConfig, PswapDistributionConfig, RewardsConfig, Runtime, SessionConfig,
+    StakerStatus, StakingConfig, SystemConfig, TechAccountId, TechnicalConfig, TokensConfig,
+    TradingPairConfig, XSTPoolConfig, WASM_BINARY,
 };
 use hex_literal::hex;
 use permissions::Scope;
@@ -641,6 +641,13 @@ fn testnet_genesis(
         framenode_runtime::GetMarketMakerRewardsTechAccountId::get();
     let market_maker_rewards_account_id = framenode_runtime::GetMarketMakerRewardsAccountId::get();
 
+    let farming_rewards_tech_account_id = framenode_runtime::GetFarmingRewardsTechAccountId::get();
+    let farming_rewards_account_id = framenode_runtime::GetFarmingRewardsAccountId::get();
+
+    let referral_rewards_tech_account_id =
+        framenode_runtime::GetReferralRewardsTechAccountId::get();
+    let referral_rewards_account_id = framenode_runtime::GetReferralRewardsAccountId::get();
+
     let liquidity_proxy_tech_account_id = framenode_runtime::GetLiquidityProxyTechAccountId::get();
     let liquidity_proxy_account_id = framenode_runtime::GetLiquidityProxyAccountId::get();
 
@@ -722,6 +729,14 @@ fn testnet_genesis(
             market_maker_rewards_account_id.clone(),
             market_maker_rewards_tech_account_id.clone(),
         ),
+        (
+            farming_rewards_account_id.clone(),
+            farming_rewards_tech_account_id.clone(),
+        ),
+        (
+            referral_rewards_account_id.clone(),
+            referral_rewards_tech_account_id.clone(),
+        ),
     ];
     let accounts = bonding_curve_distribution_accounts();
     for account in &accounts.accounts() {
@@ -747,6 +762,8 @@ fn testnet_genesis(
         (mbc_pool_free_reserves_account_id.clone(), 0),
         (xst_pool_permissioned_account_id.clone(), 0),
         (market_maker_rewards_account_id.clone(), 0),
+        (farming_rewards_account_id.clone(), 0),
+        (referral_rewards_account_id.clone(), 0),
     ]
     .into_iter()
     .chain(
@@ -859,6 +876,11 @@ fn testnet_genesis(
         tokens_endowed_accounts.push((faucet_account_id, PSWAP, initial_faucet_balance));
         FaucetConfig {
             reserves_account_id: faucet_tech_account_id,
+            asset_limits: vec![
+                (XOR.into(), faucet::DEFAULT_BALANCE_LIMIT),
+                (VAL.into(), faucet::DEFAULT_BALANCE_LIMIT),
+                (PSWAP.into(), faucet::DEFAULT_BALANCE_LIMIT),
+            ],
         }
     };
 
@@ -1174,6 +1196,10 @@ fn testnet_genesis(
             reference_asset_id: DAI,
             initial_synthetic_assets: vec![XSTUSD],
         }),
+        price_oracle: Some(PriceOracleConfig {
+            relayers: Vec::new(),
+            quote_asset_id: DAI,
+        }),
     }
 }
 
@@ -1340,6 +1366,13 @@ fn mainnet_genesis(
         framenode_runtime::GetMarketMakerRewardsTechAccountId::get();
     let market_maker_rewards_account_id = framenode_runtime::GetMarketMakerRewardsAccountId::get();
 
+    let farming_rewards_tech_account_id = framenode_runtime::GetFarmingRewardsTechAccountId::get();
+    let farming_rewards_account_id = framenode_runtime::GetFarmingRewardsAccountId::get();
+
+    let referral_rewards_tech_account_id =
+        framenode_runtime::GetReferralRewardsTechAccountId::get();
+    let referral_rewards_account_id = framenode_runtime::GetReferralRewardsAccountId::get();
+
     let liquidity_proxy_tech_account_id = framenode_runtime::GetLiquidityProxyTechAccountId::get();
     let liquidity_proxy_account_id = framenode_runtime::GetLiquidityProxyAccountId::get();
 
@@ -1417,6 +1450,14 @@ fn mainnet_genesis(
             market_maker_rewards_account_id.clone(),
             market_maker_rewards_tech_account_id.clone(),
         ),
+        (
+            farming_rewards_account_id.clone(),
+            farming_rewards_tech_account_id.clone(),
+        ),
+        (
+            referral_rewards_account_id.clone(),
+            referral_rewards_tech_account_id.clone(),
+        ),
     ];
     let accounts = bonding_curve_distribution_accounts();
     for account in &accounts.accounts() {
@@ -1645,6 +1686,8 @@ fn mainnet_genesis(
                 (mbc_pool_rewards_account_id.clone(), 0),
                 (mbc_pool_free_reserves_account_id.clone(), 0),
                 (market_maker_rewards_account_id.clone(), 0),
+                (farming_rewards_account_id.clone(), 0),
+                (referral_rewards_account_id.clone(), 0),
             ]
             .into_iter()
             .chain(
@@ -1779,6 +1822,10 @@ fn mainnet_genesis(
         pallet_elections_phragmen: Default::default(),
         pallet_membership_Instance1: Default::default(),
         pallet_im_online: Default::default(),
+        price_oracle: Some(PriceOracleConfig {
+            relayers: Vec::new(),
+            quote_asset_id: DAI,
+        }),
     }
 }
 
@@ -33,8 +33,9 @@
 use common::TradingPair;
 use framenode_runtime::opaque::Block;
 use framenode_runtime::{
-    eth_bridge, AccountId, AssetId, AssetName, AssetSymbol, Balance, BalancePrecision, DEXId,
-    FilterMode, Index, LiquiditySourceType, Runtime, SwapVariant,
+    eth_bridge, permissions, AccountId, AssetId, AssetName, AssetSymbol, Balance, BalancePrecision,
+    BlockNumber, DEXId, FilterMode, Index, LiquiditySourceType, Runtime, SwapVariant,
+    TechAccountId,
 };
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 use sp_api::ProvideRuntimeApi;
@@ -112,9 +113,24 @@ where
         framenode_runtime::NetworkId,
         framenode_runtime::BalancePrecision,
     >,
-    C::Api: iroha_migration_rpc::IrohaMigrationRuntimeAPI<Block>,
-    C::Api: pswap_distribution_rpc::PswapDistributionRuntimeAPI<Block, AccountId, Balance>,
+    C::Api: iroha_migration_rpc::IrohaMigrationRuntimeAPI<Block, BlockNumber>,
+    C::Api:
+        pswap_distribution_rpc::PswapDistributionRuntimeAPI<Block, AccountId, BlockNumber, Balance>,
+    C::Api: farming_rpc::FarmingRuntimeAPI<Block, AccountId, AssetId, BlockNumber, Balance>,
+    C::Api: multicollateral_bonding_curve_pool_rpc::MulticollateralBondingCurvePoolRuntimeAPI<
+        Block,
+        AccountId,
+        AssetId,
+        Balance,
+        SwapVariant,
+    >,
     C::Api: rewards_rpc::RewardsRuntimeAPI<Block, sp_core::H160, Balance>,
+    C::Api: referral_system_rpc::ReferralSystemRuntimeAPI<Block, AccountId, Balance>,
+    C::Api: vested_rewards_rpc::VestedRewardsRuntimeAPI<Block, AccountId, Balance>,
+    C::Api:
+        permissions_rpc::PermissionsRuntimeAPI<Block, AccountId, permissions::Scope, BlockNumber>,
+    C::Api: faucet_rpc::FaucetRuntimeAPI<Block, AccountId, AssetId, Balance>,
+    C::Api: technical_rpc::TechnicalRuntimeAPI<Block, AccountId, TechAccountId>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + Send + Sync + 'static,
 {
@@ -122,14 +138,22 @@ where
     use dex_api_rpc::{DEX, DEXAPI};
     use dex_manager_rpc::{DEXManager, DEXManagerAPI};
     use eth_bridge_rpc::{EthBridgeApi, EthBridgeRpc};
-    // use farming_rpc::*;
+    use farming_rpc::{FarmingAPI, FarmingClient};
+    use faucet_rpc::{FaucetAPI, FaucetClient};
     use iroha_migration_rpc::{IrohaMigrationAPI, IrohaMigrationClient};
     use liquidity_proxy_rpc::{LiquidityProxyAPI, LiquidityProxyClient};
+    use multicollateral_bonding_curve_pool_rpc::{
+        MulticollateralBondingCurvePoolAPI, MulticollateralBondingCurvePoolClient,
+    };
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+    use permissions_rpc::{PermissionsAPI, PermissionsClient};
     use pswap_distribution_rpc::{PswapDistributionAPI, PswapDistributionClient};
+    use referral_system_rpc::{ReferralSystemAPI, ReferralSystemClient};
     use rewards_rpc::{RewardsAPI, RewardsClient};
     use substrate_frame_rpc_system::{FullSystem, SystemApi};
+    use technical_rpc::{TechnicalAPI, TechnicalClient};
     use trading_pair_rpc::{TradingPairAPI, TradingPairClient};
+    use vested_rewards_rpc::{VestedRewardsAPI, VestedRewardsClient};
 
     let mut io = jsonrpc_core::IoHandler::default();
     let FullDeps {
@@ -154,7 +178,7 @@ where
     io.extend_with(LiquidityProxyAPI::to_delegate(LiquidityProxyClient::new(
         client.clone(),
     )));
-    // io.extend_with(FarmingApi::to_delegate(FarmingRpc::new(client.clone())));
+    io.extend_with(FarmingAPI::to_delegate(FarmingClient::new(client.clone())));
     io.extend_with(EthBridgeApi::to_delegate(EthBridgeRpc::new(client.clone())));
     io.extend_with(IrohaMigrationAPI::to_delegate(IrohaMigrationClient::new(
         client.clone(),
@@ -162,6 +186,22 @@ where
     io.extend_with(PswapDistributionAPI::to_delegate(
         PswapDistributionClient::new(client.clone()),
     ));
+    io.extend_with(MulticollateralBondingCurvePoolAPI::to_delegate(
+        MulticollateralBondingCurvePoolClient::new(client.clone()),
+    ));
     io.extend_with(RewardsAPI::to_delegate(RewardsClient::new(client.clone())));
+    io.extend_with(ReferralSystemAPI::to_delegate(ReferralSystemClient::new(
+        client.clone(),
+    )));
+    io.extend_with(VestedRewardsAPI::to_delegate(VestedRewardsClient::new(
+        client.clone(),
+    )));
+    io.extend_with(PermissionsAPI::to_delegate(PermissionsClient::new(
+        client.clone(),
+    )));
+    io.extend_with(FaucetAPI::to_delegate(FaucetClient::new(client.clone())));
+    io.extend_with(TechnicalAPI::to_delegate(TechnicalClient::new(
+        client.clone(),
+    )));
     io
 }
@@ -0,0 +1,173 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Decode;
+use common::{balance, VAL};
+use frame_benchmarking::benchmarks;
+use frame_system::{EventRecord, RawOrigin};
+use hex_literal::hex;
+use sp_core::H256;
+use sp_std::prelude::*;
+
+use crate::{
+    leaf_hash, node_hash, CampaignId, Campaigns, Claimant, Claimed, Config, Event, Module, Pallet,
+    MAX_PROOF_LENGTH, MAX_SWEPT_CLAIMS,
+};
+
+fn alice<T: Config>() -> T::AccountId {
+    let bytes = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+    T::AccountId::decode(&mut &bytes[..]).unwrap_or_default()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Module::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+// Creates the campaign funded by alice whose only leaf is paid to alice
+fn create_campaign<T: Config>(merkle_root: H256) -> CampaignId {
+    let caller = alice::<T>();
+    let asset_id: T::AssetId = VAL.into();
+    let owner = assets::Module::<T>::asset_owner(&asset_id).unwrap();
+    assets::Module::<T>::mint_to(&asset_id, &owner, &caller, balance!(1000)).unwrap();
+    Pallet::<T>::create_campaign(
+        RawOrigin::Root.into(),
+        asset_id,
+        merkle_root,
+        1000u32.into(),
+        caller.clone(),
+    )
+    .unwrap();
+    let campaign_id = Pallet::<T>::next_campaign_id() - 1;
+    Pallet::<T>::fund_campaign(
+        RawOrigin::Signed(caller).into(),
+        campaign_id,
+        balance!(1000),
+    )
+    .unwrap();
+    campaign_id
+}
+
+benchmarks! {
+    create_campaign {
+        let campaign_id = Pallet::<T>::next_campaign_id();
+    }: {
+        Pallet::<T>::create_campaign(
+            RawOrigin::Root.into(),
+            VAL.into(),
+            H256::zero(),
+            1000u32.into(),
+            alice::<T>(),
+        )?;
+    }
+    verify {
+        assert_last_event::<T>(Event::CampaignCreated(campaign_id, VAL.into(), H256::zero()).into())
+    }
+
+    fund_campaign {
+        let caller = alice::<T>();
+        let campaign_id = create_campaign::<T>(H256::zero());
+        let asset_id: T::AssetId = VAL.into();
+        let owner = assets::Module::<T>::asset_owner(&asset_id).unwrap();
+        assets::Module::<T>::mint_to(&asset_id, &owner, &caller, balance!(100)).unwrap();
+    }: {
+        Pallet::<T>::fund_campaign(RawOrigin::Signed(caller.clone()).into(), campaign_id, balance!(100))?;
+    }
+    verify {
+        assert_last_event::<T>(Event::CampaignFunded(campaign_id, caller, balance!(100)).into())
+    }
+
+    claim {
+        let n in 0 .. MAX_PROOF_LENGTH;
+        let caller = alice::<T>();
+        let leaf = leaf_hash(0, &Claimant::Sora(caller.clone()), balance!(1));
+        let proof: Vec<H256> = (0..n).map(|i| H256::repeat_byte(i as u8)).collect();
+        let merkle_root = proof.iter().fold(leaf, |hash, sibling| node_hash(&hash, sibling));
+        let campaign_id = create_campaign::<T>(merkle_root);
+    }: {
+        Pallet::<T>::claim(RawOrigin::Signed(caller.clone()).into(), campaign_id, 0, balance!(1), proof, None)?;
+    }
+    verify {
+        assert_last_event::<T>(Event::Claimed(campaign_id, 0, caller, balance!(1)).into())
+    }
+
+    sweep {
+        let n in 0 .. MAX_SWEPT_CLAIMS;
+        let caller = alice::<T>();
+        let campaign_id = create_campaign::<T>(H256::zero());
+        for index in 0..n {
+            Claimed::<T>::insert(campaign_id, index, ());
+        }
+        frame_system::Module::<T>::set_block_number(1000u32.into());
+    }: {
+        Pallet::<T>::sweep(RawOrigin::Signed(caller.clone()).into(), campaign_id, n)?;
+    }
+    verify {
+        assert!(!Campaigns::<T>::contains_key(campaign_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::assert_ok;
+
+    use crate::mock::{ExtBuilder, Runtime};
+
+    #[test]
+    fn create_campaign() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(super::test_benchmark_create_campaign::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn fund_campaign() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(super::test_benchmark_fund_campaign::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn claim() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(super::test_benchmark_claim::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn sweep() {
+        ExtBuilder::build().execute_with(|| {
+            assert_ok!(super::test_benchmark_sweep::<Runtime>());
+        });
+    }
+}
@@ -0,0 +1,404 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! This pallet runs airdrop campaigns of any asset.
+//!
+//! Instead of storing every eligible address on chain, governance posts the Merkle root of the
+//! `(index, claimant, amount)` leaves of a campaign. Anyone can fund the campaign, and claimants
+//! prove their leaf with a Merkle proof. A claimant is either a SORA account, which proves the
+//! ownership by signing the claim extrinsic, or an Ethereum address, which proves the ownership
+//! with an Ethereum signature of the claiming account. Funds left after the campaign expires are
+//! swept back to its refund account.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{ensure, RuntimeDebug};
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
+
+use assets::AssetIdOf;
+use common::{eth, AccountIdOf, Balance};
+
+pub use self::pallet::*;
+
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+type EthereumAddress = H160;
+type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+type WeightInfoOf<T> = <T as Config>::WeightInfo;
+type CampaignOf<T> = Campaign<AccountIdOf<T>, AssetIdOf<T>, BlockNumberOf<T>>;
+
+pub type CampaignId = u32;
+
+pub const TECH_ACCOUNT_PREFIX: &[u8] = b"airdrop";
+pub const TECH_ACCOUNT_MAIN: &[u8] = b"main";
+
+/// Max number of hashes in a Merkle proof, enough for campaigns of 2^32 leaves.
+pub const MAX_PROOF_LENGTH: u32 = 32;
+/// Max number of claims of the swept campaign which are cleared in one call.
+pub const MAX_SWEPT_CLAIMS: u32 = 1024;
+
+pub trait WeightInfo {
+    fn create_campaign() -> Weight;
+    fn fund_campaign() -> Weight;
+    fn claim(proof_length: u32) -> Weight;
+    fn sweep(claims: u32) -> Weight;
+}
+
+/// Owner of an airdrop leaf.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum Claimant<AccountId> {
+    /// Ethereum address, proven by an Ethereum signature of the claiming account.
+    Ethereum(EthereumAddress),
+    /// SORA account, proven by signing the claim extrinsic.
+    Sora(AccountId),
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct Campaign<AccountId, AssetId, BlockNumber> {
+    /// Asset given out by the campaign.
+    pub asset_id: AssetId,
+    /// Merkle root of the `(index, claimant, amount)` leaves.
+    pub merkle_root: H256,
+    /// Funds of the campaign which aren't claimed yet.
+    pub balance: Balance,
+    /// Block starting from which claims are rejected and the campaign can be swept.
+    pub expires_at: BlockNumber,
+    /// Account receiving the unclaimed funds of the expired campaign.
+    pub refund_account: AccountId,
+}
+
+/// Hash of the leaf of the campaign Merkle tree.
+pub fn leaf_hash<AccountId: Encode>(
+    index: u32,
+    claimant: &Claimant<AccountId>,
+    amount: Balance,
+) -> H256 {
+    H256(keccak_256(&(index, claimant, amount).encode()))
+}
+
+/// Hash of the inner node of the campaign Merkle tree. The children are sorted, so proofs don't
+/// need to specify the side of each hash.
+pub fn node_hash(a: &H256, b: &H256) -> H256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    H256(keccak_256(&[left.as_bytes(), right.as_bytes()].concat()))
+}
+
+/// Checks that `leaf` is in the Merkle tree with the given `root`.
+pub fn verify_proof(root: &H256, leaf: H256, proof: &[H256]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |hash, sibling| node_hash(&hash, sibling))
+        == *root
+}
+
+impl<T: Config> Pallet<T> {
+    /// Recovers the Ethereum address which signed the `account_id`.
+    fn recover_eth_address(
+        account_id: &T::AccountId,
+        signature: &[u8],
+    ) -> Result<EthereumAddress, Error<T>> {
+        eth::recover_signer(&account_id.encode(), signature).map_err(|error| match error {
+            eth::SignatureError::Invalid => Error::<T>::SignatureInvalid,
+            eth::SignatureError::VerificationFailed => Error::<T>::SignatureVerificationFailed,
+        })
+    }
+
+    /// Registers the technical account holding the campaign funds, it's done lazily so the pallet
+    /// needs neither genesis config nor a migration.
+    fn ensure_reserves_registered() -> Result<T::TechAccountId, DispatchError> {
+        let tech_account_id = T::GetTechAccountId::get();
+        let account_id = technical::Pallet::<T>::tech_account_id_to_account_id(&tech_account_id)?;
+        if technical::Pallet::<T>::lookup_tech_account_id(&account_id).is_err() {
+            technical::Pallet::<T>::register_tech_account_id(tech_account_id.clone())?;
+        }
+        Ok(tech_account_id)
+    }
+
+    fn ensure_not_expired(campaign: &CampaignOf<T>) -> DispatchResult {
+        ensure!(
+            frame_system::Pallet::<T>::block_number() < campaign.expires_at,
+            Error::<T>::CampaignExpired
+        );
+        Ok(())
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use frame_support::transactional;
+    use frame_system::pallet_prelude::*;
+
+    use super::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + assets::Config + technical::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+        /// Technical account holding the funds of all campaigns
+        type GetTechAccountId: Get<Self::TechAccountId>;
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Creates a campaign giving out `asset_id` to the leaves of `merkle_root` until
+        /// `expires_at`. The campaign has no funds until it's funded with `fund_campaign`.
+        /// Can be only called by root.
+        #[pallet::weight(WeightInfoOf::<T>::create_campaign())]
+        pub fn create_campaign(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            merkle_root: H256,
+            expires_at: BlockNumberFor<T>,
+            refund_account: AccountIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            assets::Pallet::<T>::ensure_asset_exists(&asset_id)?;
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidExpiration
+            );
+            let campaign_id = NextCampaignId::<T>::get();
+            let next_campaign_id = campaign_id
+                .checked_add(1)
+                .ok_or(Error::<T>::CampaignIdOverflow)?;
+            Campaigns::<T>::insert(
+                campaign_id,
+                Campaign {
+                    asset_id,
+                    merkle_root,
+                    balance: 0,
+                    expires_at,
+                    refund_account,
+                },
+            );
+            NextCampaignId::<T>::put(next_campaign_id);
+            Self::deposit_event(Event::CampaignCreated(campaign_id, asset_id, merkle_root));
+            Ok(().into())
+        }
+
+        /// Transfers `amount` of the campaign asset from the caller to the campaign.
+        #[pallet::weight(WeightInfoOf::<T>::fund_campaign())]
+        #[transactional]
+        pub fn fund_campaign(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let mut campaign =
+                Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            Self::ensure_not_expired(&campaign)?;
+            let reserves = Self::ensure_reserves_registered()?;
+            technical::Pallet::<T>::transfer_in(&campaign.asset_id, &who, &reserves, amount)?;
+            campaign.balance = campaign.balance.saturating_add(amount);
+            Campaigns::<T>::insert(campaign_id, campaign);
+            Self::deposit_event(Event::CampaignFunded(campaign_id, who, amount));
+            Ok(().into())
+        }
+
+        /// Claims the `amount` of the leaf `index` of the campaign to the caller.
+        ///
+        /// If `eth_signature` is provided, the leaf claimant is the Ethereum address which signed
+        /// the caller account id, otherwise it's the caller itself.
+        #[pallet::weight(WeightInfoOf::<T>::claim(proof.len() as u32))]
+        #[transactional]
+        pub fn claim(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            index: u32,
+            amount: Balance,
+            proof: Vec<H256>,
+            eth_signature: Option<Vec<u8>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                proof.len() as u32 <= MAX_PROOF_LENGTH,
+                Error::<T>::InvalidProof
+            );
+            let mut campaign =
+                Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            Self::ensure_not_expired(&campaign)?;
+            ensure!(
+                !Claimed::<T>::contains_key(campaign_id, index),
+                Error::<T>::AlreadyClaimed
+            );
+            let claimant = match eth_signature {
+                Some(signature) => Claimant::Ethereum(Self::recover_eth_address(&who, &signature)?),
+                None => Claimant::Sora(who.clone()),
+            };
+            ensure!(
+                verify_proof(
+                    &campaign.merkle_root,
+                    leaf_hash(index, &claimant, amount),
+                    &proof
+                ),
+                Error::<T>::InvalidProof
+            );
+            campaign.balance = campaign
+                .balance
+                .checked_sub(amount)
+                .ok_or(Error::<T>::InsufficientCampaignFunds)?;
+            technical::Pallet::<T>::transfer_out(
+                &campaign.asset_id,
+                &T::GetTechAccountId::get(),
+                &who,
+                amount,
+            )?;
+            Campaigns::<T>::insert(campaign_id, campaign);
+            Claimed::<T>::insert(campaign_id, index, ());
+            Self::deposit_event(Event::Claimed(campaign_id, index, who, amount));
+            Ok(().into())
+        }
+
+        /// Clears up to `limit` claims of the expired campaign. Once all of them are cleared,
+        /// removes the campaign and transfers its unclaimed funds to the refund account.
+        /// Anyone can sweep the campaign, since the funds can only go to the refund account.
+        ///
+        /// - `campaign_id`: the expired campaign.
+        /// - `limit`: max number of claims to clear, capped by `MAX_SWEPT_CLAIMS`.
+        #[pallet::weight(WeightInfoOf::<T>::sweep((*limit).min(MAX_SWEPT_CLAIMS)))]
+        #[transactional]
+        pub fn sweep(
+            origin: OriginFor<T>,
+            campaign_id: CampaignId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+            let campaign = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= campaign.expires_at,
+                Error::<T>::CampaignNotExpired
+            );
+            let limit = limit.min(MAX_SWEPT_CLAIMS) as usize;
+            let claims: Vec<u32> = Claimed::<T>::iter_prefix(campaign_id)
+                .map(|(index, _)| index)
+                .take(limit + 1)
+                .collect();
+            let cleared = claims.len().min(limit);
+            for index in &claims[..cleared] {
+                Claimed::<T>::remove(campaign_id, index);
+            }
+            let actual_weight = WeightInfoOf::<T>::sweep(cleared as u32);
+            if claims.len() > limit {
+                // The campaign is removed by one of the next calls.
+                return Ok(Some(actual_weight).into());
+            }
+            if campaign.balance > 0 {
+                technical::Pallet::<T>::transfer_out(
+                    &campaign.asset_id,
+                    &T::GetTechAccountId::get(),
+                    &campaign.refund_account,
+                    campaign.balance,
+                )?;
+            }
+            Campaigns::<T>::remove(campaign_id);
+            Self::deposit_event(Event::CampaignSwept(
+                campaign_id,
+                campaign.refund_account,
+                campaign.balance,
+            ));
+            Ok(Some(actual_weight).into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::metadata(AccountIdOf<T> = "AccountId", AssetIdOf<T> = "AssetId")]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// The campaign is created. [campaign, asset, merkle root]
+        CampaignCreated(CampaignId, AssetIdOf<T>, H256),
+        /// The campaign is funded. [campaign, funder, amount]
+        CampaignFunded(CampaignId, AccountIdOf<T>, Balance),
+        /// The leaf of the campaign is claimed. [campaign, leaf index, account, amount]
+        Claimed(CampaignId, u32, AccountIdOf<T>, Balance),
+        /// The expired campaign is removed. [campaign, refund account, refunded amount]
+        CampaignSwept(CampaignId, AccountIdOf<T>, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The campaign doesn't exist
+        CampaignNotFound,
+        /// The campaign has expired
+        CampaignExpired,
+        /// The campaign hasn't expired yet
+        CampaignNotExpired,
+        /// The expiration block has already passed
+        InvalidExpiration,
+        /// No more campaigns can be created
+        CampaignIdOverflow,
+        /// The leaf has already been claimed
+        AlreadyClaimed,
+        /// The Merkle proof doesn't match the campaign root
+        InvalidProof,
+        /// The campaign doesn't have enough funds for the claim
+        InsufficientCampaignFunds,
+        /// The signature is invalid
+        SignatureInvalid,
+        /// The signature verification failed
+        SignatureVerificationFailed,
+    }
+
+    /// Id of the next created campaign
+    #[pallet::storage]
+    #[pallet::getter(fn next_campaign_id)]
+    pub type NextCampaignId<T: Config> = StorageValue<_, CampaignId, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn campaign)]
+    pub type Campaigns<T: Config> = StorageMap<_, Identity, CampaignId, CampaignOf<T>>;
+
+    /// Claimed leaves of each campaign
+    #[pallet::storage]
+    pub type Claimed<T: Config> =
+        StorageDoubleMap<_, Identity, CampaignId, Identity, u32, (), OptionQuery>;
+}
@@ -0,0 +1,277 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use common::{balance, Balance, VAL, XOR};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::DispatchError;
+
+use crate::mock::*;
+use crate::{leaf_hash, node_hash, Campaigns, Claimant, Claimed};
+
+type Pallet = crate::Pallet<Runtime>;
+type Error = crate::Error<Runtime>;
+type Assets = assets::Pallet<Runtime>;
+
+const EXPIRES_AT: u64 = 100;
+
+/// Leaves of the test campaign: bob, the Ethereum address and alice.
+fn leaves() -> Vec<H256> {
+    vec![
+        leaf_hash(0, &Claimant::Sora(bob()), balance!(10)),
+        leaf_hash(
+            1,
+            &Claimant::<AccountId>::Ethereum(eth_address()),
+            balance!(20),
+        ),
+        leaf_hash(2, &Claimant::Sora(alice()), balance!(30)),
+    ]
+}
+
+fn merkle_root() -> H256 {
+    let leaves = leaves();
+    node_hash(&node_hash(&leaves[0], &leaves[1]), &leaves[2])
+}
+
+fn proof(index: usize) -> Vec<H256> {
+    let leaves = leaves();
+    match index {
+        0 => vec![leaves[1], leaves[2]],
+        1 => vec![leaves[0], leaves[2]],
+        _ => vec![node_hash(&leaves[0], &leaves[1])],
+    }
+}
+
+fn create_funded_campaign(amount: Balance) {
+    assert_ok!(Pallet::create_campaign(
+        Origin::root(),
+        VAL,
+        merkle_root(),
+        EXPIRES_AT,
+        alice()
+    ));
+    assert_ok!(Pallet::fund_campaign(Origin::signed(alice()), 0, amount));
+}
+
+#[test]
+fn create_campaign_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            Pallet::create_campaign(
+                Origin::signed(alice()),
+                VAL,
+                merkle_root(),
+                EXPIRES_AT,
+                alice()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Pallet::create_campaign(Origin::root(), VAL, merkle_root(), 1, alice()),
+            Error::InvalidExpiration
+        );
+        assert_noop!(
+            Pallet::create_campaign(Origin::root(), XOR, merkle_root(), EXPIRES_AT, alice()),
+            assets::Error::<Runtime>::AssetIdNotExists
+        );
+        assert_ok!(Pallet::create_campaign(
+            Origin::root(),
+            VAL,
+            merkle_root(),
+            EXPIRES_AT,
+            alice()
+        ));
+        assert_eq!(Pallet::next_campaign_id(), 1);
+        let campaign = Pallet::campaign(0).unwrap();
+        assert_eq!(campaign.merkle_root, merkle_root());
+        assert_eq!(campaign.balance, 0);
+    });
+}
+
+#[test]
+fn fund_campaign_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            Pallet::fund_campaign(Origin::signed(alice()), 0, balance!(1)),
+            Error::CampaignNotFound
+        );
+        create_funded_campaign(balance!(60));
+        assert_eq!(Pallet::campaign(0).unwrap().balance, balance!(60));
+        assert_eq!(
+            Assets::free_balance(&VAL, &reserves_account_id()).unwrap(),
+            balance!(60)
+        );
+        assert_eq!(Assets::free_balance(&VAL, &alice()).unwrap(), balance!(940));
+    });
+}
+
+#[test]
+fn claim_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        create_funded_campaign(balance!(60));
+        assert_ok!(Pallet::claim(
+            Origin::signed(bob()),
+            0,
+            0,
+            balance!(10),
+            proof(0),
+            None
+        ));
+        assert_ok!(Pallet::claim(
+            Origin::signed(eth_signer()),
+            0,
+            1,
+            balance!(20),
+            proof(1),
+            Some(eth_signature())
+        ));
+        assert_eq!(Assets::free_balance(&VAL, &bob()).unwrap(), balance!(10));
+        assert_eq!(
+            Assets::free_balance(&VAL, &eth_signer()).unwrap(),
+            balance!(20)
+        );
+        assert_eq!(Pallet::campaign(0).unwrap().balance, balance!(30));
+        assert_noop!(
+            Pallet::claim(Origin::signed(bob()), 0, 0, balance!(10), proof(0), None),
+            Error::AlreadyClaimed
+        );
+    });
+}
+
+#[test]
+fn claim_fails_with_invalid_proof() {
+    ExtBuilder::build().execute_with(|| {
+        create_funded_campaign(balance!(60));
+        // Wrong amount
+        assert_noop!(
+            Pallet::claim(Origin::signed(bob()), 0, 0, balance!(20), proof(0), None),
+            Error::InvalidProof
+        );
+        // The leaf belongs to another account
+        assert_noop!(
+            Pallet::claim(Origin::signed(alice()), 0, 0, balance!(10), proof(0), None),
+            Error::InvalidProof
+        );
+        // The leaf belongs to the Ethereum address, not the SORA account
+        assert_noop!(
+            Pallet::claim(
+                Origin::signed(eth_signer()),
+                0,
+                1,
+                balance!(20),
+                proof(1),
+                None
+            ),
+            Error::InvalidProof
+        );
+        assert_noop!(
+            Pallet::claim(
+                Origin::signed(eth_signer()),
+                0,
+                1,
+                balance!(20),
+                proof(1),
+                Some(eth_signature()[1..].to_vec())
+            ),
+            Error::SignatureInvalid
+        );
+    });
+}
+
+#[test]
+fn claim_fails_with_insufficient_funds() {
+    ExtBuilder::build().execute_with(|| {
+        create_funded_campaign(balance!(25));
+        assert_noop!(
+            Pallet::claim(Origin::signed(alice()), 0, 2, balance!(30), proof(2), None),
+            Error::InsufficientCampaignFunds
+        );
+        assert_ok!(Pallet::fund_campaign(
+            Origin::signed(alice()),
+            0,
+            balance!(5)
+        ));
+        assert_ok!(Pallet::claim(
+            Origin::signed(alice()),
+            0,
+            2,
+            balance!(30),
+            proof(2),
+            None
+        ));
+    });
+}
+
+#[test]
+fn sweep_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        create_funded_campaign(balance!(60));
+        assert_ok!(Pallet::claim(
+            Origin::signed(bob()),
+            0,
+            0,
+            balance!(10),
+            proof(0),
+            None
+        ));
+        assert_noop!(
+            Pallet::sweep(Origin::signed(bob()), 0, 10),
+            Error::CampaignNotExpired
+        );
+
+        System::set_block_number(EXPIRES_AT);
+        assert_noop!(
+            Pallet::claim(Origin::signed(alice()), 0, 2, balance!(30), proof(2), None),
+            Error::CampaignExpired
+        );
+        assert_noop!(
+            Pallet::fund_campaign(Origin::signed(alice()), 0, balance!(1)),
+            Error::CampaignExpired
+        );
+        // Claims are cleared before the campaign is removed.
+        assert_ok!(Pallet::sweep(Origin::signed(bob()), 0, 0));
+        assert!(Campaigns::<Runtime>::contains_key(0));
+        assert!(Claimed::<Runtime>::contains_key(0, 0));
+        assert_eq!(Assets::free_balance(&VAL, &alice()).unwrap(), balance!(940));
+
+        assert_ok!(Pallet::sweep(Origin::signed(bob()), 0, 10));
+        assert!(!Campaigns::<Runtime>::contains_key(0));
+        assert!(!Claimed::<Runtime>::contains_key(0, 0));
+        assert_eq!(Assets::free_balance(&VAL, &alice()).unwrap(), balance!(990));
+        assert_eq!(
+            Assets::free_balance(&VAL, &reserves_account_id()).unwrap(),
+            0
+        );
+        assert_noop!(
+            Pallet::sweep(Origin::signed(bob()), 0, 10),
+            Error::CampaignNotFound
+        );
+    });
+}
@@ -0,0 +1,83 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use core::marker::PhantomData;
+
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+use common::prelude::constants::EXTRINSIC_FIXED_WEIGHT;
+
+pub struct WeightInfo<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+    fn create_campaign() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn fund_campaign() -> Weight {
+        (110_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn claim(proof_length: u32) -> Weight {
+        (420_000_000 as Weight)
+            .saturating_add((2_500_000 as Weight).saturating_mul(proof_length as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn sweep(claims: u32) -> Weight {
+        (120_000_000 as Weight)
+            .saturating_add((3_000_000 as Weight).saturating_mul(claims as Weight))
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().reads(claims as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(claims as Weight))
+    }
+}
+
+impl crate::WeightInfo for () {
+    fn create_campaign() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn fund_campaign() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn claim(_proof_length: u32) -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn sweep(_claims: u32) -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+}
@@ -124,6 +124,7 @@ impl common::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl tokens::Config for Runtime {
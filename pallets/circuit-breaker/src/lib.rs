@@ -0,0 +1,294 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use common::prelude::{Fixed, FixedWrapper};
+use common::{fixed, CircuitBreaker, LiquiditySourceType};
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::fail;
+use frame_support::weights::Weight;
+use sp_runtime::traits::Saturating;
+
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub trait WeightInfo {
+    fn set_parameters() -> Weight;
+    fn halt_trading() -> Weight;
+    fn resume_trading() -> Weight;
+}
+
+type AssetIdOf<T> = <T as Config>::AssetId;
+type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{MaybeSerializeDeserialize, Zero};
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+        type AssetId: Parameter + MaybeSerializeDeserialize + Ord + Copy + Default;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set max allowed deviation of average price from spot prices and number of blocks trading is halted for
+        /// once deviation is exceeded.
+        #[pallet::weight(<T as Config>::WeightInfo::set_parameters())]
+        pub fn set_parameters(
+            origin: OriginFor<T>,
+            max_price_deviation: Fixed,
+            cooldown_period: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                max_price_deviation > fixed!(0),
+                Error::<T>::InvalidMaxPriceDeviation
+            );
+            ensure!(
+                !cooldown_period.is_zero(),
+                Error::<T>::InvalidCooldownPeriod
+            );
+            MaxPriceDeviation::<T>::put(max_price_deviation);
+            CooldownPeriod::<T>::put(cooldown_period);
+            Self::deposit_event(Event::ParametersChanged(
+                max_price_deviation,
+                cooldown_period,
+            ));
+            Ok(().into())
+        }
+
+        /// Halt trading of asset on liquidity source for given number of blocks regardless of prices.
+        #[pallet::weight(<T as Config>::WeightInfo::halt_trading())]
+        pub fn halt_trading(
+            origin: OriginFor<T>,
+            source: LiquiditySourceType,
+            asset_id: T::AssetId,
+            period: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let halted_until = frame_system::Pallet::<T>::block_number().saturating_add(period);
+            HaltedUntil::<T>::insert(source, asset_id, halted_until);
+            OverriddenUntil::<T>::remove(source, asset_id);
+            Self::deposit_event(Event::TradingHalted(source, asset_id, halted_until));
+            Ok(().into())
+        }
+
+        /// Resume halted trading of asset on liquidity source. Price deviation is not checked for
+        /// `override_period` blocks afterwards, so trading is not halted again right away.
+        #[pallet::weight(<T as Config>::WeightInfo::resume_trading())]
+        pub fn resume_trading(
+            origin: OriginFor<T>,
+            source: LiquiditySourceType,
+            asset_id: T::AssetId,
+            override_period: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            HaltedUntil::<T>::remove(source, asset_id);
+            if override_period.is_zero() {
+                OverriddenUntil::<T>::remove(source, asset_id);
+            } else {
+                let overridden_until =
+                    frame_system::Pallet::<T>::block_number().saturating_add(override_period);
+                OverriddenUntil::<T>::insert(source, asset_id, overridden_until);
+            }
+            Self::deposit_event(Event::TradingResumed(source, asset_id));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::metadata(AssetIdOf<T> = "AssetId", BlockNumberOf<T> = "BlockNumber")]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Average price deviates from spot prices more than allowed. [Liquidity Source, Asset Id, Deviation]
+        PriceDeviationExceeded(LiquiditySourceType, AssetIdOf<T>, Fixed),
+        /// Trading of asset on liquidity source is halted. [Liquidity Source, Asset Id, Halted Until Block]
+        TradingHalted(LiquiditySourceType, AssetIdOf<T>, BlockNumberOf<T>),
+        /// Trading of asset on liquidity source is resumed. [Liquidity Source, Asset Id]
+        TradingResumed(LiquiditySourceType, AssetIdOf<T>),
+        /// Circuit breaker parameters have been changed. [Max Price Deviation, Cooldown Period]
+        ParametersChanged(Fixed, BlockNumberOf<T>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Trading of asset on liquidity source is halted.
+        TradingHalted,
+        /// Average price deviates from spot prices more than allowed, trading is halted.
+        PriceDeviationExceeded,
+        /// Max price deviation should be positive.
+        InvalidMaxPriceDeviation,
+        /// Cooldown period should be positive.
+        InvalidCooldownPeriod,
+        /// An error occurred while calculating price deviation.
+        PriceCalculationFailed,
+    }
+
+    #[pallet::type_value]
+    pub(super) fn DefaultForMaxPriceDeviation() -> Fixed {
+        fixed!(0.2)
+    }
+
+    /// Max allowed relative deviation of average price from spot prices: 20%.
+    #[pallet::storage]
+    #[pallet::getter(fn max_price_deviation)]
+    pub type MaxPriceDeviation<T: Config> =
+        StorageValue<_, Fixed, ValueQuery, DefaultForMaxPriceDeviation>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultForCooldownPeriod<T: Config>() -> T::BlockNumber {
+        600u32.into()
+    }
+
+    /// Number of blocks trading is halted for once price deviation is exceeded: 1 hour.
+    #[pallet::storage]
+    #[pallet::getter(fn cooldown_period)]
+    pub type CooldownPeriod<T: Config> =
+        StorageValue<_, T::BlockNumber, ValueQuery, DefaultForCooldownPeriod<T>>;
+
+    /// Block number until which trading of asset on liquidity source is halted.
+    #[pallet::storage]
+    #[pallet::getter(fn halted_until)]
+    pub type HaltedUntil<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        LiquiditySourceType,
+        Twox64Concat,
+        T::AssetId,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    /// Block number until which price deviation is not checked for asset on liquidity source, set by governance.
+    #[pallet::storage]
+    #[pallet::getter(fn overridden_until)]
+    pub type OverriddenUntil<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        LiquiditySourceType,
+        Twox64Concat,
+        T::AssetId,
+        T::BlockNumber,
+        OptionQuery,
+    >;
+}
+
+impl<T: Config> Pallet<T> {
+    /// Relative deviation of `average_price` from band of spot prices [`sell_price`, `buy_price`].
+    /// Zero if average price is within the band.
+    pub fn price_deviation(
+        buy_price: Fixed,
+        sell_price: Fixed,
+        average_price: Fixed,
+    ) -> Result<Fixed, DispatchError> {
+        if average_price <= fixed!(0) {
+            fail!(Error::<T>::PriceCalculationFailed);
+        }
+        let (upper, lower) = if buy_price >= sell_price {
+            (buy_price, sell_price)
+        } else {
+            (sell_price, buy_price)
+        };
+        let difference = if average_price > upper {
+            FixedWrapper::from(average_price) - upper
+        } else if average_price < lower {
+            FixedWrapper::from(lower) - average_price
+        } else {
+            return Ok(fixed!(0));
+        };
+        (difference / average_price)
+            .get()
+            .map_err(|_| Error::<T>::PriceCalculationFailed.into())
+    }
+}
+
+impl<T: Config> CircuitBreaker<T::AssetId> for Pallet<T> {
+    /// NOTE: halting is persisted only if calling extrinsic succeeds, i.e. checks performed from `on_initialize`
+    /// halt trading, while checks performed during exchange only refuse it.
+    fn check_price_deviation(
+        source: LiquiditySourceType,
+        asset_id: &T::AssetId,
+        buy_price: Fixed,
+        sell_price: Fixed,
+        average_price: Fixed,
+    ) -> DispatchResult {
+        let now = frame_system::Pallet::<T>::block_number();
+        if let Some(halted_until) = HaltedUntil::<T>::get(source, asset_id) {
+            if now < halted_until {
+                fail!(Error::<T>::TradingHalted);
+            }
+            HaltedUntil::<T>::remove(source, asset_id);
+            Self::deposit_event(Event::TradingResumed(source, *asset_id));
+        }
+        if let Some(overridden_until) = OverriddenUntil::<T>::get(source, asset_id) {
+            if now < overridden_until {
+                return Ok(());
+            }
+            OverriddenUntil::<T>::remove(source, asset_id);
+        }
+        let deviation = Self::price_deviation(buy_price, sell_price, average_price)?;
+        if deviation > MaxPriceDeviation::<T>::get() {
+            let halted_until = now.saturating_add(CooldownPeriod::<T>::get());
+            HaltedUntil::<T>::insert(source, asset_id, halted_until);
+            Self::deposit_event(Event::PriceDeviationExceeded(source, *asset_id, deviation));
+            Self::deposit_event(Event::TradingHalted(source, *asset_id, halted_until));
+            fail!(Error::<T>::PriceDeviationExceeded);
+        }
+        Ok(())
+    }
+
+    fn is_trading_halted(source: LiquiditySourceType, asset_id: &T::AssetId) -> bool {
+        HaltedUntil::<T>::get(source, asset_id)
+            .map(|halted_until| frame_system::Pallet::<T>::block_number() < halted_until)
+            .unwrap_or(false)
+    }
+}
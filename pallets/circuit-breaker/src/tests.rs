@@ -0,0 +1,202 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::mock::*;
+use crate::{Error, Pallet};
+use common::{fixed, CircuitBreaker, LiquiditySourceType, VAL, XSTUSD};
+use frame_support::{assert_err, assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+type Breaker = Pallet<Runtime>;
+
+const XST: LiquiditySourceType = LiquiditySourceType::XSTPool;
+const TBC: LiquiditySourceType = LiquiditySourceType::MulticollateralBondingCurvePool;
+
+fn set_block(n: BlockNumber) {
+    frame_system::Pallet::<Runtime>::set_block_number(n);
+}
+
+#[test]
+fn price_deviation_is_calculated() {
+    ExtBuilder::default().build().execute_with(|| {
+        // average within spot prices band
+        assert_eq!(
+            Breaker::price_deviation(fixed!(110), fixed!(90), fixed!(100)).unwrap(),
+            fixed!(0)
+        );
+        assert_eq!(
+            Breaker::price_deviation(fixed!(100), fixed!(100), fixed!(100)).unwrap(),
+            fixed!(0)
+        );
+        // average above buy price
+        assert_eq!(
+            Breaker::price_deviation(fixed!(80), fixed!(60), fixed!(100)).unwrap(),
+            fixed!(0.2)
+        );
+        // average below sell price
+        assert_eq!(
+            Breaker::price_deviation(fixed!(150), fixed!(125), fixed!(100)).unwrap(),
+            fixed!(0.25)
+        );
+        assert_noop!(
+            Breaker::price_deviation(fixed!(1), fixed!(1), fixed!(0)),
+            Error::<Runtime>::PriceCalculationFailed
+        );
+    });
+}
+
+#[test]
+fn spot_price_spike_halts_trading_for_cooldown_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Breaker::check_price_deviation(
+            XST,
+            &XSTUSD,
+            fixed!(110),
+            fixed!(110),
+            fixed!(100)
+        ));
+        // attacker manipulates spot price, deviation is 50%
+        assert_err!(
+            Breaker::check_price_deviation(XST, &XSTUSD, fixed!(200), fixed!(200), fixed!(400)),
+            Error::<Runtime>::PriceDeviationExceeded
+        );
+        assert!(Breaker::is_trading_halted(XST, &XSTUSD));
+        assert_eq!(Breaker::halted_until(XST, XSTUSD), Some(601));
+        // other sources and assets are not affected
+        assert!(!Breaker::is_trading_halted(TBC, &XSTUSD));
+        assert!(!Breaker::is_trading_halted(XST, &VAL));
+
+        // prices are restored, but trading is halted until cooldown ends
+        set_block(600);
+        assert_err!(
+            Breaker::check_price_deviation(XST, &XSTUSD, fixed!(100), fixed!(100), fixed!(100)),
+            Error::<Runtime>::TradingHalted
+        );
+        set_block(601);
+        assert!(!Breaker::is_trading_halted(XST, &XSTUSD));
+        assert_ok!(Breaker::check_price_deviation(
+            XST,
+            &XSTUSD,
+            fixed!(100),
+            fixed!(100),
+            fixed!(100)
+        ));
+        assert_eq!(Breaker::halted_until(XST, XSTUSD), None);
+    });
+}
+
+#[test]
+fn persistent_manipulation_keeps_trading_halted() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_err!(
+            Breaker::check_price_deviation(TBC, &VAL, fixed!(2), fixed!(1), fixed!(3)),
+            Error::<Runtime>::PriceDeviationExceeded
+        );
+        set_block(601);
+        // spot price is still manipulated after cooldown
+        assert_err!(
+            Breaker::check_price_deviation(TBC, &VAL, fixed!(2), fixed!(1), fixed!(3)),
+            Error::<Runtime>::PriceDeviationExceeded
+        );
+        assert_eq!(Breaker::halted_until(TBC, VAL), Some(1201));
+    });
+}
+
+#[test]
+fn governance_can_override_circuit_breaker() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Breaker::halt_trading(Origin::signed(alice()), XST, XSTUSD, 10),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Breaker::halt_trading(Origin::root(), XST, XSTUSD, 10));
+        assert!(Breaker::is_trading_halted(XST, &XSTUSD));
+        set_block(11);
+        assert!(!Breaker::is_trading_halted(XST, &XSTUSD));
+
+        assert_err!(
+            Breaker::check_price_deviation(XST, &XSTUSD, fixed!(1), fixed!(1), fixed!(2)),
+            Error::<Runtime>::PriceDeviationExceeded
+        );
+        assert_noop!(
+            Breaker::resume_trading(Origin::signed(alice()), XST, XSTUSD, 0),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Breaker::resume_trading(Origin::root(), XST, XSTUSD, 100));
+        assert!(!Breaker::is_trading_halted(XST, &XSTUSD));
+        // deviation is ignored while overridden by governance
+        assert_ok!(Breaker::check_price_deviation(
+            XST,
+            &XSTUSD,
+            fixed!(1),
+            fixed!(1),
+            fixed!(2)
+        ));
+        set_block(111);
+        assert_err!(
+            Breaker::check_price_deviation(XST, &XSTUSD, fixed!(1), fixed!(1), fixed!(2)),
+            Error::<Runtime>::PriceDeviationExceeded
+        );
+    });
+}
+
+#[test]
+fn parameters_can_be_changed() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(Breaker::max_price_deviation(), fixed!(0.2));
+        assert_eq!(Breaker::cooldown_period(), 600);
+        assert_noop!(
+            Breaker::set_parameters(Origin::signed(alice()), fixed!(0.5), 10),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Breaker::set_parameters(Origin::root(), fixed!(0), 10),
+            Error::<Runtime>::InvalidMaxPriceDeviation
+        );
+        assert_noop!(
+            Breaker::set_parameters(Origin::root(), fixed!(0.5), 0),
+            Error::<Runtime>::InvalidCooldownPeriod
+        );
+        assert_ok!(Breaker::set_parameters(Origin::root(), fixed!(0.5), 10));
+        // deviation of 40% is allowed now
+        assert_ok!(Breaker::check_price_deviation(
+            TBC,
+            &VAL,
+            fixed!(6),
+            fixed!(6),
+            fixed!(10)
+        ));
+        assert_err!(
+            Breaker::check_price_deviation(TBC, &VAL, fixed!(4), fixed!(4), fixed!(10)),
+            Error::<Runtime>::PriceDeviationExceeded
+        );
+        assert_eq!(Breaker::halted_until(TBC, VAL), Some(11));
+    });
+}
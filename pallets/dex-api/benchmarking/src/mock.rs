@@ -79,6 +79,7 @@ parameter_types! {
     pub GetParliamentAccountId: AccountId = AccountId32::from([8; 32]);
     pub GetMarketMakerRewardsAccountId: AccountId = AccountId32::from([9; 32]);
     pub GetBondingCurveRewardsAccountId: AccountId = AccountId32::from([10; 32]);
+    pub GetFarmingRewardsAccountId: AccountId = AccountId32::from([12; 32]);
     pub GetTeamReservesAccountId: AccountId = AccountId32::from([11; 32]);
     pub GetXykFee: Fixed = fixed!(0.003);
 }
@@ -94,7 +95,7 @@ construct_runtime! {
         Currencies: currencies::{Module, Call, Storage, Event<T>},
         Assets: assets::{Module, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Module, Call, Config<T>, Storage, Event<T>},
         Technical: technical::{Module, Call, Config<T>, Storage, Event<T>},
         Permissions: permissions::{Module, Call, Config<T>, Storage, Event<T>},
         DexApi: dex_api::{Module, Call, Config, Storage, Event<T>},
@@ -176,7 +177,11 @@ impl pallet_balances::Config for Runtime {
     type MaxLocks = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl technical::Config for Runtime {
     type Event = Event;
@@ -185,10 +190,12 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl dex_api::Config for Runtime {
@@ -200,6 +207,7 @@ impl dex_api::Config for Runtime {
     type XYKPool = pool_xyk::Module<Runtime>;
     type XSTPool = ();
     type MulticollateralBondingCurvePool = ();
+    type IsSourceEnabled = trading_pair::Module<Runtime>;
     type WeightInfo = ();
 }
 
@@ -222,6 +230,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = ();
     type WeightInfo = ();
 }
 
@@ -238,6 +247,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = ();
 }
 
 pub struct MockPriceTools;
@@ -260,6 +270,8 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
     type EnsureDEXManager = dex_manager::Module<Runtime>;
     type EnsureTradingPairExists = trading_pair::Module<Runtime>;
     type PriceToolsPallet = MockPriceTools;
+    type PriceOracle = ();
+    type CircuitBreaker = ();
     type VestedRewardsPallet = VestedRewards;
     type WeightInfo = ();
 }
@@ -267,6 +279,7 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
 impl vested_rewards::Config for Runtime {
     type Event = Event;
     type GetMarketMakerRewardsAccountId = GetMarketMakerRewardsAccountId;
+    type GetFarmingRewardsAccountId = GetFarmingRewardsAccountId;
     type GetBondingCurveRewardsAccountId = GetBondingCurveRewardsAccountId;
     type WeightInfo = ();
 }
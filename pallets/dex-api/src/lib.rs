@@ -32,9 +32,11 @@
 
 use common::prelude::{Balance, QuoteAmount, SwapAmount, SwapOutcome, SwapVariant};
 use common::{
-    LiquidityRegistry, LiquiditySource, LiquiditySourceFilter, LiquiditySourceId,
+    IsSourceEnabled, LiquidityRegistry, LiquiditySource, LiquiditySourceFilter, LiquiditySourceId,
     LiquiditySourceType, RewardReason,
 };
+use frame_support::dispatch::DispatchResult;
+use frame_support::ensure;
 use frame_support::sp_runtime::DispatchError;
 use frame_support::weights::Weight;
 use frame_system::ensure_signed;
@@ -53,6 +55,7 @@ pub trait WeightInfo {
 }
 
 type DEXManager<T> = dex_manager::Pallet<T>;
+type TradingPair<T> = trading_pair::Pallet<T>;
 
 impl<T: Config>
     LiquiditySource<
@@ -69,6 +72,14 @@ impl<T: Config>
         output_asset_id: &T::AssetId,
     ) -> bool {
         use LiquiditySourceType::*;
+        if !T::IsSourceEnabled::is_source_enabled(
+            &liquidity_source_id.dex_id,
+            input_asset_id,
+            output_asset_id,
+            liquidity_source_id.liquidity_source_index,
+        ) {
+            return false;
+        }
         macro_rules! can_exchange {
             ($source_type:ident) => {
                 T::$source_type::can_exchange(
@@ -97,6 +108,7 @@ impl<T: Config>
         amount: QuoteAmount<Balance>,
     ) -> Result<SwapOutcome<Balance>, DispatchError> {
         use LiquiditySourceType::*;
+        Self::ensure_source_enabled(liquidity_source_id, input_asset_id, output_asset_id)?;
         macro_rules! quote {
             ($source_type:ident) => {
                 T::$source_type::quote(
@@ -128,6 +140,7 @@ impl<T: Config>
         swap_amount: SwapAmount<Balance>,
     ) -> Result<SwapOutcome<Balance>, DispatchError> {
         use LiquiditySourceType::*;
+        Self::ensure_source_enabled(liquidity_source_id, input_asset_id, output_asset_id)?;
         macro_rules! exchange {
             ($source_type:ident) => {
                 T::$source_type::exchange(
@@ -221,6 +234,29 @@ impl<T: Config> Pallet<T> {
     pub fn get_supported_types() -> Vec<LiquiditySourceType> {
         EnabledSourceTypes::<T>::get()
     }
+
+    /// Ensures the trading pair of the assets isn't paused and the source is enabled for it.
+    fn ensure_source_enabled(
+        liquidity_source_id: &LiquiditySourceId<T::DEXId, LiquiditySourceType>,
+        input_asset_id: &T::AssetId,
+        output_asset_id: &T::AssetId,
+    ) -> DispatchResult {
+        TradingPair::<T>::ensure_trading_not_paused(
+            &liquidity_source_id.dex_id,
+            input_asset_id,
+            output_asset_id,
+        )?;
+        ensure!(
+            T::IsSourceEnabled::is_source_enabled(
+                &liquidity_source_id.dex_id,
+                input_asset_id,
+                output_asset_id,
+                liquidity_source_id.liquidity_source_index,
+            ),
+            trading_pair::Error::<T>::SourceIsNotEnabled
+        );
+        Ok(())
+    }
 }
 
 impl<T: Config>
@@ -325,6 +361,8 @@ pub mod pallet {
             Balance,
             DispatchError,
         >;
+        /// Checks if the source is enabled for the trading pair, so that it can be used for exchanges.
+        type IsSourceEnabled: IsSourceEnabled<Self::DEXId, Self::AssetId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
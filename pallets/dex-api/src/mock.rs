@@ -108,8 +108,8 @@ construct_runtime! {
         MockLiquiditySource3: mock_liquidity_source::<Instance3>::{Module, Call, Config<T>, Storage},
         MockLiquiditySource4: mock_liquidity_source::<Instance4>::{Module, Call, Config<T>, Storage},
         Technical: technical::{Module, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Storage},
-        TradingPair: trading_pair::{Module, Call, Storage, Event<T>},
+        DexManager: dex_manager::{Module, Call, Storage, Event<T>},
+        TradingPair: trading_pair::{Module, Call, Config<T>, Storage, Event<T>},
         PoolXYK: pool_xyk::{Module, Call, Storage, Event<T>},
         PswapDistribution: pswap_distribution::{Module, Call, Storage, Event<T>},
     }
@@ -153,6 +153,7 @@ impl Config for Runtime {
     type MulticollateralBondingCurvePool = ();
     type XYKPool = pool_xyk::Module<Runtime>;
     type XSTPool = ();
+    type IsSourceEnabled = trading_pair::Module<Runtime>;
     type WeightInfo = ();
 }
 
@@ -168,6 +169,7 @@ impl tokens::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl currencies::Config for Runtime {
@@ -236,9 +238,14 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -259,6 +266,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = ();
     type WeightInfo = ();
 }
 
@@ -275,6 +283,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = ();
 }
 
 pub struct ExtBuilder {
@@ -358,6 +367,37 @@ impl ExtBuilder {
             .build_storage::<Runtime>()
             .unwrap();
 
+        // Mock sources are enabled for the trading pairs they have reserves for
+        let mut mock_sources = Vec::new();
+        for (source_type, reserves) in [
+            (LiquiditySourceType::MockPool, &self.reserves),
+            (LiquiditySourceType::MockPool2, &self.reserves_2),
+            (LiquiditySourceType::MockPool3, &self.reserves_3),
+            (LiquiditySourceType::MockPool4, &self.reserves_4),
+        ]
+        .iter()
+        {
+            for (dex_id, target_asset_id, _) in reserves.iter() {
+                if let Some((_, dex_info)) = self.dex_list.iter().find(|(id, _)| id == dex_id) {
+                    let pair = trading_pair::TradingPair::<Runtime> {
+                        base_asset_id: dex_info.base_asset_id,
+                        target_asset_id: *target_asset_id,
+                    };
+                    mock_sources.push((*dex_id, pair, *source_type));
+                }
+            }
+        }
+        let mut trading_pairs = Vec::new();
+        for (dex_id, pair, _) in mock_sources.iter() {
+            if !trading_pairs.contains(&(*dex_id, *pair)) {
+                trading_pairs.push((*dex_id, *pair));
+            }
+        }
+
+        trading_pair::GenesisConfig::<Runtime> { trading_pairs }
+            .assimilate_storage(&mut t)
+            .unwrap();
+
         tokens::GenesisConfig::<Runtime> {
             endowed_accounts: self.endowed_accounts,
         }
@@ -413,6 +453,18 @@ impl ExtBuilder {
         )
         .unwrap();
 
-        t.into()
+        let mut ext: sp_io::TestExternalities = t.into();
+        ext.execute_with(|| {
+            for (dex_id, pair, source_type) in mock_sources {
+                trading_pair::Module::<Runtime>::enable_source_for_trading_pair(
+                    &dex_id,
+                    &pair.base_asset_id,
+                    &pair.target_asset_id,
+                    source_type,
+                )
+                .unwrap();
+            }
+        });
+        ext
     }
 }
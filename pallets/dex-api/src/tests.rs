@@ -35,6 +35,7 @@ use common::{
     balance, LiquidityRegistry, LiquiditySource, LiquiditySourceFilter, LiquiditySourceId,
     LiquiditySourceType, DOT, XOR,
 };
+use frame_support::assert_ok;
 
 type DexApi = Module<Runtime>;
 
@@ -137,3 +138,38 @@ fn test_different_reserves_should_pass() {
         );
     })
 }
+
+#[test]
+fn test_disabled_source_should_fail() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let source_id = LiquiditySourceId::new(DEX_A_ID, LiquiditySourceType::MockPool);
+        assert!(DexApi::can_exchange(&source_id, &XOR, &DOT));
+        assert_ok!(trading_pair::Module::<Runtime>::disable_source(
+            Origin::root(),
+            DEX_A_ID,
+            XOR,
+            DOT,
+            LiquiditySourceType::MockPool,
+        ));
+        assert!(!DexApi::can_exchange(&source_id, &XOR, &DOT));
+        assert!(!DexApi::can_exchange(&source_id, &DOT, &XOR));
+        assert_eq!(
+            DexApi::quote(
+                &source_id,
+                &XOR,
+                &DOT,
+                QuoteAmount::with_desired_input(balance!(100)),
+            )
+            .unwrap_err(),
+            trading_pair::Error::<Runtime>::SourceIsNotEnabled.into()
+        );
+        assert!(DexApi::quote(
+            &LiquiditySourceId::new(DEX_A_ID, LiquiditySourceType::MockPool2),
+            &XOR,
+            &DOT,
+            QuoteAmount::with_desired_input(balance!(100)),
+        )
+        .is_ok());
+    })
+}
@@ -31,15 +31,20 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use assets::AssetIdOf;
-use common::prelude::EnsureDEXManager;
+use common::prelude::{Balance, EnsureDEXManager};
 use common::{hash, ManagementMode};
 use frame_support::dispatch::DispatchResult;
-use frame_support::ensure;
+use frame_support::sp_runtime::traits::Zero;
 use frame_support::sp_runtime::DispatchError;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use frame_support::{ensure, transactional};
 use frame_system::RawOrigin;
 use permissions::{Scope, MANAGE_DEX};
 use sp_std::vec::Vec;
 
+pub mod weights;
+
 #[cfg(test)]
 mod mock;
 
@@ -47,6 +52,15 @@ mod mock;
 mod tests;
 
 type DEXInfo<T> = common::prelude::DEXInfo<AssetIdOf<T>>;
+type Assets<T> = assets::Pallet<T>;
+type Permissions<T> = permissions::Pallet<T>;
+
+pub trait WeightInfo {
+    fn register_dex() -> Weight;
+    fn add_dex_manager() -> Weight;
+    fn deregister_dex() -> Weight;
+    fn set_dex_owner() -> Weight;
+}
 
 impl<T: Config> EnsureDEXManager<T::DEXId, T::AccountId, DispatchError> for Module<T> {
     fn ensure_can_manage<OuterOrigin>(
@@ -88,6 +102,48 @@ impl<T: Config> Module<T> {
         DEXInfos::<T>::iter().map(|(k, _)| k).collect()
     }
 
+    /// Registers a new DEX quoted in `base_asset_id` on behalf of `owner`.
+    ///
+    /// The registration deposit is reserved from the owner in the network base asset and
+    /// `MANAGE_DEX` permission in the scope of the new DEX is assigned to the owner.
+    /// Only one DEX can be quoted in a particular asset, so that pools of different DEXes
+    /// never share their reserves records. Ids of deregistered DEXes can't be reused, so that
+    /// managers of the former DEX can't manage the new one.
+    pub fn register_dex_from(
+        owner: &T::AccountId,
+        dex_id: T::DEXId,
+        base_asset_id: AssetIdOf<T>,
+        is_public: bool,
+    ) -> DispatchResult {
+        ensure!(
+            !DEXInfos::<T>::contains_key(&dex_id) && !DEXOwners::<T>::contains_key(&dex_id),
+            Error::<T>::DEXIdAlreadyExists
+        );
+        Assets::<T>::ensure_asset_exists(&base_asset_id)?;
+        ensure!(
+            DEXInfos::<T>::iter_values().all(|info| info.base_asset_id != base_asset_id),
+            Error::<T>::BaseAssetAlreadyUsed
+        );
+        let deposit = T::DEXRegistrationDeposit::get();
+        Assets::<T>::reserve(T::GetBaseAssetId::get(), owner, deposit)?;
+        Permissions::<T>::assign_permission(
+            owner.clone(),
+            owner,
+            MANAGE_DEX,
+            Scope::Limited(hash(&dex_id)),
+        )?;
+        DEXInfos::<T>::insert(
+            dex_id,
+            common::prelude::DEXInfo {
+                base_asset_id,
+                is_public,
+            },
+        );
+        DEXOwners::<T>::insert(dex_id, (owner.clone(), deposit));
+        Self::deposit_event(Event::DEXRegistered(dex_id, owner.clone(), base_asset_id));
+        Ok(())
+    }
+
     fn ensure_direct_manager(dex_id: &T::DEXId, who: &T::AccountId) -> DispatchResult {
         permissions::Module::<T>::check_permission_with_scope(
             who.clone(),
@@ -103,11 +159,18 @@ pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    use common::{AccountIdOf, DexIdOf};
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + common::Config + assets::Config {}
+    pub trait Config: frame_system::Config + common::Config + assets::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+        /// Amount of the network base asset reserved from the account registering a DEX.
+        type DEXRegistrationDeposit: Get<Balance>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
@@ -117,11 +180,139 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {}
+    impl<T: Config> Pallet<T> {
+        /// Register a new DEX quoted in the given base asset.
+        /// The caller becomes the owner of the DEX and pays the registration deposit.
+        ///
+        /// - `dex_id`: ID of the new exchange.
+        /// - `base_asset_id`: asset which all trading pairs of the exchange are quoted in.
+        /// - `is_public`: whether anyone can register trading pairs and pools on the exchange.
+        #[pallet::weight(<T as Config>::WeightInfo::register_dex())]
+        pub fn register_dex(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: AssetIdOf<T>,
+            is_public: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::register_dex_from(&who, dex_id, base_asset_id, is_public)?;
+            Ok(().into())
+        }
+
+        /// Allow `account_id` to manage the DEX.
+        /// Can be only called by the DEX owner.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `account_id`: account to grant `MANAGE_DEX` permission to.
+        #[pallet::weight(<T as Config>::WeightInfo::add_dex_manager())]
+        pub fn add_dex_manager(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            account_id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_dex_exists(&dex_id)?;
+            Permissions::<T>::grant_permission_with_scope(
+                who,
+                account_id.clone(),
+                MANAGE_DEX,
+                Scope::Limited(hash(&dex_id)),
+            )?;
+            Self::deposit_event(Event::DEXManagerAdded(dex_id, account_id));
+            Ok(().into())
+        }
+
+        /// Deregister the DEX registered by an account and return the registration deposit to its owner.
+        /// Trading pairs and pools of the DEX can't be used afterwards, the DEX id can't be registered again.
+        /// Can be only called by root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        #[pallet::weight(<T as Config>::WeightInfo::deregister_dex())]
+        #[transactional]
+        pub fn deregister_dex(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Self::ensure_dex_exists(&dex_id)?;
+            let (owner, deposit) =
+                DEXOwners::<T>::get(&dex_id).ok_or(Error::<T>::DEXNotRegisteredByAccount)?;
+            Permissions::<T>::revoke_permission_with_scope(
+                owner.clone(),
+                owner.clone(),
+                MANAGE_DEX,
+                Scope::Limited(hash(&dex_id)),
+            )?;
+            Assets::<T>::unreserve(T::GetBaseAssetId::get(), &owner, deposit)?;
+            DEXInfos::<T>::remove(&dex_id);
+            // the owner is kept to prevent the DEX id from being registered again
+            DEXOwners::<T>::insert(&dex_id, (owner, Balance::zero()));
+            Self::deposit_event(Event::DEXDeregistered(dex_id));
+            Ok(().into())
+        }
+
+        /// Make `new_owner` the owner of the DEX registered by an account and return the registration
+        /// deposit to the former owner. Managers added by the former owner keep managing the DEX until the
+        /// new owner revokes their permissions.
+        /// Can be only called by root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `new_owner`: account which becomes the owner of the exchange.
+        #[pallet::weight(<T as Config>::WeightInfo::set_dex_owner())]
+        #[transactional]
+        pub fn set_dex_owner(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Self::ensure_dex_exists(&dex_id)?;
+            let (owner, deposit) =
+                DEXOwners::<T>::get(&dex_id).ok_or(Error::<T>::DEXNotRegisteredByAccount)?;
+            ensure!(owner != new_owner, Error::<T>::AlreadyDEXOwner);
+            let scope = Scope::Limited(hash(&dex_id));
+            Permissions::<T>::transfer_permission(
+                owner.clone(),
+                new_owner.clone(),
+                MANAGE_DEX,
+                scope,
+            )?;
+            Permissions::<T>::grant_permission_with_scope(
+                new_owner.clone(),
+                new_owner.clone(),
+                MANAGE_DEX,
+                scope,
+            )?;
+            Permissions::<T>::revoke_permission_with_scope(
+                new_owner.clone(),
+                owner.clone(),
+                MANAGE_DEX,
+                scope,
+            )?;
+            Assets::<T>::unreserve(T::GetBaseAssetId::get(), &owner, deposit)?;
+            DEXOwners::<T>::insert(&dex_id, (new_owner.clone(), Balance::zero()));
+            Self::deposit_event(Event::DEXOwnerChanged(dex_id, new_owner));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::metadata(DexIdOf<T> = "DEXId", AccountIdOf<T> = "AccountId", AssetIdOf<T> = "AssetId")]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// DEX was registered. [DEX Id, Owner, Base Asset Id]
+        DEXRegistered(DexIdOf<T>, AccountIdOf<T>, AssetIdOf<T>),
+        /// Account was allowed to manage the DEX. [DEX Id, Manager]
+        DEXManagerAdded(DexIdOf<T>, AccountIdOf<T>),
+        /// DEX was deregistered. [DEX Id]
+        DEXDeregistered(DexIdOf<T>),
+        /// Owner of the DEX was changed. [DEX Id, New Owner]
+        DEXOwnerChanged(DexIdOf<T>, AccountIdOf<T>),
+    }
 
     #[pallet::error]
     pub enum Error<T> {
-        /// DEX with given id is already registered.
+        /// DEX with given id is already registered or was deregistered.
         DEXIdAlreadyExists,
         /// DEX with given Id is not registered.
         DEXDoesNotExist,
@@ -129,12 +320,24 @@ pub mod pallet {
         InvalidFeeValue,
         /// Account with given Id is not registered.
         InvalidAccountId,
+        /// There is already a DEX quoted in the given base asset.
+        BaseAssetAlreadyUsed,
+        /// DEX wasn't registered by an account, e.g. it was registered at genesis.
+        DEXNotRegisteredByAccount,
+        /// Account is already the owner of the DEX.
+        AlreadyDEXOwner,
     }
 
     #[pallet::storage]
     #[pallet::getter(fn dex_id)]
     pub type DEXInfos<T: Config> = StorageMap<_, Twox64Concat, T::DEXId, DEXInfo<T>>;
 
+    /// Owners of the permissionlessly registered DEXes and the deposits reserved from them. Owners of
+    /// the deregistered DEXes are kept with zero deposits.
+    #[pallet::storage]
+    #[pallet::getter(fn dex_owner)]
+    pub type DEXOwners<T: Config> = StorageMap<_, Twox64Concat, T::DEXId, (T::AccountId, Balance)>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub dex_list: Vec<(T::DEXId, DEXInfo<T>)>,
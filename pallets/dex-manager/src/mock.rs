@@ -31,7 +31,10 @@
 use crate::{self as dex_manager, Config};
 use common::mock::ExistentialDeposits;
 use common::prelude::Balance;
-use common::{self, fixed_from_basis_points, AssetId32, DEXInfo, Fixed, DOT, XOR};
+use common::{
+    self, fixed_from_basis_points, AssetId32, AssetName, AssetSymbol, BalancePrecision, DEXInfo,
+    Fixed, DOT, VAL, XOR,
+};
 use currencies::BasicCurrencyAdapter;
 use frame_support::traits::GenesisBuild;
 use frame_support::weights::Weight;
@@ -47,7 +50,7 @@ pub type AccountId = u128;
 pub type BlockNumber = u64;
 pub type Amount = i128;
 pub type DEXId = u32;
-type AssetId = AssetId32<common::PredefinedAssetId>;
+pub type AssetId = AssetId32<common::PredefinedAssetId>;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -55,6 +58,7 @@ pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const DEX_A_ID: DEXId = 1;
 pub const DEX_B_ID: DEXId = 2;
+pub const DEX_REGISTRATION_DEPOSIT: Balance = 100_000_000_000_000_000_000u128;
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
@@ -68,6 +72,7 @@ parameter_types! {
     pub const TransactionByteFee: u128 = 1;
     pub GetTeamReservesAccountId: AccountId = 3000u128;
     pub GetFee: Fixed = fixed_from_basis_points(30u16);
+    pub const DEXRegistrationDeposit: Balance = DEX_REGISTRATION_DEPOSIT;
 }
 
 construct_runtime! {
@@ -77,7 +82,7 @@ construct_runtime! {
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Module, Call, Config<T>, Storage, Event<T>},
         Tokens: tokens::{Module, Call, Config<T>, Storage, Event<T>},
         Permissions: permissions::{Module, Call, Config<T>, Storage, Event<T>},
         Currencies: currencies::{Module, Call, Storage, Event<T>},
@@ -111,7 +116,11 @@ impl frame_system::Config for Runtime {
     type SS58Prefix = ();
 }
 
-impl Config for Runtime {}
+impl Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = DEXRegistrationDeposit;
+    type WeightInfo = ();
+}
 
 impl tokens::Config for Runtime {
     type Event = Event;
@@ -125,6 +134,7 @@ impl tokens::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl currencies::Config for Runtime {
@@ -164,6 +174,15 @@ impl pallet_balances::Config for Runtime {
 
 pub struct ExtBuilder {
     pub initial_dex_list: Vec<(DEXId, DEXInfo<AssetId>)>,
+    pub endowed_assets: Vec<(
+        AssetId,
+        AccountId,
+        AssetSymbol,
+        AssetName,
+        BalancePrecision,
+        Balance,
+        bool,
+    )>,
     pub endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
     pub initial_permission_owners: Vec<(u32, Scope, Vec<AccountId>)>,
     pub initial_permissions: Vec<(AccountId, Scope, Vec<u32>)>,
@@ -173,6 +192,35 @@ impl Default for ExtBuilder {
     fn default() -> Self {
         Self {
             initial_dex_list: Vec::new(),
+            endowed_assets: vec![
+                (
+                    XOR,
+                    ALICE,
+                    AssetSymbol(b"XOR".to_vec()),
+                    AssetName(b"SORA".to_vec()),
+                    18,
+                    Balance::from(0u32),
+                    true,
+                ),
+                (
+                    DOT,
+                    ALICE,
+                    AssetSymbol(b"DOT".to_vec()),
+                    AssetName(b"Polkadot".to_vec()),
+                    18,
+                    Balance::from(0u32),
+                    true,
+                ),
+                (
+                    VAL,
+                    ALICE,
+                    AssetSymbol(b"VAL".to_vec()),
+                    AssetName(b"SORA Validator Token".to_vec()),
+                    18,
+                    Balance::from(0u32),
+                    true,
+                ),
+            ],
             endowed_accounts: vec![
                 (ALICE, XOR, 1_000_000_000_000_000_000u128.into()),
                 (BOB, DOT, 1_000_000_000_000_000_000u128.into()),
@@ -195,12 +243,24 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
-        tokens::GenesisConfig::<Runtime> {
-            endowed_accounts: self.endowed_accounts,
+        let (native_accounts, endowed_accounts): (Vec<_>, Vec<_>) = self
+            .endowed_accounts
+            .into_iter()
+            .partition(|(_, asset_id, _)| *asset_id == XOR);
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: native_accounts
+                .into_iter()
+                .map(|(account_id, _, balance)| (account_id, balance))
+                .collect(),
         }
         .assimilate_storage(&mut t)
         .unwrap();
 
+        tokens::GenesisConfig::<Runtime> { endowed_accounts }
+            .assimilate_storage(&mut t)
+            .unwrap();
+
         permissions::GenesisConfig::<Runtime> {
             initial_permission_owners: self.initial_permission_owners,
             initial_permissions: self.initial_permissions,
@@ -208,6 +268,12 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
+        assets::GenesisConfig::<Runtime> {
+            endowed_assets: self.endowed_assets,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         t.into()
     }
 }
@@ -31,7 +31,8 @@
 use crate::mock::*;
 use crate::{Error, Module};
 use common::prelude::DEXInfo;
-use common::{hash, EnsureDEXManager, ManagementMode, VAL, XOR};
+use common::{hash, EnsureDEXManager, ManagementMode, DOT, VAL, XOR};
+use frame_support::sp_runtime::DispatchError;
 use frame_support::{assert_noop, assert_ok};
 use permissions::{Scope, MANAGE_DEX};
 
@@ -333,3 +334,192 @@ fn test_queries_for_nonexistant_dex_should_fail() {
         );
     })
 }
+
+fn xor_dex() -> Vec<(DEXId, DEXInfo<AssetId>)> {
+    vec![(
+        DEX_A_ID,
+        DEXInfo {
+            base_asset_id: XOR,
+            is_public: true,
+        },
+    )]
+}
+
+#[test]
+fn test_register_dex_should_pass() {
+    let mut ext = ExtBuilder {
+        initial_dex_list: xor_dex(),
+        ..Default::default()
+    }
+    .build();
+    ext.execute_with(|| {
+        assert_ok!(DEXModule::register_dex(
+            Origin::signed(ALICE),
+            DEX_B_ID,
+            VAL,
+            false
+        ));
+        assert_eq!(
+            DEXModule::dex_id(DEX_B_ID),
+            Some(DEXInfo {
+                base_asset_id: VAL,
+                is_public: false,
+            })
+        );
+        assert_eq!(
+            DEXModule::dex_owner(DEX_B_ID),
+            Some((ALICE, DEX_REGISTRATION_DEPOSIT))
+        );
+        assert_eq!(
+            pallet_balances::Module::<Runtime>::reserved_balance(ALICE),
+            DEX_REGISTRATION_DEPOSIT
+        );
+        assert_ok!(DEXModule::ensure_can_manage(
+            &DEX_B_ID,
+            Origin::signed(ALICE),
+            ManagementMode::Private
+        ));
+        assert_noop!(
+            DEXModule::ensure_can_manage(&DEX_B_ID, Origin::signed(BOB), ManagementMode::Public),
+            permissions::Error::<Runtime>::Forbidden
+        );
+    })
+}
+
+#[test]
+fn test_register_dex_should_fail() {
+    let mut ext = ExtBuilder {
+        initial_dex_list: xor_dex(),
+        ..Default::default()
+    }
+    .build();
+    ext.execute_with(|| {
+        assert_noop!(
+            DEXModule::register_dex(Origin::signed(ALICE), DEX_A_ID, VAL, true),
+            Error::<Runtime>::DEXIdAlreadyExists
+        );
+        assert_noop!(
+            DEXModule::register_dex(Origin::signed(ALICE), DEX_B_ID, XOR, true),
+            Error::<Runtime>::BaseAssetAlreadyUsed
+        );
+        assert_noop!(
+            DEXModule::register_dex(Origin::signed(BOB), DEX_B_ID, DOT, true),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+    })
+}
+
+#[test]
+fn test_add_dex_manager_should_pass() {
+    let mut ext = ExtBuilder {
+        initial_dex_list: xor_dex(),
+        ..Default::default()
+    }
+    .build();
+    ext.execute_with(|| {
+        assert_ok!(DEXModule::register_dex(
+            Origin::signed(ALICE),
+            DEX_B_ID,
+            DOT,
+            false
+        ));
+        assert_noop!(
+            DEXModule::add_dex_manager(Origin::signed(BOB), DEX_B_ID, BOB),
+            permissions::Error::<Runtime>::PermissionNotFound
+        );
+        assert_ok!(DEXModule::add_dex_manager(
+            Origin::signed(ALICE),
+            DEX_B_ID,
+            BOB
+        ));
+        assert_ok!(DEXModule::ensure_can_manage(
+            &DEX_B_ID,
+            Origin::signed(BOB),
+            ManagementMode::Private
+        ));
+    })
+}
+
+#[test]
+fn test_deregister_dex_should_pass() {
+    let mut ext = ExtBuilder {
+        initial_dex_list: xor_dex(),
+        ..Default::default()
+    }
+    .build();
+    ext.execute_with(|| {
+        assert_ok!(DEXModule::register_dex(
+            Origin::signed(ALICE),
+            DEX_B_ID,
+            DOT,
+            false
+        ));
+        assert_noop!(
+            DEXModule::deregister_dex(Origin::signed(ALICE), DEX_B_ID),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            DEXModule::deregister_dex(Origin::root(), DEX_A_ID),
+            Error::<Runtime>::DEXNotRegisteredByAccount
+        );
+        assert_ok!(DEXModule::deregister_dex(Origin::root(), DEX_B_ID));
+        assert_eq!(DEXModule::dex_id(DEX_B_ID), None);
+        assert_eq!(
+            pallet_balances::Module::<Runtime>::reserved_balance(ALICE),
+            0
+        );
+        assert_noop!(
+            DEXModule::ensure_can_manage(&DEX_B_ID, Origin::signed(ALICE), ManagementMode::Private),
+            Error::<Runtime>::DEXDoesNotExist
+        );
+        assert_noop!(
+            DEXModule::register_dex(Origin::signed(ALICE), DEX_B_ID, DOT, false),
+            Error::<Runtime>::DEXIdAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn test_set_dex_owner_should_pass() {
+    let mut ext = ExtBuilder {
+        initial_dex_list: xor_dex(),
+        ..Default::default()
+    }
+    .build();
+    ext.execute_with(|| {
+        assert_ok!(DEXModule::register_dex(
+            Origin::signed(ALICE),
+            DEX_B_ID,
+            DOT,
+            false
+        ));
+        assert_noop!(
+            DEXModule::set_dex_owner(Origin::signed(BOB), DEX_B_ID, BOB),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            DEXModule::set_dex_owner(Origin::root(), DEX_B_ID, ALICE),
+            Error::<Runtime>::AlreadyDEXOwner
+        );
+        assert_ok!(DEXModule::set_dex_owner(Origin::root(), DEX_B_ID, BOB));
+        assert_eq!(DEXModule::dex_owner(DEX_B_ID), Some((BOB, 0)));
+        assert_eq!(
+            pallet_balances::Module::<Runtime>::reserved_balance(ALICE),
+            0
+        );
+        assert_ok!(DEXModule::ensure_can_manage(
+            &DEX_B_ID,
+            Origin::signed(BOB),
+            ManagementMode::Private
+        ));
+        assert_noop!(
+            DEXModule::ensure_can_manage(&DEX_B_ID, Origin::signed(ALICE), ManagementMode::Private),
+            permissions::Error::<Runtime>::Forbidden
+        );
+        assert_ok!(DEXModule::add_dex_manager(
+            Origin::signed(BOB),
+            DEX_B_ID,
+            ALICE
+        ));
+    })
+}
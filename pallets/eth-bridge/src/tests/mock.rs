@@ -368,6 +368,7 @@ impl common::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl bridge_multisig::Config for Runtime {
@@ -0,0 +1,204 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+
+use common::InvokeRPCError;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+// Runtime API imports.
+pub use farming_runtime_api::FarmingAPI as FarmingRuntimeAPI;
+use farming_runtime_api::{FarmId, FarmInfo, FarmerInfo, LiquidityLockInfo};
+
+#[rpc]
+pub trait FarmingAPI<BlockHash, AccountId, OptionalFarmInfo, FarmerInfo, OptionalLiquidityLockInfo>
+{
+    #[rpc(name = "farming_farmInfo")]
+    fn farm_info(&self, farm_id: FarmId, at: Option<BlockHash>) -> Result<OptionalFarmInfo>;
+
+    #[rpc(name = "farming_poolFarms")]
+    fn pool_farms(&self, pool: AccountId, at: Option<BlockHash>) -> Result<Vec<FarmId>>;
+
+    #[rpc(name = "farming_farmerInfo")]
+    fn farmer_info(
+        &self,
+        farm_id: FarmId,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<FarmerInfo>;
+
+    #[rpc(name = "farming_accruedReward")]
+    fn accrued_reward(
+        &self,
+        account_id: AccountId,
+        pool: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<FarmerInfo>;
+
+    #[rpc(name = "farming_liquidityLock")]
+    fn liquidity_lock(
+        &self,
+        account_id: AccountId,
+        pool: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<OptionalLiquidityLockInfo>;
+}
+
+pub struct FarmingClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> FarmingClient<C, B> {
+    /// Construct default `Template`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, AssetId, BlockNumber, Balance>
+    FarmingAPI<
+        <Block as BlockT>::Hash,
+        AccountId,
+        Option<FarmInfo<AccountId, AssetId, BlockNumber, Balance>>,
+        FarmerInfo<Balance>,
+        Option<LiquidityLockInfo<BlockNumber, Balance>>,
+    > for FarmingClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: FarmingRuntimeAPI<Block, AccountId, AssetId, BlockNumber, Balance>,
+    AccountId: Codec,
+    AssetId: Codec,
+    BlockNumber: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+{
+    fn farm_info(
+        &self,
+        farm_id: FarmId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<FarmInfo<AccountId, AssetId, BlockNumber, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.farm_info(&at, farm_id).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get farm info.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn pool_farms(
+        &self,
+        pool: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<FarmId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.pool_farms(&at, pool).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get farms of the pool.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn farmer_info(
+        &self,
+        farm_id: FarmId,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<FarmerInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.farmer_info(&at, farm_id, account_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get farmer info.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn accrued_reward(
+        &self,
+        account_id: AccountId,
+        pool: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<FarmerInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.accrued_reward(&at, account_id, pool)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get accrued farming reward.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn liquidity_lock(
+        &self,
+        account_id: AccountId,
+        pool: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<LiquidityLockInfo<BlockNumber, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.liquidity_lock(&at, account_id, pool)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get liquidity lock.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+}
@@ -127,6 +127,37 @@ fn prepare_good_accounts<T: Config>(count: u32, assets: &[T::AssetId]) {
     }
 }
 
+fn prepare_farms<T: Config>(count: u32, asset: &T::AssetId) -> T::BlockNumber {
+    let owner = asset_owner::<T>();
+    permissions::Module::<T>::assign_permission(
+        owner.clone(),
+        &owner,
+        permissions::CREATE_FARM,
+        permissions::Scope::Unlimited,
+    )
+    .unwrap();
+    assert_ok!(assets::Module::<T>::mint_to(
+        asset,
+        &owner,
+        &owner,
+        balance!(1000000),
+    ));
+    let start_block = frame_system::Module::<T>::block_number();
+    let end_block = start_block + 1000u32.into();
+    for _i in 0..count {
+        assert_ok!(Module::<T>::create_farm(
+            signed_origin::<T>(owner.clone()),
+            XOR.into(),
+            asset.clone(),
+            asset.clone(),
+            balance!(1),
+            start_block,
+            end_block,
+        ));
+    }
+    end_block
+}
+
 benchmarks! {
     refresh_pool {
         let a in 1..100;
@@ -160,14 +191,67 @@ benchmarks! {
         Module::<T>::vest_account_rewards(accounts);
     }
 
-    save_data {
-        let a in 1..29;
-        let b in 1..43;
-        let (pools, assets) = prepare_pools::<T>(a);
-        prepare_good_accounts::<T>(b, &assets);
-        Module::<T>::refresh_pools(T::VESTING_FREQUENCY);
+    on_pool_tokens_changed {
+        let a in 1..MAX_FARMS_PER_POOL;
+        let (mut pools, assets) = prepare_pools::<T>(1);
+        prepare_good_accounts::<T>(1, &assets);
+        let end_block = prepare_farms::<T>(a, &assets[0]);
+        frame_system::Module::<T>::set_block_number(end_block);
+        let pool = pools.remove(0);
+        let account = utils::account::<T>(0);
+        let pool_tokens = PoolProviders::<T>::get(&pool, &account).unwrap();
+        let total_issuance = pool_xyk::TotalIssuances::<T>::get(&pool).unwrap();
     }: {
-        Module::<T>::save_data(T::VESTING_FREQUENCY);
+        Module::<T>::on_pool_tokens_changed(&pool, &account, pool_tokens, total_issuance);
+    }
+
+    create_farm {
+        let (_pools, assets) = prepare_pools::<T>(1);
+        prepare_farms::<T>(MAX_FARMS_PER_POOL - 1, &assets[0]);
+        let start_block = frame_system::Module::<T>::block_number();
+        let farm_id = NextFarmId::<T>::get();
+    }: _(
+        signed_origin::<T>(asset_owner::<T>()),
+        XOR.into(),
+        assets[0].clone(),
+        assets[0].clone(),
+        balance!(1),
+        start_block,
+        start_block + 1000u32.into()
+    )
+    verify {
+        assert!(Farms::<T>::contains_key(farm_id));
+    }
+
+    claim_farm_reward {
+        let (_pools, assets) = prepare_pools::<T>(1);
+        prepare_good_accounts::<T>(1, &assets);
+        let end_block = prepare_farms::<T>(1, &assets[0]);
+        frame_system::Module::<T>::set_block_number(end_block);
+        let account = utils::account::<T>(0);
+    }: _(signed_origin::<T>(account.clone()), 0)
+    verify {
+        assert!(FarmerRewards::<T>::get(0, &account).is_zero());
+    }
+
+    withdraw_farm_remainder {
+        let (_pools, assets) = prepare_pools::<T>(1);
+        let end_block = prepare_farms::<T>(1, &assets[0]);
+        frame_system::Module::<T>::set_block_number(end_block);
+    }: _(signed_origin::<T>(asset_owner::<T>()), 0)
+    verify {
+        assert!(Farms::<T>::get(0).unwrap().undistributed_reward.is_zero());
+    }
+
+    close_farm {
+        let (_pools, assets) = prepare_pools::<T>(1);
+        prepare_good_accounts::<T>(1, &assets);
+        let end_block = prepare_farms::<T>(1, &assets[0]);
+        frame_system::Module::<T>::set_block_number(end_block + FARM_CLAIM_PERIOD.into());
+    }: _(signed_origin::<T>(asset_owner::<T>()), 0)
+    verify {
+        assert!(!Farms::<T>::contains_key(0));
+        assert!(FarmerRewardPerSharePaid::<T>::iter_prefix(0).next().is_none());
     }
 }
 
@@ -185,7 +269,11 @@ mod tests {
             assert_ok!(test_benchmark_refresh_pool::<Runtime>());
             assert_ok!(test_benchmark_prepare_accounts_for_vesting::<Runtime>());
             assert_ok!(test_benchmark_vest_account_rewards::<Runtime>());
-            assert_ok!(test_benchmark_save_data::<Runtime>());
+            assert_ok!(test_benchmark_on_pool_tokens_changed::<Runtime>());
+            assert_ok!(test_benchmark_create_farm::<Runtime>());
+            assert_ok!(test_benchmark_claim_farm_reward::<Runtime>());
+            assert_ok!(test_benchmark_withdraw_farm_remainder::<Runtime>());
+            assert_ok!(test_benchmark_close_farm::<Runtime>());
         });
     }
 }
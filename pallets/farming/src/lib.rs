@@ -41,28 +41,47 @@ mod tests;
 mod utils;
 mod weights;
 
+use assets::AssetIdOf;
 use codec::{Decode, Encode};
-use common::RewardReason;
-use frame_support::dispatch::DispatchResult;
+use common::{FromGenericPair, RewardReason};
+use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
-use pool_xyk::PoolProviders;
+use pool_xyk::{LiquidityLock, PoolProviders, MAX_LIQUIDITY_LOCK_DURATION};
 use sp_arithmetic::traits::UniqueSaturatedInto;
-use sp_runtime::traits::Saturating;
+use sp_runtime::helpers_128bit::multiply_by_rational;
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::collections::btree_map::{BTreeMap, Entry};
 use sp_std::vec::Vec;
 
 use common::prelude::FixedWrapper;
-use common::{balance, AccountIdOf, Balance, DexIdOf, OnPoolCreated};
+use common::{balance, AccountIdOf, Balance, DexIdOf, OnPoolCreated, OnPoolTokensChanged};
 
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
+pub type FarmId = u32;
+pub type FarmInfoOf<T> = FarmInfo<AccountIdOf<T>, AssetIdOf<T>, BlockNumberFor<T>>;
+
+pub const TECH_ACCOUNT_PREFIX: &[u8] = b"farming";
+pub const TECH_ACCOUNT_FARMS: &[u8] = b"farms";
+/// Maximal number of farms of the pool, both running and finished, until they are closed.
+pub const MAX_FARMS_PER_POOL: u32 = 10;
+/// Number of blocks farmers have to claim their rewards after the farm has ended, before the farm
+/// owner is able to close the farm.
+pub const FARM_CLAIM_PERIOD: u32 = 432000;
+/// Multiplier of the farm reward per pool token, so that small rewards distributed among large pool
+/// tokens issuance are not rounded down to zero.
+pub const REWARD_PER_SHARE_PRECISION: Balance = 1_000_000_000_000_000_000;
 
 pub trait WeightInfo {
     fn refresh_pool(a: u32) -> Weight;
     fn prepare_accounts_for_vesting(a: u32, b: u32) -> Weight;
     fn vest_account_rewards(a: u32) -> Weight;
-    fn save_data(a: u32, b: u32) -> Weight;
+    fn on_pool_tokens_changed(a: u32) -> Weight;
+    fn create_farm() -> Weight;
+    fn claim_farm_reward() -> Weight;
+    fn withdraw_farm_remainder() -> Weight;
+    fn close_farm() -> Weight;
 }
 
 impl<T: Config> OnPoolCreated for Pallet<T> {
@@ -74,12 +93,55 @@ impl<T: Config> OnPoolCreated for Pallet<T> {
         _dex_id: Self::DEXId,
         pool_account: Self::AccountId,
     ) -> DispatchResult {
-        Self::add_pool(pool_account, frame_system::Module::<T>::block_number());
+        // Farming weights are valued in XOR, so only pools quoted in XOR are farmed.
+        if Self::is_xor_pool(&pool_account) {
+            Self::add_pool(pool_account, frame_system::Module::<T>::block_number());
+        }
         Ok(())
     }
 }
 
+impl<T: Config> OnPoolTokensChanged<AccountIdOf<T>> for Pallet<T> {
+    /// Accumulates rewards of the pool farms up to the current block with the pool tokens issuance
+    /// before the change and settles the rewards of the account for its pool tokens before the change.
+    fn on_pool_tokens_changed(
+        pool_account: &AccountIdOf<T>,
+        account_id: &AccountIdOf<T>,
+        old_pool_tokens: Balance,
+        old_total_issuance: Balance,
+    ) {
+        let now = frame_system::Module::<T>::block_number();
+        let new_pool_tokens = PoolProviders::<T>::get(pool_account, account_id).unwrap_or(0);
+        for farm_id in FarmsByPool::<T>::get(pool_account) {
+            Farms::<T>::mutate(farm_id, |farm| {
+                if let Some(farm) = farm {
+                    Self::update_farm(farm, now, old_total_issuance);
+                    Self::update_farmer_reward(
+                        farm_id,
+                        farm,
+                        account_id,
+                        old_pool_tokens,
+                        new_pool_tokens,
+                    );
+                }
+            });
+        }
+    }
+
+    fn on_pool_tokens_changed_weight() -> Weight {
+        WeightInfoOf::<T>::on_pool_tokens_changed(MAX_FARMS_PER_POOL)
+    }
+}
+
 impl<T: Config> Pallet<T> {
+    fn is_xor_pool(pool_account: &AccountIdOf<T>) -> bool {
+        pool_xyk::Module::<T>::get_pool_trading_pair(pool_account)
+            .map(|trading_pair| {
+                trading_pair.base_asset_id == <T as assets::Config>::GetBaseAssetId::get()
+            })
+            .unwrap_or(false)
+    }
+
     fn add_pool(pool_account: AccountIdOf<T>, block_number: BlockNumberFor<T>) {
         Pools::<T>::mutate(block_number % T::REFRESH_FREQUENCY, |pools| {
             pools.push(pool_account)
@@ -96,14 +158,114 @@ impl<T: Config> Pallet<T> {
         total_weight
     }
 
+    /// Accumulates the reward of the farm per pool token from the last update up to the block `now`,
+    /// `total_pool_tokens` is the pool tokens issuance over this period. The reward is not accumulated
+    /// while the pool has no liquidity, so it remains undistributed.
+    fn update_farm(farm: &mut FarmInfoOf<T>, now: T::BlockNumber, total_pool_tokens: Balance) {
+        let to = now.min(farm.end_block);
+        if to <= farm.last_distribution_block {
+            return;
+        }
+        if !total_pool_tokens.is_zero() {
+            let blocks: u128 = (to - farm.last_distribution_block).unique_saturated_into();
+            let reward = farm
+                .reward_per_block
+                .saturating_mul(blocks)
+                .min(farm.undistributed_reward);
+            let reward_per_share =
+                multiply_by_rational(reward, REWARD_PER_SHARE_PRECISION, total_pool_tokens)
+                    .unwrap_or(0);
+            farm.reward_per_share = farm.reward_per_share.saturating_add(reward_per_share);
+            farm.undistributed_reward = farm.undistributed_reward.saturating_sub(reward);
+            farm.unclaimed_reward = farm.unclaimed_reward.saturating_add(reward);
+        }
+        farm.last_distribution_block = to;
+    }
+
+    /// Settles the reward the farmer has accumulated in the farm with `old_pool_tokens` and starts
+    /// accumulating the reward for `new_pool_tokens`. The farm must be updated up to the current block.
+    fn update_farmer_reward(
+        farm_id: FarmId,
+        farm: &FarmInfoOf<T>,
+        account_id: &T::AccountId,
+        old_pool_tokens: Balance,
+        new_pool_tokens: Balance,
+    ) {
+        let reward = Self::unsettled_farmer_reward(farm_id, farm, account_id, old_pool_tokens);
+        if !reward.is_zero() {
+            FarmerRewards::<T>::mutate(farm_id, account_id, |farmer_reward| {
+                *farmer_reward = farmer_reward.saturating_add(reward)
+            });
+        }
+        if new_pool_tokens.is_zero() {
+            FarmerRewardPerSharePaid::<T>::remove(farm_id, account_id);
+        } else {
+            FarmerRewardPerSharePaid::<T>::insert(farm_id, account_id, farm.reward_per_share);
+        }
+    }
+
+    /// Reward accumulated by the farmer in the farm and not claimed yet, including the reward for the
+    /// blocks since the last update of the farm.
+    pub fn farmer_pending_reward(farm_id: FarmId, account_id: &T::AccountId) -> Balance {
+        let mut farm = if let Some(farm) = Farms::<T>::get(farm_id) {
+            farm
+        } else {
+            return 0;
+        };
+        let now = frame_system::Module::<T>::block_number();
+        let total_pool_tokens = pool_xyk::TotalIssuances::<T>::get(&farm.pool).unwrap_or(0);
+        Self::update_farm(&mut farm, now, total_pool_tokens);
+        let pool_tokens = PoolProviders::<T>::get(&farm.pool, account_id).unwrap_or(0);
+        FarmerRewards::<T>::get(farm_id, account_id).saturating_add(Self::unsettled_farmer_reward(
+            farm_id,
+            &farm,
+            account_id,
+            pool_tokens,
+        ))
+    }
+
+    /// Reward accumulated in the farm for `pool_tokens` of the farmer since its last settlement.
+    fn unsettled_farmer_reward(
+        farm_id: FarmId,
+        farm: &FarmInfoOf<T>,
+        account_id: &T::AccountId,
+        pool_tokens: Balance,
+    ) -> Balance {
+        let paid_reward_per_share = FarmerRewardPerSharePaid::<T>::get(farm_id, account_id);
+        multiply_by_rational(
+            pool_tokens,
+            farm.reward_per_share.saturating_sub(paid_reward_per_share),
+            REWARD_PER_SHARE_PRECISION,
+        )
+        .unwrap_or(0)
+    }
+
+    fn farms_tech_account_id() -> T::TechAccountId {
+        T::TechAccountId::from_generic_pair(
+            TECH_ACCOUNT_PREFIX.to_vec(),
+            TECH_ACCOUNT_FARMS.to_vec(),
+        )
+    }
+
+    fn ensure_farms_tech_account_registered() -> DispatchResult {
+        let tech_account_id = Self::farms_tech_account_id();
+        let account_id = technical::Module::<T>::tech_account_id_to_account_id(&tech_account_id)?;
+        if technical::Module::<T>::lookup_tech_account_id(&account_id).is_err() {
+            technical::Module::<T>::register_tech_account_id(tech_account_id)?;
+        }
+        Ok(())
+    }
+
     fn refresh_pool(pool: T::AccountId, now: T::BlockNumber) -> u32 {
         let mut read_count = 0;
         let old_farmers = PoolFarmers::<T>::get(&pool);
         let mut new_farmers = Vec::new();
         for (account, pool_tokens) in PoolProviders::<T>::iter_prefix(&pool) {
             read_count += 1;
+            // expired locks don't affect the weight anymore, so they are cleaned up on refresh
+            pool_xyk::Module::<T>::remove_expired_liquidity_lock(&pool, &account);
 
-            let weight = Self::get_account_weight(&pool, pool_tokens);
+            let weight = Self::get_account_weight(&pool, &account, pool_tokens, now);
             if weight == 0 {
                 continue;
             }
@@ -129,13 +291,21 @@ impl<T: Config> Pallet<T> {
         read_count
     }
 
-    fn get_account_weight(pool: &T::AccountId, pool_tokens: Balance) -> Balance {
+    fn get_account_weight(
+        pool: &T::AccountId,
+        account: &T::AccountId,
+        pool_tokens: Balance,
+        now: T::BlockNumber,
+    ) -> Balance {
         let trading_pair =
             if let Ok(trading_pair) = pool_xyk::Module::<T>::get_pool_trading_pair(&pool) {
                 trading_pair
             } else {
                 return 0;
             };
+        if trading_pair.base_asset_id != <T as assets::Config>::GetBaseAssetId::get() {
+            return 0;
+        }
 
         let xor =
             pool_xyk::Module::<T>::get_xor_part_from_pool_account(pool, &trading_pair, pool_tokens)
@@ -148,6 +318,16 @@ impl<T: Config> Pallet<T> {
             .iter()
             .any(|asset_id| trading_pair.consists_of(asset_id));
 
+        let xor = if let Some(lock) = pool_xyk::Module::<T>::active_liquidity_lock(pool, account) {
+            let locked_part = FixedWrapper::from(lock.pool_tokens.min(pool_tokens))
+                / FixedWrapper::from(pool_tokens);
+            let multiplier = FixedWrapper::from(balance!(1))
+                + locked_part * (Self::get_lock_multiplier(&lock, now) - balance!(1));
+            (multiplier * xor).try_into_balance().unwrap_or(xor)
+        } else {
+            xor
+        };
+
         if pool_doubles_reward {
             xor * 2
         } else {
@@ -155,7 +335,22 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    #[allow(unused)]
+    /// The farming weight multiplier of locked pool tokens. It decreases linearly from 2 for the longest lock
+    /// to 1 when the lock expires.
+    pub fn get_lock_multiplier(
+        lock: &LiquidityLock<T::BlockNumber>,
+        now: T::BlockNumber,
+    ) -> FixedWrapper {
+        if lock.unlock_block <= now {
+            return FixedWrapper::from(balance!(1));
+        }
+        let remaining: u128 = (lock.unlock_block - now).unique_saturated_into();
+        let remaining = remaining.min(MAX_LIQUIDITY_LOCK_DURATION as u128);
+        FixedWrapper::from(balance!(1))
+            + FixedWrapper::from(balance!(remaining))
+                / FixedWrapper::from(balance!(MAX_LIQUIDITY_LOCK_DURATION))
+    }
+
     fn vest(now: T::BlockNumber) -> Weight {
         let mut accounts = BTreeMap::new();
         let function_weight: Weight = Self::prepare_accounts_for_vesting(now, &mut accounts);
@@ -166,7 +361,6 @@ impl<T: Config> Pallet<T> {
         function_weight
     }
 
-    #[allow(unused)]
     fn prepare_accounts_for_vesting(
         now: T::BlockNumber,
         accounts: &mut BTreeMap<T::AccountId, FixedWrapper>,
@@ -183,7 +377,6 @@ impl<T: Config> Pallet<T> {
         WeightInfoOf::<T>::prepare_accounts_for_vesting(pool_count, farmer_count)
     }
 
-    #[allow(unused)]
     fn prepare_pool_accounts_for_vesting(
         farmers: Vec<PoolFarmer<T>>,
         now: T::BlockNumber,
@@ -193,18 +386,8 @@ impl<T: Config> Pallet<T> {
             return;
         }
 
-        let now_u128: u128 = now.unique_saturated_into();
         for farmer in farmers {
-            // Ti
-            let farmer_farming_time: u32 = (now - farmer.block).unique_saturated_into();
-            let farmer_farming_time = FixedWrapper::from(balance!(farmer_farming_time));
-
-            // Vi(t)
-            let coeff = (FixedWrapper::from(balance!(1))
-                + farmer_farming_time / FixedWrapper::from(balance!(now_u128)))
-            .pow(T::VESTING_COEFF);
-
-            let weight = coeff * farmer.weight;
+            let weight = Self::get_farmer_vesting_weight(&farmer, now);
             match accounts.entry(farmer.account) {
                 Entry::Vacant(entry) => {
                     entry.insert(weight);
@@ -216,21 +399,39 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    #[allow(unused)]
+    /// The weight of the farmer in the vesting, namely the farmer weight in the pool multiplied by Vi(t)
+    fn get_farmer_vesting_weight(farmer: &PoolFarmer<T>, now: T::BlockNumber) -> FixedWrapper {
+        let now_u128: u128 = now.unique_saturated_into();
+
+        // Ti
+        let farmer_farming_time: u32 = (now - farmer.block).unique_saturated_into();
+        let farmer_farming_time = FixedWrapper::from(balance!(farmer_farming_time));
+
+        // Vi(t)
+        let coeff = (FixedWrapper::from(balance!(1))
+            + farmer_farming_time / FixedWrapper::from(balance!(now_u128)))
+        .pow(T::VESTING_COEFF);
+
+        coeff * farmer.weight
+    }
+
+    /// PSWAP distributed among farmers each VESTING_FREQUENCY blocks
+    fn get_vesting_reward() -> FixedWrapper {
+        let reward_per_day = FixedWrapper::from(T::PSWAP_PER_DAY);
+        let freq: u128 = T::VESTING_FREQUENCY.unique_saturated_into();
+        let blocks: u128 = T::BLOCKS_PER_DAY.unique_saturated_into();
+        let reward_vesting_part =
+            FixedWrapper::from(balance!(freq)) / FixedWrapper::from(balance!(blocks));
+        reward_per_day * reward_vesting_part
+    }
+
     fn vest_account_rewards(accounts: BTreeMap<T::AccountId, FixedWrapper>) {
         let mut total_weight = FixedWrapper::from(0);
         for weight in accounts.values() {
             total_weight = total_weight + weight.clone();
         }
 
-        let reward = {
-            let reward_per_day = FixedWrapper::from(T::PSWAP_PER_DAY);
-            let freq: u128 = T::VESTING_FREQUENCY.unique_saturated_into();
-            let blocks: u128 = T::BLOCKS_PER_DAY.unique_saturated_into();
-            let reward_vesting_part =
-                FixedWrapper::from(balance!(freq)) / FixedWrapper::from(balance!(blocks));
-            reward_per_day * reward_vesting_part
-        };
+        let reward = Self::get_vesting_reward();
 
         for (account, weight) in accounts {
             let account_reward = reward.clone() * weight / total_weight.clone();
@@ -243,35 +444,70 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    fn save_data(now: T::BlockNumber) -> Weight {
-        let mut values = Vec::new();
-        let mut pool_count = 0;
-        let mut farmer_count = 0;
-        for (pool, farmers) in PoolFarmers::<T>::iter() {
-            pool_count += 1;
-            farmer_count += farmers.len() as u32;
-
-            values.push((pool, farmers));
+    /// Estimates PSWAP the account would get from the pool if the farming rewards were vested at the current block.
+    pub fn accrued_reward(account_id: &T::AccountId, pool: &T::AccountId) -> Balance {
+        let now = frame_system::Module::<T>::block_number();
+        if now.is_zero() {
+            return 0;
         }
 
-        SavedValues::<T>::insert(now, values);
+        let mut total_weight = FixedWrapper::from(0);
+        let mut account_weight = FixedWrapper::from(0);
+        for (farmers_pool, farmers) in PoolFarmers::<T>::iter() {
+            for farmer in farmers {
+                let weight = Self::get_farmer_vesting_weight(&farmer, now);
+                if &farmers_pool == pool && &farmer.account == account_id {
+                    account_weight = account_weight + weight.clone();
+                }
+                total_weight = total_weight + weight;
+            }
+        }
 
-        WeightInfoOf::<T>::save_data(pool_count, farmer_count)
+        (Self::get_vesting_reward() * account_weight / total_weight)
+            .try_into_balance()
+            .unwrap_or(0)
     }
 }
 
 pub use pallet::*;
 
+/// Farm created by user to reward the pool liquidity providers with arbitrary asset
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq)]
+pub struct FarmInfo<AccountId, AssetId, BlockNumber> {
+    /// The account that created and funded the farm
+    pub owner: AccountId,
+    /// The pool whose liquidity providers are rewarded
+    pub pool: AccountId,
+    /// The asset rewards are paid in
+    pub reward_asset: AssetId,
+    /// The reward distributed among farmers each block
+    pub reward_per_block: Balance,
+    /// The block rewards start to accumulate at
+    pub start_block: BlockNumber,
+    /// The block rewards stop to accumulate at
+    pub end_block: BlockNumber,
+    /// The block rewards have been accumulated up to
+    pub last_distribution_block: BlockNumber,
+    /// The reward accumulated per pool token since the farm start, multiplied by `REWARD_PER_SHARE_PRECISION`
+    pub reward_per_share: Balance,
+    /// The funded reward that has not been accumulated for farmers yet
+    pub undistributed_reward: Balance,
+    /// The reward accumulated for farmers that has not been claimed yet
+    pub unclaimed_reward: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use assets::AssetIdOf;
+    use common::hash;
     use frame_support::pallet_prelude::*;
     use frame_support::traits::schedule::Anon;
     use frame_support::traits::PalletVersion;
-    use frame_system::ensure_root;
+    use frame_support::transactional;
     use frame_system::pallet_prelude::OriginFor;
-    use sp_runtime::traits::Zero;
+    use frame_system::{ensure_root, ensure_signed};
+    use permissions::{Scope, CREATE_FARM};
 
     #[pallet::config]
     pub trait Config:
@@ -293,6 +529,7 @@ pub mod pallet {
         type SchedulerOriginCaller: From<frame_system::RawOrigin<Self::AccountId>>;
         type Scheduler: Anon<Self::BlockNumber, <Self as Config>::Call, Self::SchedulerOriginCaller>;
         type RewardDoublingAssets: Get<Vec<AssetIdOf<Self>>>;
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -311,13 +548,8 @@ pub mod pallet {
             let mut total_weight = Self::refresh_pools(now);
 
             if (now % T::VESTING_FREQUENCY).is_zero() {
-                // TBD: Remove for the next runtime upgrade
-                let weight = Self::save_data(now);
+                let weight = Self::vest(now);
                 total_weight = total_weight.saturating_add(weight);
-
-                // TBD: Uncomment for the next runtime upgrade
-                // let weight = Self::vest(now);
-                // total_weight = total_weight.saturating_add(weight);
             }
 
             total_weight
@@ -325,7 +557,11 @@ pub mod pallet {
 
         fn on_runtime_upgrade() -> Weight {
             match Self::storage_version() {
-                Some(PalletVersion { major: 0, .. }) | None => migrations::v1_1::migrate::<T>(),
+                Some(PalletVersion { major: 0, .. }) | None => migrations::v1_1::migrate::<T>()
+                    .saturating_add(migrations::v1_2::migrate::<T>()),
+                Some(PalletVersion {
+                    major: 1, minor, ..
+                }) if minor < 2 => migrations::v1_2::migrate::<T>(),
                 _ => 0,
             }
         }
@@ -339,12 +575,222 @@ pub mod pallet {
             let weight = migrations::v1_1::migrate::<T>();
             Ok(Some(weight).into())
         }
+
+        /// Create a farm rewarding liquidity providers of the pool with `reward_asset_id`.
+        ///
+        /// The whole reward of the farm, namely `reward_per_block` for each block from `start_block` until
+        /// `end_block`, is transferred from the farm owner when the farm is created. Requires `CREATE_FARM`
+        /// permission, either unlimited or limited to the pool.
+        #[pallet::weight(WeightInfoOf::<T>::create_farm())]
+        #[transactional]
+        pub fn create_farm(
+            origin: OriginFor<T>,
+            base_asset_id: AssetIdOf<T>,
+            target_asset_id: AssetIdOf<T>,
+            reward_asset_id: AssetIdOf<T>,
+            reward_per_block: Balance,
+            start_block: T::BlockNumber,
+            end_block: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let (pool, _) = pool_xyk::Properties::<T>::get(&base_asset_id, &target_asset_id)
+                .ok_or(Error::<T>::PoolDoesNotExist)?;
+            ensure!(
+                (FarmsByPool::<T>::decode_len(&pool).unwrap_or(0) as u32) < MAX_FARMS_PER_POOL,
+                Error::<T>::TooManyFarms
+            );
+            permissions::Module::<T>::check_permission_with_scope(
+                who.clone(),
+                CREATE_FARM,
+                &Scope::Limited(hash(&pool)),
+            )?;
+            ensure!(
+                !reward_per_block.is_zero(),
+                Error::<T>::InvalidRewardPerBlock
+            );
+            let now = frame_system::Module::<T>::block_number();
+            ensure!(
+                start_block >= now && start_block < end_block,
+                Error::<T>::InvalidFarmPeriod
+            );
+            let blocks: u128 = (end_block - start_block).unique_saturated_into();
+            let total_reward = reward_per_block
+                .checked_mul(blocks)
+                .ok_or(Error::<T>::InvalidRewardPerBlock)?;
+
+            Self::ensure_farms_tech_account_registered()?;
+            technical::Module::<T>::transfer_in(
+                &reward_asset_id,
+                &who,
+                &Self::farms_tech_account_id(),
+                total_reward,
+            )?;
+
+            let farm_id = NextFarmId::<T>::get();
+            NextFarmId::<T>::put(farm_id.wrapping_add(1));
+            Farms::<T>::insert(
+                farm_id,
+                FarmInfo {
+                    owner: who.clone(),
+                    pool: pool.clone(),
+                    reward_asset: reward_asset_id,
+                    reward_per_block,
+                    start_block,
+                    end_block,
+                    last_distribution_block: start_block,
+                    reward_per_share: 0,
+                    undistributed_reward: total_reward,
+                    unclaimed_reward: 0,
+                },
+            );
+            FarmsByPool::<T>::append(&pool, farm_id);
+            Self::deposit_event(Event::FarmCreated(farm_id, who, pool));
+            Ok(().into())
+        }
+
+        /// Claim rewards accumulated by the farmer in the farm.
+        #[pallet::weight(WeightInfoOf::<T>::claim_farm_reward())]
+        #[transactional]
+        pub fn claim_farm_reward(
+            origin: OriginFor<T>,
+            farm_id: FarmId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let reward = Farms::<T>::try_mutate(farm_id, |farm| {
+                let farm = farm.as_mut().ok_or(Error::<T>::FarmDoesNotExist)?;
+                let now = frame_system::Module::<T>::block_number();
+                let total_pool_tokens = pool_xyk::TotalIssuances::<T>::get(&farm.pool).unwrap_or(0);
+                Self::update_farm(farm, now, total_pool_tokens);
+                let pool_tokens = PoolProviders::<T>::get(&farm.pool, &who).unwrap_or(0);
+                Self::update_farmer_reward(farm_id, farm, &who, pool_tokens, pool_tokens);
+                let reward = FarmerRewards::<T>::take(farm_id, &who);
+                ensure!(!reward.is_zero(), Error::<T>::NothingToClaim);
+                farm.unclaimed_reward = farm.unclaimed_reward.saturating_sub(reward);
+                technical::Module::<T>::transfer_out(
+                    &farm.reward_asset,
+                    &Self::farms_tech_account_id(),
+                    &who,
+                    reward,
+                )?;
+                Ok::<_, DispatchError>(reward)
+            })?;
+            Self::deposit_event(Event::FarmRewardClaimed(farm_id, who, reward));
+            Ok(().into())
+        }
+
+        /// Withdraw the reward that has not been distributed among farmers, e.g. because the pool had no
+        /// liquidity providers for some time. Available to the farm owner once the farm has ended.
+        #[pallet::weight(WeightInfoOf::<T>::withdraw_farm_remainder())]
+        #[transactional]
+        pub fn withdraw_farm_remainder(
+            origin: OriginFor<T>,
+            farm_id: FarmId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let remainder = Farms::<T>::try_mutate(farm_id, |farm| {
+                let farm = farm.as_mut().ok_or(Error::<T>::FarmDoesNotExist)?;
+                ensure!(farm.owner == who, Error::<T>::NotFarmOwner);
+                let now = frame_system::Module::<T>::block_number();
+                ensure!(now >= farm.end_block, Error::<T>::FarmNotFinished);
+                let total_pool_tokens = pool_xyk::TotalIssuances::<T>::get(&farm.pool).unwrap_or(0);
+                Self::update_farm(farm, now, total_pool_tokens);
+                ensure!(
+                    !farm.undistributed_reward.is_zero(),
+                    Error::<T>::NothingToClaim
+                );
+                let remainder = farm.undistributed_reward;
+                farm.undistributed_reward = 0;
+                technical::Module::<T>::transfer_out(
+                    &farm.reward_asset,
+                    &Self::farms_tech_account_id(),
+                    &who,
+                    remainder,
+                )?;
+                Ok::<_, DispatchError>(remainder)
+            })?;
+            Self::deposit_event(Event::FarmRemainderWithdrawn(farm_id, who, remainder));
+            Ok(().into())
+        }
+
+        /// Close the farm, withdrawing both undistributed and unclaimed rewards, so that the pool can get
+        /// new farms. Available to the farm owner once `FARM_CLAIM_PERIOD` blocks have passed since the farm
+        /// has ended, rewards not claimed by farmers by then are lost for them.
+        #[pallet::weight(WeightInfoOf::<T>::close_farm())]
+        #[transactional]
+        pub fn close_farm(origin: OriginFor<T>, farm_id: FarmId) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let mut farm = Farms::<T>::get(farm_id).ok_or(Error::<T>::FarmDoesNotExist)?;
+            ensure!(farm.owner == who, Error::<T>::NotFarmOwner);
+            let now = frame_system::Module::<T>::block_number();
+            ensure!(
+                now >= farm.end_block.saturating_add(FARM_CLAIM_PERIOD.into()),
+                Error::<T>::FarmClaimPeriodNotFinished
+            );
+            let total_pool_tokens = pool_xyk::TotalIssuances::<T>::get(&farm.pool).unwrap_or(0);
+            Self::update_farm(&mut farm, now, total_pool_tokens);
+            let remainder = farm
+                .undistributed_reward
+                .saturating_add(farm.unclaimed_reward);
+            if !remainder.is_zero() {
+                technical::Module::<T>::transfer_out(
+                    &farm.reward_asset,
+                    &Self::farms_tech_account_id(),
+                    &who,
+                    remainder,
+                )?;
+            }
+            Farms::<T>::remove(farm_id);
+            FarmerRewards::<T>::remove_prefix(farm_id);
+            FarmerRewardPerSharePaid::<T>::remove_prefix(farm_id);
+            FarmsByPool::<T>::mutate_exists(&farm.pool, |farm_ids| {
+                if let Some(ids) = farm_ids {
+                    ids.retain(|id| *id != farm_id);
+                    if ids.is_empty() {
+                        *farm_ids = None;
+                    }
+                }
+            });
+            Self::deposit_event(Event::FarmClosed(farm_id, who, remainder));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::metadata(AccountIdOf<T> = "AccountId")]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Farm was created. [farm, owner, pool]
+        FarmCreated(FarmId, AccountIdOf<T>, AccountIdOf<T>),
+        /// Farmer claimed the farm reward. [farm, farmer, amount]
+        FarmRewardClaimed(FarmId, AccountIdOf<T>, Balance),
+        /// Farm owner withdrew the undistributed reward. [farm, owner, amount]
+        FarmRemainderWithdrawn(FarmId, AccountIdOf<T>, Balance),
+        /// Farm was closed, its remaining reward was withdrawn by the owner. [farm, owner, amount]
+        FarmClosed(FarmId, AccountIdOf<T>, Balance),
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Increment account reference error.
         IncRefError,
+        /// There is no pool for the given pair of assets.
+        PoolDoesNotExist,
+        /// Reward per block is zero or the total reward of the farm overflows.
+        InvalidRewardPerBlock,
+        /// Start block is in the past or is not before end block.
+        InvalidFarmPeriod,
+        /// There is no farm with the given id.
+        FarmDoesNotExist,
+        /// The account is not the owner of the farm.
+        NotFarmOwner,
+        /// The farm has not ended yet.
+        FarmNotFinished,
+        /// There is no reward to claim.
+        NothingToClaim,
+        /// The pool has the maximal number of farms, finished farms should be closed first.
+        TooManyFarms,
+        /// The farm has ended recently, so farmers can still claim rewards.
+        FarmClaimPeriodNotFinished,
     }
 
     /// Pools whose farmers are refreshed at the specific block. Block => Pools
@@ -357,12 +803,42 @@ pub mod pallet {
     pub type PoolFarmers<T: Config> =
         StorageMap<_, Identity, T::AccountId, Vec<PoolFarmer<T>>, ValueQuery>;
 
+    /// The id the next created farm gets
+    #[pallet::storage]
+    pub type NextFarmId<T: Config> = StorageValue<_, FarmId, ValueQuery>;
+
+    /// User-created farms. Farm => Farm info
+    #[pallet::storage]
+    #[pallet::getter(fn farm)]
+    pub type Farms<T: Config> = StorageMap<_, Twox64Concat, FarmId, FarmInfoOf<T>>;
+
+    /// Farms of the pool that are not closed yet, at most `MAX_FARMS_PER_POOL`. Pool => Farms
+    #[pallet::storage]
+    pub type FarmsByPool<T: Config> =
+        StorageMap<_, Identity, T::AccountId, Vec<FarmId>, ValueQuery>;
+
+    /// Rewards settled for farmers in farms on their pool tokens changes and not claimed yet. Farm => Farmer => Reward
+    #[pallet::storage]
+    #[pallet::getter(fn farmer_reward)]
+    pub type FarmerRewards<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        FarmId,
+        Blake2_128Concat,
+        T::AccountId,
+        Balance,
+        ValueQuery,
+    >;
+
+    /// Reward per pool token of the farm the farmer has been rewarded up to. Farm => Farmer => Reward per share
     #[pallet::storage]
-    pub type SavedValues<T: Config> = StorageMap<
+    pub type FarmerRewardPerSharePaid<T: Config> = StorageDoubleMap<
         _,
-        Identity,
-        T::BlockNumber,
-        Vec<(T::AccountId, Vec<PoolFarmer<T>>)>,
+        Twox64Concat,
+        FarmId,
+        Blake2_128Concat,
+        T::AccountId,
+        Balance,
         ValueQuery,
     >;
 }
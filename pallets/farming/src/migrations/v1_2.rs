@@ -0,0 +1,140 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use common::FromGenericPair;
+use frame_support::dispatch::Weight;
+use frame_support::print;
+use frame_support::storage::migration::StorageIterator;
+use frame_support::traits::{Get, PalletInfo};
+
+use crate::{Config, Module};
+
+pub fn migrate<T: Config>() -> Weight {
+    remove_saved_values::<T>().saturating_add(init_farming_rewards_account::<T>())
+}
+
+/// Removes `SavedValues` that temporarily stored pool farmers at vesting blocks before
+/// the rewards vesting was enabled.
+fn remove_saved_values<T: Config>() -> Weight {
+    let pallet_name = match <T as frame_system::Config>::PalletInfo::name::<Module<T>>() {
+        Some(pallet_name) => pallet_name,
+        None => return 0,
+    };
+    let removed_count = StorageIterator::<()>::new(pallet_name.as_bytes(), b"SavedValues")
+        .drain()
+        .count() as Weight;
+    T::DbWeight::get().reads_writes(removed_count, removed_count)
+}
+
+/// Registers the technical account farming rewards are claimed from, as it's done in the genesis
+/// for new networks. The account is funded with PSWAP by a transfer approved by governance.
+fn init_farming_rewards_account<T: Config>() -> Weight {
+    let tech_account_id = T::TechAccountId::from_generic_pair(
+        vested_rewards::TECH_ACCOUNT_PREFIX.to_vec(),
+        vested_rewards::TECH_ACCOUNT_FARMING.to_vec(),
+    );
+    let account_id = match technical::Module::<T>::tech_account_id_to_account_id(&tech_account_id) {
+        Ok(account_id) => account_id,
+        Err(_) => {
+            print("farming migration to v1.2 failed to get farming rewards account");
+            return 0;
+        }
+    };
+    if technical::Module::<T>::lookup_tech_account_id(&account_id).is_ok() {
+        // already registered in the genesis
+        return T::DbWeight::get().reads(1);
+    }
+    if technical::Module::<T>::register_tech_account_id(tech_account_id).is_err() {
+        print("farming migration to v1.2 failed to register farming rewards account");
+        return T::DbWeight::get().reads(1);
+    }
+    T::DbWeight::get().reads_writes(2, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use codec::Encode;
+    use common::{FromGenericPair, PSWAP};
+    use frame_support::storage::migration::{get_storage_value, put_storage_value};
+
+    use crate::mock::{ExtBuilder, Runtime, TechAccountId, VESTING_FREQUENCY};
+
+    #[test]
+    fn saved_values_are_removed() {
+        ExtBuilder::default().build().execute_with(|| {
+            let key = VESTING_FREQUENCY.encode();
+            put_storage_value(b"Farming", b"SavedValues", &key, 1u32);
+            put_storage_value(
+                b"Farming",
+                b"SavedValues",
+                &(VESTING_FREQUENCY * 2).encode(),
+                2u32,
+            );
+
+            super::migrate::<Runtime>();
+
+            assert_eq!(
+                get_storage_value::<u32>(b"Farming", b"SavedValues", &key),
+                None
+            );
+            assert_eq!(
+                get_storage_value::<u32>(
+                    b"Farming",
+                    b"SavedValues",
+                    &(VESTING_FREQUENCY * 2).encode()
+                ),
+                None
+            );
+        });
+    }
+    #[test]
+    fn farming_rewards_account_is_registered() {
+        ExtBuilder::default().build().execute_with(|| {
+            let tech_account_id = TechAccountId::from_generic_pair(
+                vested_rewards::TECH_ACCOUNT_PREFIX.to_vec(),
+                vested_rewards::TECH_ACCOUNT_FARMING.to_vec(),
+            );
+            let account_id =
+                technical::Module::<Runtime>::tech_account_id_to_account_id(&tech_account_id)
+                    .unwrap();
+
+            super::migrate::<Runtime>();
+            assert_eq!(
+                technical::Module::<Runtime>::lookup_tech_account_id(&account_id).unwrap(),
+                tech_account_id
+            );
+            // No PSWAP is minted by the migration
+            assert_eq!(
+                assets::Module::<Runtime>::free_balance(&PSWAP, &account_id).unwrap(),
+                0
+            );
+        });
+    }
+}
@@ -124,7 +124,7 @@ construct_runtime! {
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
         Permissions: permissions::{Module, Call, Config<T>, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Module, Call, Config<T>, Storage, Event<T>},
         TradingPair: trading_pair::{Module, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Storage, Event<T>},
         Tokens: tokens::{Module, Call, Config<T>, Storage, Event<T>},
@@ -137,7 +137,7 @@ construct_runtime! {
         VestedRewards: vested_rewards::{Module, Storage, Event<T>},
         Scheduler: pallet_scheduler::{Module, Call, Storage, Event<T>},
 
-        Farming: farming::{Module, Call, Storage},
+        Farming: farming::{Module, Call, Storage, Event<T>},
     }
 }
 
@@ -168,9 +168,14 @@ impl frame_system::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -231,6 +236,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
 impl pool_xyk::Config for Runtime {
@@ -246,6 +252,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = (PswapDistribution, Farming);
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = Farming;
     type WeightInfo = ();
 }
 
@@ -262,6 +269,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = ();
 }
 
 impl multicollateral_bonding_curve_pool::Config for Runtime {
@@ -270,6 +278,8 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
     type EnsureTradingPairExists = trading_pair::Module<Runtime>;
     type EnsureDEXManager = dex_manager::Module<Runtime>;
     type PriceToolsPallet = ();
+    type PriceOracle = ();
+    type CircuitBreaker = ();
     type VestedRewardsPallet = VestedRewards;
     type WeightInfo = ();
 }
@@ -277,6 +287,7 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
 impl vested_rewards::Config for Runtime {
     type Event = Event;
     type GetMarketMakerRewardsAccountId = ();
+    type GetFarmingRewardsAccountId = ();
     type GetBondingCurveRewardsAccountId = ();
     type WeightInfo = ();
 }
@@ -302,6 +313,7 @@ impl Config for Runtime {
     type SchedulerOriginCaller = OriginCaller;
     type Scheduler = Scheduler;
     type RewardDoublingAssets = RewardDoublingAssets;
+    type Event = Event;
     type WeightInfo = ();
 }
 
@@ -28,16 +28,21 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 
-use common::{balance, DOT, PSWAP, XOR};
-use pool_xyk::Properties;
+use common::{balance, RewardReason, DOT, PSWAP, XOR};
+use pool_xyk::{PoolProviders, Properties};
 
 use crate::mock::{
-    self, AssetId, ExtBuilder, Origin, Runtime, ALICE, BOB, CHARLIE, DEX_A_ID, REFRESH_FREQUENCY,
-    VESTING_FREQUENCY,
+    self, AccountId, AssetId, ExtBuilder, Origin, Runtime, ALICE, BOB, CHARLIE, DEX_A_ID,
+    REFRESH_FREQUENCY, VESTING_FREQUENCY,
 };
-use crate::{PoolFarmer, PoolFarmers, SavedValues};
+use crate::{
+    Error, FarmerRewardPerSharePaid, FarmerRewards, Farms, FarmsByPool, PoolFarmer, PoolFarmers,
+    Pools, FARM_CLAIM_PERIOD, MAX_FARMS_PER_POOL,
+};
+
+type Farming = crate::Module<Runtime>;
 
 fn init_pool(other_asset: AssetId) {
     assert_ok!(trading_pair::Module::<Runtime>::register(
@@ -208,14 +213,11 @@ fn test() {
 
         mock::run_to_block(VESTING_FREQUENCY);
 
-        // TBD: Remove for the next release
-        let values = SavedValues::<Runtime>::get(VESTING_FREQUENCY);
-        assert_eq!(values.len(), 2);
-        let (dot_values, pswap_values) = values.split_at(1);
-        assert_eq!(
-            dot_values,
-            &[(
-                dot_pool,
+        // Check that the vesting uses farmers after the refresh at the same block
+        {
+            let farmers = PoolFarmers::<Runtime>::get(&dot_pool);
+            assert_eq!(
+                farmers,
                 vec![
                     PoolFarmer {
                         account: ALICE(),
@@ -228,12 +230,11 @@ fn test() {
                         weight: balance!(1.099999999999999998),
                     },
                 ]
-            )]
-        );
-        assert_eq!(
-            pswap_values,
-            &[(
-                pswap_pool,
+            );
+
+            let farmers = PoolFarmers::<Runtime>::get(&pswap_pool);
+            assert_eq!(
+                farmers,
                 vec![
                     PoolFarmer {
                         account: ALICE(),
@@ -246,88 +247,382 @@ fn test() {
                         weight: balance!(19.999999999999999950),
                     },
                 ]
-            )]
+            );
+        }
+
+        let farming_reward = |account: &AccountId| {
+            vested_rewards::Rewards::<Runtime>::get(account)
+                .rewards
+                .get(&RewardReason::LiquidityProvisionFarming)
+                .cloned()
+                .unwrap_or(0)
+        };
+        let alice_reward = farming_reward(&ALICE());
+        let bob_reward = farming_reward(&BOB());
+        let charlie_reward = farming_reward(&CHARLIE());
+
+        // Charlie has the biggest weight, Alice farms in both pools but has just joined the PSWAP pool
+        assert!(charlie_reward > alice_reward);
+        assert!(alice_reward > bob_reward);
+        assert!(bob_reward > 0);
+
+        // The whole reward of the vesting period is distributed up to the rounding
+        let vested = alice_reward + bob_reward + charlie_reward;
+        let expected = balance!(103878.116343490304709141);
+        assert!(vested <= expected);
+        assert!(expected - vested < balance!(0.000000001));
+        assert_eq!(vested_rewards::TotalRewards::<Runtime>::get(), vested);
+
+        // The accrued reward estimate matches the vested reward at the vesting block
+        let alice_accrued = Farming::accrued_reward(&ALICE(), &dot_pool)
+            + Farming::accrued_reward(&ALICE(), &pswap_pool);
+        assert!(alice_accrued <= alice_reward + 1 && alice_reward <= alice_accrued + 1);
+        assert_eq!(Farming::accrued_reward(&BOB(), &dot_pool), bob_reward);
+        assert_eq!(Farming::accrued_reward(&BOB(), &pswap_pool), 0);
+
+        // Nothing is vested until the next vesting block
+        mock::run_to_block(VESTING_FREQUENCY + REFRESH_FREQUENCY);
+        assert_eq!(farming_reward(&BOB()), bob_reward);
+
+        mock::run_to_block(VESTING_FREQUENCY * 2);
+        assert!(farming_reward(&BOB()) > bob_reward);
+    });
+}
+
+// Checks that user-created farm distributes its reward among liquidity providers of the pool proportionally to
+// their pool tokens over time, and the reward that hasn't been distributed can be withdrawn by the farm owner
+// after the farm ends.
+#[test]
+fn user_farm() {
+    let dex_id = DEX_A_ID;
+    ExtBuilder::default().build().execute_with(|| {
+        init_pool(DOT);
+        let dot_pool = Properties::<Runtime>::get(XOR, DOT).unwrap().0;
+
+        assert_noop!(
+            Farming::create_farm(
+                Origin::signed(ALICE()),
+                XOR,
+                PSWAP,
+                PSWAP,
+                balance!(1),
+                0,
+                REFRESH_FREQUENCY * 2
+            ),
+            Error::<Runtime>::PoolDoesNotExist
+        );
+        assert_noop!(
+            Farming::create_farm(
+                Origin::signed(AccountId::from([9; 32])),
+                XOR,
+                DOT,
+                PSWAP,
+                balance!(1),
+                0,
+                REFRESH_FREQUENCY * 2
+            ),
+            permissions::Error::<Runtime>::Forbidden
+        );
+        assert_noop!(
+            Farming::create_farm(
+                Origin::signed(ALICE()),
+                XOR,
+                DOT,
+                PSWAP,
+                0,
+                0,
+                REFRESH_FREQUENCY * 2
+            ),
+            Error::<Runtime>::InvalidRewardPerBlock
+        );
+        assert_noop!(
+            Farming::create_farm(
+                Origin::signed(ALICE()),
+                XOR,
+                DOT,
+                PSWAP,
+                balance!(1),
+                REFRESH_FREQUENCY,
+                REFRESH_FREQUENCY
+            ),
+            Error::<Runtime>::InvalidFarmPeriod
+        );
+
+        let alice_pswap = assets::Module::<Runtime>::free_balance(&PSWAP, &ALICE()).unwrap();
+        assert_ok!(Farming::create_farm(
+            Origin::signed(ALICE()),
+            XOR,
+            DOT,
+            PSWAP,
+            balance!(1),
+            0,
+            REFRESH_FREQUENCY * 2
+        ));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&PSWAP, &ALICE()).unwrap(),
+            alice_pswap - balance!(400)
+        );
+        assert_eq!(FarmsByPool::<Runtime>::get(&dot_pool), vec![0]);
+
+        // There are no liquidity providers during the first refresh period, so nothing is distributed
+        mock::run_to_block(REFRESH_FREQUENCY);
+        let deposit = |account: AccountId| {
+            assert_ok!(pool_xyk::Module::<Runtime>::deposit_liquidity(
+                Origin::signed(account),
+                dex_id,
+                XOR,
+                DOT,
+                balance!(1.1),
+                balance!(4.4),
+                balance!(1.1),
+                balance!(4.4),
+            ));
+        };
+        deposit(ALICE());
+        let farm = Farms::<Runtime>::get(0).unwrap();
+        assert_eq!(farm.last_distribution_block, REFRESH_FREQUENCY);
+        assert_eq!(farm.undistributed_reward, balance!(400));
+        deposit(BOB());
+
+        // Charlie provides liquidity just before the farm ends, so he gets the reward only for the last block
+        mock::run_to_block(REFRESH_FREQUENCY * 2 - 1);
+        deposit(CHARLIE());
+        mock::run_to_block(REFRESH_FREQUENCY * 2);
+        let alice_reward = Farming::farmer_pending_reward(0, &ALICE());
+        let bob_reward = Farming::farmer_pending_reward(0, &BOB());
+        let charlie_reward = Farming::farmer_pending_reward(0, &CHARLIE());
+        assert!(alice_reward > balance!(99.83) && alice_reward <= balance!(99.84));
+        assert!(bob_reward > balance!(99.83) && bob_reward <= balance!(99.84));
+        assert!(charlie_reward > balance!(0.33) && charlie_reward <= balance!(0.34));
+
+        // The reward doesn't grow after the farm has ended
+        mock::run_to_block(REFRESH_FREQUENCY * 2 + 1);
+        assert_eq!(Farming::farmer_pending_reward(0, &ALICE()), alice_reward);
+
+        let alice_pswap = assets::Module::<Runtime>::free_balance(&PSWAP, &ALICE()).unwrap();
+        assert_ok!(Farming::claim_farm_reward(Origin::signed(ALICE()), 0));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&PSWAP, &ALICE()).unwrap(),
+            alice_pswap + alice_reward
+        );
+        assert_noop!(
+            Farming::claim_farm_reward(Origin::signed(ALICE()), 0),
+            Error::<Runtime>::NothingToClaim
+        );
+        assert_noop!(
+            Farming::claim_farm_reward(Origin::signed(ALICE()), 1),
+            Error::<Runtime>::FarmDoesNotExist
+        );
+
+        // The reward of the first refresh period hasn't been distributed
+        assert_noop!(
+            Farming::withdraw_farm_remainder(Origin::signed(BOB()), 0),
+            Error::<Runtime>::NotFarmOwner
+        );
+        assert_ok!(Farming::withdraw_farm_remainder(Origin::signed(ALICE()), 0));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&PSWAP, &ALICE()).unwrap(),
+            alice_pswap + alice_reward + balance!(200)
+        );
+        assert_noop!(
+            Farming::withdraw_farm_remainder(Origin::signed(ALICE()), 0),
+            Error::<Runtime>::NothingToClaim
+        );
+
+        // Bob still can claim his reward after the remainder is withdrawn, also after he withdraws liquidity
+        assert_ok!(pool_xyk::Module::<Runtime>::withdraw_liquidity(
+            Origin::signed(BOB()),
+            dex_id,
+            XOR,
+            DOT,
+            PoolProviders::<Runtime>::get(&dot_pool, BOB()).unwrap(),
+            0,
+            0,
+        ));
+        assert_eq!(Farming::farmer_pending_reward(0, &BOB()), bob_reward);
+        let bob_pswap = assets::Module::<Runtime>::free_balance(&PSWAP, &BOB()).unwrap();
+        assert_ok!(Farming::claim_farm_reward(Origin::signed(BOB()), 0));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&PSWAP, &BOB()).unwrap(),
+            bob_pswap + bob_reward
+        );
+
+        // The farm is closed after the claim period, the rewards not claimed by then go to the owner
+        assert_noop!(
+            Farming::close_farm(Origin::signed(ALICE()), 0),
+            Error::<Runtime>::FarmClaimPeriodNotFinished
+        );
+        frame_system::Module::<Runtime>::set_block_number(
+            REFRESH_FREQUENCY * 2 + FARM_CLAIM_PERIOD as u64,
+        );
+        assert_noop!(
+            Farming::close_farm(Origin::signed(BOB()), 0),
+            Error::<Runtime>::NotFarmOwner
+        );
+        assert!(FarmerRewardPerSharePaid::<Runtime>::contains_key(
+            0,
+            CHARLIE()
+        ));
+        let alice_pswap = assets::Module::<Runtime>::free_balance(&PSWAP, &ALICE()).unwrap();
+        assert_ok!(Farming::close_farm(Origin::signed(ALICE()), 0));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&PSWAP, &ALICE()).unwrap(),
+            alice_pswap + balance!(200) - alice_reward - bob_reward
+        );
+        assert!(Farms::<Runtime>::get(0).is_none());
+        assert!(FarmsByPool::<Runtime>::get(&dot_pool).is_empty());
+        // Farmers' records of the closed farm are cleared
+        assert!(FarmerRewards::<Runtime>::iter_prefix(0).next().is_none());
+        assert!(FarmerRewardPerSharePaid::<Runtime>::iter_prefix(0)
+            .next()
+            .is_none());
+        assert_noop!(
+            Farming::claim_farm_reward(Origin::signed(CHARLIE()), 0),
+            Error::<Runtime>::FarmDoesNotExist
+        );
+    });
+}
+
+// Checks that the pool can't have more than MAX_FARMS_PER_POOL farms until finished farms are closed.
+#[test]
+fn user_farms_are_limited_per_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        init_pool(DOT);
+        let create_farm = || {
+            Farming::create_farm(
+                Origin::signed(ALICE()),
+                XOR,
+                DOT,
+                PSWAP,
+                balance!(1),
+                0,
+                REFRESH_FREQUENCY,
+            )
+        };
+        for _ in 0..MAX_FARMS_PER_POOL {
+            assert_ok!(create_farm());
+        }
+        assert_noop!(create_farm(), Error::<Runtime>::TooManyFarms);
+
+        frame_system::Module::<Runtime>::set_block_number(
+            REFRESH_FREQUENCY + FARM_CLAIM_PERIOD as u64,
+        );
+        assert_ok!(Farming::close_farm(Origin::signed(ALICE()), 0));
+        assert_ok!(Farming::create_farm(
+            Origin::signed(ALICE()),
+            XOR,
+            DOT,
+            PSWAP,
+            balance!(1),
+            REFRESH_FREQUENCY + FARM_CLAIM_PERIOD as u64,
+            REFRESH_FREQUENCY * 2 + FARM_CLAIM_PERIOD as u64,
+        ));
+    });
+}
+
+#[test]
+fn user_farm_cannot_be_withdrawn_before_end() {
+    ExtBuilder::default().build().execute_with(|| {
+        init_pool(DOT);
+
+        assert_ok!(Farming::create_farm(
+            Origin::signed(ALICE()),
+            XOR,
+            DOT,
+            PSWAP,
+            balance!(1),
+            0,
+            REFRESH_FREQUENCY * 2
+        ));
+
+        mock::run_to_block(REFRESH_FREQUENCY);
+        assert_noop!(
+            Farming::withdraw_farm_remainder(Origin::signed(ALICE()), 0),
+            Error::<Runtime>::FarmNotFinished
         );
+    });
+}
 
-        // TBD: Uncomment for the next release
-        // let info = Rewards::<Runtime>::get(&ALICE());
-        // assert_eq!(
-        //     *info
-        //         .rewards
-        //         .get(&RewardReason::LiquidityProvisionFarming)
-        //         .unwrap(),
-        //     balance!(34626.038781163425878113)
-        // );
-
-        // let info = Rewards::<Runtime>::get(&BOB());
-        // assert_eq!(
-        //     *info
-        //         .rewards
-        //         .get(&RewardReason::LiquidityProvisionFarming)
-        //         .unwrap(),
-        //     balance!(34626.038781163441621885)
-        // );
-
-        // assert_ok!(pool_xyk::Module::<Runtime>::deposit_liquidity(
-        //     Origin::signed(ALICE()),
-        //     dex_id,
-        //     XOR,
-        //     DOT,
-        //     balance!(0.5),
-        //     balance!(2),
-        //     balance!(0.3),
-        //     balance!(0.5),
-        // ));
-
-        // assert_ok!(pool_xyk::Module::<Runtime>::withdraw_liquidity(
-        //     Origin::signed(BOB()),
-        //     dex_id,
-        //     XOR,
-        //     DOT,
-        //     balance!(1.5),
-        //     balance!(0.5),
-        //     balance!(2),
-        // ));
-
-        // run_to_block(VESTING_FREQUENCY + REFRESH_FREQUENCY);
-
-        // let farmers = PoolFarmers::<Runtime>::get(&pool_account);
-        // assert_eq!(
-        //     farmers,
-        //     vec![
-        //         PoolFarmer {
-        //             account: ALICE(),
-        //             block: 200,
-        //             pool_tokens: balance!(3.199999999999998993),
-        //         },
-        //         PoolFarmer {
-        //             account: BOB(),
-        //             block: 200,
-        //             pool_tokens: balance!(0.699999999999999995),
-        //         }
-        //     ]
-        // );
-
-        // run_to_block(VESTING_FREQUENCY + VESTING_FREQUENCY);
-
-        // let info = Rewards::<Runtime>::get(&ALICE());
-        // // ALICE received all PSWAP
-        // assert_eq!(
-        //     *info
-        //         .rewards
-        //         .get(&RewardReason::LiquidityProvisionFarming)
-        //         .unwrap(),
-        //     balance!(103878.116343490293378112)
-        // );
-
-        // let info = Rewards::<Runtime>::get(&BOB());
-        // // BOB's rewards didn't change
-        // assert_eq!(
-        //     *info
-        //         .rewards
-        //         .get(&RewardReason::LiquidityProvisionFarming)
-        //         .unwrap(),
-        //     balance!(34626.038781163441621885)
-        // );
+// Checks that locked pool tokens boost the farming weight of the liquidity provider until the lock expires.
+#[test]
+fn non_xor_pools_are_not_farmed() {
+    ExtBuilder::default().build().execute_with(|| {
+        init_pool(DOT);
+        assert_ok!(trading_pair::Module::<Runtime>::register(
+            Origin::signed(BOB()),
+            DEX_A_ID,
+            DOT,
+            PSWAP
+        ));
+        assert_ok!(pool_xyk::Module::<Runtime>::initialize_pool(
+            Origin::signed(BOB()),
+            DEX_A_ID,
+            DOT,
+            PSWAP,
+        ));
+
+        let dot_pool = Properties::<Runtime>::get(XOR, DOT).unwrap().0;
+        let farmed_pools: Vec<AccountId> = Pools::<Runtime>::iter()
+            .flat_map(|(_, pools)| pools)
+            .collect();
+        assert_eq!(farmed_pools, vec![dot_pool]);
+    });
+}
+
+#[test]
+fn locked_liquidity_boosts_weight() {
+    let dex_id = DEX_A_ID;
+    ExtBuilder::default().build().execute_with(|| {
+        init_pool(DOT);
+        let dot_pool = Properties::<Runtime>::get(XOR, DOT).unwrap().0;
+
+        for account in [ALICE(), BOB()].iter() {
+            assert_ok!(pool_xyk::Module::<Runtime>::deposit_liquidity(
+                Origin::signed(account.clone()),
+                dex_id,
+                XOR,
+                DOT,
+                balance!(1.1),
+                balance!(4.4),
+                balance!(1.1),
+                balance!(4.4),
+            ));
+        }
+
+        let alice_pool_tokens = PoolProviders::<Runtime>::get(&dot_pool, &ALICE()).unwrap();
+        assert_ok!(pool_xyk::Module::<Runtime>::lock_liquidity(
+            Origin::signed(ALICE()),
+            dex_id,
+            XOR,
+            DOT,
+            alice_pool_tokens,
+            pool_xyk::MAX_LIQUIDITY_LOCK_DURATION as u64
+        ));
+
+        mock::run_to_block(REFRESH_FREQUENCY);
+
+        let farmers = PoolFarmers::<Runtime>::get(&dot_pool);
+        let alice_weight = farmers
+            .iter()
+            .find(|f| f.account == ALICE())
+            .unwrap()
+            .weight;
+        let bob_weight = farmers.iter().find(|f| f.account == BOB()).unwrap().weight;
+        assert!(alice_weight > bob_weight * 19 / 10);
+        assert!(alice_weight < bob_weight * 2);
+
+        // The boost is gone after the lock expires
+        frame_system::Module::<Runtime>::set_block_number(
+            pool_xyk::MAX_LIQUIDITY_LOCK_DURATION as u64,
+        );
+        mock::run_to_block(pool_xyk::MAX_LIQUIDITY_LOCK_DURATION as u64 + REFRESH_FREQUENCY);
+
+        let farmers = PoolFarmers::<Runtime>::get(&dot_pool);
+        let alice_weight = farmers
+            .iter()
+            .find(|f| f.account == ALICE())
+            .unwrap()
+            .weight;
+        assert!(alice_weight <= bob_weight);
     });
 }
@@ -62,15 +62,33 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
             .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(a as Weight)))
     }
-    fn save_data(a: u32, b: u32) -> Weight {
-        (0 as Weight)
-            // Standard Error: 107_000
-            .saturating_add((43_547_000 as Weight).saturating_mul(a as Weight))
-            // Standard Error: 75_000
-            .saturating_add((21_925_000 as Weight).saturating_mul(b as Weight))
-            .saturating_add(T::DbWeight::get().reads(1 as Weight))
-            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
-            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    fn on_pool_tokens_changed(a: u32) -> Weight {
+        (11_403_000 as Weight)
+            // Standard Error: 24_000
+            .saturating_add((27_126_000 as Weight).saturating_mul(a as Weight))
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(a as Weight)))
+            .saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(a as Weight)))
+    }
+    fn create_farm() -> Weight {
+        (143_582_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(10 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn claim_farm_reward() -> Weight {
+        (118_307_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(8 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn withdraw_farm_remainder() -> Weight {
+        (96_518_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn close_farm() -> Weight {
+        (104_276_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(7 as Weight))
+            .saturating_add(T::DbWeight::get().writes(6 as Weight))
     }
 }
 
@@ -87,7 +105,23 @@ impl crate::WeightInfo for () {
         EXTRINSIC_FIXED_WEIGHT
     }
 
-    fn save_data(_a: u32, _b: u32) -> Weight {
+    fn on_pool_tokens_changed(_a: u32) -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn create_farm() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn claim_farm_reward() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn withdraw_farm_remainder() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn close_farm() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
 }
@@ -32,7 +32,7 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use super::{Config, Event, Module, Pallet};
+use super::{AssetLimits, Config, Event, Module, Pallet, PowDifficulty};
 
 use codec::Decode;
 use frame_benchmarking::{benchmarks, Zero};
@@ -41,7 +41,7 @@ use hex_literal::hex;
 use sp_std::prelude::*;
 
 use common::eth::EthereumAddress;
-use common::{AssetName, AssetSymbol, Balance, XOR};
+use common::{balance, AssetName, AssetSymbol, Balance, VAL, XOR};
 use rewards::{PswapFarmOwners, PswapWaifuOwners, RewardInfo, ValOwners};
 
 use assets::Pallet as Assets;
@@ -92,6 +92,7 @@ benchmarks! {
         let n in 1 .. 1000 => add_assets::<T>(n)?;
         let caller = alice::<T>();
         let caller_origin: <T as frame_system::Config>::Origin = RawOrigin::Signed(caller.clone()).into();
+        AssetLimits::<T>::insert(T::AssetId::from(XOR), balance!(6000));
     }: {
         Pallet::<T>::transfer(
             caller_origin,
@@ -104,6 +105,25 @@ benchmarks! {
         assert_last_event::<T>(Event::Transferred(caller, 100_u32.into()).into())
     }
 
+    transfer_unsigned {
+        let caller = alice::<T>();
+        AssetLimits::<T>::insert(T::AssetId::from(XOR), balance!(6000));
+        PowDifficulty::<T>::put(0);
+        frame_system::Pallet::<T>::set_block_number(2u32.into());
+    }: {
+        Pallet::<T>::transfer_unsigned(
+            RawOrigin::None.into(),
+            XOR.into(),
+            caller.clone(),
+            100_u32.into(),
+            1u32.into(),
+            0
+        )?;
+    }
+    verify {
+        assert_last_event::<T>(Event::Transferred(caller, 100_u32.into()).into())
+    }
+
     reset_rewards {
         let n in 1 .. 1000 => add_rewards::<T>(n);
         let caller = alice::<T>();
@@ -111,6 +131,22 @@ benchmarks! {
     }: {
         Pallet::<T>::reset_rewards(caller_origin)?;
     }
+
+    set_asset_limit {
+    }: {
+        Pallet::<T>::set_asset_limit(RawOrigin::Root.into(), VAL.into(), Some(balance!(100)))?;
+    }
+    verify {
+        assert_last_event::<T>(Event::AssetLimitSet(VAL.into(), balance!(100)).into())
+    }
+
+    set_config {
+    }: {
+        Pallet::<T>::set_config(RawOrigin::Root.into(), 100_u32.into(), 20)?;
+    }
+    verify {
+        assert_last_event::<T>(Event::ConfigUpdated(100_u32.into(), 20).into())
+    }
 }
 
 #[cfg(test)]
@@ -124,7 +160,10 @@ mod tests {
     fn test_benchmarks() {
         ExtBuilder::build().execute_with(|| {
             assert_ok!(test_benchmark_transfer::<Runtime>());
+            assert_ok!(test_benchmark_transfer_unsigned::<Runtime>());
             assert_ok!(test_benchmark_reset_rewards::<Runtime>());
+            assert_ok!(test_benchmark_set_asset_limit::<Runtime>());
+            assert_ok!(test_benchmark_set_config::<Runtime>());
         });
     }
 }
@@ -30,12 +30,16 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use common::{balance, Balance, PSWAP, VAL, XOR};
+use common::{balance, Balance};
 use frame_support::ensure;
 use frame_support::weights::Weight;
 use sp_arithmetic::traits::Saturating;
+use sp_core::H256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_std::vec::Vec;
 
 mod benchmarking;
+mod migration;
 
 #[cfg(test)]
 mod mock;
@@ -46,7 +50,10 @@ pub mod weights;
 
 pub trait WeightInfo {
     fn transfer() -> Weight;
+    fn transfer_unsigned() -> Weight;
     fn reset_rewards() -> Weight;
+    fn set_asset_limit() -> Weight;
+    fn set_config() -> Weight;
 }
 
 type Assets<T> = assets::Module<T>;
@@ -58,13 +65,13 @@ type WeightInfoOf<T> = <T as Config>::WeightInfo;
 pub const TECH_ACCOUNT_PREFIX: &[u8] = b"faucet";
 pub const TECH_ACCOUNT_MAIN: &[u8] = b"main";
 
-pub fn balance_limit() -> Balance {
-    balance!(6000)
-}
-
-pub fn transfer_limit_block_count<T: frame_system::Config>() -> BlockNumberOf<T> {
-    14400u32.into()
-}
+/// Limit of an asset which used to be hardcoded, it's used for assets added by the migration.
+pub const DEFAULT_BALANCE_LIMIT: Balance = balance!(6000);
+/// Max number of leading zero bits the proof of work of unsigned transfers may require.
+pub const MAX_POW_DIFFICULTY: u32 = 64;
+/// Number of blocks the proof of work of an unsigned transfer stays valid after the block it's
+/// bound to.
+const UNSIGNED_TRANSFER_LONGEVITY: u32 = 64;
 
 pub use pallet::*;
 
@@ -75,6 +82,7 @@ pub mod pallet {
     use hex_literal::hex;
     use sp_core::H160;
 
+    use assets::AssetIdOf;
     use common::AccountIdOf;
     use rewards::{PswapFarmOwners, PswapWaifuOwners, RewardInfo, ValOwners};
 
@@ -93,42 +101,66 @@ pub mod pallet {
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            migration::migrate::<T>()
+        }
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let longevity = BlockNumberOf::<T>::from(UNSIGNED_TRANSFER_LONGEVITY);
+            if now > longevity {
+                // Removal of each used proof is paid by the unsigned transfer that stored it.
+                UsedProofs::<T>::remove_prefix(now - longevity - 1u32.into());
+                T::DbWeight::get().writes(1)
+            } else {
+                0
+            }
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Transfers the specified amount of asset to the specified account.
-        /// The supported assets are: XOR, VAL, PSWAP.
+        /// The supported assets and their limits are configured by root.
         ///
         /// # Errors
         ///
         /// AssetNotSupported is returned if `asset_id` is something the function doesn't support.
-        /// AmountAboveLimit is returned if `target` has already received their daily limit of `asset_id`.
+        /// AmountAboveLimit is returned if `target` has already received their limit of `asset_id`.
         /// NotEnoughReserves is returned if `amount` is greater than the reserves
-        #[pallet::weight((WeightInfoOf::<T>::transfer(), Pays::No))]
+        #[pallet::weight(WeightInfoOf::<T>::transfer())]
         pub fn transfer(
-            _origin: OriginFor<T>,
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            target: AccountIdOf<T>,
+            amount: Balance,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+            Self::transfer_from_reserves(asset_id, target, amount)?;
+            Ok(().into())
+        }
+
+        /// Transfers the specified amount of asset to the specified account without a signed
+        /// origin, so accounts without funds can use the faucet.
+        ///
+        /// To prevent spam, the request must carry a proof of work: the hash of
+        /// `(target, asset_id, amount, block_hash, nonce)` must have at least `PowDifficulty`
+        /// leading zero bits, where `block_hash` is the hash of the recent block `block_number`.
+        /// A proof stays valid for `UNSIGNED_TRANSFER_LONGEVITY` blocks and can be used once.
+        #[pallet::weight(WeightInfoOf::<T>::transfer_unsigned())]
+        pub fn transfer_unsigned(
+            origin: OriginFor<T>,
             asset_id: T::AssetId,
             target: AccountIdOf<T>,
             amount: Balance,
+            block_number: BlockNumberOf<T>,
+            nonce: u64,
         ) -> DispatchResultWithPostInfo {
-            Self::ensure_asset_supported(asset_id)?;
-            let block_number = System::<T>::block_number();
-            let (block_number, taken_amount) =
-                Self::prepare_transfer(&target, asset_id, amount, block_number)?;
-            let reserves_tech_account_id = Self::reserves_account_id();
-            let reserves_account_id =
-                Technical::<T>::tech_account_id_to_account_id(&reserves_tech_account_id)?;
-            let reserves_amount = Assets::<T>::total_balance(&asset_id, &reserves_account_id)?;
-            ensure!(amount <= reserves_amount, Error::<T>::NotEnoughReserves);
-            technical::Module::<T>::transfer_out(
-                &asset_id,
-                &reserves_tech_account_id,
-                &target,
-                amount,
-            )?;
-            Transfers::<T>::insert(target.clone(), asset_id, (block_number, taken_amount));
-            Self::deposit_event(Event::Transferred(target, amount));
+            ensure_none(origin)?;
+            let proof =
+                Self::ensure_proof_of_work(&target, &asset_id, amount, block_number, nonce)?;
+            Self::transfer_from_reserves(asset_id, target, amount)?;
+            UsedProofs::<T>::insert(block_number, proof, ());
             Ok(().into())
         }
 
@@ -162,14 +194,109 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Sets the amount of the asset an account can receive during the transfer limit period.
+        /// `None` removes the asset from the faucet.
+        /// Can be only called by root.
+        #[pallet::weight(WeightInfoOf::<T>::set_asset_limit())]
+        pub fn set_asset_limit(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            limit: Option<Balance>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            match limit {
+                Some(limit) => {
+                    Assets::<T>::ensure_asset_exists(&asset_id)?;
+                    AssetLimits::<T>::insert(&asset_id, limit);
+                    Self::deposit_event(Event::AssetLimitSet(asset_id, limit));
+                }
+                None => {
+                    ensure!(
+                        AssetLimits::<T>::contains_key(&asset_id),
+                        Error::<T>::AssetNotSupported
+                    );
+                    AssetLimits::<T>::remove(&asset_id);
+                    Self::deposit_event(Event::AssetRemoved(asset_id));
+                }
+            }
+            Ok(().into())
+        }
+
+        /// Sets the length of the transfer limit period and the proof of work difficulty of
+        /// unsigned transfers.
+        /// Can be only called by root.
+        #[pallet::weight(WeightInfoOf::<T>::set_config())]
+        pub fn set_config(
+            origin: OriginFor<T>,
+            transfer_limit_block_count: BlockNumberOf<T>,
+            pow_difficulty: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                pow_difficulty <= MAX_POW_DIFFICULTY,
+                Error::<T>::InvalidPowDifficulty
+            );
+            TransferLimitBlockCount::<T>::put(transfer_limit_block_count);
+            PowDifficulty::<T>::put(pow_difficulty);
+            Self::deposit_event(Event::ConfigUpdated(
+                transfer_limit_block_count,
+                pow_difficulty,
+            ));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            if let Call::transfer_unsigned(asset_id, target, amount, block_number, nonce) = call {
+                Self::ensure_proof_of_work(target, asset_id, *amount, *block_number, *nonce)
+                    .map_err(|error| match error {
+                        Error::<T>::ProofOfWorkExpired | Error::<T>::ProofOfWorkAlreadyUsed => {
+                            InvalidTransaction::Stale
+                        }
+                        _ => InvalidTransaction::BadProof,
+                    })?;
+                let limit =
+                    Self::ensure_asset_supported(asset_id).map_err(|_| InvalidTransaction::Call)?;
+                Self::prepare_transfer(
+                    target,
+                    *asset_id,
+                    *amount,
+                    limit,
+                    System::<T>::block_number(),
+                )
+                .map_err(|_| InvalidTransaction::ExhaustsResources)?;
+                ValidTransaction::with_tag_prefix("Faucet")
+                    .and_provides((target, asset_id, block_number, nonce))
+                    .longevity(UNSIGNED_TRANSFER_LONGEVITY.into())
+                    .propagate(true)
+                    .build()
+            } else {
+                InvalidTransaction::Call.into()
+            }
+        }
     }
 
     #[pallet::event]
-    #[pallet::metadata(AccountIdOf<T> = "AccountId")]
+    #[pallet::metadata(
+        AccountIdOf<T> = "AccountId",
+        AssetIdOf<T> = "AssetId",
+        BlockNumberOf<T> = "BlockNumber"
+    )]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         // The amount is transferred to the account. [account, amount]
         Transferred(AccountIdOf<T>, Balance),
+        // The asset limit is set. [asset, limit]
+        AssetLimitSet(AssetIdOf<T>, Balance),
+        // The asset is removed from the faucet. [asset]
+        AssetRemoved(AssetIdOf<T>),
+        // The faucet configuration is updated. [transfer limit block count, pow difficulty]
+        ConfigUpdated(BlockNumberOf<T>, u32),
     }
 
     #[pallet::error]
@@ -180,6 +307,14 @@ pub mod pallet {
         AmountAboveLimit,
         /// Not enough reserves.
         NotEnoughReserves,
+        /// Proof of work of the unsigned transfer doesn't meet the difficulty.
+        InvalidProofOfWork,
+        /// Proof of work difficulty is too high.
+        InvalidPowDifficulty,
+        /// Proof of work of the unsigned transfer isn't bound to a recent block.
+        ProofOfWorkExpired,
+        /// Proof of work of the unsigned transfer has already been used.
+        ProofOfWorkAlreadyUsed,
     }
 
     #[pallet::storage]
@@ -196,9 +331,44 @@ pub mod pallet {
         (BlockNumberOf<T>, Balance),
     >;
 
+    /// Assets given out by the faucet along with the amount an account can receive during the
+    /// transfer limit period.
+    #[pallet::storage]
+    #[pallet::getter(fn asset_limit)]
+    pub(super) type AssetLimits<T: Config> = StorageMap<_, Blake2_256, T::AssetId, Balance>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultForTransferLimitBlockCount<T: Config>() -> BlockNumberOf<T> {
+        14400u32.into()
+    }
+
+    /// Length of the transfer limit period in blocks.
+    #[pallet::storage]
+    #[pallet::getter(fn transfer_limit_block_count)]
+    pub(super) type TransferLimitBlockCount<T: Config> =
+        StorageValue<_, BlockNumberOf<T>, ValueQuery, DefaultForTransferLimitBlockCount<T>>;
+
+    #[pallet::type_value]
+    pub(super) fn DefaultForPowDifficulty() -> u32 {
+        16
+    }
+
+    /// Number of leading zero bits the proof of work of unsigned transfers must have.
+    #[pallet::storage]
+    #[pallet::getter(fn pow_difficulty)]
+    pub(super) type PowDifficulty<T: Config> =
+        StorageValue<_, u32, ValueQuery, DefaultForPowDifficulty>;
+
+    /// Proofs of work used by unsigned transfers, by the block they're bound to. Kept until the
+    /// proofs expire.
+    #[pallet::storage]
+    pub(super) type UsedProofs<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, BlockNumberOf<T>, Identity, H256, ()>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub reserves_account_id: T::TechAccountId,
+        pub asset_limits: Vec<(T::AssetId, Balance)>,
     }
 
     #[cfg(feature = "std")]
@@ -206,6 +376,7 @@ pub mod pallet {
         fn default() -> Self {
             Self {
                 reserves_account_id: Default::default(),
+                asset_limits: Default::default(),
             }
         }
     }
@@ -214,20 +385,73 @@ pub mod pallet {
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
             ReservesAcc::<T>::put(&self.reserves_account_id);
+            for (asset_id, limit) in &self.asset_limits {
+                AssetLimits::<T>::insert(asset_id, limit);
+            }
         }
     }
 }
 
 impl<T: Config> Pallet<T> {
-    fn ensure_asset_supported(asset_id: T::AssetId) -> Result<(), Error<T>> {
-        let xor = XOR.into();
-        let val = VAL.into();
-        let pswap = PSWAP.into();
-        if asset_id == xor || asset_id == val || asset_id == pswap {
-            Ok(())
-        } else {
-            Err(Error::AssetNotSupported)
-        }
+    /// Returns the limit of the asset if it's supported.
+    fn ensure_asset_supported(asset_id: &T::AssetId) -> Result<Balance, Error<T>> {
+        AssetLimits::<T>::get(asset_id).ok_or(Error::AssetNotSupported)
+    }
+
+    /// Checks the proof of work of the unsigned transfer and returns its hash.
+    ///
+    /// The proof is bound to the hash of a recent block, so it can't be reused once the block is
+    /// `UNSIGNED_TRANSFER_LONGEVITY` blocks old, and it's tracked in `UsedProofs` until then.
+    fn ensure_proof_of_work(
+        target: &T::AccountId,
+        asset_id: &T::AssetId,
+        amount: Balance,
+        block_number: BlockNumberOf<T>,
+        nonce: u64,
+    ) -> Result<H256, Error<T>> {
+        let current_block_number = System::<T>::block_number();
+        ensure!(
+            block_number < current_block_number
+                && current_block_number.saturating_sub(block_number)
+                    <= UNSIGNED_TRANSFER_LONGEVITY.into(),
+            Error::<T>::ProofOfWorkExpired
+        );
+        let block_hash = System::<T>::block_hash(block_number);
+        let hash = BlakeTwo256::hash_of(&(target, asset_id, amount, block_hash, nonce));
+        ensure!(
+            leading_zero_bits(&hash) >= PowDifficulty::<T>::get(),
+            Error::<T>::InvalidProofOfWork
+        );
+        ensure!(
+            !UsedProofs::<T>::contains_key(block_number, hash),
+            Error::<T>::ProofOfWorkAlreadyUsed
+        );
+        Ok(hash)
+    }
+
+    fn transfer_from_reserves(
+        asset_id: T::AssetId,
+        target: T::AccountId,
+        amount: Balance,
+    ) -> frame_support::dispatch::DispatchResult {
+        let limit = Self::ensure_asset_supported(&asset_id)?;
+        let block_number = System::<T>::block_number();
+        let (block_number, taken_amount) =
+            Self::prepare_transfer(&target, asset_id, amount, limit, block_number)?;
+        let reserves_tech_account_id = Self::reserves_account_id();
+        let reserves_account_id =
+            Technical::<T>::tech_account_id_to_account_id(&reserves_tech_account_id)?;
+        let reserves_amount = Assets::<T>::total_balance(&asset_id, &reserves_account_id)?;
+        ensure!(amount <= reserves_amount, Error::<T>::NotEnoughReserves);
+        technical::Module::<T>::transfer_out(
+            &asset_id,
+            &reserves_tech_account_id,
+            &target,
+            amount,
+        )?;
+        Transfers::<T>::insert(target.clone(), asset_id, (block_number, taken_amount));
+        Self::deposit_event(Event::Transferred(target, amount));
+        Ok(())
     }
 
     /// Checks if new transfer is allowed, considering previous transfers.
@@ -237,12 +461,12 @@ impl<T: Config> Pallet<T> {
         target: &T::AccountId,
         asset_id: T::AssetId,
         amount: Balance,
+        balance_limit: Balance,
         current_block_number: BlockNumberOf<T>,
     ) -> Result<(BlockNumberOf<T>, Balance), Error<T>> {
-        let balance_limit = balance_limit();
         ensure!(amount <= balance_limit, Error::AmountAboveLimit);
         if let Some((initial_block_number, taken_amount)) = Transfers::<T>::get(target, asset_id) {
-            let transfer_limit_block_count = transfer_limit_block_count::<T>();
+            let transfer_limit_block_count = Self::transfer_limit_block_count();
             if transfer_limit_block_count
                 <= current_block_number.saturating_sub(initial_block_number)
             {
@@ -258,4 +482,37 @@ impl<T: Config> Pallet<T> {
             Ok((current_block_number, amount))
         }
     }
+
+    /// Returns the amount of each supported asset the account can still receive in the current
+    /// transfer limit period.
+    pub fn remaining_allowances(target: &T::AccountId) -> Vec<(T::AssetId, Balance)> {
+        let current_block_number = System::<T>::block_number();
+        let transfer_limit_block_count = Self::transfer_limit_block_count();
+        AssetLimits::<T>::iter()
+            .map(|(asset_id, limit)| {
+                let remaining = match Transfers::<T>::get(target, asset_id) {
+                    Some((initial_block_number, taken_amount))
+                        if transfer_limit_block_count
+                            > current_block_number.saturating_sub(initial_block_number) =>
+                    {
+                        limit.saturating_sub(taken_amount)
+                    }
+                    _ => limit,
+                };
+                (asset_id, remaining)
+            })
+            .collect()
+    }
+}
+
+/// Number of leading zero bits of the hash.
+fn leading_zero_bits(hash: &H256) -> u32 {
+    let mut zeros = 0;
+    for byte in hash.as_bytes() {
+        zeros += byte.leading_zeros();
+        if *byte != 0 {
+            break;
+        }
+    }
+    zeros
 }
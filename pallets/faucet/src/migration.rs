@@ -0,0 +1,60 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{AssetLimits, Config, Pallet, Weight, DEFAULT_BALANCE_LIMIT};
+use common::{PSWAP, VAL, XOR};
+use frame_support::traits::{Get, GetPalletVersion, PalletVersion};
+
+pub fn migrate<T: Config>() -> Weight {
+    let mut weight: Weight = 0;
+
+    match Pallet::<T>::storage_version() {
+        // Initial version is 0.1.0
+        // Version 1.1.0 moves the hardcoded assets and their limits to storage
+        Some(version) if version == PalletVersion::new(0, 1, 0) => {
+            let migrated_weight = set_default_asset_limits::<T>();
+            weight = weight.saturating_add(migrated_weight)
+        }
+        _ => (),
+    }
+
+    weight
+}
+
+pub fn set_default_asset_limits<T: Config>() -> Weight {
+    let mut weight: Weight = 0;
+
+    for asset_id in &[XOR, VAL, PSWAP] {
+        AssetLimits::<T>::insert(T::AssetId::from(*asset_id), DEFAULT_BALANCE_LIMIT);
+        weight = weight.saturating_add(T::DbWeight::get().writes(1));
+    }
+
+    weight
+}
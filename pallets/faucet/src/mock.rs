@@ -46,11 +46,11 @@ use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 use sp_runtime::{Perbill, Percent};
 
 type DEXId = common::DEXId;
-type AccountId = AccountId32;
+pub type AccountId = AccountId32;
 type BlockNumber = u64;
 type TechAccountId = common::TechAccountId<AccountId, TechAssetId, DEXId>;
 type TechAssetId = common::TechAssetId<common::PredefinedAssetId>;
-type AssetId = AssetId32<common::PredefinedAssetId>;
+pub type AssetId = AssetId32<common::PredefinedAssetId>;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -91,7 +91,7 @@ construct_runtime! {
         NodeBlock = Block,
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
-        Faucet: faucet::{Module, Call, Config<T>, Storage, Event<T>},
+        Faucet: faucet::{Module, Call, Config<T>, Storage, Event<T>, ValidateUnsigned},
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
         Technical: technical::{Module, Call, Config<T>, Storage, Event<T>},
         Assets: assets::{Module, Call, Config<T>, Storage, Event<T>},
@@ -150,6 +150,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = ();
+    type WeightInfo = ();
 }
 
 impl assets::Config for Runtime {
@@ -172,6 +173,7 @@ impl common::Config for Runtime {
 // Required by assets::Config
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 // Required by assets::Config
@@ -268,6 +270,7 @@ impl ExtBuilder {
 
         FaucetConfig {
             reserves_account_id: tech_account_id,
+            asset_limits: vec![(XOR, balance!(6000)), (VAL.into(), balance!(6000))],
         }
         .assimilate_storage(&mut t)
         .unwrap();
@@ -28,8 +28,11 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use common::balance;
+use common::{balance, Balance};
+use frame_support::pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned};
+use frame_support::traits::Hooks;
 use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
 
 use crate::mock::*;
 use crate::*;
@@ -38,27 +41,165 @@ type Module = crate::Module<Runtime>;
 type Assets = assets::Module<Runtime>;
 type System = frame_system::Module<Runtime>;
 
+/// Finds the nonce satisfying the proof of work of the unsigned transfer bound to the block.
+fn find_nonce(target: &AccountId, asset_id: &AssetId, amount: Balance, block_number: u64) -> u64 {
+    (0..)
+        .find(|nonce| {
+            Module::ensure_proof_of_work(target, asset_id, amount, block_number, *nonce).is_ok()
+        })
+        .unwrap()
+}
+
 #[test]
 fn transfer_passes_unsigned() {
     ExtBuilder::build().execute_with(|| {
-        // Receive 100 (Limit) in two transfers
+        System::set_block_number(2);
+        PowDifficulty::<Runtime>::put(4);
+        // Receive 6000 (Limit) in two transfers
+        for amount in [balance!(2999.91), balance!(3000.09)].iter().cloned() {
+            let nonce = find_nonce(&bob(), &XOR, amount, 1);
+            let call = crate::Call::<Runtime>::transfer_unsigned(XOR, bob(), amount, 1, nonce);
+            assert!(<Module as ValidateUnsigned>::validate_unsigned(
+                TransactionSource::External,
+                &call
+            )
+            .is_ok());
+            assert_ok!(Module::transfer_unsigned(
+                Origin::none(),
+                XOR,
+                bob(),
+                amount,
+                1,
+                nonce
+            ));
+        }
+        assert_eq!(
+            Assets::free_balance(&XOR, &account_id()).unwrap(),
+            balance!(3000)
+        );
+        assert_eq!(Assets::free_balance(&XOR, &bob()).unwrap(), balance!(6000));
+    });
+}
+
+#[test]
+fn transfer_unsigned_fails_with_invalid_proof_of_work() {
+    ExtBuilder::build().execute_with(|| {
+        System::set_block_number(2);
+        PowDifficulty::<Runtime>::put(MAX_POW_DIFFICULTY);
+        let call = crate::Call::<Runtime>::transfer_unsigned(XOR, bob(), balance!(1), 1, 0);
+        assert_eq!(
+            <Module as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+            Err(InvalidTransaction::BadProof.into())
+        );
+        assert_noop!(
+            Module::transfer_unsigned(Origin::none(), XOR, bob(), balance!(1), 1, 0),
+            crate::Error::<Runtime>::InvalidProofOfWork
+        );
+    });
+}
+
+#[test]
+fn transfer_unsigned_is_not_valid_above_limit() {
+    ExtBuilder::build().execute_with(|| {
+        PowDifficulty::<Runtime>::put(0);
         assert_ok!(Module::transfer(
-            Origin::none(),
+            Origin::signed(alice()),
             XOR,
             bob(),
-            balance!(2999.91)
+            balance!(6000)
         ));
-        assert_ok!(Module::transfer(
+        System::set_block_number(2);
+        let call = crate::Call::<Runtime>::transfer_unsigned(XOR, bob(), balance!(1), 1, 0);
+        assert_eq!(
+            <Module as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+            Err(InvalidTransaction::ExhaustsResources.into())
+        );
+    });
+}
+
+#[test]
+fn transfer_unsigned_fails_with_reused_proof_of_work() {
+    ExtBuilder::build().execute_with(|| {
+        System::set_block_number(2);
+        PowDifficulty::<Runtime>::put(4);
+        let nonce = find_nonce(&bob(), &XOR, balance!(1), 1);
+        assert_ok!(Module::transfer_unsigned(
             Origin::none(),
             XOR,
             bob(),
-            balance!(3000.09)
+            balance!(1),
+            1,
+            nonce
         ));
+        let call = crate::Call::<Runtime>::transfer_unsigned(XOR, bob(), balance!(1), 1, nonce);
         assert_eq!(
-            Assets::free_balance(&XOR, &account_id()).unwrap(),
-            balance!(3000)
+            <Module as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+            Err(InvalidTransaction::Stale.into())
+        );
+        assert_noop!(
+            Module::transfer_unsigned(Origin::none(), XOR, bob(), balance!(1), 1, nonce),
+            crate::Error::<Runtime>::ProofOfWorkAlreadyUsed
+        );
+    });
+}
+
+#[test]
+fn transfer_unsigned_fails_with_expired_proof_of_work() {
+    ExtBuilder::build().execute_with(|| {
+        PowDifficulty::<Runtime>::put(0);
+        System::set_block_number(2 + UNSIGNED_TRANSFER_LONGEVITY as u64);
+        let call = crate::Call::<Runtime>::transfer_unsigned(XOR, bob(), balance!(1), 1, 0);
+        assert_eq!(
+            <Module as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call),
+            Err(InvalidTransaction::Stale.into())
+        );
+        assert_noop!(
+            Module::transfer_unsigned(Origin::none(), XOR, bob(), balance!(1), 1, 0),
+            crate::Error::<Runtime>::ProofOfWorkExpired
+        );
+        // Proofs can't be bound to the current block, its hash isn't known yet
+        assert_noop!(
+            Module::transfer_unsigned(
+                Origin::none(),
+                XOR,
+                bob(),
+                balance!(1),
+                2 + UNSIGNED_TRANSFER_LONGEVITY as u64,
+                0
+            ),
+            crate::Error::<Runtime>::ProofOfWorkExpired
+        );
+    });
+}
+
+#[test]
+fn used_proofs_are_removed_when_expired() {
+    ExtBuilder::build().execute_with(|| {
+        System::set_block_number(2);
+        PowDifficulty::<Runtime>::put(0);
+        assert_ok!(Module::transfer_unsigned(
+            Origin::none(),
+            XOR,
+            bob(),
+            balance!(1),
+            1,
+            0
+        ));
+        assert_eq!(UsedProofs::<Runtime>::iter_prefix(1).count(), 1);
+        Module::on_initialize(1 + UNSIGNED_TRANSFER_LONGEVITY as u64);
+        assert_eq!(UsedProofs::<Runtime>::iter_prefix(1).count(), 1);
+        Module::on_initialize(2 + UNSIGNED_TRANSFER_LONGEVITY as u64);
+        assert_eq!(UsedProofs::<Runtime>::iter_prefix(1).count(), 0);
+    });
+}
+
+#[test]
+fn transfer_fails_unsigned_without_proof_of_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            Module::transfer(Origin::none(), XOR, bob(), balance!(1)),
+            DispatchError::BadOrigin
         );
-        assert_eq!(Assets::free_balance(&XOR, &bob()).unwrap(), balance!(6000));
     });
 }
 
@@ -189,3 +330,86 @@ fn transfer_fails_with_not_enough_reserves() {
         );
     });
 }
+
+#[test]
+fn set_asset_limit_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            Module::set_asset_limit(Origin::signed(alice()), VAL, Some(balance!(1))),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Module::set_asset_limit(
+            Origin::root(),
+            VAL,
+            Some(balance!(1))
+        ));
+        assert_eq!(Module::asset_limit(VAL), Some(balance!(1)));
+        assert_noop!(
+            Module::transfer(Origin::signed(alice()), VAL, bob(), balance!(2)),
+            crate::Error::<Runtime>::AmountAboveLimit
+        );
+
+        assert_ok!(Module::set_asset_limit(Origin::root(), VAL, None));
+        assert_eq!(Module::asset_limit(VAL), None);
+        assert_noop!(
+            Module::transfer(Origin::signed(alice()), VAL, bob(), balance!(1)),
+            crate::Error::<Runtime>::AssetNotSupported
+        );
+        assert_noop!(
+            Module::set_asset_limit(Origin::root(), VAL, None),
+            crate::Error::<Runtime>::AssetNotSupported
+        );
+    });
+}
+
+#[test]
+fn set_config_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            Module::set_config(Origin::signed(alice()), 10, 8),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Module::set_config(Origin::root(), 10, MAX_POW_DIFFICULTY + 1),
+            crate::Error::<Runtime>::InvalidPowDifficulty
+        );
+        assert_ok!(Module::set_config(Origin::root(), 10, 8));
+        assert_eq!(Module::transfer_limit_block_count(), 10);
+        assert_eq!(Module::pow_difficulty(), 8);
+
+        assert_ok!(Module::transfer(
+            Origin::signed(alice()),
+            XOR,
+            bob(),
+            balance!(6000)
+        ));
+        System::set_block_number(11);
+        assert_ok!(Module::transfer(
+            Origin::signed(alice()),
+            XOR,
+            bob(),
+            balance!(3000)
+        ));
+    });
+}
+
+#[test]
+fn remaining_allowances_should_work() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(Module::transfer(
+            Origin::signed(alice()),
+            XOR,
+            bob(),
+            balance!(2000)
+        ));
+        let mut allowances = Module::remaining_allowances(&bob());
+        allowances.sort();
+        let mut expected = vec![(XOR, balance!(4000)), (VAL, balance!(6000))];
+        expected.sort();
+        assert_eq!(allowances, expected);
+
+        System::set_block_number(14401);
+        let allowances = Module::remaining_allowances(&bob());
+        assert!(allowances.contains(&(XOR, balance!(6000))));
+    });
+}
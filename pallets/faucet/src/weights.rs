@@ -69,9 +69,22 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(6 as Weight))
             .saturating_add(T::DbWeight::get().writes(3 as Weight))
     }
+    fn transfer_unsigned() -> Weight {
+        (140_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(9 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
     fn reset_rewards() -> Weight {
         (32_479_000 as Weight).saturating_add(T::DbWeight::get().writes(9 as Weight))
     }
+    fn set_asset_limit() -> Weight {
+        (25_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_config() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
 }
 
 impl crate::WeightInfo for () {
@@ -79,7 +92,19 @@ impl crate::WeightInfo for () {
         EXTRINSIC_FIXED_WEIGHT
     }
 
+    fn transfer_unsigned() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
     fn reset_rewards() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+
+    fn set_asset_limit() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn set_config() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
 }
@@ -28,6 +28,8 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use codec::Codec;
+
 use common::InvokeRPCError;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
@@ -40,11 +42,18 @@ use std::sync::Arc;
 
 // Runtime API imports.
 pub use iroha_migration_runtime_api::IrohaMigrationAPI as IrohaMigrationRuntimeAPI;
+use iroha_migration_runtime_api::PendingMultisigMigrationInfo;
 
 #[rpc]
-pub trait IrohaMigrationAPI<BlockHash> {
+pub trait IrohaMigrationAPI<BlockHash, PendingMultisigMigrationInfoVec> {
     #[rpc(name = "irohaMigration_needsMigration")]
     fn needs_migration(&self, iroha_address: String, at: Option<BlockHash>) -> Result<bool>;
+
+    #[rpc(name = "irohaMigration_pendingMultisigMigrations")]
+    fn pending_multisig_migrations(
+        &self,
+        at: Option<BlockHash>,
+    ) -> Result<PendingMultisigMigrationInfoVec>;
 }
 
 pub struct IrohaMigrationClient<C, B> {
@@ -62,12 +71,15 @@ impl<C, B> IrohaMigrationClient<C, B> {
     }
 }
 
-impl<C, Block> IrohaMigrationAPI<<Block as BlockT>::Hash> for IrohaMigrationClient<C, Block>
+impl<C, Block, BlockNumber>
+    IrohaMigrationAPI<<Block as BlockT>::Hash, Vec<PendingMultisigMigrationInfo<BlockNumber>>>
+    for IrohaMigrationClient<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: IrohaMigrationRuntimeAPI<Block>,
+    C::Api: IrohaMigrationRuntimeAPI<Block, BlockNumber>,
+    BlockNumber: Codec,
 {
     fn needs_migration(&self, iroha_address: String, at: Option<Block::Hash>) -> Result<bool> {
         let api = self.client.runtime_api();
@@ -82,4 +94,20 @@ where
                 data: Some(format!("{:?}", e).into()),
             })
     }
+
+    fn pending_multisig_migrations(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<PendingMultisigMigrationInfo<BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.pending_multisig_migrations(&at).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get pending multi-signature migrations.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
 }
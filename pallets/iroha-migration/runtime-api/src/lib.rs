@@ -29,12 +29,34 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
 
 extern crate alloc;
 use alloc::string::String;
+use codec::{Codec, Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PendingMultisigMigrationInfo<BlockNumber> {
+    pub iroha_address: String,
+    /// Number of public keys that have already approved the migration.
+    pub approval_count: u32,
+    pub public_key_count: u32,
+    pub quorum: u8,
+    /// Block after which the account is migrated with the current approvals, if the quorum is reached.
+    pub migrate_at: Option<BlockNumber>,
+}
 
 sp_api::decl_runtime_apis! {
-    pub trait IrohaMigrationAPI {
+    pub trait IrohaMigrationAPI<BlockNumber> where
+        BlockNumber: Codec,
+    {
         fn needs_migration(iroha_address: String) -> bool;
+        fn pending_multisig_migrations() -> Vec<PendingMultisigMigrationInfo<BlockNumber>>;
     }
 }
@@ -41,8 +41,8 @@ use sp_std::prelude::*;
 use common::VAL;
 
 use crate::{
-    Balances, Config, Event, MigratedAccounts, Module, Pallet, PendingMultiSigAccounts, PublicKeys,
-    Quorums,
+    Balances, Config, Event, MigratedAccounts, MigrationClosed, Module, Pallet,
+    PendingMultiSigAccounts, PendingMultisigAccount, PublicKeys, Quorums, Referrers,
 };
 
 fn alice<T: Config>() -> T::AccountId {
@@ -101,6 +101,22 @@ fn add_accounts<T: Config>(n: u32) {
     Quorums::<T>::insert(&multi_sig_account_id, 2);
 }
 
+// Adds `n` of single-signature accounts with some balance that are never migrated
+fn add_unmigrated_accounts<T: Config>(n: u32) {
+    for i in 0..n {
+        let iroha_address = format!("did_sora_unmigrated_{}@sora", i);
+        Balances::<T>::insert(&iroha_address, 1);
+        PublicKeys::<T>::insert(
+            &iroha_address,
+            vec![(
+                false,
+                "D9BDA3688c6f608ab15c03a55b171da0413788a40a25722b4ae4d3672890bcd7".to_lowercase(),
+            )],
+        );
+        Referrers::<T>::insert(&iroha_address, "did_sora_referrer@sora".to_string());
+    }
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
     let events = frame_system::Module::<T>::events();
     let system_event: <T as frame_system::Config>::Event = generic_event.into();
@@ -157,6 +173,33 @@ benchmarks! {
         assert!(!PendingMultiSigAccounts::<T>::contains_key(&iroha_address));
         assert_eq!(assets::Pallet::<T>::free_balance(&VAL.into(), &multi_account_of_2).unwrap(), 1000);
     }
+
+    extend_multisig_deadline {
+        let iroha_address = "did_sora_multi_sig@sora".to_string();
+        PendingMultiSigAccounts::<T>::insert(&iroha_address, PendingMultisigAccount::<T> {
+            approving_accounts: vec![alice::<T>(), bob::<T>()],
+            migrate_at: Some(100u32.into()),
+        });
+    }: _(RawOrigin::Root, iroha_address.clone(), 50u32.into())
+    verify {
+        assert_last_event::<T>(Event::MultisigDeadlineExtended(iroha_address, 150u32.into()).into())
+    }
+
+    close_migration {
+    }: _(RawOrigin::Root)
+    verify {
+        assert!(MigrationClosed::<T>::get());
+    }
+
+    transfer_unmigrated_balances {
+        let n in 1 .. 100 => add_unmigrated_accounts::<T>(n);
+        MigrationClosed::<T>::put(true);
+        let treasury = bob::<T>();
+    }: _(RawOrigin::Root, treasury.clone(), n)
+    verify {
+        assert_eq!(Balances::<T>::iter().count(), 0);
+        assert_eq!(assets::Pallet::<T>::free_balance(&VAL.into(), &treasury).unwrap(), n as u128);
+    }
 }
 
 #[cfg(test)]
@@ -172,4 +215,25 @@ mod tests {
             assert_ok!(super::test_benchmark_on_initialize::<Runtime>());
         });
     }
+
+    #[test]
+    fn extend_multisig_deadline() {
+        mock::test_ext(false).execute_with(|| {
+            assert_ok!(super::test_benchmark_extend_multisig_deadline::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn close_migration() {
+        mock::test_ext(false).execute_with(|| {
+            assert_ok!(super::test_benchmark_close_migration::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn transfer_unmigrated_balances() {
+        mock::test_ext(false).execute_with(|| {
+            assert_ok!(super::test_benchmark_transfer_unmigrated_balances::<Runtime>());
+        });
+    }
 }
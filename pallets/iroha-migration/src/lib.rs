@@ -57,7 +57,7 @@ use common::{FromGenericPair, VAL};
 use ed25519_dalek_iroha::{Digest, PublicKey, Signature, SIGNATURE_LENGTH};
 use frame_support::codec::{Decode, Encode};
 use frame_support::dispatch::{DispatchError, Weight};
-use frame_support::sp_runtime::traits::Zero;
+use frame_support::sp_runtime::traits::{Saturating, Zero};
 use frame_support::weights::Pays;
 use frame_support::{ensure, RuntimeDebug};
 use frame_system::ensure_signed;
@@ -75,6 +75,9 @@ pub const TECH_ACCOUNT_MAIN: &[u8] = b"main";
 pub trait WeightInfo {
     fn migrate() -> Weight;
     fn on_initialize() -> Weight;
+    fn extend_multisig_deadline() -> Weight;
+    fn close_migration() -> Weight;
+    fn transfer_unmigrated_balances(n: u32) -> Weight;
 }
 
 fn blocks_till_migration<T>() -> T::BlockNumber
@@ -113,6 +116,24 @@ impl<T: Config> Pallet<T> {
             && !MigratedAccounts::<T>::contains_key(iroha_address)
     }
 
+    /// Returns multi-signature accounts that have at least one approval but are not migrated yet
+    /// as `(iroha_address, approval_count, public_key_count, quorum, migrate_at)`.
+    pub fn pending_multisig_migrations() -> Vec<(String, u32, u32, u8, Option<T::BlockNumber>)> {
+        PendingMultiSigAccounts::<T>::iter()
+            .map(|(iroha_address, pending_account)| {
+                let key_count = PublicKeys::<T>::decode_len(&iroha_address).unwrap_or(0) as u32;
+                let quorum = Quorums::<T>::get(&iroha_address);
+                (
+                    iroha_address,
+                    pending_account.approving_accounts.len() as u32,
+                    key_count,
+                    quorum,
+                    pending_account.migrate_at,
+                )
+            })
+            .collect()
+    }
+
     fn migrate_weight(
         iroha_address: &String,
         iroha_public_key: &String,
@@ -135,6 +156,7 @@ impl<T: Config> Pallet<T> {
     ) -> Result<(), DispatchError> {
         let iroha_public_key = iroha_public_key.to_lowercase();
         let iroha_signature = iroha_signature.to_lowercase();
+        ensure!(!MigrationClosed::<T>::get(), Error::<T>::MigrationClosed);
         ensure!(
             !MigratedAccounts::<T>::contains_key(&iroha_address),
             Error::<T>::AccountAlreadyMigrated
@@ -290,6 +312,17 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Removes all data of an account that will never be migrated and returns its balance.
+    fn remove_unmigrated_account(iroha_address: &String) -> Balance {
+        let balance = Balances::<T>::take(iroha_address).unwrap_or_default();
+        Referrers::<T>::remove(iroha_address);
+        PublicKeys::<T>::remove(iroha_address);
+        Quorums::<T>::remove(iroha_address);
+        PendingMultiSigAccounts::<T>::remove(iroha_address);
+        PendingReferrals::<T>::remove(iroha_address);
+        balance
+    }
+
     fn migrate_referrals(
         iroha_address: &String,
         account: &T::AccountId,
@@ -324,6 +357,7 @@ pub mod pallet {
     use common::AccountIdOf;
     use frame_support::dispatch::PostDispatchInfo;
     use frame_support::pallet_prelude::*;
+    use frame_support::transactional;
     use frame_system::pallet_prelude::*;
 
     #[pallet::config]
@@ -341,6 +375,10 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(block_number: T::BlockNumber) -> Weight {
+            // Nothing is migrated automatically once the migration window is closed
+            if MigrationClosed::<T>::get() {
+                return T::DbWeight::get().reads(1);
+            }
             // Migrate accounts whose quorum has been reached and enough time has passed since then
             PendingMultiSigAccounts::<T>::translate(|key, mut value: PendingMultisigAccount<T>| {
                 if let Some(migrate_at) = value.migrate_at {
@@ -375,6 +413,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             common::with_transaction(|| {
                 let who = ensure_signed(origin)?;
+                ensure!(!MigrationClosed::<T>::get(), Error::<T>::MigrationClosed);
                 let iroha_public_key = iroha_public_key.to_lowercase();
                 let iroha_signature = iroha_signature.to_lowercase();
                 frame_support::debug::error!("faucet: iroha_public_key: {}", iroha_public_key);
@@ -407,14 +446,104 @@ pub mod pallet {
                 })
             })
         }
+
+        /// Postpones the automatic migration of a multi-signature account whose quorum has been reached.
+        ///
+        /// - `origin`: the root account,
+        /// - `iroha_address`: the multi-signature Iroha account,
+        /// - `extension`: number of blocks to add to the current deadline.
+        #[pallet::weight(WeightInfoOf::<T>::extend_multisig_deadline())]
+        pub fn extend_multisig_deadline(
+            origin: OriginFor<T>,
+            iroha_address: String,
+            extension: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(!MigrationClosed::<T>::get(), Error::<T>::MigrationClosed);
+            let migrate_at = PendingMultiSigAccounts::<T>::try_mutate(
+                &iroha_address,
+                |pending_account| -> Result<T::BlockNumber, DispatchError> {
+                    let migrate_at = pending_account
+                        .migrate_at
+                        .as_mut()
+                        .ok_or(Error::<T>::NoPendingMultisigDeadline)?;
+                    *migrate_at = (*migrate_at).saturating_add(extension);
+                    Ok(*migrate_at)
+                },
+            )?;
+            Self::deposit_event(Event::MultisigDeadlineExtended(iroha_address, migrate_at));
+            Ok(().into())
+        }
+
+        /// Closes the migration window. No accounts can be migrated after that, and the
+        /// remaining balances can be moved with `transfer_unmigrated_balances`.
+        ///
+        /// - `origin`: the root account.
+        #[pallet::weight(WeightInfoOf::<T>::close_migration())]
+        pub fn close_migration(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(!MigrationClosed::<T>::get(), Error::<T>::MigrationClosed);
+            MigrationClosed::<T>::put(true);
+            Self::deposit_event(Event::MigrationWindowClosed);
+            Ok(().into())
+        }
+
+        /// Moves VAL of at most `limit` unmigrated accounts to the `treasury` account and
+        /// removes these accounts. Can be called repeatedly until no accounts are left.
+        ///
+        /// - `origin`: the root account,
+        /// - `treasury`: the account receiving the balances,
+        /// - `limit`: maximum number of accounts to process.
+        #[pallet::weight(WeightInfoOf::<T>::transfer_unmigrated_balances(*limit))]
+        #[transactional]
+        pub fn transfer_unmigrated_balances(
+            origin: OriginFor<T>,
+            treasury: T::AccountId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(MigrationClosed::<T>::get(), Error::<T>::MigrationNotClosed);
+            let iroha_addresses = Balances::<T>::iter()
+                .map(|(iroha_address, _)| iroha_address)
+                .take(limit as usize)
+                .collect::<Vec<_>>();
+            let mut total = Balance::zero();
+            for iroha_address in &iroha_addresses {
+                total = total.saturating_add(Self::remove_unmigrated_account(iroha_address));
+            }
+            if !total.is_zero() {
+                let eth_bridge_tech_account_id = <T>::TechAccountId::from_generic_pair(
+                    eth_bridge::TECH_ACCOUNT_PREFIX.to_vec(),
+                    eth_bridge::TECH_ACCOUNT_MAIN.to_vec(),
+                );
+                technical::Module::<T>::transfer_out(
+                    &VAL.into(),
+                    &eth_bridge_tech_account_id,
+                    &treasury,
+                    total,
+                )?;
+            }
+            let count = iroha_addresses.len() as u32;
+            Self::deposit_event(Event::UnmigratedBalancesTransferred(treasury, count, total));
+            Ok(PostDispatchInfo {
+                actual_weight: Some(WeightInfoOf::<T>::transfer_unmigrated_balances(count)),
+                pays_fee: Pays::Yes,
+            })
+        }
     }
 
     #[pallet::event]
-    #[pallet::metadata(AccountIdOf<T> = "AccountId")]
+    #[pallet::metadata(AccountIdOf<T> = "AccountId", BlockNumberFor<T> = "BlockNumber")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Migrated. [source, target]
         Migrated(String, AccountIdOf<T>),
+        /// Multi-signature account migration deadline extended. [account, new_deadline]
+        MultisigDeadlineExtended(String, BlockNumberFor<T>),
+        /// Migration window closed. []
+        MigrationWindowClosed,
+        /// Unmigrated balances transferred. [treasury, account_count, amount]
+        UnmigratedBalancesTransferred(AccountIdOf<T>, u32, Balance),
     }
 
     #[pallet::error]
@@ -439,6 +568,12 @@ pub mod pallet {
         MultiSigCreationFailed,
         /// Signatory addition to multi-signature account failed
         SignatoryAdditionFailed,
+        /// Multi-signature account has no pending migration deadline
+        NoPendingMultisigDeadline,
+        /// Migration window is closed
+        MigrationClosed,
+        /// Migration window is not closed yet
+        MigrationNotClosed,
     }
 
     #[pallet::storage]
@@ -469,6 +604,10 @@ pub mod pallet {
     pub(super) type PendingReferrals<T: Config> =
         StorageMap<_, Blake2_128Concat, String, Vec<T::AccountId>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn migration_closed)]
+    pub(super) type MigrationClosed<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub account_id: T::AccountId,
@@ -64,6 +64,8 @@ parameter_types! {
     pub const AvailableBlockRatio: Perbill = Perbill::from_percent(75);
     pub const GetBaseAssetId: AssetId32<PredefinedAssetId> = AssetId32::from_asset_id(XOR);
     pub const ExistentialDeposit: u128 = 0;
+    pub const ReferrerSetGracePeriod: BlockNumber = 100;
+    pub GetReferralRewardsAccountId: AccountId = 5000u64;
     pub const DepositBase: u64 = 1;
     pub const DepositFactor: u64 = 1;
     pub const MaxSignatories: u16 = 4;
@@ -84,7 +86,7 @@ construct_runtime!(
         Assets: assets::{Module, Call, Storage, Config<T>, Event<T>},
         Technical: technical::{Module, Call, Config<T>, Event<T>},
         Permissions: permissions::{Module, Call, Storage, Config<T>, Event<T>},
-        ReferralSystem: referral_system::{Module, Call, Storage, Config<T>},
+        ReferralSystem: referral_system::{Module, Call, Storage, Config<T>, Event<T>},
         IrohaMigration: iroha_migration::{Module, Call, Storage, Config<T>, Event<T>}
     }
 );
@@ -121,6 +123,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = ();
+    type WeightInfo = ();
 }
 
 impl assets::Config for Runtime {
@@ -142,6 +145,7 @@ impl common::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 // Required by assets::Config
@@ -174,7 +178,13 @@ impl tokens::Config for Runtime {
     type OnDust = ();
 }
 
-impl referral_system::Config for Runtime {}
+impl referral_system::Config for Runtime {
+    type Event = Event;
+    type ReferrerSetGracePeriod = ReferrerSetGracePeriod;
+    type Currency = Balances;
+    type ReferralRewardsAccountId = GetReferralRewardsAccountId;
+    type WeightInfo = ();
+}
 
 impl pallet_multisig::Config for Runtime {
     type Call = Call;
@@ -29,12 +29,16 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::mock::*;
-use crate::{Error, MigratedAccounts, Pallet, PendingMultiSigAccounts, PendingReferrals};
+use crate::{
+    Balances, Error, MigratedAccounts, Pallet, PendingMultiSigAccounts, PendingReferrals,
+    PublicKeys,
+};
 use common::prelude::Balance;
 use common::VAL;
 use frame_support::traits::OnInitialize;
 use frame_support::{assert_noop, assert_ok};
 use referral_system::Referrers;
+use sp_runtime::DispatchError;
 
 type Assets = assets::Pallet<Runtime>;
 
@@ -241,3 +245,131 @@ fn test_migrate_multi_sig_public_key_already_used() {
         );
     });
 }
+
+fn approve_multi_sig_by_alice_and_bob() {
+    let iroha_address = "did_sora_multi_sig@sora".to_string();
+    assert_ok!(Pallet::<Runtime>::migrate(
+        Origin::signed(ALICE),
+        iroha_address.clone(),
+        "f7d89d39d48a67e4741a612de10650234f9148e84fE9e8b2a9fad322b0d8e5bc".to_string(),
+        "d5f6dcc6967aa05df71894dd2c253085b236026efC1c66d4b33ee88dda20fc751b516aef631d1f96919f8cba2e15334022e04ef6602298d6b9820daeefe13e03".to_string())
+    );
+    assert_ok!(Pallet::<Runtime>::migrate(
+        Origin::signed(BOB),
+        iroha_address,
+        "f56b4880ed91a25b257144acab749f615855c4b1b6A5d7891e1a6cdd9fd695e9".to_string(),
+        "5c0f4296175b9836baac7c2d92116c90961bb80f87C30e3e2e2b2d5819d0c278fa55d3f04793d7fbf19a78afeb8b52f17b5ba55bf7373e726723da7155cad70d".to_string())
+    );
+}
+
+#[test]
+fn test_extend_multisig_deadline() {
+    new_test_ext().execute_with(|| {
+        let iroha_address = "did_sora_multi_sig@sora".to_string();
+        assert_noop!(
+            Pallet::<Runtime>::extend_multisig_deadline(Origin::root(), iroha_address.clone(), 100),
+            Error::<Runtime>::NoPendingMultisigDeadline
+        );
+        approve_multi_sig_by_alice_and_bob();
+        let deadline = crate::blocks_till_migration::<Runtime>();
+        assert_eq!(
+            Pallet::<Runtime>::pending_multisig_migrations(),
+            vec![(iroha_address.clone(), 2, 3, 2, Some(deadline))]
+        );
+        assert_noop!(
+            Pallet::<Runtime>::extend_multisig_deadline(
+                Origin::signed(ALICE),
+                iroha_address.clone(),
+                100
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Pallet::<Runtime>::extend_multisig_deadline(
+            Origin::root(),
+            iroha_address.clone(),
+            100
+        ));
+        assert_eq!(
+            Pallet::<Runtime>::pending_multisig_migrations(),
+            vec![(iroha_address.clone(), 2, 3, 2, Some(deadline + 100))]
+        );
+
+        Pallet::<Runtime>::on_initialize(deadline + 1);
+        assert!(!MigratedAccounts::<Runtime>::contains_key(&iroha_address));
+
+        Pallet::<Runtime>::on_initialize(deadline + 101);
+        assert!(MigratedAccounts::<Runtime>::contains_key(&iroha_address));
+        assert!(Pallet::<Runtime>::pending_multisig_migrations().is_empty());
+    });
+}
+
+#[test]
+fn test_close_migration() {
+    new_test_ext().execute_with(|| {
+        let iroha_address = "did_sora_multi_sig@sora".to_string();
+        approve_multi_sig_by_alice_and_bob();
+        assert_noop!(
+            Pallet::<Runtime>::transfer_unmigrated_balances(Origin::root(), CHARLIE, 10),
+            Error::<Runtime>::MigrationNotClosed
+        );
+        assert_noop!(
+            Pallet::<Runtime>::close_migration(Origin::signed(ALICE)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Pallet::<Runtime>::close_migration(Origin::root()));
+        assert!(Pallet::<Runtime>::migration_closed());
+        assert_noop!(
+            Pallet::<Runtime>::close_migration(Origin::root()),
+            Error::<Runtime>::MigrationClosed
+        );
+        assert_noop!(Pallet::<Runtime>::migrate(
+            Origin::signed(ALICE),
+             "did_sora_balance@sora".to_string(),
+              "9a685d77bcd3f60e6cc1e91eedc7a48e11bbcf1a036b920f3bae0372a78A5432".to_string(),
+              "233896712f752760713539f56c92534ff8f4f290812e8f129Ce0b513b99cbdffcea95abeed68edd1b0a4e4b52877c13c26c6c89e5bb6bf023ac6c0f4f53c0c02".to_string()),
+            Error::<Runtime>::MigrationClosed);
+        assert_noop!(
+            Pallet::<Runtime>::extend_multisig_deadline(Origin::root(), iroha_address.clone(), 100),
+            Error::<Runtime>::MigrationClosed
+        );
+
+        // Pending multi-signature accounts are not migrated after the window is closed
+        Pallet::<Runtime>::on_initialize(crate::blocks_till_migration::<Runtime>() + 1);
+        assert!(!MigratedAccounts::<Runtime>::contains_key(&iroha_address));
+        assert!(PendingMultiSigAccounts::<Runtime>::contains_key(&iroha_address));
+    });
+}
+
+#[test]
+fn test_transfer_unmigrated_balances() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Pallet::<Runtime>::migrate(
+            Origin::signed(ALICE),
+             "did_sora_balance@sora".to_string(),
+              "9a685d77bcd3f60e6cc1e91eedc7a48e11bbcf1a036b920f3bae0372a78A5432".to_string(),
+              "233896712f752760713539f56c92534ff8f4f290812e8f129Ce0b513b99cbdffcea95abeed68edd1b0a4e4b52877c13c26c6c89e5bb6bf023ac6c0f4f53c0c02".to_string()));
+        approve_multi_sig_by_alice_and_bob();
+        assert_ok!(Pallet::<Runtime>::close_migration(Origin::root()));
+        assert_eq!(Balances::<Runtime>::iter().count(), 4);
+
+        assert_ok!(Pallet::<Runtime>::transfer_unmigrated_balances(
+            Origin::root(),
+            CHARLIE,
+            2
+        ));
+        assert_eq!(Balances::<Runtime>::iter().count(), 2);
+        assert_ok!(Pallet::<Runtime>::transfer_unmigrated_balances(
+            Origin::root(),
+            CHARLIE,
+            10
+        ));
+        assert_eq!(Balances::<Runtime>::iter().count(), 0);
+        assert_eq!(PublicKeys::<Runtime>::iter().count(), 0);
+        assert!(Pallet::<Runtime>::pending_multisig_migrations().is_empty());
+        assert!(!Pallet::<Runtime>::needs_migration(
+            &"did_sora_multi_sig@sora".to_string()
+        ));
+        assert_eq!(Assets::free_balance(&VAL, &ALICE).unwrap(), Balance::from(300u128));
+        assert_eq!(Assets::free_balance(&VAL, &CHARLIE).unwrap(), Balance::from(1000u128));
+    });
+}
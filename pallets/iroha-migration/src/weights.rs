@@ -74,6 +74,24 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(11 as Weight))
             .saturating_add(T::DbWeight::get().writes(8 as Weight))
     }
+    fn extend_multisig_deadline() -> Weight {
+        (32_540_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn close_migration() -> Weight {
+        (21_300_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn transfer_unmigrated_balances(n: u32) -> Weight {
+        (118_460_000 as Weight)
+            .saturating_add((24_170_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(n as Weight)))
+    }
 }
 
 impl crate::WeightInfo for () {
@@ -84,4 +102,16 @@ impl crate::WeightInfo for () {
     fn on_initialize() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+
+    fn extend_multisig_deadline() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn close_migration() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn transfer_unmigrated_balances(_n: u32) -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
 }
@@ -108,7 +108,7 @@ construct_runtime! {
         Currencies: currencies::{Module, Call, Storage, Event<T>},
         Assets: assets::{Module, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Module, Call, Config<T>, Storage, Event<T>},
         Technical: technical::{Module, Call, Config<T>, Storage, Event<T>},
         Permissions: permissions::{Module, Call, Config<T>, Storage, Event<T>},
         DexApi: dex_api::{Module, Call, Config, Storage, Event<T>},
@@ -156,6 +156,7 @@ impl liquidity_proxy::Config for Runtime {
     type PrimaryMarketXST = ();
     type SecondaryMarket = ();
     type VestedRewardsPallet = vested_rewards::Module<Runtime>;
+    type OnSwapFeePaid = ();
 }
 
 impl tokens::Config for Runtime {
@@ -203,7 +204,11 @@ impl pallet_balances::Config for Runtime {
     type MaxLocks = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl mock_liquidity_source::Config<mock_liquidity_source::Instance1> for Runtime {
     type GetFee = GetFee;
@@ -236,10 +241,12 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl dex_api::Config for Runtime {
@@ -251,6 +258,7 @@ impl dex_api::Config for Runtime {
     type XYKPool = pool_xyk::Module<Runtime>;
     type XSTPool = ();
     type MulticollateralBondingCurvePool = multicollateral_bonding_curve_pool::Module<Runtime>;
+    type IsSourceEnabled = trading_pair::Module<Runtime>;
     type WeightInfo = ();
 }
 
@@ -273,6 +281,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = ();
     type WeightInfo = ();
 }
 
@@ -430,6 +439,8 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
     type EnsureDEXManager = dex_manager::Module<Runtime>;
     type EnsureTradingPairExists = trading_pair::Module<Runtime>;
     type PriceToolsPallet = MockPriceTools;
+    type PriceOracle = ();
+    type CircuitBreaker = ();
     type VestedRewardsPallet = VestedRewards;
     type WeightInfo = ();
 }
@@ -447,6 +458,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = ();
 }
 
 impl price_tools::Config for Runtime {
@@ -39,8 +39,8 @@ use common::prelude::fixnum::ops::{Bounded, Zero as _};
 use common::prelude::{Balance, FixedWrapper, QuoteAmount, SwapAmount, SwapOutcome, SwapVariant};
 use common::{
     balance, fixed_wrapper, FilterMode, Fixed, GetMarketInfo, GetPoolReserves, LiquidityRegistry,
-    LiquiditySource, LiquiditySourceFilter, LiquiditySourceId, LiquiditySourceType, RewardReason,
-    TradingPair, VestedRewardsPallet, XSTUSD,
+    LiquiditySource, LiquiditySourceFilter, LiquiditySourceId, LiquiditySourceType, OnSwapFeePaid,
+    RewardReason, TradingPair, VestedRewardsPallet, XSTUSD,
 };
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
@@ -216,8 +216,17 @@ impl<T: Config> Pallet<T> {
             input_asset_id != output_asset_id,
             Error::<T>::UnavailableExchangePath
         );
+        let base_asset_id = Self::dex_base_asset_id(&filter.dex_id)?;
+        // Market maker volumes are only tracked in XOR, so swaps on DEXes quoted in other
+        // assets are not accounted for.
+        let track_volume = base_asset_id == T::GetBaseAssetId::get();
         common::with_transaction(|| {
-            match Self::construct_trivial_path(*input_asset_id, *output_asset_id) {
+            match Self::construct_trivial_path(
+                &filter.dex_id,
+                base_asset_id,
+                *input_asset_id,
+                *output_asset_id,
+            ) {
                 ExchangePath::Direct {
                     from_asset_id,
                     to_asset_id,
@@ -230,9 +239,20 @@ impl<T: Config> Pallet<T> {
                         amount,
                         filter,
                     )?;
-                    let xor_volume =
-                        Self::get_xor_amount(from_asset_id, to_asset_id, amount, outcome.clone());
-                    T::VestedRewardsPallet::update_market_maker_records(&sender, xor_volume, 1)?;
+                    // Direct exchanges between two non-base assets don't have a XOR leg.
+                    if track_volume
+                        && (from_asset_id == base_asset_id || to_asset_id == base_asset_id)
+                    {
+                        let xor_volume = Self::get_xor_amount(
+                            from_asset_id,
+                            to_asset_id,
+                            amount,
+                            outcome.clone(),
+                        );
+                        T::VestedRewardsPallet::update_market_maker_records(
+                            &sender, xor_volume, 1,
+                        )?;
+                    }
                     Ok(outcome)
                 }
                 ExchangePath::Twofold {
@@ -265,11 +285,13 @@ impl<T: Config> Pallet<T> {
                             second_swap.amount >= min_amount_out,
                             Error::<T>::SlippageNotTolerated
                         );
-                        T::VestedRewardsPallet::update_market_maker_records(
-                            &sender,
-                            first_swap.amount,
-                            2,
-                        )?;
+                        if track_volume {
+                            T::VestedRewardsPallet::update_market_maker_records(
+                                &sender,
+                                first_swap.amount,
+                                2,
+                            )?;
+                        }
                         let cumulative_fee = first_swap
                             .fee
                             .checked_add(second_swap.fee)
@@ -315,11 +337,13 @@ impl<T: Config> Pallet<T> {
                             SwapAmount::with_desired_input(first_swap.amount, Balance::zero()),
                             filter,
                         )?;
-                        T::VestedRewardsPallet::update_market_maker_records(
-                            &sender,
-                            first_swap.amount,
-                            2,
-                        )?;
+                        if track_volume {
+                            T::VestedRewardsPallet::update_market_maker_records(
+                                &sender,
+                                first_swap.amount,
+                                2,
+                            )?;
+                        }
                         let cumulative_fee = first_swap
                             .fee
                             .checked_add(second_swap.fee)
@@ -394,7 +418,13 @@ impl<T: Config> Pallet<T> {
             input_asset_id != output_asset_id,
             Error::<T>::UnavailableExchangePath
         );
-        match Self::construct_trivial_path(*input_asset_id, *output_asset_id) {
+        let base_asset_id = Self::dex_base_asset_id(&filter.dex_id)?;
+        match Self::construct_trivial_path(
+            &filter.dex_id,
+            base_asset_id,
+            *input_asset_id,
+            *output_asset_id,
+        ) {
             ExchangePath::Direct {
                 from_asset_id,
                 to_asset_id,
@@ -661,12 +691,24 @@ impl<T: Config> Pallet<T> {
         Ok(outcome_without_impact)
     }
 
+    /// Returns the asset the given DEX is quoted in, i.e. the intermediate asset of trivial paths.
+    pub fn dex_base_asset_id(dex_id: &T::DEXId) -> Result<T::AssetId, DispatchError> {
+        Ok(dex_manager::Pallet::<T>::get_dex_info(dex_id)?.base_asset_id)
+    }
+
+    /// Constructs the path between two assets on the DEX. Assets are exchanged directly if one of
+    /// them is the DEX base asset or if they form a trading pair with usable sources, otherwise
+    /// the exchange goes through the base asset.
     pub fn construct_trivial_path(
+        dex_id: &T::DEXId,
+        base_asset_id: T::AssetId,
         input_asset_id: T::AssetId,
         output_asset_id: T::AssetId,
     ) -> ExchangePath<T> {
-        let base_asset_id = T::GetBaseAssetId::get();
-        if input_asset_id == base_asset_id || output_asset_id == base_asset_id {
+        if input_asset_id == base_asset_id
+            || output_asset_id == base_asset_id
+            || Self::is_direct_pair_usable(dex_id, &input_asset_id, &output_asset_id)
+        {
             ExchangePath::Direct {
                 from_asset_id: input_asset_id,
                 to_asset_id: output_asset_id,
@@ -680,19 +722,31 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    fn is_direct_pair_usable(
+        dex_id: &T::DEXId,
+        asset_a: &T::AssetId,
+        asset_b: &T::AssetId,
+    ) -> bool {
+        trading_pair::Pallet::<T>::list_enabled_sources_for_trading_pair(dex_id, asset_a, asset_b)
+            .map(|sources| !sources.is_empty())
+            .unwrap_or(false)
+    }
+
     /// Check if given two arbitrary tokens can be used to perform an exchange via any available sources.
     pub fn is_path_available(
         dex_id: T::DEXId,
         input_asset_id: T::AssetId,
         output_asset_id: T::AssetId,
     ) -> Result<bool, DispatchError> {
-        let path = Self::construct_trivial_path(input_asset_id, output_asset_id);
+        let base_asset_id = Self::dex_base_asset_id(&dex_id)?;
+        let path =
+            Self::construct_trivial_path(&dex_id, base_asset_id, input_asset_id, output_asset_id);
         let path_exists = match path {
             ExchangePath::Direct {
                 from_asset_id,
                 to_asset_id,
             } => {
-                let pair = Self::weak_sort_pair(from_asset_id, to_asset_id);
+                let pair = Self::weak_sort_pair(base_asset_id, from_asset_id, to_asset_id);
                 !trading_pair::Pallet::<T>::list_enabled_sources_for_trading_pair(
                     &dex_id,
                     &pair.base_asset_id,
@@ -729,13 +783,15 @@ impl<T: Config> Pallet<T> {
         input_asset_id: T::AssetId,
         output_asset_id: T::AssetId,
     ) -> Result<Vec<LiquiditySourceType>, DispatchError> {
-        let path = Self::construct_trivial_path(input_asset_id, output_asset_id);
+        let base_asset_id = Self::dex_base_asset_id(&dex_id)?;
+        let path =
+            Self::construct_trivial_path(&dex_id, base_asset_id, input_asset_id, output_asset_id);
         match path {
             ExchangePath::Direct {
                 from_asset_id,
                 to_asset_id,
             } => {
-                let pair = Self::weak_sort_pair(from_asset_id, to_asset_id);
+                let pair = Self::weak_sort_pair(base_asset_id, from_asset_id, to_asset_id);
                 let sources = trading_pair::Pallet::<T>::list_enabled_sources_for_trading_pair(
                     &dex_id,
                     &pair.base_asset_id,
@@ -785,8 +841,13 @@ impl<T: Config> Pallet<T> {
     }
 
     // Not full sort, just ensure that if there is base asset then it's sorted, otherwise order is unchanged.
-    fn weak_sort_pair(asset_a: T::AssetId, asset_b: T::AssetId) -> TradingPair<T::AssetId> {
-        if asset_b == T::GetBaseAssetId::get() {
+    // Pairs without base asset are resolved to the stored order by the trading pair pallet.
+    fn weak_sort_pair(
+        base_asset_id: T::AssetId,
+        asset_a: T::AssetId,
+        asset_b: T::AssetId,
+    ) -> TradingPair<T::AssetId> {
+        if asset_b == base_asset_id {
             TradingPair {
                 base_asset_id: asset_b,
                 target_asset_id: asset_a,
@@ -1295,6 +1356,8 @@ pub mod pallet {
         type PrimaryMarketXST: GetMarketInfo<Self::AssetId>;
         type SecondaryMarket: GetPoolReserves<Self::AssetId>;
         type VestedRewardsPallet: VestedRewardsPallet<Self::AccountId>;
+        /// Receives the XOR fees paid by the accounts for swaps, e.g. to reward their referrers.
+        type OnSwapFeePaid: OnSwapFeePaid<Self::AccountId>;
         /// Weight information for the extrinsics in this Pallet.
         type WeightInfo: WeightInfo;
     }
@@ -1355,6 +1418,7 @@ pub mod pallet {
                     desired_amount_out, ..
                 } => (outcome.amount, desired_amount_out, outcome.fee),
             };
+            T::OnSwapFeePaid::on_swap_fee_paid(&who, fee_amount);
             Self::deposit_event(Event::<T>::Exchange(
                 who,
                 dex_id,
@@ -124,7 +124,7 @@ construct_runtime! {
         Currencies: currencies::{Module, Call, Storage, Event<T>},
         Assets: assets::{Module, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Storage},
+        DexManager: dex_manager::{Module, Call, Storage, Event<T>},
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Module, Call, Config<T>, Storage},
         MockLiquiditySource2: mock_liquidity_source::<Instance2>::{Module, Call, Config<T>, Storage},
         MockLiquiditySource3: mock_liquidity_source::<Instance3>::{Module, Call, Config<T>, Storage},
@@ -175,6 +175,7 @@ impl Config for Runtime {
     type PrimaryMarketXST = MockXSTPool;
     type SecondaryMarket = mock_liquidity_source::Module<Runtime, mock_liquidity_source::Instance1>;
     type VestedRewardsPallet = vested_rewards::Module<Runtime>;
+    type OnSwapFeePaid = ();
 }
 
 impl tokens::Config for Runtime {
@@ -222,7 +223,11 @@ impl pallet_balances::Config for Runtime {
     type MaxLocks = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl mock_liquidity_source::Config<mock_liquidity_source::Instance1> for Runtime {
     type GetFee = GetFee0;
@@ -255,10 +260,12 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl dex_api::Config for Runtime {
@@ -274,6 +281,7 @@ impl dex_api::Config for Runtime {
     type XYKPool = ();
     type MulticollateralBondingCurvePool = MockMCBCPool;
     type XSTPool = MockXSTPool;
+    type IsSourceEnabled = ();
     type WeightInfo = ();
 }
 
@@ -296,6 +304,7 @@ impl pswap_distribution::Config for Runtime {
     type PoolXykPallet = pool_xyk::Module<Runtime>;
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
+    type PriceToolsPallet = ();
 }
 
 impl pool_xyk::Config for Runtime {
@@ -310,6 +319,7 @@ impl pool_xyk::Config for Runtime {
     type EnsureDEXManager = dex_manager::Module<Runtime>;
     type OnPoolCreated = pswap_distribution::Module<Runtime>;
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = ();
     type GetFee = GetXykFee;
     type WeightInfo = ();
 }
@@ -321,6 +331,8 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
     type EnsureDEXManager = dex_manager::Module<Runtime>;
     type VestedRewardsPallet = VestedRewards;
     type PriceToolsPallet = ();
+    type PriceOracle = ();
+    type CircuitBreaker = ();
     type WeightInfo = ();
 }
 
@@ -29,7 +29,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::mock::*;
-use crate::{Error, LiquidityProxyTrait};
+use crate::{Error, ExchangePath, LiquidityProxyTrait};
 use common::prelude::fixnum::ops::CheckedSub;
 use common::prelude::{Balance, QuoteAmount, SwapAmount};
 use common::{
@@ -1583,6 +1583,23 @@ fn test_is_path_available_should_pass_3() {
     });
 }
 
+#[test]
+#[rustfmt::skip]
+fn test_is_path_available_should_pass_5() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        use LiquiditySourceType::*;
+        TradingPair::register(Origin::signed(alice()), 0, VAL, PSWAP).expect("failed to register pair");
+        TradingPair::enable_source_for_trading_pair(&0, &PSWAP, &VAL, XYKPool).expect("failed to enable source");
+        assert!(matches!(LiquidityProxy::construct_trivial_path(&0, XOR, VAL, PSWAP), ExchangePath::Direct { .. }));
+        assert!(matches!(LiquidityProxy::construct_trivial_path(&0, XOR, VAL, USDT), ExchangePath::Twofold { .. }));
+        assert_eq!(LiquidityProxy::is_path_available(0, XOR, VAL).unwrap(), false);
+        assert_eq!(LiquidityProxy::is_path_available(0, VAL, PSWAP).unwrap(), true);
+        assert_eq!(LiquidityProxy::is_path_available(0, PSWAP, VAL).unwrap(), true);
+        assert_eq!(LiquidityProxy::list_enabled_sources_for_path(0, PSWAP, VAL).unwrap(), vec![XYKPool]);
+    });
+}
+
 #[test]
 #[rustfmt::skip]
 fn test_is_path_available_should_pass_4() {
@@ -0,0 +1,253 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+
+use common::{BalanceWrapper, InvokeRPCError};
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+// Runtime API imports.
+pub use multicollateral_bonding_curve_pool_runtime_api::MulticollateralBondingCurvePoolAPI as MulticollateralBondingCurvePoolRuntimeAPI;
+use multicollateral_bonding_curve_pool_runtime_api::{BalanceInfo, CurveStateInfo, RewardsInfo};
+
+#[rpc]
+pub trait MulticollateralBondingCurvePoolAPI<
+    BlockHash,
+    AccountId,
+    AssetId,
+    SwapVariant,
+    CurveStateInfo,
+    RewardsInfo,
+    BalanceInfo,
+    OptionalBalanceInfo,
+>
+{
+    #[rpc(name = "multicollateralBondingCurvePool_curveState")]
+    fn curve_state(&self, at: Option<BlockHash>) -> Result<CurveStateInfo>;
+
+    #[rpc(name = "multicollateralBondingCurvePool_rewards")]
+    fn rewards(&self, account_id: AccountId, at: Option<BlockHash>) -> Result<RewardsInfo>;
+
+    #[rpc(name = "multicollateralBondingCurvePool_totalRewards")]
+    fn total_rewards(&self, at: Option<BlockHash>) -> Result<BalanceInfo>;
+
+    #[rpc(name = "multicollateralBondingCurvePool_collateralReserves")]
+    fn collateral_reserves(
+        &self,
+        collateral_asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> Result<OptionalBalanceInfo>;
+
+    #[rpc(name = "multicollateralBondingCurvePool_idealReserves")]
+    fn ideal_reserves(&self, at: Option<BlockHash>) -> Result<OptionalBalanceInfo>;
+
+    #[rpc(name = "multicollateralBondingCurvePool_buyPrice")]
+    fn buy_price(
+        &self,
+        collateral_asset_id: AssetId,
+        amount: BalanceWrapper,
+        swap_variant: SwapVariant,
+        at: Option<BlockHash>,
+    ) -> Result<OptionalBalanceInfo>;
+
+    #[rpc(name = "multicollateralBondingCurvePool_sellPrice")]
+    fn sell_price(
+        &self,
+        collateral_asset_id: AssetId,
+        amount: BalanceWrapper,
+        swap_variant: SwapVariant,
+        at: Option<BlockHash>,
+    ) -> Result<OptionalBalanceInfo>;
+}
+
+pub struct MulticollateralBondingCurvePoolClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> MulticollateralBondingCurvePoolClient<C, B> {
+    /// Construct default `Template`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, AssetId, Balance, SwapVariant>
+    MulticollateralBondingCurvePoolAPI<
+        <Block as BlockT>::Hash,
+        AccountId,
+        AssetId,
+        SwapVariant,
+        Option<CurveStateInfo<AssetId, Balance>>,
+        RewardsInfo<Balance>,
+        BalanceInfo<Balance>,
+        Option<BalanceInfo<Balance>>,
+    > for MulticollateralBondingCurvePoolClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api:
+        MulticollateralBondingCurvePoolRuntimeAPI<Block, AccountId, AssetId, Balance, SwapVariant>,
+    AccountId: Codec,
+    AssetId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+    SwapVariant: Codec,
+{
+    fn curve_state(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<CurveStateInfo<AssetId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.curve_state(&at).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get bonding curve state.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn rewards(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<RewardsInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.rewards(&at, account_id).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get PSWAP rewards for account.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn total_rewards(&self, at: Option<<Block as BlockT>::Hash>) -> Result<BalanceInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.total_rewards(&at).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get total PSWAP rewards.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn collateral_reserves(
+        &self,
+        collateral_asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<BalanceInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.collateral_reserves(&at, collateral_asset_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get collateral reserves.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn ideal_reserves(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<BalanceInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.ideal_reserves(&at).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get ideal reserves.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn buy_price(
+        &self,
+        collateral_asset_id: AssetId,
+        amount: BalanceWrapper,
+        swap_variant: SwapVariant,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<BalanceInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.buy_price(&at, collateral_asset_id, amount, swap_variant)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get buy price.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn sell_price(
+        &self,
+        collateral_asset_id: AssetId,
+        amount: BalanceWrapper,
+        swap_variant: SwapVariant,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<BalanceInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.sell_price(&at, collateral_asset_id, amount, swap_variant)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get sell price.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+}
@@ -41,7 +41,7 @@ use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use sp_std::prelude::*;
 
-use common::{fixed, AssetName, AssetSymbol, DAI, USDT, XOR};
+use common::{fixed, AssetName, AssetSymbol, DAI, PSWAP, USDT, XOR};
 
 use crate::Pallet as MBCPool;
 use assets::Pallet as Assets;
@@ -208,6 +208,97 @@ benchmarks! {
         assert_last_event::<T>(Event::OptionalRewardMultiplierUpdated(USDT.into(), Some(fixed!(123))).into())
     }
 
+    retire_collateral {
+        let caller = alice::<T>();
+        frame_system::Module::<T>::inc_providers(&caller);
+        let dex_id: T::DEXId = common::DEXId::Polkaswap.into();
+        Permissions::<T>::assign_permission(
+            caller.clone(),
+            &caller,
+            permissions::MANAGE_DEX,
+            permissions::Scope::Limited(common::hash(&dex_id)),
+        ).unwrap();
+        Assets::<T>::register_asset_id(caller.clone(), USDT.into(), AssetSymbol(b"TESTUSD".to_vec()), AssetName(b"USD".to_vec()), 18, Balance::zero(), true).unwrap();
+        TradingPair::<T>::register(RawOrigin::Signed(caller.clone()).into(), common::DEXId::Polkaswap.into(), XOR.into(), USDT.into()).unwrap();
+        MBCPool::<T>::initialize_pool(RawOrigin::Signed(caller.clone()).into(), USDT.into()).unwrap();
+    }: {
+        Module::<T>::retire_collateral(
+            RawOrigin::Signed(caller.clone()).into(),
+            USDT.into()
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::CollateralRetired(USDT.into()).into())
+    }
+
+    reinstate_collateral {
+        let caller = alice::<T>();
+        frame_system::Module::<T>::inc_providers(&caller);
+        let dex_id: T::DEXId = common::DEXId::Polkaswap.into();
+        Permissions::<T>::assign_permission(
+            caller.clone(),
+            &caller,
+            permissions::MANAGE_DEX,
+            permissions::Scope::Limited(common::hash(&dex_id)),
+        ).unwrap();
+        Assets::<T>::register_asset_id(caller.clone(), USDT.into(), AssetSymbol(b"TESTUSD".to_vec()), AssetName(b"USD".to_vec()), 18, Balance::zero(), true).unwrap();
+        TradingPair::<T>::register(RawOrigin::Signed(caller.clone()).into(), common::DEXId::Polkaswap.into(), XOR.into(), USDT.into()).unwrap();
+        MBCPool::<T>::initialize_pool(RawOrigin::Signed(caller.clone()).into(), USDT.into()).unwrap();
+        MBCPool::<T>::retire_collateral(RawOrigin::Signed(caller.clone()).into(), USDT.into()).unwrap();
+    }: {
+        Module::<T>::reinstate_collateral(
+            RawOrigin::Signed(caller.clone()).into(),
+            USDT.into()
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::CollateralReinstated(USDT.into()).into())
+    }
+
+    set_price_source {
+    }: {
+        Module::<T>::set_price_source(
+            RawOrigin::Root.into(),
+            PriceSource::Oracle
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::PriceSourceChanged(PriceSource::Oracle).into())
+    }
+
+    set_curve_parameter {
+        let caller = alice::<T>();
+        frame_system::Module::<T>::inc_providers(&caller);
+        let dex_id: T::DEXId = common::DEXId::Polkaswap.into();
+        Permissions::<T>::assign_permission(
+            caller.clone(),
+            &caller,
+            permissions::MANAGE_DEX,
+            permissions::Scope::Limited(common::hash(&dex_id)),
+        ).unwrap();
+    }: {
+        Module::<T>::set_base_fee(
+            RawOrigin::Signed(caller.clone()).into(),
+            fixed!(0.004)
+        ).unwrap();
+    }
+    verify {
+        assert_last_event::<T>(Event::BaseFeeChanged(fixed!(0.004)).into())
+    }
+
+    claim_incentives {
+        setup_benchmark::<T>().unwrap();
+        let caller = alice::<T>();
+        let incentives_account_id = IncentivesAccountId::<T>::get();
+        Assets::<T>::mint_to(&PSWAP.into(), &caller, &incentives_account_id, balance!(1000)).unwrap();
+        // claim is limited, so that remaining rewards are kept in storage
+        Rewards::<T>::insert(&caller, (balance!(50), balance!(100)));
+        TotalRewards::<T>::put(balance!(100));
+    }: _(RawOrigin::Signed(caller.clone()), None, false)
+    verify {
+        assert_last_event::<T>(Event::RewardsClaimed(caller, balance!(50)).into())
+    }
+
     on_initialize {
         let n in 0 .. 10;
         setup_benchmark::<T>().unwrap();
@@ -231,6 +322,11 @@ mod tests {
             assert_ok!(test_benchmark_initialize_pool::<Runtime>());
             assert_ok!(test_benchmark_set_reference_asset::<Runtime>());
             assert_ok!(test_benchmark_set_optional_reward_multiplier::<Runtime>());
+            assert_ok!(test_benchmark_retire_collateral::<Runtime>());
+            assert_ok!(test_benchmark_reinstate_collateral::<Runtime>());
+            assert_ok!(test_benchmark_set_price_source::<Runtime>());
+            assert_ok!(test_benchmark_set_curve_parameter::<Runtime>());
+            assert_ok!(test_benchmark_claim_incentives::<Runtime>());
         });
     }
 }
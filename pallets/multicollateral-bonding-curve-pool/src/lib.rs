@@ -46,20 +46,21 @@ use assets::AssetIdOf;
 use codec::{Decode, Encode};
 use common::fixnum::ops::Zero as _;
 use common::prelude::{
-    Balance, EnsureDEXManager, EnsureTradingPairExists, Fixed, FixedWrapper, PriceToolsPallet,
-    QuoteAmount, SwapAmount, SwapOutcome,
+    Balance, EnsureDEXManager, EnsureTradingPairExists, Fixed, FixedWrapper, PriceOracle,
+    PriceToolsPallet, QuoteAmount, SwapAmount, SwapOutcome,
 };
 use common::{
-    balance, fixed, fixed_wrapper, DEXId, DexIdOf, GetMarketInfo, LiquiditySource,
-    LiquiditySourceFilter, LiquiditySourceType, ManagementMode, RewardReason, VestedRewardsPallet,
-    PSWAP, VAL, XSTUSD,
+    balance, fixed, fixed_wrapper, CircuitBreaker, DEXId, DexIdOf, GetMarketInfo, LiquiditySource,
+    LiquiditySourceFilter, LiquiditySourceType, ManagementMode, PriceSource, RewardReason,
+    VestedRewardsPallet, PSWAP, VAL, XSTUSD,
 };
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
-use frame_support::{ensure, fail};
+use frame_support::{ensure, fail, transactional};
 use liquidity_proxy::LiquidityProxyTrait;
 use permissions::{Scope, BURN, MINT};
 use sp_arithmetic::traits::Zero;
+use sp_runtime::traits::UniqueSaturatedInto;
 use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
@@ -74,10 +75,17 @@ pub trait WeightInfo {
     fn initialize_pool() -> Weight;
     fn set_reference_asset() -> Weight;
     fn set_optional_reward_multiplier() -> Weight;
+    fn set_price_source() -> Weight;
+    fn check_price_deviation(n: u32) -> Weight;
+    fn retire_collateral() -> Weight;
+    fn reinstate_collateral() -> Weight;
+    fn set_curve_parameter() -> Weight;
+    fn claim_incentives() -> Weight;
 }
 
 type Assets<T> = assets::Module<T>;
 type Technical<T> = technical::Module<T>;
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 pub const TECH_ACCOUNT_PREFIX: &[u8] = b"multicollateral-bonding-curve-pool";
 pub const TECH_ACCOUNT_RESERVES: &[u8] = b"reserves";
@@ -85,6 +93,8 @@ pub const TECH_ACCOUNT_REWARDS: &[u8] = b"rewards";
 pub const TECH_ACCOUNT_FREE_RESERVES: &[u8] = b"free_reserves";
 
 pub const RETRY_DISTRIBUTION_FREQUENCY: u32 = 1000;
+/// Maximum number of collateral assets checked by circuit breaker in a single block.
+pub const MAX_CIRCUIT_BREAKER_CHECKS_PER_BLOCK: u32 = 10;
 
 pub use pallet::*;
 
@@ -212,6 +222,10 @@ pub mod pallet {
             DispatchError,
         >;
         type PriceToolsPallet: PriceToolsPallet<Self::AssetId>;
+        /// External price oracle, which can be chosen as a source of reference prices instead of price tools.
+        type PriceOracle: PriceOracle<Self::AssetId>;
+        /// Circuit breaker, which halts trading of collateral assets when prices deviate too much from average.
+        type CircuitBreaker: CircuitBreaker<Self::AssetId>;
         type VestedRewardsPallet: VestedRewardsPallet<Self::AccountId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
@@ -224,12 +238,14 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(block_number: T::BlockNumber) -> Weight {
-            if (block_number % RETRY_DISTRIBUTION_FREQUENCY.into()).is_zero() {
+            let weight = if (block_number % RETRY_DISTRIBUTION_FREQUENCY.into()).is_zero() {
                 let elems = Module::<T>::free_reserves_distribution_routine();
                 <T as Config>::WeightInfo::on_initialize(elems)
             } else {
                 <T as Config>::WeightInfo::on_initialize(0)
-            }
+            };
+            let checked = Module::<T>::circuit_breaker_routine(block_number);
+            weight.saturating_add(<T as Config>::WeightInfo::check_price_deviation(checked))
         }
     }
 
@@ -293,10 +309,188 @@ pub mod pallet {
             ));
             Ok(().into())
         }
+
+        /// Change source of reference prices used to determine collateral assets value.
+        #[pallet::weight(<T as Config>::WeightInfo::set_price_source())]
+        pub fn set_price_source(
+            origin: OriginFor<T>,
+            price_source: PriceSource,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ReferencePriceSource::<T>::put(price_source);
+            Self::deposit_event(Event::PriceSourceChanged(price_source));
+            Ok(().into())
+        }
+
+        /// Retire collateral asset: buying XOR with it is disabled, while selling XOR for it remains
+        /// possible, so that collateral reserves can be sold out.
+        #[pallet::weight(<T as Config>::WeightInfo::retire_collateral())]
+        pub fn retire_collateral(
+            origin: OriginFor<T>,
+            collateral_asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            let _who = <T as Config>::EnsureDEXManager::ensure_can_manage(
+                &DEXId::Polkaswap.into(),
+                origin,
+                ManagementMode::Private,
+            )?;
+            ensure!(
+                Self::enabled_targets().contains(&collateral_asset_id),
+                Error::<T>::UnsupportedCollateralAssetId
+            );
+            RetiredTargets::<T>::try_mutate(|set| -> DispatchResult {
+                ensure!(
+                    set.insert(collateral_asset_id),
+                    Error::<T>::CollateralAlreadyRetired
+                );
+                Ok(())
+            })?;
+            Self::deposit_event(Event::CollateralRetired(collateral_asset_id));
+            Ok(().into())
+        }
+
+        /// Reinstate previously retired collateral asset: buying XOR with it is enabled again.
+        #[pallet::weight(<T as Config>::WeightInfo::reinstate_collateral())]
+        pub fn reinstate_collateral(
+            origin: OriginFor<T>,
+            collateral_asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            let _who = <T as Config>::EnsureDEXManager::ensure_can_manage(
+                &DEXId::Polkaswap.into(),
+                origin,
+                ManagementMode::Private,
+            )?;
+            RetiredTargets::<T>::try_mutate(|set| -> DispatchResult {
+                ensure!(
+                    set.remove(&collateral_asset_id),
+                    Error::<T>::CollateralNotRetired
+                );
+                Ok(())
+            })?;
+            Self::deposit_event(Event::CollateralReinstated(collateral_asset_id));
+            Ok(().into())
+        }
+
+        /// Change buy price starting constant. Should be positive.
+        #[pallet::weight(<T as Config>::WeightInfo::set_curve_parameter())]
+        pub fn set_initial_price(
+            origin: OriginFor<T>,
+            initial_price: Fixed,
+        ) -> DispatchResultWithPostInfo {
+            let _who = <T as Config>::EnsureDEXManager::ensure_can_manage(
+                &DEXId::Polkaswap.into(),
+                origin,
+                ManagementMode::Private,
+            )?;
+            ensure!(
+                initial_price > fixed!(0),
+                Error::<T>::CurveParameterOutOfBounds
+            );
+            InitialPrice::<T>::put(initial_price);
+            Self::deposit_event(Event::InitialPriceChanged(initial_price));
+            Ok(().into())
+        }
+
+        /// Change price change step of buy price function. Should be positive.
+        #[pallet::weight(<T as Config>::WeightInfo::set_curve_parameter())]
+        pub fn set_price_change_step(
+            origin: OriginFor<T>,
+            price_change_step: Fixed,
+        ) -> DispatchResultWithPostInfo {
+            let _who = <T as Config>::EnsureDEXManager::ensure_can_manage(
+                &DEXId::Polkaswap.into(),
+                origin,
+                ManagementMode::Private,
+            )?;
+            ensure!(
+                price_change_step > fixed!(0),
+                Error::<T>::CurveParameterOutOfBounds
+            );
+            PriceChangeStep::<T>::put(price_change_step);
+            Self::deposit_event(Event::PriceChangeStepChanged(price_change_step));
+            Ok(().into())
+        }
+
+        /// Change price change rate of buy price function. Should be positive.
+        #[pallet::weight(<T as Config>::WeightInfo::set_curve_parameter())]
+        pub fn set_price_change_rate(
+            origin: OriginFor<T>,
+            price_change_rate: Fixed,
+        ) -> DispatchResultWithPostInfo {
+            let _who = <T as Config>::EnsureDEXManager::ensure_can_manage(
+                &DEXId::Polkaswap.into(),
+                origin,
+                ManagementMode::Private,
+            )?;
+            ensure!(
+                price_change_rate > fixed!(0),
+                Error::<T>::CurveParameterOutOfBounds
+            );
+            PriceChangeRate::<T>::put(price_change_rate);
+            Self::deposit_event(Event::PriceChangeRateChanged(price_change_rate));
+            Ok(().into())
+        }
+
+        /// Change fraction of buy price which determines sell price. Should be in range (0, 1].
+        #[pallet::weight(<T as Config>::WeightInfo::set_curve_parameter())]
+        pub fn set_sell_price_coefficient(
+            origin: OriginFor<T>,
+            sell_price_coefficient: Fixed,
+        ) -> DispatchResultWithPostInfo {
+            let _who = <T as Config>::EnsureDEXManager::ensure_can_manage(
+                &DEXId::Polkaswap.into(),
+                origin,
+                ManagementMode::Private,
+            )?;
+            ensure!(
+                sell_price_coefficient > fixed!(0) && sell_price_coefficient <= fixed!(1),
+                Error::<T>::CurveParameterOutOfBounds
+            );
+            SellPriceCoefficient::<T>::put(sell_price_coefficient);
+            Self::deposit_event(Event::SellPriceCoefficientChanged(sell_price_coefficient));
+            Ok(().into())
+        }
+
+        /// Change base fee deducted on all trades. Should be in range [0, 1).
+        #[pallet::weight(<T as Config>::WeightInfo::set_curve_parameter())]
+        pub fn set_base_fee(origin: OriginFor<T>, base_fee: Fixed) -> DispatchResultWithPostInfo {
+            let _who = <T as Config>::EnsureDEXManager::ensure_can_manage(
+                &DEXId::Polkaswap.into(),
+                origin,
+                ManagementMode::Private,
+            )?;
+            ensure!(
+                base_fee >= fixed!(0) && base_fee < fixed!(1),
+                Error::<T>::CurveParameterOutOfBounds
+            );
+            BaseFee::<T>::put(base_fee);
+            Self::deposit_event(Event::BaseFeeChanged(base_fee));
+            Ok(().into())
+        }
+
+        /// Claim PSWAP rewards owned for buying XOR on bonding curve.
+        /// - `amount`: amount to claim, `None` claims everything allowed by current claim limit.
+        /// - `via_vested_rewards`: move rewards to vested rewards registry instead of paying them out,
+        ///   so that they can be claimed along with other PSWAP rewards.
+        #[pallet::weight(<T as Config>::WeightInfo::claim_incentives())]
+        #[transactional]
+        pub fn claim_incentives(
+            origin: OriginFor<T>,
+            amount: Option<Balance>,
+            via_vested_rewards: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::claim_incentives_inner(&who, amount, via_vested_rewards)?;
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
-    #[pallet::metadata(DexIdOf<T> = "DEXId", AssetIdOf<T> = "AssetId")]
+    #[pallet::metadata(
+        AccountIdOf<T> = "AccountId",
+        DexIdOf<T> = "DEXId",
+        AssetIdOf<T> = "AssetId"
+    )]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Pool is initialized for pair. [DEX Id, Collateral Asset Id]
@@ -305,6 +499,26 @@ pub mod pallet {
         ReferenceAssetChanged(AssetIdOf<T>),
         /// Multiplier for reward has been updated on particular asset. [Asset Id, New Multiplier]
         OptionalRewardMultiplierUpdated(AssetIdOf<T>, Option<Fixed>),
+        /// Source of reference prices has been changed. [New Price Source]
+        PriceSourceChanged(PriceSource),
+        /// Collateral asset has been retired, buying XOR with it is disabled. [Collateral Asset Id]
+        CollateralRetired(AssetIdOf<T>),
+        /// Retired collateral asset has been reinstated, buying XOR with it is enabled again. [Collateral Asset Id]
+        CollateralReinstated(AssetIdOf<T>),
+        /// Buy price starting constant has been changed. [New Initial Price]
+        InitialPriceChanged(Fixed),
+        /// Price change step of buy function has been changed. [New Price Change Step]
+        PriceChangeStepChanged(Fixed),
+        /// Price change rate of buy function has been changed. [New Price Change Rate]
+        PriceChangeRateChanged(Fixed),
+        /// Sell price coefficient has been changed. [New Sell Price Coefficient]
+        SellPriceCoefficientChanged(Fixed),
+        /// Base fee has been changed. [New Base Fee]
+        BaseFeeChanged(Fixed),
+        /// PSWAP rewards have been claimed by account. [Account Id, Claimed Amount]
+        RewardsClaimed(AccountIdOf<T>, Balance),
+        /// PSWAP rewards of account have been moved to vested rewards. [Account Id, Moved Amount]
+        RewardsMovedToVesting(AccountIdOf<T>, Balance),
     }
 
     #[pallet::error]
@@ -335,6 +549,12 @@ pub mod pallet {
         CantExchange,
         /// Increment account reference error.
         IncRefError,
+        /// Indicated collateral asset is already retired.
+        CollateralAlreadyRetired,
+        /// Indicated collateral asset is not retired.
+        CollateralNotRetired,
+        /// Indicated value of bonding curve parameter is out of allowed bounds.
+        CurveParameterOutOfBounds,
     }
 
     /// Technical account used to store collateral tokens.
@@ -432,11 +652,21 @@ pub mod pallet {
     #[pallet::getter(fn enabled_targets)]
     pub type EnabledTargets<T: Config> = StorageValue<_, BTreeSet<T::AssetId>, ValueQuery>;
 
+    /// Collateral Assets retired from bonding curve: XOR can be sold for them, but can't be bought with them.
+    #[pallet::storage]
+    #[pallet::getter(fn retired_targets)]
+    pub type RetiredTargets<T: Config> = StorageValue<_, BTreeSet<T::AssetId>, ValueQuery>;
+
     /// Asset that is used to compare collateral assets by value, e.g., DAI.
     #[pallet::storage]
     #[pallet::getter(fn reference_asset_id)]
     pub type ReferenceAssetId<T: Config> = StorageValue<_, T::AssetId, ValueQuery>;
 
+    /// Source of reference prices, either price tools averages or external price oracle.
+    #[pallet::storage]
+    #[pallet::getter(fn reference_price_source)]
+    pub type ReferencePriceSource<T: Config> = StorageValue<_, PriceSource, ValueQuery>;
+
     /// Registry to store information about rewards owned by users in PSWAP. (claim_limit, available_rewards)
     #[pallet::storage]
     #[pallet::getter(fn rewards)]
@@ -476,6 +706,7 @@ pub mod pallet {
 
     /// Current reserves balance for collateral tokens, used for client usability.
     #[pallet::storage]
+    #[pallet::getter(fn collateral_reserves)]
     pub(super) type CollateralReserves<T: Config> =
         StorageMap<_, Twox64Concat, T::AssetId, Balance, ValueQuery>;
 
@@ -778,6 +1009,43 @@ impl<T: Config> Module<T> {
         })
     }
 
+    /// Claim up to `amount` of PSWAP rewards owned by account, as much as claim limit and rewards supply allow.
+    /// Unclaimed remainder is kept for account. Returns actually claimed amount.
+    pub fn claim_incentives_inner(
+        account_id: &T::AccountId,
+        amount: Option<Balance>,
+        via_vested_rewards: bool,
+    ) -> Result<Balance, DispatchError> {
+        let (limit, available) = Rewards::<T>::get(account_id);
+        let claimable = amount.unwrap_or(limit).min(limit).min(available);
+        ensure!(!claimable.is_zero(), Error::<T>::NothingToClaim);
+        let claimed = if via_vested_rewards {
+            claimable
+        } else {
+            let incentives_account_id = IncentivesAccountId::<T>::get();
+            let supply = Assets::<T>::free_balance(&PSWAP.into(), &incentives_account_id)?;
+            let claimed = claimable.min(supply);
+            ensure!(!claimed.is_zero(), Error::<T>::RewardsSupplyShortage);
+            Assets::<T>::transfer_from(&PSWAP.into(), &incentives_account_id, account_id, claimed)?;
+            claimed
+        };
+        let remaining = available.saturating_sub(claimed);
+        if remaining.is_zero() {
+            Rewards::<T>::remove(account_id);
+        } else {
+            Rewards::<T>::insert(account_id, (limit.saturating_sub(claimed), remaining));
+        }
+        TotalRewards::<T>::mutate(|total| *total = total.saturating_sub(claimed));
+        if via_vested_rewards {
+            // claimed amount is already vested by the pool, so it's not locked again
+            T::VestedRewardsPallet::add_vested_tbc_reward(account_id, claimed)?;
+            Self::deposit_event(Event::RewardsMovedToVesting(account_id.clone(), claimed));
+        } else {
+            Self::deposit_event(Event::RewardsClaimed(account_id.clone(), claimed));
+        }
+        Ok(claimed)
+    }
+
     #[inline]
     fn update_collateral_reserves(
         collateral_asset: &T::AssetId,
@@ -1277,14 +1545,67 @@ impl<T: Config> Module<T> {
         let price = if asset_id == &reference_asset_id {
             balance!(1)
         } else {
-            <T as pallet::Config>::PriceToolsPallet::get_average_price(
-                asset_id,
-                &reference_asset_id,
-            )?
+            match ReferencePriceSource::<T>::get() {
+                PriceSource::PriceTools => {
+                    <T as pallet::Config>::PriceToolsPallet::get_average_price(
+                        asset_id,
+                        &reference_asset_id,
+                    )?
+                }
+                PriceSource::Oracle => {
+                    <T as pallet::Config>::PriceOracle::get_price(asset_id, &reference_asset_id)?
+                }
+            }
         };
         Ok(price)
     }
 
+    /// Compare spot buy and sell prices of base asset in terms of collateral asset with its average price, trading
+    /// of collateral asset is halted by circuit breaker if they deviate too much.
+    fn check_price_deviation(collateral_asset_id: &T::AssetId) -> DispatchResult {
+        let base_asset_id = &T::GetBaseAssetId::get();
+        // nothing to compare spot prices with if average price is not available
+        let average_price = match <T as pallet::Config>::PriceToolsPallet::get_average_price(
+            base_asset_id,
+            collateral_asset_id,
+        ) {
+            Ok(price) => FixedWrapper::from(price)
+                .get()
+                .map_err(|_| Error::<T>::PriceCalculationFailed)?,
+            Err(_) => return Ok(()),
+        };
+        let buy_price =
+            <Self as GetMarketInfo<T::AssetId>>::buy_price(base_asset_id, collateral_asset_id)?;
+        let sell_price =
+            <Self as GetMarketInfo<T::AssetId>>::sell_price(base_asset_id, collateral_asset_id)?;
+        T::CircuitBreaker::check_price_deviation(
+            LiquiditySourceType::MulticollateralBondingCurvePool,
+            collateral_asset_id,
+            buy_price,
+            sell_price,
+            average_price,
+        )
+    }
+
+    /// Check price deviation for enabled collateral assets. At most `MAX_CIRCUIT_BREAKER_CHECKS_PER_BLOCK` assets
+    /// are checked in a block, if there are more of them, they are checked in turns. Returns number of checked assets.
+    pub fn circuit_breaker_routine(block_number: T::BlockNumber) -> u32 {
+        let targets: Vec<T::AssetId> = EnabledTargets::<T>::get().into_iter().collect();
+        let len = targets.len() as u64;
+        if len == 0 {
+            return 0;
+        }
+        let count = len.min(MAX_CIRCUIT_BREAKER_CHECKS_PER_BLOCK as u64);
+        let block_number: u64 = block_number.unique_saturated_into();
+        let start = (block_number % len) * count % len;
+        for i in 0..count {
+            let collateral_asset_id = &targets[((start + i) % len) as usize];
+            // failure means trading is halted, which is tracked by circuit breaker
+            let _ = Self::check_price_deviation(collateral_asset_id);
+        }
+        count as u32
+    }
+
     /// Calculate USD price for single collateral asset that is stored in reserves account. In other words, find out how much
     /// reserves worth, considering only one asset type.
     fn actual_reserves_reference_price(
@@ -1300,7 +1621,7 @@ impl<T: Config> Module<T> {
 
     /// Calculate USD price for all XOR in network, this is done by applying ideal sell function to XOR total supply.
     /// - `delta` is a XOR supply offset from current total supply.
-    fn ideal_reserves_reference_price(delta: Fixed) -> Result<Balance, DispatchError> {
+    pub fn ideal_reserves_reference_price(delta: Fixed) -> Result<Balance, DispatchError> {
         let base_asset_id = T::GetBaseAssetId::get();
         let base_total_supply = Assets::<T>::total_issuance(&base_asset_id)?;
         let initial_state = FixedWrapper::from(Self::initial_price());
@@ -1381,11 +1702,19 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
         if *dex_id != DEXId::Polkaswap.into() {
             return false;
         }
-        if input_asset_id == &T::GetBaseAssetId::get() {
-            EnabledTargets::<T>::get().contains(&output_asset_id)
+        let collateral_asset_id = if input_asset_id == &T::GetBaseAssetId::get() {
+            output_asset_id
         } else {
-            EnabledTargets::<T>::get().contains(&input_asset_id)
-        }
+            if RetiredTargets::<T>::get().contains(input_asset_id) {
+                return false;
+            }
+            input_asset_id
+        };
+        EnabledTargets::<T>::get().contains(collateral_asset_id)
+            && !T::CircuitBreaker::is_trading_halted(
+                LiquiditySourceType::MulticollateralBondingCurvePool,
+                collateral_asset_id,
+            )
     }
 
     fn quote(
@@ -1427,6 +1756,11 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
             fail!(Error::<T>::CannotExchangeWithSelf);
         }
         let base_asset_id = &T::GetBaseAssetId::get();
+        if input_asset_id == base_asset_id {
+            Self::check_price_deviation(output_asset_id)?;
+        } else {
+            Self::check_price_deviation(input_asset_id)?;
+        }
         if input_asset_id == base_asset_id {
             let outcome = Self::sell_main_asset(
                 dex_id,
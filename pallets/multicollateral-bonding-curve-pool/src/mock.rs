@@ -31,12 +31,12 @@
 use crate::{self as multicollateral_bonding_curve_pool, Config, Rewards, TotalRewards};
 use common::mock::ExistentialDeposits;
 use common::prelude::{
-    Balance, FixedWrapper, PriceToolsPallet, QuoteAmount, SwapAmount, SwapOutcome,
+    Balance, FixedWrapper, PriceOracle, PriceToolsPallet, QuoteAmount, SwapAmount, SwapOutcome,
 };
 use common::{
-    self, balance, fixed, fixed_wrapper, hash, Amount, AssetId32, AssetName, AssetSymbol, DEXInfo,
-    Fixed, LiquiditySourceFilter, LiquiditySourceType, TechPurpose, VestedRewardsPallet, PSWAP,
-    USDT, VAL, XOR, XSTUSD,
+    self, balance, fixed, fixed_wrapper, hash, Amount, AssetId32, AssetName, AssetSymbol,
+    CircuitBreaker, DEXInfo, Fixed, LiquiditySourceFilter, LiquiditySourceType, TechPurpose,
+    VestedRewardsPallet, PSWAP, USDT, VAL, XOR, XSTUSD,
 };
 use currencies::BasicCurrencyAdapter;
 use frame_support::traits::GenesisBuild;
@@ -50,6 +50,7 @@ use sp_core::H256;
 use sp_runtime::testing::Header;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup, Zero};
 use sp_runtime::{DispatchError, DispatchResult, Perbill};
+use std::cell::RefCell;
 use std::collections::HashMap;
 
 pub type AccountId = AccountId32;
@@ -126,7 +127,7 @@ construct_runtime! {
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Storage},
+        DexManager: dex_manager::{Module, Call, Storage, Event<T>},
         TradingPair: trading_pair::{Module, Call, Storage, Event<T>},
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Module, Call, Config<T>, Storage},
         // VestedRewards: vested_rewards::{Module, Call, Storage, Event<T>},
@@ -167,7 +168,11 @@ impl frame_system::Config for Runtime {
     type SS58Prefix = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -187,6 +192,8 @@ impl Config for Runtime {
     type EnsureTradingPairExists = trading_pair::Module<Runtime>;
     type EnsureDEXManager = dex_manager::Module<Runtime>;
     type PriceToolsPallet = MockDEXApi;
+    type PriceOracle = MockPriceOracle;
+    type CircuitBreaker = MockCircuitBreaker;
     type VestedRewardsPallet = MockVestedRewards;
     type WeightInfo = ();
 }
@@ -208,6 +215,17 @@ impl VestedRewardsPallet<AccountId> for MockVestedRewards {
         Ok(())
     }
 
+    fn add_vested_tbc_reward(account: &AccountId, amount: Balance) -> DispatchResult {
+        Rewards::<Runtime>::mutate(account, |(limit, old_amount)| {
+            *limit = limit.saturating_add(amount);
+            *old_amount = old_amount.saturating_add(amount)
+        });
+        TotalRewards::<Runtime>::mutate(|old_amount| {
+            *old_amount = old_amount.saturating_add(amount)
+        });
+        Ok(())
+    }
+
     fn add_farming_reward(_: &AccountId, _: Balance) -> DispatchResult {
         // do nothing
         Ok(())
@@ -256,6 +274,7 @@ impl assets::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl technical::Config for Runtime {
@@ -265,6 +284,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
 impl pallet_balances::Config for Runtime {
@@ -290,6 +310,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = ();
 }
 
 impl pool_xyk::Config for Runtime {
@@ -305,6 +326,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = ();
     type WeightInfo = ();
 }
 
@@ -529,6 +551,62 @@ impl PriceToolsPallet<AssetId> for MockDEXApi {
     }
 }
 
+/// Mock of external price oracle, reports prices twice as high as average prices.
+pub struct MockPriceOracle;
+
+impl PriceOracle<AssetId> for MockPriceOracle {
+    fn get_price(
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+    ) -> Result<Balance, DispatchError> {
+        let price = MockDEXApi::get_average_price(input_asset_id, output_asset_id)?;
+        Ok(price.saturating_mul(2))
+    }
+}
+
+thread_local! {
+    /// Price deviation checks performed by `MockCircuitBreaker`: (asset, buy price, sell price, average price).
+    pub static CIRCUIT_BREAKER_CHECKS: RefCell<Vec<(AssetId, Fixed, Fixed, Fixed)>> = RefCell::new(Vec::new());
+    /// Assets for which trading is halted by `MockCircuitBreaker`.
+    pub static HALTED_ASSETS: RefCell<Vec<AssetId>> = RefCell::new(Vec::new());
+}
+
+/// Mock of circuit breaker, records price deviation checks and halts trading of assets only on request.
+pub struct MockCircuitBreaker;
+
+impl MockCircuitBreaker {
+    pub fn checks() -> Vec<(AssetId, Fixed, Fixed, Fixed)> {
+        CIRCUIT_BREAKER_CHECKS.with(|checks| checks.borrow().clone())
+    }
+
+    pub fn halt(asset_id: AssetId) {
+        HALTED_ASSETS.with(|halted| halted.borrow_mut().push(asset_id));
+    }
+}
+
+impl CircuitBreaker<AssetId> for MockCircuitBreaker {
+    fn check_price_deviation(
+        source: LiquiditySourceType,
+        asset_id: &AssetId,
+        buy_price: Fixed,
+        sell_price: Fixed,
+        average_price: Fixed,
+    ) -> DispatchResult {
+        assert_eq!(source, LiquiditySourceType::MulticollateralBondingCurvePool);
+        CIRCUIT_BREAKER_CHECKS.with(|checks| {
+            checks
+                .borrow_mut()
+                .push((*asset_id, buy_price, sell_price, average_price))
+        });
+        Ok(())
+    }
+
+    fn is_trading_halted(source: LiquiditySourceType, asset_id: &AssetId) -> bool {
+        source == LiquiditySourceType::MulticollateralBondingCurvePool
+            && HALTED_ASSETS.with(|halted| halted.borrow().contains(asset_id))
+    }
+}
+
 pub struct ExtBuilder {
     endowed_accounts: Vec<(AccountId, AssetId, Balance, AssetSymbol, AssetName, u8)>,
     dex_list: Vec<(DEXId, DEXInfo<AssetId>)>,
@@ -30,16 +30,16 @@
 
 #[rustfmt::skip]
 mod tests {
-    use crate::{mock::*, DistributionAccountData, Module, DistributionAccounts, DistributionAccount, Error, RETRY_DISTRIBUTION_FREQUENCY};
+    use crate::{mock::*, DistributionAccountData, Module, DistributionAccounts, DistributionAccount, Error, Rewards, TotalRewards, RETRY_DISTRIBUTION_FREQUENCY};
     use common::{
         self, balance, fixed, fixed_wrapper, Fixed, fixnum::ops::One as _, fixnum::ops::Zero as _,
         prelude::{Balance, SwapAmount, SwapOutcome, QuoteAmount, FixedWrapper,},
-        AssetName, AssetSymbol, DEXId, LiquiditySource, TechPurpose, USDT, VAL, XOR, PSWAP, XSTUSD, LiquiditySourceFilter,
+        AssetName, AssetSymbol, DEXId, GetMarketInfo, LiquiditySource, PriceToolsPallet, TechPurpose, USDT, VAL, XOR, PSWAP, XSTUSD, LiquiditySourceFilter, PriceSource,
     };
     use hex_literal::hex;
     use frame_support::traits::OnInitialize;
     use liquidity_proxy::LiquidityProxyTrait;
-    use frame_support::{assert_err, assert_noop};
+    use frame_support::{assert_err, assert_noop, assert_ok};
     use frame_support::storage::{with_transaction, TransactionOutcome};
     use sp_arithmetic::traits::{Zero};
     use sp_runtime::DispatchError;
@@ -1938,4 +1938,209 @@ mod tests {
             assert!(quote_outcome_d.amount > quote_without_impact_d.amount);
         });
     }
+
+    #[test]
+    fn should_switch_price_source() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = bonding_curve_pool_init(vec![]).unwrap();
+            let average_price = MBCPool::reference_price(&VAL).unwrap();
+            assert_eq!(MBCPool::reference_price_source(), PriceSource::PriceTools);
+
+            assert_noop!(MBCPool::set_price_source(Origin::signed(alice()), PriceSource::Oracle), DispatchError::BadOrigin);
+            assert_ok!(MBCPool::set_price_source(Origin::root(), PriceSource::Oracle));
+            assert_eq!(MBCPool::reference_price_source(), PriceSource::Oracle);
+            // mock oracle reports prices twice as high as average prices
+            assert_eq!(MBCPool::reference_price(&VAL).unwrap(), average_price * 2);
+
+            assert_ok!(MBCPool::set_price_source(Origin::root(), PriceSource::PriceTools));
+            assert_eq!(MBCPool::reference_price(&VAL).unwrap(), average_price);
+        });
+    }
+
+    #[test]
+    fn circuit_breaker_should_check_enabled_collaterals() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = bonding_curve_pool_init(vec![]).unwrap();
+            let alice = &alice();
+            TradingPair::register(Origin::signed(alice.clone()), DEXId::Polkaswap.into(), XOR, VAL).expect("Failed to register trading pair.");
+            MBCPool::initialize_pool_unchecked(VAL, false).expect("Failed to initialize pool.");
+
+            // spot prices of enabled collateral are compared with its average price on block initialization
+            MBCPool::on_initialize(1);
+            let buy_price = <MBCPool as GetMarketInfo<AssetId>>::buy_price(&XOR, &VAL).unwrap();
+            let sell_price = <MBCPool as GetMarketInfo<AssetId>>::sell_price(&XOR, &VAL).unwrap();
+            let average_price = FixedWrapper::from(MockDEXApi::get_average_price(&XOR, &VAL).unwrap()).get().unwrap();
+            assert_eq!(MockCircuitBreaker::checks(), vec![(VAL, buy_price, sell_price, average_price)]);
+            assert!(MBCPool::can_exchange(&DEXId::Polkaswap.into(), &VAL, &XOR));
+
+            // trading of collateral is disabled in both directions while it's halted by circuit breaker
+            MockCircuitBreaker::halt(VAL);
+            assert!(!MBCPool::can_exchange(&DEXId::Polkaswap.into(), &VAL, &XOR));
+            assert!(!MBCPool::can_exchange(&DEXId::Polkaswap.into(), &XOR, &VAL));
+            assert_noop!(
+                MBCPool::exchange(alice, alice, &DEXId::Polkaswap, &VAL, &XOR, SwapAmount::with_desired_input(balance!(100), 0)),
+                Error::<Runtime>::CantExchange,
+            );
+            assert_noop!(
+                MBCPool::exchange(alice, alice, &DEXId::Polkaswap, &XOR, &VAL, SwapAmount::with_desired_input(balance!(1), 0)),
+                Error::<Runtime>::CantExchange,
+            );
+        });
+    }
+
+    #[test]
+    fn should_retire_collateral() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = bonding_curve_pool_init(vec![]).unwrap();
+            let alice = &alice();
+            TradingPair::register(Origin::signed(alice.clone()), DEXId::Polkaswap.into(), XOR, VAL).expect("Failed to register trading pair.");
+            MBCPool::initialize_pool_unchecked(VAL, false).expect("Failed to initialize pool.");
+            MBCPool::exchange(alice, alice, &DEXId::Polkaswap, &VAL, &XOR, SwapAmount::with_desired_input(balance!(10000), 0)).expect("Failed to buy XOR.");
+
+            assert_noop!(MBCPool::retire_collateral(Origin::signed(bob()), VAL), permissions::Error::<Runtime>::Forbidden);
+            assert_noop!(MBCPool::retire_collateral(Origin::signed(alice.clone()), PSWAP), Error::<Runtime>::UnsupportedCollateralAssetId);
+            assert_ok!(MBCPool::retire_collateral(Origin::signed(alice.clone()), VAL));
+            assert!(MBCPool::retired_targets().contains(&VAL));
+            assert!(MBCPool::enabled_targets().contains(&VAL));
+            assert_noop!(MBCPool::retire_collateral(Origin::signed(alice.clone()), VAL), Error::<Runtime>::CollateralAlreadyRetired);
+
+            // buying XOR with retired collateral is disabled
+            assert!(!MBCPool::can_exchange(&DEXId::Polkaswap.into(), &VAL, &XOR));
+            assert_noop!(
+                MBCPool::exchange(alice, alice, &DEXId::Polkaswap, &VAL, &XOR, SwapAmount::with_desired_input(balance!(100), 0)),
+                Error::<Runtime>::CantExchange,
+            );
+
+            // selling XOR for retired collateral is still possible
+            assert!(MBCPool::can_exchange(&DEXId::Polkaswap.into(), &XOR, &VAL));
+            assert_ok!(MBCPool::exchange(alice, alice, &DEXId::Polkaswap, &XOR, &VAL, SwapAmount::with_desired_input(balance!(1), 0)));
+        });
+    }
+
+    #[test]
+    fn should_reinstate_collateral() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = bonding_curve_pool_init(vec![]).unwrap();
+            let alice = &alice();
+            TradingPair::register(Origin::signed(alice.clone()), DEXId::Polkaswap.into(), XOR, VAL).expect("Failed to register trading pair.");
+            MBCPool::initialize_pool_unchecked(VAL, false).expect("Failed to initialize pool.");
+
+            assert_noop!(MBCPool::reinstate_collateral(Origin::signed(alice.clone()), VAL), Error::<Runtime>::CollateralNotRetired);
+            assert_ok!(MBCPool::retire_collateral(Origin::signed(alice.clone()), VAL));
+            assert!(!MBCPool::can_exchange(&DEXId::Polkaswap.into(), &VAL, &XOR));
+
+            assert_noop!(MBCPool::reinstate_collateral(Origin::signed(bob()), VAL), permissions::Error::<Runtime>::Forbidden);
+            assert_ok!(MBCPool::reinstate_collateral(Origin::signed(alice.clone()), VAL));
+            assert!(!MBCPool::retired_targets().contains(&VAL));
+            assert_noop!(MBCPool::reinstate_collateral(Origin::signed(alice.clone()), VAL), Error::<Runtime>::CollateralNotRetired);
+
+            // buying XOR with reinstated collateral is enabled again
+            assert!(MBCPool::can_exchange(&DEXId::Polkaswap.into(), &VAL, &XOR));
+            assert_ok!(MBCPool::exchange(alice, alice, &DEXId::Polkaswap, &VAL, &XOR, SwapAmount::with_desired_input(balance!(100), 0)));
+        });
+    }
+
+    #[test]
+    fn should_set_curve_parameters() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = bonding_curve_pool_init(vec![]).unwrap();
+            let alice = alice();
+            let buy_price = MBCPool::buy_function(&XOR, Fixed::ZERO).unwrap();
+
+            assert_noop!(MBCPool::set_initial_price(Origin::signed(bob()), fixed!(700)), permissions::Error::<Runtime>::Forbidden);
+            assert_noop!(MBCPool::set_initial_price(Origin::signed(alice.clone()), fixed!(0)), Error::<Runtime>::CurveParameterOutOfBounds);
+            assert_ok!(MBCPool::set_initial_price(Origin::signed(alice.clone()), fixed!(700)));
+            assert_eq!(MBCPool::initial_price(), fixed!(700));
+            assert!(MBCPool::buy_function(&XOR, Fixed::ZERO).unwrap() > buy_price);
+
+            assert_noop!(MBCPool::set_price_change_step(Origin::signed(alice.clone()), Fixed::from_bits(-1)), Error::<Runtime>::CurveParameterOutOfBounds);
+            assert_ok!(MBCPool::set_price_change_step(Origin::signed(alice.clone()), fixed!(2000)));
+            assert_eq!(MBCPool::price_change_step(), fixed!(2000));
+
+            assert_noop!(MBCPool::set_price_change_rate(Origin::signed(alice.clone()), fixed!(0)), Error::<Runtime>::CurveParameterOutOfBounds);
+            assert_ok!(MBCPool::set_price_change_rate(Origin::signed(alice.clone()), fixed!(2)));
+            assert_eq!(MBCPool::price_change_rate(), fixed!(2));
+
+            assert_noop!(MBCPool::set_sell_price_coefficient(Origin::signed(alice.clone()), fixed!(0)), Error::<Runtime>::CurveParameterOutOfBounds);
+            assert_noop!(MBCPool::set_sell_price_coefficient(Origin::signed(alice.clone()), fixed!(1.1)), Error::<Runtime>::CurveParameterOutOfBounds);
+            assert_ok!(MBCPool::set_sell_price_coefficient(Origin::signed(alice.clone()), fixed!(1)));
+            assert_eq!(MBCPool::sell_price_coefficient(), fixed!(1));
+            assert_eq!(MBCPool::sell_function(&XOR, Fixed::ZERO).unwrap(), MBCPool::buy_function(&XOR, Fixed::ZERO).unwrap());
+
+            assert_noop!(MBCPool::set_base_fee(Origin::signed(alice.clone()), fixed!(1)), Error::<Runtime>::CurveParameterOutOfBounds);
+            assert_noop!(MBCPool::set_base_fee(Origin::signed(alice.clone()), Fixed::from_bits(-1)), Error::<Runtime>::CurveParameterOutOfBounds);
+            assert_ok!(MBCPool::set_base_fee(Origin::signed(alice.clone()), fixed!(0)));
+            assert_eq!(MBCPool::base_fee(), fixed!(0));
+        });
+    }
+
+    #[test]
+    fn should_claim_incentives_partially() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            Assets::mint_to(&PSWAP, &alice(), &incentives_account(), balance!(1000)).unwrap();
+            Rewards::<Runtime>::insert(alice(), (balance!(30), balance!(100)));
+            TotalRewards::<Runtime>::put(balance!(100));
+
+            assert_noop!(MBCPool::claim_incentives(Origin::signed(bob()), None, false), Error::<Runtime>::NothingToClaim);
+            assert_noop!(MBCPool::claim_incentives(Origin::signed(alice()), Some(0), false), Error::<Runtime>::NothingToClaim);
+
+            assert_ok!(MBCPool::claim_incentives(Origin::signed(alice()), Some(balance!(10)), false));
+            assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(10));
+            assert_eq!(MBCPool::rewards(&alice()), (balance!(20), balance!(90)));
+            assert_eq!(MBCPool::total_rewards(), balance!(90));
+
+            // requested amount is capped by claim limit
+            assert_ok!(MBCPool::claim_incentives(Origin::signed(alice()), Some(balance!(50)), false));
+            assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(30));
+            assert_eq!(MBCPool::rewards(&alice()), (balance!(0), balance!(70)));
+            assert_eq!(MBCPool::total_rewards(), balance!(70));
+
+            assert_noop!(MBCPool::claim_incentives(Origin::signed(alice()), None, false), Error::<Runtime>::NothingToClaim);
+        });
+    }
+
+    #[test]
+    fn should_claim_incentives_limited_by_supply() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            Assets::mint_to(&PSWAP, &alice(), &incentives_account(), balance!(5)).unwrap();
+            Rewards::<Runtime>::insert(alice(), (balance!(30), balance!(100)));
+            TotalRewards::<Runtime>::put(balance!(100));
+
+            assert_ok!(MBCPool::claim_incentives(Origin::signed(alice()), None, false));
+            assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(5));
+            assert_eq!(Assets::free_balance(&PSWAP, &incentives_account()).unwrap(), balance!(0));
+            assert_eq!(MBCPool::rewards(&alice()), (balance!(25), balance!(95)));
+            assert_eq!(MBCPool::total_rewards(), balance!(95));
+
+            assert_noop!(MBCPool::claim_incentives(Origin::signed(alice()), None, false), Error::<Runtime>::RewardsSupplyShortage);
+        });
+    }
+
+    #[test]
+    fn should_claim_incentives_via_vested_rewards() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            Rewards::<Runtime>::insert(alice(), (balance!(30), balance!(30)));
+            TotalRewards::<Runtime>::put(balance!(30));
+
+            // no supply is needed, as rewards are not paid out
+            assert_ok!(MBCPool::claim_incentives(Origin::signed(alice()), None, true));
+            assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(0));
+            // mock vested rewards pallet stores added rewards back to pool rewards as claimable
+            assert_eq!(MBCPool::rewards(&alice()), (balance!(30), balance!(30)));
+            assert_eq!(MBCPool::total_rewards(), balance!(30));
+        });
+    }
 }
@@ -50,6 +50,36 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(5 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    fn set_price_source() -> Weight {
+        (21_850_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn check_price_deviation(n: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((40_000_000 as Weight).saturating_mul(n as Weight))
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+    }
+    fn retire_collateral() -> Weight {
+        (52_917_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn reinstate_collateral() -> Weight {
+        (47_208_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_curve_parameter() -> Weight {
+        (40_735_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn claim_incentives() -> Weight {
+        (85_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
     fn on_initialize(n: u32) -> Weight {
         (47_613_000 as Weight)
             // Standard Error: 438_000
@@ -72,4 +102,22 @@ impl crate::WeightInfo for () {
     fn set_optional_reward_multiplier() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+    fn set_price_source() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn check_price_deviation(_n: u32) -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn retire_collateral() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn reinstate_collateral() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn set_curve_parameter() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn claim_incentives() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
 }
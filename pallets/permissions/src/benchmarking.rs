@@ -0,0 +1,241 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Permissions module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::codec::Decode;
+use frame_support::traits::Hooks;
+use frame_system::{EventRecord, RawOrigin};
+use sp_std::prelude::*;
+
+// Id of the first permission created for the benchmarks
+const PERMISSION: PermissionId = 1_000;
+
+// Account which is different for every index
+fn account<T: Config>(index: u32) -> T::AccountId {
+    let mut bytes = [0u8; 32];
+    bytes[..4].copy_from_slice(&(index + 1).to_le_bytes());
+    let account_id = T::AccountId::decode(&mut &bytes[..]).unwrap_or_default();
+    let _ = frame_system::Pallet::<T>::inc_providers(&account_id);
+    account_id
+}
+
+// Creates `count` permissions owned by `owner`, returns their ids
+fn create_permissions<T: Config>(owner: &T::AccountId, count: u32) -> Vec<PermissionId> {
+    (PERMISSION..PERMISSION + count)
+        .map(|permission_id| {
+            Pallet::<T>::create_permission(
+                owner.clone(),
+                owner.clone(),
+                permission_id,
+                Scope::Unlimited,
+                Mode::Permit,
+            )
+            .unwrap();
+            permission_id
+        })
+        .collect()
+}
+
+fn role() -> RoleName {
+    vec![b'r'; MAX_ROLE_NAME_LENGTH]
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Module::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+    grant {
+        let caller = account::<T>(0);
+        let holder = account::<T>(1);
+        create_permissions::<T>(&caller, 1);
+        // prolonging the grant unschedules its previous expiration
+        Pallet::<T>::grant_permission_until(
+            caller.clone(),
+            holder.clone(),
+            PERMISSION,
+            Scope::Unlimited,
+            Some(5u32.into()),
+        )
+        .unwrap();
+    }: _(
+        RawOrigin::Signed(caller),
+        holder.clone(),
+        PERMISSION,
+        Scope::Unlimited,
+        Some(10u32.into())
+    )
+    verify {
+        assert_last_event::<T>(Event::PermissionGranted(PERMISSION, holder).into())
+    }
+
+    revoke {
+        let caller = account::<T>(0);
+        let holder = account::<T>(1);
+        create_permissions::<T>(&caller, 1);
+        Pallet::<T>::grant_permission_until(
+            caller.clone(),
+            holder.clone(),
+            PERMISSION,
+            Scope::Unlimited,
+            Some(10u32.into()),
+        )
+        .unwrap();
+    }: _(RawOrigin::Signed(caller), holder.clone(), PERMISSION, Scope::Unlimited)
+    verify {
+        assert_last_event::<T>(Event::PermissionRevoked(PERMISSION, holder).into())
+    }
+
+    renounce {
+        let owner = account::<T>(0);
+        let caller = account::<T>(1);
+        create_permissions::<T>(&owner, 1);
+        Pallet::<T>::grant_permission_until(
+            owner,
+            caller.clone(),
+            PERMISSION,
+            Scope::Unlimited,
+            Some(10u32.into()),
+        )
+        .unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), PERMISSION, Scope::Unlimited)
+    verify {
+        assert_last_event::<T>(Event::PermissionRenounced(PERMISSION, caller).into())
+    }
+
+    set_role {
+        let owner = account::<T>(0);
+        let permissions = create_permissions::<T>(&owner, MAX_ROLE_PERMISSIONS as u32);
+    }: _(RawOrigin::Root, role(), permissions)
+    verify {
+        assert_last_event::<T>(Event::RoleSet(role()).into())
+    }
+
+    remove_role {
+        let owner = account::<T>(0);
+        let permissions = create_permissions::<T>(&owner, MAX_ROLE_PERMISSIONS as u32);
+        Roles::<T>::insert(role(), permissions);
+    }: _(RawOrigin::Root, role())
+    verify {
+        assert_last_event::<T>(Event::RoleRemoved(role()).into())
+    }
+
+    grant_role {
+        let caller = account::<T>(0);
+        let holder = account::<T>(1);
+        let permissions = create_permissions::<T>(&caller, MAX_ROLE_PERMISSIONS as u32);
+        let last_permission = *permissions.last().unwrap();
+        Roles::<T>::insert(role(), permissions);
+    }: _(
+        RawOrigin::Signed(caller),
+        holder.clone(),
+        role(),
+        Scope::Unlimited,
+        Some(10u32.into())
+    )
+    verify {
+        assert_last_event::<T>(Event::PermissionGranted(last_permission, holder).into())
+    }
+
+    revoke_role {
+        let caller = account::<T>(0);
+        let holder = account::<T>(1);
+        let permissions = create_permissions::<T>(&caller, MAX_ROLE_PERMISSIONS as u32);
+        let last_permission = *permissions.last().unwrap();
+        for permission_id in &permissions {
+            Pallet::<T>::grant_permission_until(
+                caller.clone(),
+                holder.clone(),
+                *permission_id,
+                Scope::Unlimited,
+                Some(10u32.into()),
+            )
+            .unwrap();
+        }
+        Roles::<T>::insert(role(), permissions);
+    }: _(RawOrigin::Signed(caller), holder.clone(), role(), Scope::Unlimited)
+    verify {
+        assert_last_event::<T>(Event::PermissionRevoked(last_permission, holder).into())
+    }
+
+    on_initialize {
+        // Number of grants lapsing at the block
+        let n in 0 .. MAX_EXPIRING_GRANTS_PER_BLOCK as u32;
+        let owner = account::<T>(0);
+        create_permissions::<T>(&owner, 1);
+        for i in 0..n {
+            Pallet::<T>::grant_permission_until(
+                owner.clone(),
+                account::<T>(i + 1),
+                PERMISSION,
+                Scope::Unlimited,
+                Some(10u32.into()),
+            )
+            .unwrap();
+        }
+    }: {
+        let _ = Pallet::<T>::on_initialize(10u32.into());
+    }
+    verify {
+        assert!(!ExpiringGrants::<T>::contains_key(T::BlockNumber::from(10u32)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{ExtBuilder, Runtime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_benchmarks() {
+        ExtBuilder::default().build().execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            assert_ok!(test_benchmark_grant::<Runtime>());
+            assert_ok!(test_benchmark_revoke::<Runtime>());
+            assert_ok!(test_benchmark_renounce::<Runtime>());
+            assert_ok!(test_benchmark_set_role::<Runtime>());
+            assert_ok!(test_benchmark_remove_role::<Runtime>());
+            assert_ok!(test_benchmark_grant_role::<Runtime>());
+            assert_ok!(test_benchmark_revoke_role::<Runtime>());
+            assert_ok!(test_benchmark_on_initialize::<Runtime>());
+        });
+    }
+}
@@ -47,12 +47,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::codec::{Decode, Encode};
+use frame_support::weights::Weight;
 use frame_support::{ensure, RuntimeDebug};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_core::hash::H512;
 use sp_std::vec::Vec;
 
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[cfg(test)]
 mod mock;
 
@@ -64,7 +70,21 @@ pub type OwnerId<T> = <T as frame_system::Config>::AccountId;
 /// The id of the account having a permission
 pub type HolderId<T> = <T as frame_system::Config>::AccountId;
 pub type PermissionId = u32;
+/// The name of a role bundling several permissions
+pub type RoleName = Vec<u8>;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+
+pub trait WeightInfo {
+    fn grant() -> Weight;
+    fn revoke() -> Weight;
+    fn renounce() -> Weight;
+    fn set_role() -> Weight;
+    fn remove_role() -> Weight;
+    fn grant_role() -> Weight;
+    fn revoke_role() -> Weight;
+    fn on_initialize(expired: u32) -> Weight;
+}
 
 #[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -96,6 +116,13 @@ pub const CLAIM_FROM_FARM: PermissionId = 10;
 pub const GET_FARM_INFO: PermissionId = 11;
 pub const GET_FARMER_INFO: PermissionId = 12;
 
+/// Max length of a role name.
+pub const MAX_ROLE_NAME_LENGTH: usize = 32;
+/// Max number of permissions a role can bundle.
+pub const MAX_ROLE_PERMISSIONS: usize = 16;
+/// Max number of time-limited grants which can lapse at the same block.
+pub const MAX_EXPIRING_GRANTS_PER_BLOCK: usize = 64;
+
 /// Permissions module declaration.
 impl<T: Config> Pallet<T> {
     /// Method checks a permission of an Account.
@@ -139,29 +166,121 @@ impl<T: Config> Pallet<T> {
         account_id: HolderId<T>,
         permission_id: PermissionId,
         scope: Scope,
+    ) -> Result<(), Error<T>> {
+        Self::ensure_owns_permission(&who, permission_id, &scope)?;
+        if Permissions::<T>::iter_prefix_values(&account_id).count() == 0 {
+            frame_system::Pallet::<T>::inc_consumers(&account_id)
+                .map_err(|_| Error::<T>::IncRefError)?;
+        }
+        Permissions::<T>::mutate(&account_id, &scope, |permissions| {
+            if let Err(index) = permissions.binary_search(&permission_id) {
+                permissions.insert(index, permission_id);
+            }
+        });
+        Self::deposit_event(Event::<T>::PermissionGranted(permission_id, account_id));
+        Ok(())
+    }
+
+    /// Method grants a permission with defined scope to an Account until the `expires_at` block.
+    /// The permission is granted permanently if `expires_at` is `None`. Granting a permission
+    /// which the Account already holds replaces its expiration.
+    pub fn grant_permission_until(
+        who: OwnerId<T>,
+        account_id: HolderId<T>,
+        permission_id: PermissionId,
+        scope: Scope,
+        expires_at: Option<BlockNumberOf<T>>,
+    ) -> Result<(), Error<T>> {
+        if let Some(expires_at) = expires_at {
+            ensure!(
+                expires_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::InvalidExpiration
+            );
+            // Expired grants are removed in `on_initialize`, so their number per block is bounded.
+            ensure!(
+                ExpiringGrants::<T>::decode_len(expires_at).unwrap_or(0)
+                    < MAX_EXPIRING_GRANTS_PER_BLOCK,
+                Error::<T>::TooManyExpiringGrants
+            );
+        }
+        Self::grant_permission_with_scope(who, account_id.clone(), permission_id, scope)?;
+        Self::unschedule_expiration(&account_id, scope, permission_id);
+        if let Some(expires_at) = expires_at {
+            GrantExpirations::<T>::insert(&account_id, (scope, permission_id), expires_at);
+            ExpiringGrants::<T>::append(expires_at, (account_id, scope, permission_id));
+        }
+        Ok(())
+    }
+
+    /// Method revokes a permission with defined scope from an Account.
+    pub fn revoke_permission_with_scope(
+        who: OwnerId<T>,
+        account_id: HolderId<T>,
+        permission_id: PermissionId,
+        scope: Scope,
+    ) -> Result<(), Error<T>> {
+        Self::ensure_owns_permission(&who, permission_id, &scope)?;
+        ensure!(
+            Self::remove_permission(&account_id, scope, permission_id),
+            Error::<T>::PermissionNotFound
+        );
+        Self::deposit_event(Event::<T>::PermissionRevoked(permission_id, account_id));
+        Ok(())
+    }
+
+    /// Method removes a permission with defined scope from its holder on the holder's own request.
+    /// Restrictions, i.e. permissions in `Mode::Forbid`, can't be renounced.
+    pub fn renounce_permission_with_scope(
+        holder_id: HolderId<T>,
+        permission_id: PermissionId,
+        scope: Scope,
+    ) -> Result<(), Error<T>> {
+        ensure!(
+            Modes::<T>::get(permission_id) == Mode::Permit,
+            Error::<T>::RestrictionCannotBeRenounced
+        );
+        ensure!(
+            Self::remove_permission(&holder_id, scope, permission_id),
+            Error::<T>::PermissionNotFound
+        );
+        Self::deposit_event(Event::<T>::PermissionRenounced(permission_id, holder_id));
+        Ok(())
+    }
+
+    /// Lists permissions held by an Account along with their scopes and expiration blocks.
+    pub fn list_permissions(
+        holder_id: &HolderId<T>,
+    ) -> Vec<(PermissionId, Scope, Option<BlockNumberOf<T>>)> {
+        Permissions::<T>::iter_prefix(holder_id)
+            .flat_map(|(scope, permissions)| {
+                permissions.into_iter().map(move |permission_id| {
+                    (
+                        permission_id,
+                        scope,
+                        GrantExpirations::<T>::get(holder_id, (scope, permission_id)),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn ensure_owns_permission(
+        who: &OwnerId<T>,
+        permission_id: PermissionId,
+        scope: &Scope,
     ) -> Result<(), Error<T>> {
         let (permission_found, owns_permission) = {
-            let owners = Owners::<T>::get(permission_id, &scope);
-            if owners.contains(&who) {
+            let owners = Owners::<T>::get(permission_id, scope);
+            if owners.contains(who) {
                 (true, true)
-            } else if scope != Scope::Unlimited {
+            } else if *scope != Scope::Unlimited {
                 let owners = Owners::<T>::get(permission_id, Scope::Unlimited);
-                (!owners.is_empty(), owners.contains(&who))
+                (!owners.is_empty(), owners.contains(who))
             } else {
                 (!owners.is_empty(), false)
             }
         };
         if owns_permission {
-            if Permissions::<T>::iter_prefix_values(&account_id).count() == 0 {
-                frame_system::Pallet::<T>::inc_consumers(&account_id)
-                    .map_err(|_| Error::<T>::IncRefError)?;
-            }
-            Permissions::<T>::mutate(&account_id, &scope, |permissions| {
-                if let Err(index) = permissions.binary_search(&permission_id) {
-                    permissions.insert(index, permission_id);
-                }
-            });
-            Self::deposit_event(Event::<T>::PermissionGranted(permission_id, account_id));
             Ok(())
         } else if permission_found {
             Err(Error::PermissionNotOwned)
@@ -170,6 +289,68 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Removes the permission from the holder, returns `false` if it wasn't held.
+    fn remove_permission(
+        holder_id: &HolderId<T>,
+        scope: Scope,
+        permission_id: PermissionId,
+    ) -> bool {
+        let mut permissions = Permissions::<T>::get(holder_id, scope);
+        match permissions.binary_search(&permission_id) {
+            Ok(index) => {
+                let _ = permissions.remove(index);
+            }
+            Err(_) => return false,
+        }
+        if permissions.is_empty() {
+            Permissions::<T>::remove(holder_id, scope);
+        } else {
+            Permissions::<T>::insert(holder_id, scope, permissions);
+        }
+        Self::unschedule_expiration(holder_id, scope, permission_id);
+        if Permissions::<T>::iter_prefix_values(holder_id).count() == 0 {
+            frame_system::Pallet::<T>::dec_consumers(holder_id);
+        }
+        true
+    }
+
+    /// Removes the expiration of the grant together with its entry scheduled for removal.
+    fn unschedule_expiration(holder_id: &HolderId<T>, scope: Scope, permission_id: PermissionId) {
+        if let Some(expires_at) = GrantExpirations::<T>::take(holder_id, (scope, permission_id)) {
+            ExpiringGrants::<T>::mutate_exists(expires_at, |grants| {
+                if let Some(scheduled) = grants {
+                    scheduled.retain(|(holder, grant_scope, grant_permission_id)| {
+                        !(holder == holder_id
+                            && *grant_scope == scope
+                            && *grant_permission_id == permission_id)
+                    });
+                    if scheduled.is_empty() {
+                        *grants = None;
+                    }
+                }
+            });
+        }
+    }
+
+    /// Removes grants which lapse at the given block. Returns the number of processed grants.
+    fn remove_expired_grants(now: BlockNumberOf<T>) -> u32 {
+        let grants = ExpiringGrants::<T>::take(now);
+        let count = grants.len() as u32;
+        for (holder_id, scope, permission_id) in grants {
+            // Revoked and prolonged grants are unscheduled, so the check is only a safeguard.
+            if GrantExpirations::<T>::get(&holder_id, (scope, permission_id)) == Some(now)
+                && Self::remove_permission(&holder_id, scope, permission_id)
+            {
+                Self::deposit_event(Event::<T>::PermissionExpired(permission_id, holder_id));
+            }
+        }
+        count
+    }
+
+    fn role_permissions(role: &RoleName) -> Result<Vec<PermissionId>, Error<T>> {
+        Roles::<T>::get(role).ok_or(Error::<T>::RoleNotFound)
+    }
+
     /// Method transfers a permission from owner to another Account.
     pub fn transfer_permission(
         who: OwnerId<T>,
@@ -288,12 +469,15 @@ pub use pallet::*;
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
+    use frame_support::transactional;
     use frame_system::pallet_prelude::*;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// Permissions pallet's events.
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -301,10 +485,175 @@ pub mod pallet {
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let expired = Pallet::<T>::remove_expired_grants(now);
+            <T as Config>::WeightInfo::on_initialize(expired)
+        }
+    }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {}
+    impl<T: Config> Pallet<T> {
+        /// Grant the permission in the scope to the account. The caller must own the permission.
+        ///
+        /// - `account_id`: account to grant the permission to.
+        /// - `permission_id`: ID of the permission.
+        /// - `scope`: scope of the permission.
+        /// - `expires_at`: block at which the permission lapses, `None` for a permanent grant.
+        #[pallet::weight(<T as Config>::WeightInfo::grant())]
+        pub fn grant(
+            origin: OriginFor<T>,
+            account_id: AccountIdOf<T>,
+            permission_id: PermissionId,
+            scope: Scope,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::grant_permission_until(who, account_id, permission_id, scope, expires_at)?;
+            Ok(().into())
+        }
+
+        /// Revoke the permission in the scope from the account. The caller must own the
+        /// permission.
+        ///
+        /// - `account_id`: account to revoke the permission from.
+        /// - `permission_id`: ID of the permission.
+        /// - `scope`: scope of the permission.
+        #[pallet::weight(<T as Config>::WeightInfo::revoke())]
+        pub fn revoke(
+            origin: OriginFor<T>,
+            account_id: AccountIdOf<T>,
+            permission_id: PermissionId,
+            scope: Scope,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::revoke_permission_with_scope(who, account_id, permission_id, scope)?;
+            Ok(().into())
+        }
+
+        /// Renounce the permission in the scope held by the caller. Lets an account get rid of
+        /// permissions it was granted without asking for them. Restrictions can't be renounced.
+        ///
+        /// - `permission_id`: ID of the permission.
+        /// - `scope`: scope of the permission.
+        #[pallet::weight(<T as Config>::WeightInfo::renounce())]
+        pub fn renounce(
+            origin: OriginFor<T>,
+            permission_id: PermissionId,
+            scope: Scope,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::renounce_permission_with_scope(who, permission_id, scope)?;
+            Ok(().into())
+        }
+
+        /// Create or replace the role bundling the given permissions.
+        /// Can be only called by root.
+        ///
+        /// - `role`: name of the role.
+        /// - `permissions`: IDs of the existing permissions the role consists of.
+        #[pallet::weight(<T as Config>::WeightInfo::set_role())]
+        pub fn set_role(
+            origin: OriginFor<T>,
+            role: RoleName,
+            mut permissions: Vec<PermissionId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                !role.is_empty() && role.len() <= MAX_ROLE_NAME_LENGTH,
+                Error::<T>::InvalidRoleName
+            );
+            permissions.sort();
+            permissions.dedup();
+            ensure!(
+                !permissions.is_empty() && permissions.len() <= MAX_ROLE_PERMISSIONS,
+                Error::<T>::InvalidRolePermissions
+            );
+            for permission_id in &permissions {
+                ensure!(
+                    Modes::<T>::contains_key(permission_id),
+                    Error::<T>::PermissionNotFound
+                );
+            }
+            Roles::<T>::insert(&role, permissions);
+            Self::deposit_event(Event::<T>::RoleSet(role));
+            Ok(().into())
+        }
+
+        /// Remove the role. Permissions granted with the role are kept by their holders.
+        /// Can be only called by root.
+        ///
+        /// - `role`: name of the role.
+        #[pallet::weight(<T as Config>::WeightInfo::remove_role())]
+        pub fn remove_role(origin: OriginFor<T>, role: RoleName) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(Roles::<T>::contains_key(&role), Error::<T>::RoleNotFound);
+            Roles::<T>::remove(&role);
+            Self::deposit_event(Event::<T>::RoleRemoved(role));
+            Ok(().into())
+        }
+
+        /// Grant all permissions of the role in the scope to the account. The caller must own
+        /// every permission of the role.
+        ///
+        /// - `account_id`: account to grant the role to.
+        /// - `role`: name of the role.
+        /// - `scope`: scope of the permissions.
+        /// - `expires_at`: block at which the permissions lapse, `None` for a permanent grant.
+        #[transactional]
+        #[pallet::weight(<T as Config>::WeightInfo::grant_role())]
+        pub fn grant_role(
+            origin: OriginFor<T>,
+            account_id: AccountIdOf<T>,
+            role: RoleName,
+            scope: Scope,
+            expires_at: Option<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            for permission_id in Self::role_permissions(&role)? {
+                Self::grant_permission_until(
+                    who.clone(),
+                    account_id.clone(),
+                    permission_id,
+                    scope,
+                    expires_at,
+                )?;
+            }
+            Ok(().into())
+        }
+
+        /// Revoke the permissions of the role held by the account in the scope. The caller must
+        /// own every revoked permission.
+        ///
+        /// - `account_id`: account to revoke the role from.
+        /// - `role`: name of the role.
+        /// - `scope`: scope of the permissions.
+        #[transactional]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_role())]
+        pub fn revoke_role(
+            origin: OriginFor<T>,
+            account_id: AccountIdOf<T>,
+            role: RoleName,
+            scope: Scope,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let held = Permissions::<T>::get(&account_id, scope);
+            let mut revoked = false;
+            for permission_id in Self::role_permissions(&role)? {
+                if held.binary_search(&permission_id).is_ok() {
+                    Self::revoke_permission_with_scope(
+                        who.clone(),
+                        account_id.clone(),
+                        permission_id,
+                        scope,
+                    )?;
+                    revoked = true;
+                }
+            }
+            ensure!(revoked, Error::<T>::PermissionNotFound);
+            Ok(().into())
+        }
+    }
 
     #[pallet::event]
     #[pallet::metadata(AccountIdOf<T> = "AccountId")]
@@ -318,6 +667,16 @@ pub mod pallet {
         PermissionCreated(u32, AccountIdOf<T>),
         /// Permission was assigned to the account in the scope. [permission, who]
         PermissionAssigned(u32, AccountIdOf<T>),
+        /// Permission was revoked from a holder. [permission, who]
+        PermissionRevoked(u32, AccountIdOf<T>),
+        /// Time-limited permission of a holder has lapsed. [permission, who]
+        PermissionExpired(u32, AccountIdOf<T>),
+        /// Permission was renounced by its holder. [permission, who]
+        PermissionRenounced(u32, AccountIdOf<T>),
+        /// Role was created or updated. [role]
+        RoleSet(RoleName),
+        /// Role was removed. [role]
+        RoleRemoved(RoleName),
     }
 
     #[pallet::error]
@@ -332,6 +691,18 @@ pub mod pallet {
         Forbidden,
         /// Increment account reference error.
         IncRefError,
+        /// Expiration block of the grant is not in the future.
+        InvalidExpiration,
+        /// Role with the given name doesn't exist.
+        RoleNotFound,
+        /// Role name is empty or too long.
+        InvalidRoleName,
+        /// Role has no permissions or too many of them.
+        InvalidRolePermissions,
+        /// Too many grants already lapse at the given block.
+        TooManyExpiringGrants,
+        /// Permissions restricting the holder can't be renounced.
+        RestrictionCannotBeRenounced,
     }
 
     #[pallet::storage]
@@ -365,6 +736,33 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Blocks at which time-limited grants lapse.
+    #[pallet::storage]
+    pub type GrantExpirations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_256,
+        HolderId<T>,
+        Blake2_256,
+        (Scope, PermissionId),
+        T::BlockNumber,
+        OptionQuery,
+    >;
+
+    /// Time-limited grants scheduled to be removed at the block.
+    #[pallet::storage]
+    pub(super) type ExpiringGrants<T: Config> = StorageMap<
+        _,
+        Blake2_256,
+        T::BlockNumber,
+        Vec<(HolderId<T>, Scope, PermissionId)>,
+        ValueQuery,
+    >;
+
+    /// Named bundles of permissions.
+    #[pallet::storage]
+    #[pallet::getter(fn role)]
+    pub type Roles<T: Config> = StorageMap<_, Blake2_256, RoleName, Vec<PermissionId>, OptionQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub initial_permission_owners: Vec<(PermissionId, Scope, Vec<OwnerId<T>>)>,
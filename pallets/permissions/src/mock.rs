@@ -104,6 +104,7 @@ impl frame_system::Config for Runtime {
 
 impl Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
@@ -30,8 +30,10 @@
 
 use crate::mock::*;
 use crate::*;
-use frame_support::assert_ok;
+use frame_support::traits::Hooks;
+use frame_support::{assert_noop, assert_ok};
 use sp_core::hash::H512;
+use sp_runtime::DispatchError;
 
 type Permissions = Module<Runtime>;
 
@@ -285,3 +287,289 @@ fn permission_create_fails_with_permission_already_exists_error() {
         }
     });
 }
+
+#[test]
+fn permission_grant_and_revoke_via_calls_passes() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Permissions::grant(
+            Origin::signed(ALICE),
+            BOB,
+            SLASH,
+            Scope::Unlimited,
+            None
+        ));
+        assert_ok!(Permissions::check_permission(BOB, SLASH));
+        assert_noop!(
+            Permissions::revoke(Origin::signed(JOHN), BOB, SLASH, Scope::Unlimited),
+            Error::<Runtime>::PermissionNotOwned
+        );
+        assert_ok!(Permissions::revoke(
+            Origin::signed(ALICE),
+            BOB,
+            SLASH,
+            Scope::Unlimited
+        ));
+        assert!(matches!(
+            Permissions::check_permission(BOB, SLASH),
+            Err(Error::<Runtime>::Forbidden)
+        ));
+        assert_noop!(
+            Permissions::revoke(Origin::signed(ALICE), BOB, SLASH, Scope::Unlimited),
+            Error::<Runtime>::PermissionNotFound
+        );
+        // Other permissions are kept
+        assert_ok!(Permissions::check_permission(BOB, BURN));
+    });
+}
+
+#[test]
+fn permission_time_limited_grant_lapses() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(2);
+        assert_noop!(
+            Permissions::grant(
+                Origin::signed(ALICE),
+                JOHN,
+                SLASH,
+                Scope::Unlimited,
+                Some(2)
+            ),
+            Error::<Runtime>::InvalidExpiration
+        );
+        assert_ok!(Permissions::grant(
+            Origin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited,
+            Some(5)
+        ));
+        assert_eq!(
+            Permissions::list_permissions(&JOHN),
+            vec![
+                (MINT, Scope::Unlimited, None),
+                (SLASH, Scope::Unlimited, Some(5))
+            ]
+        );
+
+        let _ = Permissions::on_initialize(4);
+        assert_ok!(Permissions::check_permission(JOHN, SLASH));
+        let _ = Permissions::on_initialize(5);
+        assert!(matches!(
+            Permissions::check_permission(JOHN, SLASH),
+            Err(Error::<Runtime>::Forbidden)
+        ));
+        assert_eq!(
+            Permissions::list_permissions(&JOHN),
+            vec![(MINT, Scope::Unlimited, None)]
+        );
+    });
+}
+
+#[test]
+fn permission_regrant_without_expiration_is_permanent() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Permissions::grant(
+            Origin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited,
+            Some(3)
+        ));
+        assert_ok!(Permissions::grant(
+            Origin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited,
+            None
+        ));
+        let _ = Permissions::on_initialize(3);
+        assert_ok!(Permissions::check_permission(JOHN, SLASH));
+    });
+}
+
+#[test]
+fn expiring_grants_per_block_are_limited() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        for holder in 100..100 + MAX_EXPIRING_GRANTS_PER_BLOCK as AccountId {
+            let _ = frame_system::Pallet::<Runtime>::inc_providers(&holder);
+            assert_ok!(Permissions::grant(
+                Origin::signed(ALICE),
+                holder,
+                SLASH,
+                Scope::Unlimited,
+                Some(3)
+            ));
+        }
+        assert_noop!(
+            Permissions::grant(
+                Origin::signed(ALICE),
+                JOHN,
+                SLASH,
+                Scope::Unlimited,
+                Some(3)
+            ),
+            Error::<Runtime>::TooManyExpiringGrants
+        );
+        assert_ok!(Permissions::grant(
+            Origin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited,
+            Some(4)
+        ));
+    });
+}
+
+#[test]
+fn revoked_and_prolonged_grants_are_unscheduled() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Permissions::grant(
+            Origin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited,
+            Some(3)
+        ));
+        assert_ok!(Permissions::grant(
+            Origin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited,
+            Some(4)
+        ));
+        assert!(!ExpiringGrants::<Runtime>::contains_key(3));
+        assert_eq!(
+            ExpiringGrants::<Runtime>::get(4),
+            vec![(JOHN, Scope::Unlimited, SLASH)]
+        );
+
+        assert_ok!(Permissions::revoke(
+            Origin::signed(ALICE),
+            JOHN,
+            SLASH,
+            Scope::Unlimited
+        ));
+        assert!(!ExpiringGrants::<Runtime>::contains_key(4));
+        assert_eq!(
+            GrantExpirations::<Runtime>::get(JOHN, (Scope::Unlimited, SLASH)),
+            None
+        );
+    });
+}
+
+#[test]
+fn permission_renounce_passes() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Permissions::renounce(
+            Origin::signed(BOB),
+            BURN,
+            Scope::Unlimited
+        ));
+        assert!(matches!(
+            Permissions::check_permission(BOB, BURN),
+            Err(Error::<Runtime>::Forbidden)
+        ));
+        assert_noop!(
+            Permissions::renounce(Origin::signed(BOB), BURN, Scope::Unlimited),
+            Error::<Runtime>::PermissionNotFound
+        );
+        assert_eq!(frame_system::Pallet::<Runtime>::consumers(&BOB), 1);
+        assert_ok!(Permissions::renounce(
+            Origin::signed(BOB),
+            INIT_DEX,
+            Scope::Unlimited
+        ));
+        // The account doesn't hold permissions anymore, so it can be reaped.
+        assert_eq!(frame_system::Pallet::<Runtime>::consumers(&BOB), 0);
+    });
+}
+
+#[test]
+fn permission_renounce_fails_for_restriction() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Permissions::create_permission(
+            ALICE,
+            BOB,
+            CUSTOM_PERMISSION,
+            Scope::Unlimited,
+            Mode::Forbid
+        ));
+        assert_noop!(
+            Permissions::renounce(Origin::signed(BOB), CUSTOM_PERMISSION, Scope::Unlimited),
+            Error::<Runtime>::RestrictionCannotBeRenounced
+        );
+    });
+}
+
+#[test]
+fn role_grant_and_revoke_passes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let role = b"burner".to_vec();
+        assert_noop!(
+            Permissions::set_role(Origin::signed(ALICE), role.clone(), vec![BURN, SLASH]),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Permissions::set_role(Origin::root(), role.clone(), vec![CUSTOM_PERMISSION]),
+            Error::<Runtime>::PermissionNotFound
+        );
+        assert_ok!(Permissions::set_role(
+            Origin::root(),
+            role.clone(),
+            vec![SLASH, BURN, BURN]
+        ));
+        assert_eq!(Permissions::role(&role), Some(vec![BURN, SLASH]));
+
+        assert_ok!(Permissions::grant_role(
+            Origin::signed(ALICE),
+            JOHN,
+            role.clone(),
+            Scope::Unlimited,
+            None
+        ));
+        assert_ok!(Permissions::check_permission(JOHN, BURN));
+        assert_ok!(Permissions::check_permission(JOHN, SLASH));
+
+        assert_ok!(Permissions::revoke_role(
+            Origin::signed(ALICE),
+            JOHN,
+            role.clone(),
+            Scope::Unlimited
+        ));
+        assert!(matches!(
+            Permissions::check_permission(JOHN, BURN),
+            Err(Error::<Runtime>::Forbidden)
+        ));
+        assert!(matches!(
+            Permissions::check_permission(JOHN, SLASH),
+            Err(Error::<Runtime>::Forbidden)
+        ));
+        assert_ok!(Permissions::check_permission(JOHN, MINT));
+
+        assert_ok!(Permissions::remove_role(Origin::root(), role.clone()));
+        assert_noop!(
+            Permissions::grant_role(Origin::signed(ALICE), JOHN, role, Scope::Unlimited, None),
+            Error::<Runtime>::RoleNotFound
+        );
+    });
+}
+
+#[test]
+fn role_grant_is_atomic() {
+    ExtBuilder::default().build().execute_with(|| {
+        let role = b"minter".to_vec();
+        assert_ok!(Permissions::set_role(
+            Origin::root(),
+            role.clone(),
+            vec![MINT, BURN]
+        ));
+        // John owns MINT, but not BURN
+        assert_noop!(
+            Permissions::grant_role(Origin::signed(JOHN), ALICE, role, Scope::Unlimited, None),
+            Error::<Runtime>::PermissionNotOwned
+        );
+    });
+}
@@ -0,0 +1,120 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use sp_std::marker::PhantomData;
+
+use crate::MAX_ROLE_PERMISSIONS;
+
+const FIXED_WEIGHT: Weight = 100_000_000;
+
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
+    fn grant() -> Weight {
+        (38_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn revoke() -> Weight {
+        (35_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn renounce() -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn set_role() -> Weight {
+        (24_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(MAX_ROLE_PERMISSIONS as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_role() -> Weight {
+        (18_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn grant_role() -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(
+                <Self as crate::WeightInfo>::grant().saturating_mul(MAX_ROLE_PERMISSIONS as Weight),
+            )
+    }
+    fn revoke_role() -> Weight {
+        (20_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(
+                <Self as crate::WeightInfo>::revoke()
+                    .saturating_mul(MAX_ROLE_PERMISSIONS as Weight),
+            )
+    }
+    fn on_initialize(expired: u32) -> Weight {
+        (5_000_000 as Weight)
+            .saturating_add((25_000_000 as Weight).saturating_mul(expired as Weight))
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(
+                T::DbWeight::get().reads((5 as Weight).saturating_mul(expired as Weight)),
+            )
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+            .saturating_add(
+                T::DbWeight::get().writes((3 as Weight).saturating_mul(expired as Weight)),
+            )
+    }
+}
+
+impl crate::WeightInfo for () {
+    fn grant() -> Weight {
+        FIXED_WEIGHT
+    }
+    fn revoke() -> Weight {
+        FIXED_WEIGHT
+    }
+    fn renounce() -> Weight {
+        FIXED_WEIGHT
+    }
+    fn set_role() -> Weight {
+        FIXED_WEIGHT
+    }
+    fn remove_role() -> Weight {
+        FIXED_WEIGHT
+    }
+    fn grant_role() -> Weight {
+        FIXED_WEIGHT
+    }
+    fn revoke_role() -> Weight {
+        FIXED_WEIGHT
+    }
+    fn on_initialize(_expired: u32) -> Weight {
+        FIXED_WEIGHT
+    }
+}
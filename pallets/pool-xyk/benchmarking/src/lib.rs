@@ -246,6 +246,23 @@ benchmarks! {
         );
     }
 
+    lock_liquidity {
+        setup_benchmark::<T>()?;
+        let caller = alice::<T>();
+        let unlock_block: T::BlockNumber = 100u32.into();
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        DEX.into(),
+        XOR.into(),
+        DOT.into(),
+        balance!(1000),
+        unlock_block
+    )
+    verify {
+        let (pool_account, _) = XYKPool::<T>::properties(T::AssetId::from(XOR), T::AssetId::from(DOT)).unwrap();
+        assert!(XYKPool::<T>::liquidity_lock(&pool_account, &caller).is_some());
+    }
+
     initialize_pool {
         setup_benchmark_assets_only::<T>()?;
         let caller = alice::<T>();
@@ -275,6 +292,7 @@ mod tests {
             assert_ok!(test_benchmark_deposit_liquidity::<Runtime>());
             assert_ok!(test_benchmark_withdraw_liquidity::<Runtime>());
             assert_ok!(test_benchmark_initialize_pool::<Runtime>());
+            assert_ok!(test_benchmark_lock_liquidity::<Runtime>());
         });
     }
 }
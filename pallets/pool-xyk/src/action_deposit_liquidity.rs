@@ -294,15 +294,20 @@ impl<T: Config> common::SwapAction<AccountIdOf<T>, TechAccountIdOf<T>, T>
             .is_zero()
             && !self.pool_tokens.is_zero()
         {
-            let pair = Module::<T>::strict_sort_pair(&self.source.0.asset, &self.source.1.asset)?;
-            AccountPools::<T>::mutate(receiver_account, |set| set.insert(pair.target_asset_id));
+            let pair = Module::<T>::get_trading_pair_from_tech_account(&self.pool_account)?;
+            AccountPools::<T>::mutate(receiver_account, |set| {
+                set.insert((pair.base_asset_id, pair.target_asset_id))
+            });
         }
         Module::<T>::mint(&pool_account_repr_sys, receiver_account, self.pool_tokens)?;
         let balance_a =
             <assets::Module<T>>::free_balance(&self.source.0.asset, &pool_account_repr_sys)?;
         let balance_b =
             <assets::Module<T>>::free_balance(&self.source.1.asset, &pool_account_repr_sys)?;
+        let base_asset_id =
+            Module::<T>::get_trading_pair_from_tech_account(&self.pool_account)?.base_asset_id;
         Module::<T>::update_reserves(
+            &base_asset_id,
             &self.source.0.asset,
             &self.source.1.asset,
             (&balance_a, &balance_b),
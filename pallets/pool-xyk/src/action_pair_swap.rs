@@ -120,7 +120,11 @@ impl<T: Config> common::SwapRulesValidation<AccountIdOf<T>, TechAccountIdOf<T>,
 
         match self.get_fee_from_destination {
             None => {
+                let base_asset_id =
+                    Module::<T>::get_trading_pair_from_tech_account(&self.pool_account)?
+                        .base_asset_id;
                 let is_fee_from_d = Module::<T>::decide_is_fee_from_destination(
+                    &base_asset_id,
                     &self.source.asset,
                     &self.destination.asset,
                 )?;
@@ -382,7 +386,10 @@ impl<T: Config> common::SwapAction<AccountIdOf<T>, TechAccountIdOf<T>, T>
                 <assets::Module<T>>::free_balance(&self.source.asset, &pool_account_repr_sys)?;
             let balance_b =
                 <assets::Module<T>>::free_balance(&self.destination.asset, &pool_account_repr_sys)?;
+            let base_asset_id =
+                Module::<T>::get_trading_pair_from_tech_account(&self.pool_account)?.base_asset_id;
             Module::<T>::update_reserves(
+                &base_asset_id,
                 &self.source.asset,
                 &self.destination.asset,
                 (&balance_a, &balance_b),
@@ -35,7 +35,7 @@ use sp_runtime::traits::Zero;
 
 use common::prelude::{Balance, FixedWrapper};
 
-use crate::{to_balance, AccountPools, PoolProviders, TotalIssuances};
+use crate::{to_balance, AccountPools, LiquidityLocks, PoolProviders, TotalIssuances};
 
 use crate::aliases::{AccountIdOf, AssetIdOf, TechAccountIdOf};
 use crate::{Config, Error, Module, MIN_LIQUIDITY};
@@ -166,6 +166,14 @@ impl<T: Config> common::SwapRulesValidation<AccountIdOf<T>, TechAccountIdOf<T>,
             Err(Error::<T>::SourceBalanceOfLiquidityTokensIsNotLargeEnough)?;
         }
 
+        // Locked pool tokens can't be withdrawn until the lock expires.
+        if let Some(lock) = Module::<T>::active_liquidity_lock(&pool_account_repr_sys, &source) {
+            ensure!(
+                balance_ks - self.pool_tokens >= lock.pool_tokens,
+                Error::<T>::LiquidityIsLocked
+            );
+        }
+
         //TODO: Debug why in this place checking is failed, but in transfer checks is success.
         /*
         // Checking that balances if correct and large enough for amounts.
@@ -218,17 +226,22 @@ impl<T: Config> common::SwapAction<AccountIdOf<T>, TechAccountIdOf<T>, T>
             .is_zero()
             && !self.pool_tokens.is_zero()
         {
-            let pair = Module::<T>::strict_sort_pair(
-                &self.destination.0.asset,
-                &self.destination.1.asset,
-            )?;
-            AccountPools::<T>::mutate(source, |set| set.remove(&pair.target_asset_id));
+            let pair = Module::<T>::get_trading_pair_from_tech_account(&self.pool_account)?;
+            AccountPools::<T>::mutate(source, |set| {
+                set.remove(&(pair.base_asset_id, pair.target_asset_id))
+            });
+            LiquidityLocks::<T>::remove(&pool_account_repr_sys, source);
+        } else {
+            Module::<T>::remove_expired_liquidity_lock(&pool_account_repr_sys, source);
         }
         let balance_a =
             <assets::Module<T>>::free_balance(&self.destination.0.asset, &pool_account_repr_sys)?;
         let balance_b =
             <assets::Module<T>>::free_balance(&self.destination.1.asset, &pool_account_repr_sys)?;
+        let base_asset_id =
+            Module::<T>::get_trading_pair_from_tech_account(&self.pool_account)?.base_asset_id;
         Module::<T>::update_reserves(
+            &base_asset_id,
             &self.destination.0.asset,
             &self.destination.1.asset,
             (&balance_a, &balance_b),
@@ -30,6 +30,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::PrefixIterator;
 use frame_support::traits::Get;
@@ -43,13 +44,13 @@ use common::prelude::{
 };
 use common::{
     fixed_wrapper, EnsureTradingPairExists, GetPoolReserves, LiquiditySource, LiquiditySourceType,
-    ManagementMode, OnPoolReservesChanged, PoolXykPallet, RewardReason, TechAccountId, TechPurpose,
-    ToFeeAccount, TradingPair,
+    ManagementMode, OnPoolReservesChanged, OnPoolTokensChanged, PoolXykPallet, RewardReason,
+    TechAccountId, TechPurpose, ToFeeAccount, TradingPair,
 };
 
 mod aliases;
 use aliases::{
-    AccountIdOf, AssetIdOf, DEXIdOf, DepositLiquidityActionOf, PairSwapActionOf,
+    AccountIdOf, AssetIdOf, DEXIdOf, DEXManager, DepositLiquidityActionOf, PairSwapActionOf,
     PolySwapActionStructOf, TechAccountIdOf, TechAssetIdOf, WithdrawLiquidityActionOf,
 };
 use sp_std::collections::btree_set::BTreeSet;
@@ -82,11 +83,24 @@ pub use operations::*;
 
 const MIN_LIQUIDITY: u128 = 1000;
 
+/// The longest period pool tokens can be locked for, in blocks (4 years)
+pub const MAX_LIQUIDITY_LOCK_DURATION: u32 = 4 * 365 * 14_400;
+
+/// Pool tokens locked by the liquidity provider until the specific block
+#[derive(Clone, Debug, Encode, Decode, Eq, PartialEq, Default)]
+pub struct LiquidityLock<BlockNumber> {
+    /// The amount of locked pool tokens
+    pub pool_tokens: Balance,
+    /// The block the pool tokens are unlocked at
+    pub unlock_block: BlockNumber,
+}
+
 pub trait WeightInfo {
     fn swap_pair() -> Weight;
     fn deposit_liquidity() -> Weight;
     fn withdraw_liquidity() -> Weight;
     fn initialize_pool() -> Weight;
+    fn lock_liquidity() -> Weight;
     fn can_exchange() -> Weight;
     fn quote() -> Weight;
 }
@@ -120,41 +134,48 @@ impl<T: Config> Module<T> {
         reserves_account_id: &T::AccountId,
         fees_account_id: &T::AccountId,
     ) -> DispatchResult {
-        let base_asset_id: T::AssetId = T::GetBaseAssetId::get();
-        let (sorted_asset_a, sorted_asset_b) = if &base_asset_id == asset_a {
-            (asset_a, asset_b)
-        } else if &base_asset_id == asset_b {
-            (asset_b, asset_a)
-        } else {
-            let hash_key = common::comm_merkle_op(asset_a, asset_b);
-            let (asset_a_pair, asset_b_pair) =
-                common::sort_with_hash_key(hash_key, (asset_a, &()), (asset_b, &()));
-            (asset_a_pair.0, asset_b_pair.0)
-        };
+        let base_asset_id = DEXManager::<T>::get_dex_info(dex_id)?.base_asset_id;
+        let pair = TradingPair::canonical(&base_asset_id, *asset_a, *asset_b);
+        // Pool properties and reserves are keyed by the pair only, so a pool of two non-base
+        // assets must not take the key of a pool quoted in the base asset of another DEX.
+        if pair.base_asset_id != base_asset_id {
+            ensure!(
+                DEXManager::<T>::list_dex_ids().into_iter().all(|id| {
+                    DEXManager::<T>::get_dex_info(&id)
+                        .map(|info| info.base_asset_id != pair.base_asset_id)
+                        .unwrap_or(true)
+                }),
+                Error::<T>::PairBaseAssetIsBaseOfAnotherDEX
+            );
+        }
+        ensure!(
+            !Properties::<T>::contains_key(&pair.base_asset_id, &pair.target_asset_id),
+            Error::<T>::PoolPairIsUsedByAnotherDEX
+        );
         trading_pair::Module::<T>::enable_source_for_trading_pair(
             dex_id,
-            sorted_asset_a,
-            sorted_asset_b,
+            &pair.base_asset_id,
+            &pair.target_asset_id,
             LiquiditySourceType::XYKPool,
         )?;
         Properties::<T>::insert(
-            sorted_asset_a,
-            sorted_asset_b,
+            &pair.base_asset_id,
+            &pair.target_asset_id,
             (reserves_account_id.clone(), fees_account_id.clone()),
         );
         Ok(())
     }
 
     fn update_reserves(
+        base_asset_id: &T::AssetId,
         asset_a: &T::AssetId,
         asset_b: &T::AssetId,
         balance_pair: (&Balance, &Balance),
     ) {
-        let base_asset_id: T::AssetId = T::GetBaseAssetId::get();
-        if base_asset_id == asset_a.clone() {
+        if base_asset_id == asset_a {
             Reserves::<T>::insert(asset_a, asset_b, (balance_pair.0, balance_pair.1));
             T::OnPoolReservesChanged::reserves_changed(asset_b);
-        } else if base_asset_id == asset_b.clone() {
+        } else if base_asset_id == asset_b {
             Reserves::<T>::insert(asset_b, asset_a, (balance_pair.1, balance_pair.0));
             T::OnPoolReservesChanged::reserves_changed(asset_a);
         } else {
@@ -219,15 +240,40 @@ impl<T: Config> Module<T> {
         input_a_min: Balance,
         input_b_min: Balance,
     ) -> DispatchResult {
-        let (_, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
+        let (trading_pair, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
             dex_id,
             input_asset_a,
             input_asset_b,
         )?;
+        // Liquidity can still be withdrawn from the pools of paused or delisted pairs, but
+        // no new liquidity is accepted for them.
+        let pair_base_asset_id: AssetIdOf<T> = trading_pair.base_asset_id.into();
+        let pair_target_asset_id: AssetIdOf<T> = trading_pair.target_asset_id.into();
+        trading_pair::Module::<T>::ensure_trading_pair_exists(
+            &dex_id,
+            &pair_base_asset_id,
+            &pair_target_asset_id,
+        )?;
+        trading_pair::Module::<T>::ensure_trading_not_paused(
+            &dex_id,
+            &pair_base_asset_id,
+            &pair_target_asset_id,
+        )?;
         ensure!(
             input_a_desired >= input_a_min && input_b_desired >= input_b_min,
             Error::<T>::InvalidMinimumBoundValueOfBalance
         );
+        // The liquidity action expects the assets in the order of the pool trading pair.
+        let input_a = (input_asset_a, input_a_desired, input_a_min);
+        let input_b = (input_asset_b, input_b_desired, input_b_min);
+        let (
+            (input_asset_a, input_a_desired, input_a_min),
+            (input_asset_b, input_b_desired, input_b_min),
+        ) = if input_asset_a == pair_base_asset_id {
+            (input_a, input_b)
+        } else {
+            (input_b, input_a)
+        };
         let action = PolySwapActionStructOf::<T>::DepositLiquidity(DepositLiquidityActionOf::<T> {
             client_account: None,
             receiver_account: None,
@@ -260,11 +306,19 @@ impl<T: Config> Module<T> {
         output_a_min: Balance,
         output_b_min: Balance,
     ) -> DispatchResult {
-        let (_, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
+        let (trading_pair, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
             dex_id,
             output_asset_a,
             output_asset_b,
         )?;
+        // The liquidity action expects the assets in the order of the pool trading pair.
+        let pair_base_asset_id: AssetIdOf<T> = trading_pair.base_asset_id.into();
+        let (output_asset_a, output_asset_b, output_a_min, output_b_min) =
+            if output_asset_a == pair_base_asset_id {
+                (output_asset_a, output_asset_b, output_a_min, output_b_min)
+            } else {
+                (output_asset_b, output_asset_a, output_b_min, output_a_min)
+            };
         let action =
             PolySwapActionStructOf::<T>::WithdrawLiquidity(WithdrawLiquidityActionOf::<T> {
                 client_account: None,
@@ -289,11 +343,43 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Returns the lock of the account pool tokens if it has not expired yet.
+    pub fn active_liquidity_lock(
+        pool_account: &AccountIdOf<T>,
+        account: &AccountIdOf<T>,
+    ) -> Option<LiquidityLock<T::BlockNumber>> {
+        let now = frame_system::Module::<T>::block_number();
+        LiquidityLocks::<T>::get(pool_account, account).filter(|lock| lock.unlock_block > now)
+    }
+
+    /// Removes the lock of the account pool tokens if it has expired. Returns `true` if the lock was removed.
+    pub fn remove_expired_liquidity_lock(
+        pool_account: &AccountIdOf<T>,
+        account: &AccountIdOf<T>,
+    ) -> bool {
+        let now = frame_system::Module::<T>::block_number();
+        match LiquidityLocks::<T>::get(pool_account, account) {
+            Some(lock) if lock.unlock_block <= now => {
+                LiquidityLocks::<T>::remove(pool_account, account);
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub fn get_pool_trading_pair(
         pool_account: &T::AccountId,
     ) -> Result<TradingPair<T::AssetId>, DispatchError> {
         let tech_acc = technical::Module::<T>::lookup_tech_account_id(pool_account)?;
-        match tech_acc.into() {
+        Self::get_trading_pair_from_tech_account(&tech_acc)
+    }
+
+    /// Extracts the trading pair (and therefore the base asset of the DEX) the pool technical
+    /// account was derived from.
+    pub fn get_trading_pair_from_tech_account(
+        tech_acc: &TechAccountIdOf<T>,
+    ) -> Result<TradingPair<T::AssetId>, DispatchError> {
+        match tech_acc.clone().into() {
             TechAccountId::Pure(_, TechPurpose::LiquidityKeeper(trading_pair)) => Ok(TradingPair {
                 base_asset_id: trading_pair.base_asset_id.into(),
                 target_asset_id: trading_pair.target_asset_id.into(),
@@ -307,20 +393,16 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
     for Module<T>
 {
     fn can_exchange(
-        _dex_id: &T::DEXId,
+        dex_id: &T::DEXId,
         input_asset_id: &T::AssetId,
         output_asset_id: &T::AssetId,
     ) -> bool {
-        let base_asset_id = T::GetBaseAssetId::get();
-        let target_asset_id = if input_asset_id == &base_asset_id {
-            output_asset_id
-        } else if output_asset_id == &base_asset_id {
-            input_asset_id
-        } else {
-            return false;
+        let base_asset_id = match DEXManager::<T>::get_dex_info(dex_id) {
+            Ok(dex_info) => dex_info.base_asset_id,
+            Err(_) => return false,
         };
-
-        Properties::<T>::contains_key(&base_asset_id, &target_asset_id)
+        let pair = TradingPair::canonical(&base_asset_id, *input_asset_id, *output_asset_id);
+        Properties::<T>::contains_key(&pair.base_asset_id, &pair.target_asset_id)
     }
 
     fn quote(
@@ -330,7 +412,7 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
         amount: QuoteAmount<Balance>,
     ) -> Result<SwapOutcome<Balance>, DispatchError> {
         // Get pool account.
-        let (_, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
+        let (trading_pair, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
             *dex_id,
             *input_asset_id,
             *output_asset_id,
@@ -349,8 +431,11 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
         }
 
         // Decide which side should be used for fee.
-        let get_fee_from_destination =
-            Module::<T>::decide_is_fee_from_destination(input_asset_id, output_asset_id)?;
+        let get_fee_from_destination = Module::<T>::decide_is_fee_from_destination(
+            &trading_pair.base_asset_id.into(),
+            input_asset_id,
+            output_asset_id,
+        )?;
 
         // Calculate quote.
         match amount {
@@ -455,7 +540,7 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
         amount: QuoteAmount<Balance>,
     ) -> Result<SwapOutcome<Balance>, DispatchError> {
         // Get pool account.
-        let (_, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
+        let (trading_pair, tech_acc_id) = Module::<T>::tech_account_from_dex_and_asset_pair(
             *dex_id,
             *input_asset_id,
             *output_asset_id,
@@ -474,8 +559,11 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
         }
 
         // Decide which side should be used for fee.
-        let get_fee_from_destination =
-            Module::<T>::decide_is_fee_from_destination(input_asset_id, output_asset_id)?;
+        let get_fee_from_destination = Module::<T>::decide_is_fee_from_destination(
+            &trading_pair.base_asset_id.into(),
+            input_asset_id,
+            output_asset_id,
+        )?;
 
         let input_price_wrt_output = FixedWrapper::from(reserve_output) / reserve_input;
         let fee_fraction = T::GetFee::get();
@@ -558,6 +646,7 @@ pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_support::traits::PalletVersion;
     use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::Saturating;
 
     #[pallet::config]
     pub trait Config:
@@ -584,6 +673,7 @@ pub mod pallet {
         type GetFee: Get<Fixed>;
         type OnPoolCreated: OnPoolCreated<AccountId = AccountIdOf<Self>, DEXId = DEXIdOf<Self>>;
         type OnPoolReservesChanged: OnPoolReservesChanged<Self::AssetId>;
+        type OnPoolTokensChanged: OnPoolTokensChanged<AccountIdOf<Self>>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -602,6 +692,11 @@ pub mod pallet {
                     minor: 1,
                     patch: 0,
                 }) => migrations::v1_2::migrate::<T>(),
+                Some(PalletVersion {
+                    major: 1,
+                    minor: 2,
+                    patch: 0,
+                }) => migrations::v1_3::migrate::<T>(),
                 _ => 0,
             }
         }
@@ -609,7 +704,8 @@ pub mod pallet {
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        #[pallet::weight(<T as Config>::WeightInfo::deposit_liquidity())]
+        #[pallet::weight(<T as Config>::WeightInfo::deposit_liquidity()
+            .saturating_add(T::OnPoolTokensChanged::on_pool_tokens_changed_weight()))]
         pub fn deposit_liquidity(
             origin: OriginFor<T>,
             dex_id: DEXIdOf<T>,
@@ -634,7 +730,8 @@ pub mod pallet {
             Ok(().into())
         }
 
-        #[pallet::weight(<T as Config>::WeightInfo::withdraw_liquidity())]
+        #[pallet::weight(<T as Config>::WeightInfo::withdraw_liquidity()
+            .saturating_add(T::OnPoolTokensChanged::on_pool_tokens_changed_weight()))]
         pub fn withdraw_liquidity(
             origin: OriginFor<T>,
             dex_id: DEXIdOf<T>,
@@ -697,14 +794,68 @@ pub mod pallet {
                 Ok(().into())
             })
         }
+
+        /// Lock pool tokens of the pool until `unlock_block`, so they can't be withdrawn before it.
+        ///
+        /// Locked pool tokens boost the farming weight of the liquidity provider. An active lock can
+        /// only be increased or prolonged.
+        #[pallet::weight(<T as Config>::WeightInfo::lock_liquidity())]
+        pub fn lock_liquidity(
+            origin: OriginFor<T>,
+            dex_id: DEXIdOf<T>,
+            asset_a: AssetIdOf<T>,
+            asset_b: AssetIdOf<T>,
+            pool_tokens: Balance,
+            unlock_block: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let source = ensure_signed(origin)?;
+            ensure!(pool_tokens != 0, Error::<T>::ZeroValueInAmountParameter);
+            let now = frame_system::Module::<T>::block_number();
+            ensure!(
+                unlock_block > now
+                    && unlock_block <= now.saturating_add(MAX_LIQUIDITY_LOCK_DURATION.into()),
+                Error::<T>::InvalidLiquidityLockPeriod
+            );
+            let (_, tech_acc_id) =
+                Module::<T>::tech_account_from_dex_and_asset_pair(dex_id, asset_a, asset_b)?;
+            let pool_account = technical::Module::<T>::tech_account_id_to_account_id(&tech_acc_id)?;
+            let balance = PoolProviders::<T>::get(&pool_account, &source).unwrap_or(0);
+            ensure!(
+                pool_tokens <= balance,
+                Error::<T>::SourceBalanceOfLiquidityTokensIsNotLargeEnough
+            );
+            if let Some(lock) = Module::<T>::active_liquidity_lock(&pool_account, &source) {
+                ensure!(
+                    pool_tokens >= lock.pool_tokens && unlock_block >= lock.unlock_block,
+                    Error::<T>::LiquidityLockCannotBeReduced
+                );
+            }
+            LiquidityLocks::<T>::insert(
+                &pool_account,
+                &source,
+                LiquidityLock {
+                    pool_tokens,
+                    unlock_block,
+                },
+            );
+            Self::deposit_event(Event::LiquidityLocked(
+                source,
+                pool_account,
+                pool_tokens,
+                unlock_block,
+            ));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
-    #[pallet::metadata(AccountIdOf<T> = "AccountId")]
+    #[pallet::metadata(AccountIdOf<T> = "AccountId", BlockNumberFor<T> = "BlockNumber")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         // New pool for particular pair was initialized. [Reserves Account Id]
         PoolIsInitialized(AccountIdOf<T>),
+        // Pool tokens were locked. [Account Id, Reserves Account Id, Pool Tokens, Unlock Block]
+        LiquidityLocked(AccountIdOf<T>, AccountIdOf<T>, Balance, BlockNumberFor<T>),
     }
 
     #[pallet::error]
@@ -824,6 +975,16 @@ pub mod pallet {
         UnableToDepositXorLessThanMinimum,
         /// Attempt to quote via unsupported path, i.e. both output and input tokens are not XOR.
         UnsupportedQuotePath,
+        /// Pool tokens are locked and can't be withdrawn until the lock expires.
+        LiquidityIsLocked,
+        /// Unlock block is not in the future or exceeds the maximum lock duration.
+        InvalidLiquidityLockPeriod,
+        /// Active lock can't be decreased or shortened.
+        LiquidityLockCannotBeReduced,
+        /// Canonical base asset of the non-base pair is the base asset of another DEX.
+        PairBaseAssetIsBaseOfAnotherDEX,
+        /// Pool of the same pair is already initialized on another DEX.
+        PoolPairIsUsedByAnotherDEX,
     }
 
     /// Updated after last liquidity change operation.
@@ -852,11 +1013,11 @@ pub mod pallet {
         StorageDoubleMap<_, Identity, AccountIdOf<T>, Identity, AccountIdOf<T>, Balance>;
 
     /// Set of pools in which accounts have some share.
-    /// Liquidity provider account => Target Asset of pair (assuming base asset is XOR)
+    /// Liquidity provider account => (Base Asset, Target Asset) of the pool trading pairs
     #[pallet::storage]
     #[pallet::getter(fn account_pools)]
     pub type AccountPools<T: Config> =
-        StorageMap<_, Identity, AccountIdOf<T>, BTreeSet<AssetIdOf<T>>, ValueQuery>;
+        StorageMap<_, Identity, AccountIdOf<T>, BTreeSet<(AssetIdOf<T>, AssetIdOf<T>)>, ValueQuery>;
 
     /// Total issuance of particular pool.
     /// Pool account => Total issuance
@@ -874,4 +1035,17 @@ pub mod pallet {
         T::AssetId,
         (T::AccountId, T::AccountId),
     >;
+
+    /// Pool tokens locked by liquidity providers.
+    /// Pool account => Liquidity provider => Lock
+    #[pallet::storage]
+    #[pallet::getter(fn liquidity_lock)]
+    pub type LiquidityLocks<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        AccountIdOf<T>,
+        Identity,
+        AccountIdOf<T>,
+        LiquidityLock<BlockNumberFor<T>>,
+    >;
 }
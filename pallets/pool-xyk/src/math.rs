@@ -30,6 +30,7 @@
 
 use frame_support::dispatch::DispatchError;
 use frame_support::ensure;
+use frame_support::traits::Get;
 
 use assets::AssetIdOf;
 use common::prelude::{Balance, Fixed, FixedWrapper};
@@ -196,11 +197,17 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// XOR value of the base asset part of `liq_amount` pool tokens. Only pools quoted in XOR
+    /// can be valued this way.
     pub fn get_xor_part_from_pool_account(
         pool_acc: &T::AccountId,
         trading_pair: &TradingPair<AssetIdOf<T>>,
         liq_amount: Balance,
     ) -> Result<Balance, DispatchError> {
+        ensure!(
+            trading_pair.base_asset_id == T::GetBaseAssetId::get(),
+            Error::<T>::UnableToGetXORPartFromMarkerAsset
+        );
         let b_in_pool =
             assets::Module::<T>::free_balance(&trading_pair.base_asset_id.into(), pool_acc)?;
         let t_in_pool =
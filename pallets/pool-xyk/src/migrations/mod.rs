@@ -1,2 +1,3 @@
 pub mod v1_1;
 pub mod v1_2;
+pub mod v1_3;
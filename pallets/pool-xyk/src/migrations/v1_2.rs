@@ -4,9 +4,9 @@ use frame_support::traits::Get;
 use crate::{AccountPools, Config, PoolProviders, Properties};
 
 pub fn migrate<T: Config>() -> Weight {
-    for (_, target_asset, (pool_account, _)) in Properties::<T>::iter() {
+    for (base_asset, target_asset, (pool_account, _)) in Properties::<T>::iter() {
         for (user_account, _pool_tokens_balance) in PoolProviders::<T>::iter_prefix(pool_account) {
-            AccountPools::<T>::mutate(user_account, |set| set.insert(target_asset));
+            AccountPools::<T>::mutate(user_account, |set| set.insert((base_asset, target_asset)));
         }
     }
     T::BlockWeights::get().max_block
@@ -97,7 +97,7 @@ mod tests {
                     AccountPools::<Runtime>::get(account),
                     [target_asset_a, target_asset_b, target_asset_c]
                         .iter()
-                        .cloned()
+                        .map(|target_asset| (base_asset, *target_asset))
                         .collect()
                 )
             }
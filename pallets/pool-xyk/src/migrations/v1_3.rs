@@ -0,0 +1,50 @@
+use frame_support::dispatch::Weight;
+
+use crate::{AccountPools, Config};
+
+/// Account pools were keyed by the target asset only, assuming XOR as the base asset. They are
+/// rebuilt keyed by both assets of the pool trading pair.
+pub fn migrate<T: Config>() -> Weight {
+    AccountPools::<T>::remove_all();
+    super::v1_2::migrate::<T>()
+}
+
+#[cfg(test)]
+mod tests {
+    use common::balance;
+    use frame_support::storage::unhashed;
+    use hex_literal::hex;
+    use sp_std::collections::btree_set::BTreeSet;
+
+    use crate::mock::*;
+    use crate::{AccountPools, PoolProviders, Properties};
+
+    #[test]
+    fn test() {
+        ExtBuilder::default().build().execute_with(|| {
+            let base_asset: AssetId = GoldenTicket.into();
+            let target_asset = AssetId::from_bytes(
+                hex!("0200000700000000000000000000000000000000000000000000000000000000").into(),
+            );
+            let pool_account = BOB();
+            Properties::<Runtime>::insert(
+                base_asset,
+                target_asset,
+                (pool_account.clone(), CHARLIE()),
+            );
+            PoolProviders::<Runtime>::insert(pool_account, ALICE(), balance!(42));
+            let old_pools: BTreeSet<AssetId> = [target_asset].iter().cloned().collect();
+            unhashed::put(
+                &AccountPools::<Runtime>::hashed_key_for(ALICE()),
+                &old_pools,
+            );
+
+            super::migrate::<Runtime>();
+
+            assert_eq!(
+                AccountPools::<Runtime>::get(ALICE()),
+                [(base_asset, target_asset)].iter().cloned().collect()
+            );
+        });
+    }
+}
@@ -483,6 +483,80 @@ fn deposit_less_than_minimum_3() {
     )]);
 }
 
+#[test]
+fn deposit_to_paused_or_delisted_pair_should_fail() {
+    crate::Module::<Runtime>::preset_deposited_pool(vec![Rc::new(
+        |dex_id, gt, bp, _, _, _, _, _| {
+            assert_ok!(trading_pair::Module::<Runtime>::pause(
+                Origin::root(),
+                dex_id,
+                gt,
+                bp
+            ));
+            assert_noop!(
+                crate::Module::<Runtime>::deposit_liquidity(
+                    Origin::signed(ALICE()),
+                    dex_id,
+                    GoldenTicket.into(),
+                    BlackPepper.into(),
+                    balance!(1000),
+                    balance!(400),
+                    balance!(1000),
+                    balance!(400),
+                ),
+                trading_pair::Error::<Runtime>::TradingPairIsPaused
+            );
+            // liquidity of the paused pair can still be withdrawn
+            assert_ok!(crate::Module::<Runtime>::withdraw_liquidity(
+                Origin::signed(ALICE()),
+                dex_id,
+                GoldenTicket.into(),
+                BlackPepper.into(),
+                balance!(1000),
+                balance!(0),
+                balance!(0),
+            ));
+
+            assert_ok!(trading_pair::Module::<Runtime>::delist(
+                Origin::root(),
+                dex_id,
+                gt,
+                bp
+            ));
+            assert_noop!(
+                crate::Module::<Runtime>::deposit_liquidity(
+                    Origin::signed(ALICE()),
+                    dex_id,
+                    GoldenTicket.into(),
+                    BlackPepper.into(),
+                    balance!(1000),
+                    balance!(400),
+                    balance!(1000),
+                    balance!(400),
+                ),
+                trading_pair::Error::<Runtime>::TradingPairDoesntExist
+            );
+
+            assert_ok!(trading_pair::Module::<Runtime>::relist(
+                Origin::root(),
+                dex_id,
+                gt,
+                bp
+            ));
+            assert_ok!(crate::Module::<Runtime>::deposit_liquidity(
+                Origin::signed(ALICE()),
+                dex_id,
+                GoldenTicket.into(),
+                BlackPepper.into(),
+                balance!(1000),
+                balance!(400),
+                balance!(1000),
+                balance!(400),
+            ));
+        },
+    )]);
+}
+
 #[test]
 // Deposit to an existing pool
 fn multiple_providers() {
@@ -1545,7 +1619,7 @@ fn depositing_and_withdrawing_liquidity_updates_user_pools() {
 
         assert_eq!(
             PoolXYK::account_pools(&ALICE()),
-            [target_asset_a].iter().cloned().collect()
+            [(base_asset, target_asset_a)].iter().cloned().collect()
         );
 
         assert_ok!(crate::Module::<Runtime>::deposit_liquidity(
@@ -1561,7 +1635,7 @@ fn depositing_and_withdrawing_liquidity_updates_user_pools() {
 
         assert_eq!(
             PoolXYK::account_pools(&ALICE()),
-            [target_asset_a].iter().cloned().collect()
+            [(base_asset, target_asset_a)].iter().cloned().collect()
         );
 
         assert_ok!(assets::Module::<Runtime>::register_asset_id(
@@ -1604,7 +1678,10 @@ fn depositing_and_withdrawing_liquidity_updates_user_pools() {
 
         assert_eq!(
             PoolXYK::account_pools(&ALICE()),
-            [target_asset_a, target_asset_b].iter().cloned().collect()
+            [(base_asset, target_asset_a), (base_asset, target_asset_b)]
+                .iter()
+                .cloned()
+                .collect()
         );
 
         let (_, tech_account_a) =
@@ -1625,7 +1702,7 @@ fn depositing_and_withdrawing_liquidity_updates_user_pools() {
 
         assert_eq!(
             PoolXYK::account_pools(&ALICE()),
-            [target_asset_b].iter().cloned().collect()
+            [(base_asset, target_asset_b)].iter().cloned().collect()
         );
     })]);
 }
@@ -1821,3 +1898,394 @@ fn price_without_impact_large_amount() {
         },
     )]);
 }
+
+#[test]
+fn locked_liquidity_cannot_be_withdrawn_until_unlock_block() {
+    crate::Module::<Runtime>::preset_initial(vec![Rc::new(|dex_id, _, _, _, _, _, _, _| {
+        let base_asset: AssetId = GoldenTicket.into();
+        let target_asset: AssetId = BlackPepper.into();
+
+        assert_ok!(crate::Module::<Runtime>::deposit_liquidity(
+            Origin::signed(ALICE()),
+            dex_id,
+            base_asset,
+            target_asset,
+            balance!(10),
+            balance!(20),
+            balance!(10),
+            balance!(20),
+        ));
+
+        let (_, tech_account) =
+            PoolXYK::tech_account_from_dex_and_asset_pair(dex_id, base_asset, target_asset)
+                .unwrap();
+        let pool_account = Technical::tech_account_id_to_account_id(&tech_account).unwrap();
+        let user_balance = PoolXYK::pool_providers(&pool_account, &ALICE()).unwrap();
+        let locked = user_balance / 2;
+
+        frame_system::Module::<Runtime>::set_block_number(10);
+
+        assert_noop!(
+            crate::Module::<Runtime>::lock_liquidity(
+                Origin::signed(ALICE()),
+                dex_id,
+                base_asset,
+                target_asset,
+                locked,
+                10
+            ),
+            crate::Error::<Runtime>::InvalidLiquidityLockPeriod
+        );
+        assert_noop!(
+            crate::Module::<Runtime>::lock_liquidity(
+                Origin::signed(ALICE()),
+                dex_id,
+                base_asset,
+                target_asset,
+                locked,
+                10 + crate::MAX_LIQUIDITY_LOCK_DURATION as u64 + 1
+            ),
+            crate::Error::<Runtime>::InvalidLiquidityLockPeriod
+        );
+        assert_noop!(
+            crate::Module::<Runtime>::lock_liquidity(
+                Origin::signed(ALICE()),
+                dex_id,
+                base_asset,
+                target_asset,
+                user_balance + 1,
+                100
+            ),
+            crate::Error::<Runtime>::SourceBalanceOfLiquidityTokensIsNotLargeEnough
+        );
+        assert_ok!(crate::Module::<Runtime>::lock_liquidity(
+            Origin::signed(ALICE()),
+            dex_id,
+            base_asset,
+            target_asset,
+            locked,
+            100
+        ));
+        assert_eq!(
+            PoolXYK::liquidity_lock(&pool_account, &ALICE()),
+            Some(crate::LiquidityLock {
+                pool_tokens: locked,
+                unlock_block: 100,
+            })
+        );
+        assert_noop!(
+            crate::Module::<Runtime>::lock_liquidity(
+                Origin::signed(ALICE()),
+                dex_id,
+                base_asset,
+                target_asset,
+                locked,
+                50
+            ),
+            crate::Error::<Runtime>::LiquidityLockCannotBeReduced
+        );
+
+        // Unlocked part can be withdrawn, the locked part can't
+        assert_noop!(
+            crate::Module::<Runtime>::withdraw_liquidity(
+                Origin::signed(ALICE()),
+                dex_id,
+                base_asset,
+                target_asset,
+                user_balance - locked + 1,
+                balance!(0),
+                balance!(0)
+            ),
+            crate::Error::<Runtime>::LiquidityIsLocked
+        );
+        assert_ok!(crate::Module::<Runtime>::withdraw_liquidity(
+            Origin::signed(ALICE()),
+            dex_id,
+            base_asset,
+            target_asset,
+            user_balance - locked,
+            balance!(0),
+            balance!(0)
+        ));
+
+        // The whole balance can be withdrawn after the lock expires, the expired lock is removed
+        frame_system::Module::<Runtime>::set_block_number(100);
+        assert_ok!(crate::Module::<Runtime>::withdraw_liquidity(
+            Origin::signed(ALICE()),
+            dex_id,
+            base_asset,
+            target_asset,
+            locked / 2,
+            balance!(0),
+            balance!(0)
+        ));
+        assert_eq!(PoolXYK::liquidity_lock(&pool_account, &ALICE()), None);
+        assert_ok!(crate::Module::<Runtime>::withdraw_liquidity(
+            Origin::signed(ALICE()),
+            dex_id,
+            base_asset,
+            target_asset,
+            locked - locked / 2,
+            balance!(0),
+            balance!(0)
+        ));
+    })]);
+}
+
+#[test]
+fn pool_on_dex_with_non_xor_base_asset_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let rp: AssetId = RedPepper.into();
+        let bp: AssetId = BlackPepper.into();
+        for (asset_id, symbol, name) in [
+            (GoldenTicket, "GT", "Golden Ticket"),
+            (RedPepper, "RP", "Red Pepper"),
+            (BlackPepper, "BP", "Black Pepper"),
+        ]
+        .iter()
+        .cloned()
+        {
+            assert_ok!(assets::Module::<Runtime>::register_asset_id(
+                ALICE(),
+                asset_id.into(),
+                AssetSymbol(symbol.as_bytes().to_vec()),
+                AssetName(name.as_bytes().to_vec()),
+                18,
+                Balance::from(0u32),
+                true,
+            ));
+        }
+        assert_ok!(dex_manager::Module::<Runtime>::register_dex(
+            Origin::signed(ALICE()),
+            DEX_B_ID,
+            rp,
+            true
+        ));
+        assert_ok!(trading_pair::Module::<Runtime>::register(
+            Origin::signed(ALICE()),
+            DEX_B_ID,
+            rp,
+            bp
+        ));
+        assert_ok!(crate::Module::<Runtime>::initialize_pool(
+            Origin::signed(ALICE()),
+            DEX_B_ID,
+            bp,
+            rp,
+        ));
+        assert_ok!(crate::Module::<Runtime>::deposit_liquidity(
+            Origin::signed(ALICE()),
+            DEX_B_ID,
+            rp,
+            bp,
+            balance!(10000),
+            balance!(20000),
+            balance!(0),
+            balance!(0),
+        ));
+        assert_eq!(
+            PoolXYK::reserves(rp, bp),
+            (balance!(10000), balance!(20000))
+        );
+        assert!(PoolXYK::can_exchange(&DEX_B_ID, &rp, &bp));
+        assert!(!PoolXYK::can_exchange(&DEX_B_ID, &GoldenTicket.into(), &bp));
+
+        assert_ok!(PoolXYK::exchange(
+            &ALICE(),
+            &ALICE(),
+            &DEX_B_ID,
+            &rp,
+            &bp,
+            SwapAmount::with_desired_input(balance!(100), balance!(0)),
+        ));
+        let (reserve_base, reserve_target) = PoolXYK::reserves(rp, bp);
+        assert_eq!(reserve_base, balance!(10100));
+        assert!(reserve_target < balance!(20000));
+    });
+}
+
+#[test]
+fn pool_of_two_non_base_assets_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let rp: AssetId = RedPepper.into();
+        let bp: AssetId = BlackPepper.into();
+        for (asset_id, symbol, name) in [
+            (GoldenTicket, "GT", "Golden Ticket"),
+            (RedPepper, "RP", "Red Pepper"),
+            (BlackPepper, "BP", "Black Pepper"),
+        ]
+        .iter()
+        .cloned()
+        {
+            assert_ok!(assets::Module::<Runtime>::register_asset_id(
+                ALICE(),
+                asset_id.into(),
+                AssetSymbol(symbol.as_bytes().to_vec()),
+                AssetName(name.as_bytes().to_vec()),
+                18,
+                Balance::from(0u32),
+                true,
+            ));
+        }
+        assert_ok!(trading_pair::Module::<Runtime>::register(
+            Origin::signed(ALICE()),
+            DEX_A_ID,
+            bp,
+            rp
+        ));
+        assert_ok!(crate::Module::<Runtime>::initialize_pool(
+            Origin::signed(ALICE()),
+            DEX_A_ID,
+            rp,
+            bp,
+        ));
+        let pair = common::TradingPair::canonical(&GoldenTicket.into(), rp, bp);
+        assert!(
+            trading_pair::Module::<Runtime>::is_source_enabled_for_trading_pair(
+                &DEX_A_ID,
+                &pair.base_asset_id,
+                &pair.target_asset_id,
+                LiquiditySourceType::XYKPool
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            crate::Module::<Runtime>::tech_account_from_dex_and_asset_pair(DEX_A_ID, rp, bp)
+                .unwrap(),
+            crate::Module::<Runtime>::tech_account_from_dex_and_asset_pair(DEX_A_ID, bp, rp)
+                .unwrap()
+        );
+
+        // Assets order in liquidity operations doesn't matter.
+        assert_ok!(crate::Module::<Runtime>::deposit_liquidity(
+            Origin::signed(ALICE()),
+            DEX_A_ID,
+            rp,
+            bp,
+            balance!(10000),
+            balance!(20000),
+            balance!(0),
+            balance!(0),
+        ));
+        let (reserve_rp, reserve_bp) = if pair.base_asset_id == rp {
+            PoolXYK::reserves(rp, bp)
+        } else {
+            let (reserve_bp, reserve_rp) = PoolXYK::reserves(bp, rp);
+            (reserve_rp, reserve_bp)
+        };
+        assert_eq!(reserve_rp, balance!(10000));
+        assert_eq!(reserve_bp, balance!(20000));
+        assert!(PoolXYK::can_exchange(&DEX_A_ID, &rp, &bp));
+        assert!(PoolXYK::can_exchange(&DEX_A_ID, &bp, &rp));
+
+        assert_ok!(PoolXYK::exchange(
+            &ALICE(),
+            &ALICE(),
+            &DEX_A_ID,
+            &rp,
+            &bp,
+            SwapAmount::with_desired_input(balance!(100), balance!(0)),
+        ));
+        assert_ok!(PoolXYK::exchange(
+            &ALICE(),
+            &ALICE(),
+            &DEX_A_ID,
+            &bp,
+            &rp,
+            SwapAmount::with_desired_input(balance!(100), balance!(0)),
+        ));
+    });
+}
+
+#[test]
+fn pool_of_two_non_base_assets_should_not_collide_with_other_dex_pools() {
+    ExtBuilder::default().build().execute_with(|| {
+        let rp: AssetId = RedPepper.into();
+        let bp: AssetId = BlackPepper.into();
+        for (asset_id, symbol, name) in [
+            (GoldenTicket, "GT", "Golden Ticket"),
+            (RedPepper, "RP", "Red Pepper"),
+            (BlackPepper, "BP", "Black Pepper"),
+            (Apple, "AP", "Apple"),
+            (AppleTree, "AT", "Apple Tree"),
+            (Mango, "MG", "Mango"),
+        ]
+        .iter()
+        .cloned()
+        {
+            assert_ok!(assets::Module::<Runtime>::register_asset_id(
+                ALICE(),
+                asset_id.into(),
+                AssetSymbol(symbol.as_bytes().to_vec()),
+                AssetName(name.as_bytes().to_vec()),
+                18,
+                Balance::from(0u32),
+                true,
+            ));
+        }
+        let pair = common::TradingPair::canonical(&GoldenTicket.into(), rp, bp);
+
+        // The canonical base asset of the pair is the base asset of another DEX.
+        assert_ok!(dex_manager::Module::<Runtime>::register_dex(
+            Origin::signed(ALICE()),
+            DEX_B_ID,
+            pair.base_asset_id,
+            true
+        ));
+        assert_ok!(trading_pair::Module::<Runtime>::register(
+            Origin::signed(ALICE()),
+            DEX_A_ID,
+            rp,
+            bp
+        ));
+        assert_noop!(
+            crate::Module::<Runtime>::initialize_pool(Origin::signed(ALICE()), DEX_A_ID, rp, bp),
+            crate::Error::<Runtime>::PairBaseAssetIsBaseOfAnotherDEX
+        );
+        assert_ok!(trading_pair::Module::<Runtime>::register(
+            Origin::signed(ALICE()),
+            DEX_B_ID,
+            pair.base_asset_id,
+            pair.target_asset_id
+        ));
+        assert_ok!(crate::Module::<Runtime>::initialize_pool(
+            Origin::signed(ALICE()),
+            DEX_B_ID,
+            rp,
+            bp,
+        ));
+
+        // The same pair of non-base assets is already used by a pool of another DEX.
+        let ap: AssetId = Apple.into();
+        let at: AssetId = AppleTree.into();
+        assert_ok!(trading_pair::Module::<Runtime>::register(
+            Origin::signed(ALICE()),
+            DEX_A_ID,
+            ap,
+            at
+        ));
+        assert_ok!(crate::Module::<Runtime>::initialize_pool(
+            Origin::signed(ALICE()),
+            DEX_A_ID,
+            ap,
+            at,
+        ));
+        let dex_c_id: DEXId = 222;
+        assert_ok!(dex_manager::Module::<Runtime>::register_dex(
+            Origin::signed(ALICE()),
+            dex_c_id,
+            Mango.into(),
+            true
+        ));
+        assert_ok!(trading_pair::Module::<Runtime>::register(
+            Origin::signed(ALICE()),
+            dex_c_id,
+            ap,
+            at
+        ));
+        assert_noop!(
+            crate::Module::<Runtime>::initialize_pool(Origin::signed(ALICE()), dex_c_id, ap, at),
+            crate::Error::<Runtime>::PoolPairIsUsedByAnotherDEX
+        );
+    });
+}
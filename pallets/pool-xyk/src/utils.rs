@@ -34,7 +34,9 @@ use frame_support::traits::Get;
 use frame_support::{ensure, fail};
 
 use common::prelude::{Balance, SwapAmount};
-use common::{AccountIdOf, ToFeeAccount, ToTechUnitFromDEXAndTradingPair, TradingPair};
+use common::{
+    AccountIdOf, OnPoolTokensChanged, ToFeeAccount, ToTechUnitFromDEXAndTradingPair, TradingPair,
+};
 
 use crate::aliases::{AssetIdOf, DEXManager, TechAccountIdOf, TechAssetIdOf};
 use crate::bounds::*;
@@ -49,13 +51,13 @@ impl<T: Config> Module<T> {
     }
 
     pub fn decide_is_fee_from_destination(
+        base_asset_id: &AssetIdOf<T>,
         asset_a: &AssetIdOf<T>,
         asset_b: &AssetIdOf<T>,
     ) -> Result<bool, DispatchError> {
-        let base_asset_id: T::AssetId = T::GetBaseAssetId::get();
-        if &base_asset_id == asset_a {
+        if base_asset_id == asset_a {
             Ok(false)
-        } else if &base_asset_id == asset_b {
+        } else if base_asset_id == asset_b {
             Ok(true)
         } else {
             Err(Error::<T>::UnsupportedQuotePath.into())
@@ -99,19 +101,13 @@ impl<T: Config> Module<T> {
         asset_b: T::AssetId,
     ) -> Result<(common::TradingPair<TechAssetIdOf<T>>, TechAccountIdOf<T>), DispatchError> {
         let dexinfo = DEXManager::<T>::get_dex_info(&dex_id)?;
-        let base_asset_id = dexinfo.base_asset_id;
         ensure!(asset_a != asset_b, Error::<T>::AssetsMustNotBeSame);
-        let ba = Module::<T>::try_decode_asset(base_asset_id)?;
-        let ta = if base_asset_id == asset_a {
-            Module::<T>::try_decode_asset(asset_b)?
-        } else if base_asset_id == asset_b {
-            Module::<T>::try_decode_asset(asset_a)?
-        } else {
-            Err(Error::<T>::BaseAssetIsNotMatchedWithAnyAssetArguments)?
-        };
+        // Pools of two non-base assets are keyed by the canonical pair, so the pool account
+        // doesn't depend on the order of the assets.
+        let pair = TradingPair::canonical(&dexinfo.base_asset_id, asset_a, asset_b);
         let tpair = common::TradingPair::<TechAssetIdOf<T>> {
-            base_asset_id: ba,
-            target_asset_id: ta,
+            base_asset_id: Module::<T>::try_decode_asset(pair.base_asset_id)?,
+            target_asset_id: Module::<T>::try_decode_asset(pair.target_asset_id)?,
         };
         Ok((
             tpair,
@@ -177,6 +173,8 @@ impl<T: Config> Module<T> {
         user_account: &AccountIdOf<T>,
         pool_tokens: Balance,
     ) -> Result<(), DispatchError> {
+        let old_pool_tokens = PoolProviders::<T>::get(pool_account, user_account).unwrap_or(0);
+        let old_total_issuance = TotalIssuances::<T>::get(pool_account).unwrap_or(0);
         let result: Result<_, Error<T>> =
             PoolProviders::<T>::mutate_exists(pool_account, user_account, |balance| {
                 let old_balance = balance.ok_or(Error::<T>::AccountBalanceIsInvalid)?;
@@ -196,6 +194,12 @@ impl<T: Config> Module<T> {
             Ok(())
         });
         result?;
+        T::OnPoolTokensChanged::on_pool_tokens_changed(
+            pool_account,
+            user_account,
+            old_pool_tokens,
+            old_total_issuance,
+        );
         Ok(())
     }
 
@@ -204,6 +208,8 @@ impl<T: Config> Module<T> {
         user_account: &AccountIdOf<T>,
         pool_tokens: Balance,
     ) -> Result<(), DispatchError> {
+        let old_pool_tokens = PoolProviders::<T>::get(pool_account, user_account).unwrap_or(0);
+        let old_total_issuance = TotalIssuances::<T>::get(pool_account).unwrap_or(0);
         let result: Result<_, Error<T>> =
             PoolProviders::<T>::mutate(pool_account, user_account, |balance| {
                 if balance.is_none() {
@@ -223,6 +229,12 @@ impl<T: Config> Module<T> {
             Ok(())
         });
         result?;
+        T::OnPoolTokensChanged::on_pool_tokens_changed(
+            pool_account,
+            user_account,
+            old_pool_tokens,
+            old_total_issuance,
+        );
         Ok(())
     }
 
@@ -61,6 +61,11 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(7 as Weight))
             .saturating_add(T::DbWeight::get().writes(8 as Weight))
     }
+    fn lock_liquidity() -> Weight {
+        (65_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(3 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 impl crate::WeightInfo for () {
@@ -76,6 +81,9 @@ impl crate::WeightInfo for () {
     fn initialize_pool() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+    fn lock_liquidity() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
     fn quote() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
@@ -0,0 +1,143 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Price oracle module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use codec::Decode;
+use frame_benchmarking::benchmarks;
+use frame_system::{EventRecord, RawOrigin};
+use hex_literal::hex;
+use sp_std::prelude::*;
+
+/// Max number of feeds or prices benchmarked.
+const MAX_FEEDS: u32 = 100;
+
+fn alice<T: Config>() -> T::AccountId {
+    let bytes = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+    T::AccountId::decode(&mut &bytes[..]).unwrap_or_default()
+}
+
+// Asset which is different for every index and from the predefined assets
+fn asset<T: Config>(index: u32) -> T::AssetId {
+    let mut bytes = [0u8; 32];
+    bytes[28..].copy_from_slice(&(index + 1).to_be_bytes());
+    T::AssetId::decode(&mut &bytes[..]).unwrap_or_default()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Module::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+    add_relayer {
+        let relayer = alice::<T>();
+        Relayers::<T>::kill();
+    }: _(RawOrigin::Root, relayer.clone())
+    verify {
+        assert_last_event::<T>(Event::RelayerAdded(relayer).into())
+    }
+
+    remove_relayer {
+        let relayer = alice::<T>();
+        Relayers::<T>::mutate(|relayers| relayers.insert(relayer.clone()));
+    }: _(RawOrigin::Root, relayer.clone())
+    verify {
+        assert_last_event::<T>(Event::RelayerRemoved(relayer).into())
+    }
+
+    set_quote_asset {
+        let n in 0 .. MAX_FEEDS;
+        let relayer = alice::<T>();
+        for i in 0..n {
+            Feeds::<T>::insert(asset::<T>(i), &relayer, PriceFeed {
+                price: balance!(1),
+                submitted_at: frame_system::Pallet::<T>::block_number(),
+            });
+        }
+        let quote_asset_id = asset::<T>(MAX_FEEDS);
+    }: _(RawOrigin::Root, quote_asset_id, n)
+    verify {
+        assert_last_event::<T>(Event::QuoteAssetChanged(quote_asset_id).into())
+    }
+
+    submit_prices {
+        let n in 1 .. MAX_FEEDS;
+        let relayer = alice::<T>();
+        Relayers::<T>::mutate(|relayers| relayers.insert(relayer.clone()));
+        QuoteAssetId::<T>::put(asset::<T>(MAX_FEEDS));
+        let prices: Vec<_> = (0..n).map(|i| (asset::<T>(i), balance!(1))).collect();
+    }: _(RawOrigin::Signed(relayer.clone()), prices)
+    verify {
+        assert_last_event::<T>(Event::PriceSubmitted(relayer, asset::<T>(n - 1), balance!(1)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use frame_support::assert_ok;
+
+    use crate::mock::{ExtBuilder, Runtime};
+
+    #[test]
+    fn add_relayer() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(super::test_benchmark_add_relayer::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn remove_relayer() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(super::test_benchmark_remove_relayer::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn set_quote_asset() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(super::test_benchmark_set_quote_asset::<Runtime>());
+        });
+    }
+
+    #[test]
+    fn submit_prices() {
+        ExtBuilder::default().build().execute_with(|| {
+            assert_ok!(super::test_benchmark_submit_prices::<Runtime>());
+        });
+    }
+}
@@ -0,0 +1,331 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use common::prelude::{Balance, FixedWrapper};
+use common::{balance, PriceOracle};
+use frame_support::dispatch::DispatchError;
+use frame_support::ensure;
+use frame_support::weights::Weight;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::vec::Vec;
+
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub trait WeightInfo {
+    fn add_relayer() -> Weight;
+    fn remove_relayer() -> Weight;
+    fn set_quote_asset(n: u32) -> Weight;
+    fn submit_prices(n: u32) -> Weight;
+}
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+type AssetIdOf<T> = <T as Config>::AssetId;
+
+/// Price of asset reported by a single relayer.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct PriceFeed<BlockNumber> {
+    /// Amount of quote asset corresponding to a unit (1) of asset.
+    pub price: Balance,
+    /// Block number at which the feed was submitted.
+    pub submitted_at: BlockNumber,
+}
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::MaybeSerializeDeserialize;
+    use sp_std::collections::btree_set::BTreeSet;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+        type AssetId: Parameter + MaybeSerializeDeserialize + Ord + Copy + Default;
+        /// Number of blocks after which price feed is considered stale and is not used in aggregation.
+        type StalenessPeriod: Get<Self::BlockNumber>;
+        /// Minimal number of fresh price feeds required to calculate aggregated price.
+        type MinFeeds: Get<u32>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Allow account to submit price feeds.
+        #[pallet::weight(<T as Config>::WeightInfo::add_relayer())]
+        pub fn add_relayer(
+            origin: OriginFor<T>,
+            account_id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Relayers::<T>::try_mutate(|relayers| {
+                ensure!(
+                    relayers.insert(account_id.clone()),
+                    Error::<T>::RelayerAlreadyExists
+                );
+                Ok::<_, Error<T>>(())
+            })?;
+            Self::deposit_event(Event::RelayerAdded(account_id));
+            Ok(().into())
+        }
+
+        /// Disallow account to submit price feeds. Feeds submitted earlier are no longer taken into account.
+        #[pallet::weight(<T as Config>::WeightInfo::remove_relayer())]
+        pub fn remove_relayer(
+            origin: OriginFor<T>,
+            account_id: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            Relayers::<T>::try_mutate(|relayers| {
+                ensure!(relayers.remove(&account_id), Error::<T>::RelayerNotFound);
+                Ok::<_, Error<T>>(())
+            })?;
+            Self::deposit_event(Event::RelayerRemoved(account_id));
+            Ok(().into())
+        }
+
+        /// Change asset in which submitted prices are denominated, e.g. DAI.
+        /// Feeds submitted earlier are denominated in previous quote asset, so they are cleared.
+        ///
+        /// `feed_count` must be not less than the number of stored feeds, the call is weighted by it.
+        #[pallet::weight(<T as Config>::WeightInfo::set_quote_asset(*feed_count))]
+        pub fn set_quote_asset(
+            origin: OriginFor<T>,
+            asset_id: T::AssetId,
+            feed_count: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            let actual_feed_count = Feeds::<T>::iter().count() as u32;
+            ensure!(
+                actual_feed_count <= feed_count,
+                Error::<T>::FeedCountUnderestimated
+            );
+            Feeds::<T>::remove_all();
+            QuoteAssetId::<T>::put(asset_id);
+            Self::deposit_event(Event::QuoteAssetChanged(asset_id));
+            Ok(Some(<T as Config>::WeightInfo::set_quote_asset(
+                actual_feed_count,
+            ))
+            .into())
+        }
+
+        /// Submit prices of assets denominated in quote asset. Only relayers are allowed to submit prices.
+        #[pallet::weight(<T as Config>::WeightInfo::submit_prices(prices.len() as u32))]
+        pub fn submit_prices(
+            origin: OriginFor<T>,
+            prices: Vec<(T::AssetId, Balance)>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Relayers::<T>::get().contains(&who), Error::<T>::NotRelayer);
+            let quote_asset_id = QuoteAssetId::<T>::get();
+            for (asset_id, _) in prices.iter() {
+                ensure!(
+                    *asset_id != quote_asset_id,
+                    Error::<T>::QuoteAssetPriceSubmitted
+                );
+            }
+            ensure!(
+                prices.iter().all(|(_, price)| !price.is_zero()),
+                Error::<T>::ZeroPrice
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            for (asset_id, price) in prices {
+                Feeds::<T>::insert(
+                    asset_id,
+                    &who,
+                    PriceFeed {
+                        price,
+                        submitted_at: now,
+                    },
+                );
+                Self::deposit_event(Event::PriceSubmitted(who.clone(), asset_id, price));
+            }
+            Ok(().into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::metadata(AccountIdOf<T> = "AccountId", AssetIdOf<T> = "AssetId")]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Account is allowed to submit price feeds. [Relayer]
+        RelayerAdded(AccountIdOf<T>),
+        /// Account is no longer allowed to submit price feeds. [Relayer]
+        RelayerRemoved(AccountIdOf<T>),
+        /// Quote asset has changed, previously submitted feeds are cleared. [Quote Asset Id]
+        QuoteAssetChanged(AssetIdOf<T>),
+        /// Price feed is submitted by relayer. [Relayer, Asset Id, Price]
+        PriceSubmitted(AccountIdOf<T>, AssetIdOf<T>, Balance),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Account is not allowed to submit price feeds.
+        NotRelayer,
+        /// Account is already registered as relayer.
+        RelayerAlreadyExists,
+        /// Account is not registered as relayer.
+        RelayerNotFound,
+        /// Price of quote asset is always 1 and can't be submitted.
+        QuoteAssetPriceSubmitted,
+        /// Submitted price is zero.
+        ZeroPrice,
+        /// Not enough fresh price feeds to calculate aggregated price.
+        InsufficientPriceFeeds,
+        /// Failed to calculate price from aggregated feeds.
+        PriceCalculationFailed,
+        /// Given number of feeds is less than the number of stored feeds.
+        FeedCountUnderestimated,
+    }
+
+    /// Accounts allowed to submit price feeds.
+    #[pallet::storage]
+    #[pallet::getter(fn relayers)]
+    pub type Relayers<T: Config> = StorageValue<_, BTreeSet<T::AccountId>, ValueQuery>;
+
+    /// Asset in which submitted prices are denominated, e.g. DAI.
+    #[pallet::storage]
+    #[pallet::getter(fn quote_asset_id)]
+    pub type QuoteAssetId<T: Config> = StorageValue<_, T::AssetId, ValueQuery>;
+
+    /// Latest price feeds for each asset submitted by each relayer.
+    #[pallet::storage]
+    #[pallet::getter(fn feeds)]
+    pub type Feeds<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        Blake2_128Concat,
+        T::AccountId,
+        PriceFeed<T::BlockNumber>,
+        OptionQuery,
+    >;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Accounts allowed to submit price feeds initially.
+        pub relayers: Vec<T::AccountId>,
+        /// Asset in which submitted prices are denominated, e.g. DAI.
+        pub quote_asset_id: T::AssetId,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                relayers: Default::default(),
+                quote_asset_id: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            Relayers::<T>::put(self.relayers.iter().cloned().collect::<BTreeSet<_>>());
+            QuoteAssetId::<T>::put(self.quote_asset_id);
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Median of fresh price feeds submitted by current relayers for `asset_id`, denominated in quote asset.
+    pub fn aggregated_price(asset_id: &T::AssetId) -> Result<Balance, DispatchError> {
+        let relayers = Relayers::<T>::get();
+        let now = frame_system::Pallet::<T>::block_number();
+        let staleness_period = T::StalenessPeriod::get();
+        let mut prices: Vec<Balance> = Feeds::<T>::iter_prefix(asset_id)
+            .filter(|(relayer, feed)| {
+                relayers.contains(relayer)
+                    && now.saturating_sub(feed.submitted_at) <= staleness_period
+            })
+            .map(|(_, feed)| feed.price)
+            .collect();
+        ensure!(
+            !prices.is_empty() && prices.len() as u32 >= T::MinFeeds::get(),
+            Error::<T>::InsufficientPriceFeeds
+        );
+        prices.sort_unstable();
+        let mid = prices.len() / 2;
+        let median = if prices.len() % 2 == 0 {
+            let (lower, upper) = (prices[mid - 1], prices[mid]);
+            lower / 2 + upper / 2 + (lower % 2 + upper % 2) / 2
+        } else {
+            prices[mid]
+        };
+        Ok(median)
+    }
+}
+
+impl<T: Config> PriceOracle<T::AssetId> for Pallet<T> {
+    fn get_price(
+        input_asset_id: &T::AssetId,
+        output_asset_id: &T::AssetId,
+    ) -> Result<Balance, DispatchError> {
+        if input_asset_id == output_asset_id {
+            return Ok(balance!(1));
+        }
+        let quote_asset_id = QuoteAssetId::<T>::get();
+        let input_price = if *input_asset_id == quote_asset_id {
+            balance!(1)
+        } else {
+            Self::aggregated_price(input_asset_id)?
+        };
+        if *output_asset_id == quote_asset_id {
+            return Ok(input_price);
+        }
+        let output_price = Self::aggregated_price(output_asset_id)?;
+        (FixedWrapper::from(input_price) / FixedWrapper::from(output_price))
+            .try_into_balance()
+            .map_err(|_| Error::<T>::PriceCalculationFailed.into())
+    }
+}
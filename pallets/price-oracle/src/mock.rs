@@ -0,0 +1,141 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{self as price_oracle, Config};
+use common::{AssetId32, PredefinedAssetId, DAI};
+use frame_support::traits::GenesisBuild;
+use frame_support::{construct_runtime, parameter_types};
+use sp_core::H256;
+use sp_runtime::testing::Header;
+use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::AccountId32;
+
+pub type AccountId = AccountId32;
+pub type AssetId = AssetId32<PredefinedAssetId>;
+pub type BlockNumber = u64;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+
+pub fn alice() -> AccountId {
+    AccountId32::from([1; 32])
+}
+
+pub fn bob() -> AccountId {
+    AccountId32::from([2; 32])
+}
+
+pub fn charlie() -> AccountId {
+    AccountId32::from([3; 32])
+}
+
+pub fn dave() -> AccountId {
+    AccountId32::from([4; 32])
+}
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const StalenessPeriod: BlockNumber = 10;
+    pub const MinFeeds: u32 = 2;
+}
+
+construct_runtime! {
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Module, Call, Config, Storage, Event<T>},
+        PriceOracle: price_oracle::{Module, Call, Storage, Config<T>, Event<T>},
+    }
+}
+
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Origin = Origin;
+    type Call = Call;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type Event = Event;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type AccountData = ();
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type PalletInfo = PalletInfo;
+    type SS58Prefix = ();
+}
+
+impl Config for Runtime {
+    type Event = Event;
+    type AssetId = AssetId;
+    type StalenessPeriod = StalenessPeriod;
+    type MinFeeds = MinFeeds;
+    type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+    relayers: Vec<AccountId>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {
+            relayers: vec![alice(), bob(), charlie()],
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Runtime>()
+            .unwrap();
+
+        price_oracle::GenesisConfig::<Runtime> {
+            relayers: self.relayers,
+            quote_asset_id: DAI,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        let mut ext: sp_io::TestExternalities = t.into();
+        ext.execute_with(|| frame_system::Pallet::<Runtime>::set_block_number(1));
+        ext
+    }
+}
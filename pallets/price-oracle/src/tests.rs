@@ -0,0 +1,162 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::mock::*;
+use crate::{Error, Pallet};
+use common::{balance, PriceOracle, DAI, PSWAP, VAL, XOR};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+type Oracle = Pallet<Runtime>;
+
+fn submit(relayer: AccountId, prices: Vec<(AssetId, u128)>) {
+    assert_ok!(Oracle::submit_prices(Origin::signed(relayer), prices));
+}
+
+#[test]
+fn relayers_management_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Oracle::add_relayer(Origin::signed(alice()), dave()),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(Oracle::add_relayer(Origin::root(), dave()));
+        assert!(Oracle::relayers().contains(&dave()));
+        assert_noop!(
+            Oracle::add_relayer(Origin::root(), dave()),
+            Error::<Runtime>::RelayerAlreadyExists
+        );
+        assert_ok!(Oracle::remove_relayer(Origin::root(), dave()));
+        assert!(!Oracle::relayers().contains(&dave()));
+        assert_noop!(
+            Oracle::remove_relayer(Origin::root(), dave()),
+            Error::<Runtime>::RelayerNotFound
+        );
+    });
+}
+
+#[test]
+fn submit_prices_checks_input() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Oracle::submit_prices(Origin::signed(dave()), vec![(XOR, balance!(100))]),
+            Error::<Runtime>::NotRelayer
+        );
+        assert_noop!(
+            Oracle::submit_prices(Origin::signed(alice()), vec![(DAI, balance!(1))]),
+            Error::<Runtime>::QuoteAssetPriceSubmitted
+        );
+        assert_noop!(
+            Oracle::submit_prices(
+                Origin::signed(alice()),
+                vec![(XOR, balance!(100)), (VAL, balance!(0))]
+            ),
+            Error::<Runtime>::ZeroPrice
+        );
+    });
+}
+
+#[test]
+fn median_is_calculated() {
+    ExtBuilder::default().build().execute_with(|| {
+        submit(alice(), vec![(XOR, balance!(100))]);
+        assert_noop!(
+            Oracle::aggregated_price(&XOR),
+            Error::<Runtime>::InsufficientPriceFeeds
+        );
+        submit(bob(), vec![(XOR, balance!(110))]);
+        assert_eq!(Oracle::aggregated_price(&XOR).unwrap(), balance!(105));
+        // outlier doesn't affect median
+        submit(charlie(), vec![(XOR, balance!(100000))]);
+        assert_eq!(Oracle::aggregated_price(&XOR).unwrap(), balance!(110));
+        // resubmission replaces previous feed of relayer
+        submit(charlie(), vec![(XOR, balance!(90))]);
+        assert_eq!(Oracle::aggregated_price(&XOR).unwrap(), balance!(100));
+    });
+}
+
+#[test]
+fn stale_and_removed_relayer_feeds_are_ignored() {
+    ExtBuilder::default().build().execute_with(|| {
+        submit(alice(), vec![(XOR, balance!(100))]);
+        submit(bob(), vec![(XOR, balance!(110))]);
+        frame_system::Pallet::<Runtime>::set_block_number(8);
+        submit(charlie(), vec![(XOR, balance!(120))]);
+        assert_eq!(Oracle::aggregated_price(&XOR).unwrap(), balance!(110));
+        // feeds of alice and bob are outdated
+        frame_system::Pallet::<Runtime>::set_block_number(12);
+        assert_noop!(
+            Oracle::aggregated_price(&XOR),
+            Error::<Runtime>::InsufficientPriceFeeds
+        );
+        submit(alice(), vec![(XOR, balance!(130))]);
+        assert_eq!(Oracle::aggregated_price(&XOR).unwrap(), balance!(125));
+        assert_ok!(Oracle::remove_relayer(Origin::root(), charlie()));
+        assert_noop!(
+            Oracle::aggregated_price(&XOR),
+            Error::<Runtime>::InsufficientPriceFeeds
+        );
+    });
+}
+
+#[test]
+fn cross_price_is_calculated() {
+    ExtBuilder::default().build().execute_with(|| {
+        submit(alice(), vec![(XOR, balance!(100)), (VAL, balance!(4))]);
+        submit(bob(), vec![(XOR, balance!(100)), (VAL, balance!(4))]);
+        assert_eq!(Oracle::get_price(&XOR, &XOR).unwrap(), balance!(1));
+        assert_eq!(Oracle::get_price(&XOR, &DAI).unwrap(), balance!(100));
+        assert_eq!(Oracle::get_price(&DAI, &VAL).unwrap(), balance!(0.25));
+        assert_eq!(Oracle::get_price(&XOR, &VAL).unwrap(), balance!(25));
+        assert_eq!(Oracle::get_price(&VAL, &XOR).unwrap(), balance!(0.04));
+        assert_noop!(
+            Oracle::get_price(&PSWAP, &DAI),
+            Error::<Runtime>::InsufficientPriceFeeds
+        );
+    });
+}
+
+#[test]
+fn changing_quote_asset_clears_feeds() {
+    ExtBuilder::default().build().execute_with(|| {
+        submit(alice(), vec![(XOR, balance!(100))]);
+        submit(bob(), vec![(XOR, balance!(100))]);
+        assert_noop!(
+            Oracle::set_quote_asset(Origin::root(), VAL, 1),
+            Error::<Runtime>::FeedCountUnderestimated
+        );
+        assert_ok!(Oracle::set_quote_asset(Origin::root(), VAL, 2));
+        assert_eq!(Oracle::quote_asset_id(), VAL);
+        assert_noop!(
+            Oracle::get_price(&XOR, &VAL),
+            Error::<Runtime>::InsufficientPriceFeeds
+        );
+    });
+}
@@ -103,7 +103,7 @@ construct_runtime! {
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Storage},
+        DexManager: dex_manager::{Module, Call, Storage, Event<T>},
         TradingPair: trading_pair::{Module, Call, Storage, Event<T>},
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Module, Call, Config<T>, Storage},
         Tokens: tokens::{Module, Call, Config<T>, Storage, Event<T>},
@@ -143,7 +143,11 @@ impl frame_system::Config for Runtime {
     type SS58Prefix = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -200,6 +204,7 @@ impl assets::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl technical::Config for Runtime {
@@ -209,6 +214,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
 impl pallet_balances::Config for Runtime {
@@ -234,6 +240,7 @@ impl pswap_distribution::Config for Runtime {
     type PoolXykPallet = pool_xyk::Module<Runtime>;
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
+    type PriceToolsPallet = ();
 }
 
 impl pool_xyk::Config for Runtime {
@@ -249,6 +256,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = pswap_distribution::Module<Runtime>;
     type OnPoolReservesChanged = PriceTools;
+    type OnPoolTokensChanged = ();
     type WeightInfo = ();
 }
 
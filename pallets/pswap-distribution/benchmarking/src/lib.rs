@@ -42,13 +42,15 @@ use frame_support::traits::{Get, OnInitialize};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use pool_xyk::PoolProviders;
-use pswap_distribution::{Call, ClaimableShares, Pallet, ShareholderAccounts};
+use pswap_distribution::{
+    Call, ClaimableShares, ConversionStrategy, FeesConversionStrategy, Pallet, ShareholderAccounts,
+};
 use sp_std::prelude::*;
 use sp_std::vec;
 use traits::MultiCurrencyExtended;
 
 use common::fixnum::ops::One;
-use common::{balance, fixed, Fixed, FromGenericPair, PSWAP};
+use common::{balance, fixed, Fixed, FromGenericPair, PSWAP, XOR};
 
 use assets::Pallet as Assets;
 use permissions::Pallet as Permissions;
@@ -103,6 +105,16 @@ fn prepare_for_distribution<T: Config + pool_xyk::Config>(distribution_freq: u32
     }
 }
 
+fn prepare_for_exchange<T: Config>(distribution_freq: u32, strategy: ConversionStrategy) {
+    prepare_for_distribution::<T>(distribution_freq);
+    let authority = alice::<T>();
+    for i in 1u128..10 {
+        let pool_fee_account = create_account::<T>(b"pool_fee".to_vec(), i);
+        Assets::<T>::mint_to(&XOR.into(), &authority, &pool_fee_account, balance!(1000)).unwrap();
+    }
+    FeesConversionStrategy::<T>::put(strategy);
+}
+
 fn validate_distribution<T: Config>() {
     for i in 1u128..10 {
         let pool_account = create_account::<T>(b"pool".to_vec(), i);
@@ -160,6 +172,27 @@ benchmarks! {
     verify {
         // nothing but checks is performed
     }
+
+    exchange_fees_regular {
+        let distribution_freq = 15u32;
+        prepare_for_exchange::<T>(distribution_freq - 1u32, ConversionStrategy {
+            split_into: distribution_freq,
+            ..Default::default()
+        });
+    }: {
+        Pallet::<T>::on_initialize(distribution_freq.into());
+    }
+
+    exchange_fees_checked {
+        let distribution_freq = 15u32;
+        prepare_for_exchange::<T>(distribution_freq - 1u32, ConversionStrategy {
+            max_price_impact: Some(fixed!(0)),
+            split_into: distribution_freq,
+            max_price_deviation: Some(fixed!(0)),
+        });
+    }: {
+        Pallet::<T>::on_initialize(distribution_freq.into());
+    }
 }
 
 #[cfg(test)]
@@ -175,6 +208,8 @@ mod tests {
             assert_ok!(test_benchmark_claim_incentive::<Runtime>());
             assert_ok!(test_benchmark_on_initialize_regular::<Runtime>());
             assert_ok!(test_benchmark_on_initialize_intensive::<Runtime>());
+            assert_ok!(test_benchmark_exchange_fees_regular::<Runtime>());
+            assert_ok!(test_benchmark_exchange_fees_checked::<Runtime>());
         });
     }
 }
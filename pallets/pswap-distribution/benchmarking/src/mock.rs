@@ -30,7 +30,9 @@
 
 use common::mock::ExistentialDeposits;
 use common::prelude::Balance;
-use common::{balance, fixed, AssetName, AssetSymbol, BalancePrecision, Fixed, FromGenericPair};
+use common::{
+    balance, fixed, AssetName, AssetSymbol, BalancePrecision, DEXInfo, Fixed, FromGenericPair,
+};
 use currencies::BasicCurrencyAdapter;
 use frame_support::traits::GenesisBuild;
 use frame_support::weights::Weight;
@@ -128,7 +130,7 @@ construct_runtime! {
         Assets: assets::{Module, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Config<T>, Storage, Event<T>},
         Technical: technical::{Module, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Storage},
+        DexManager: dex_manager::{Module, Call, Storage, Event<T>},
         TradingPair: trading_pair::{Module, Call, Config<T>, Storage, Event<T>},
         PoolXYK: pool_xyk::{Module, Call, Storage, Event<T>},
     }
@@ -174,6 +176,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = ();
 }
 
 impl tokens::Config for Runtime {
@@ -188,6 +191,7 @@ impl tokens::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl currencies::Config for Runtime {
@@ -233,9 +237,14 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -365,6 +374,18 @@ impl ExtBuilder {
             .assimilate_storage(&mut t)
             .unwrap();
 
+        dex_manager::GenesisConfig::<Runtime> {
+            dex_list: vec![(
+                DEX_A_ID,
+                DEXInfo {
+                    base_asset_id: GetBaseAssetId::get(),
+                    is_public: true,
+                },
+            )],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         PermissionsConfig {
             initial_permissions: self.initial_permissions,
             initial_permission_owners: self.initial_permission_owners,
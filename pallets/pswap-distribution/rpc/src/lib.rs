@@ -33,7 +33,7 @@ use codec::Codec;
 use common::InvokeRPCError;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::generic::BlockId;
 use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
@@ -41,14 +41,50 @@ use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
 use std::sync::Arc;
 
 // Runtime API imports.
-use pswap_distribution_runtime_api::BalanceInfo;
 pub use pswap_distribution_runtime_api::PswapDistributionAPI as PswapDistributionRuntimeAPI;
+use pswap_distribution_runtime_api::{BalanceInfo, BurnRateInfo, DistributionInfo};
 
 #[rpc]
-pub trait PswapDistributionAPI<BlockHash, AccountId, BalanceInfo> {
+pub trait PswapDistributionAPI<
+    BlockHash,
+    AccountId,
+    BlockNumber,
+    BalanceInfo,
+    DistributionInfoVec,
+    BurnRateInfo,
+>
+{
     #[rpc(name = "pswapDistribution_claimableAmount")]
     fn claimable_amount(&self, account_id: AccountId, at: Option<BlockHash>)
         -> Result<BalanceInfo>;
+
+    #[rpc(name = "pswapDistribution_nextDistributionBlock")]
+    fn next_distribution_block(
+        &self,
+        fees_account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<BlockNumber>>;
+
+    #[rpc(name = "pswapDistribution_distributionHistory")]
+    fn distribution_history(
+        &self,
+        fees_account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<DistributionInfoVec>;
+
+    #[rpc(name = "pswapDistribution_burnRateInfo")]
+    fn burn_rate_info(&self, at: Option<BlockHash>) -> Result<BurnRateInfo>;
+}
+
+/// Version of the runtime API the distribution schedule, history and burn rate info are available since.
+const DISTRIBUTION_INFO_API_VERSION: u32 = 2;
+
+fn unsupported_method_error() -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+        message: "The method is not supported by the runtime at the block.".into(),
+        data: None,
+    }
 }
 
 pub struct PswapDistributionClient<C, B> {
@@ -66,15 +102,22 @@ impl<C, B> PswapDistributionClient<C, B> {
     }
 }
 
-impl<C, Block, AccountId, Balance>
-    PswapDistributionAPI<<Block as BlockT>::Hash, AccountId, BalanceInfo<Balance>>
-    for PswapDistributionClient<C, Block>
+impl<C, Block, AccountId, BlockNumber, Balance>
+    PswapDistributionAPI<
+        <Block as BlockT>::Hash,
+        AccountId,
+        BlockNumber,
+        BalanceInfo<Balance>,
+        Vec<DistributionInfo<BlockNumber, Balance>>,
+        BurnRateInfo<BlockNumber, Balance>,
+    > for PswapDistributionClient<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: PswapDistributionRuntimeAPI<Block, AccountId, Balance>,
+    C::Api: PswapDistributionRuntimeAPI<Block, AccountId, BlockNumber, Balance>,
     AccountId: Codec,
+    BlockNumber: Codec,
     Balance: Codec + MaybeFromStr + MaybeDisplay,
 {
     fn claimable_amount(
@@ -93,4 +136,83 @@ where
             data: Some(format!("{:?}", e).into()),
         })
     }
+
+    fn next_distribution_block(
+        &self,
+        fees_account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<BlockNumber>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        let is_supported = api
+            .has_api_with::<dyn PswapDistributionRuntimeAPI<Block, AccountId, BlockNumber, Balance>, _>(
+                &at,
+                |version| version >= DISTRIBUTION_INFO_API_VERSION,
+            )
+            .unwrap_or(false);
+        if !is_supported {
+            return Err(unsupported_method_error());
+        }
+        api.next_distribution_block(&at, fees_account_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get next distribution block.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn distribution_history(
+        &self,
+        fees_account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<DistributionInfo<BlockNumber, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        let is_supported = api
+            .has_api_with::<dyn PswapDistributionRuntimeAPI<Block, AccountId, BlockNumber, Balance>, _>(
+                &at,
+                |version| version >= DISTRIBUTION_INFO_API_VERSION,
+            )
+            .unwrap_or(false);
+        if !is_supported {
+            return Err(unsupported_method_error());
+        }
+        api.distribution_history(&at, fees_account_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get distribution history.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn burn_rate_info(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<BurnRateInfo<BlockNumber, Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        let is_supported = api
+            .has_api_with::<dyn PswapDistributionRuntimeAPI<Block, AccountId, BlockNumber, Balance>, _>(
+                &at,
+                |version| version >= DISTRIBUTION_INFO_API_VERSION,
+            )
+            .unwrap_or(false);
+        if !is_supported {
+            return Err(unsupported_method_error());
+        }
+        api.burn_rate_info(&at).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get burn rate info.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
 }
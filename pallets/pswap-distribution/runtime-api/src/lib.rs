@@ -57,11 +57,135 @@ pub struct BalanceInfo<Balance> {
     pub balance: Balance,
 }
 
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct DistributionInfo<BlockNumber, Balance> {
+    pub block: BlockNumber,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub incentive_total: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub liquidity_providers: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub vesting: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub parliament: Balance,
+    pub shareholders_num: u32,
+}
+
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BurnRateChange<BlockNumber, Balance> {
+    pub block: BlockNumber,
+    /// Burn rate with 18 decimals precision.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub burn_rate: Balance,
+}
+
+/// Burn rate values are represented with 18 decimals precision.
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct BurnRateInfo<BlockNumber, Balance> {
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub current: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub increase_delta: Balance,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub max: Balance,
+    pub update_frequency: BlockNumber,
+    pub next_update: Option<BlockNumber>,
+    pub max_reached_at: Option<BlockNumber>,
+    pub history: Vec<BurnRateChange<BlockNumber, Balance>>,
+}
+
 sp_api::decl_runtime_apis! {
-    pub trait PswapDistributionAPI<AccountId, Balance> where
+    /// Version 2 adds distribution schedule, distribution history and burn rate info.
+    #[api_version(2)]
+    pub trait PswapDistributionAPI<AccountId, BlockNumber, Balance> where
         AccountId: Codec,
+        BlockNumber: Codec,
         Balance: Codec + MaybeFromStr + MaybeDisplay,
     {
         fn claimable_amount(account_id: AccountId) -> BalanceInfo<Balance>;
+
+        fn next_distribution_block(fees_account_id: AccountId) -> Option<BlockNumber>;
+
+        fn distribution_history(fees_account_id: AccountId) -> Vec<DistributionInfo<BlockNumber, Balance>>;
+
+        fn burn_rate_info() -> BurnRateInfo<BlockNumber, Balance>;
     }
 }
@@ -30,11 +30,13 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
 use common::fixnum::ops::{CheckedAdd, CheckedSub};
-use common::prelude::{Balance, FixedWrapper, SwapAmount};
+use common::prelude::{Balance, FixedWrapper, QuoteAmount, SwapAmount};
 use common::{
     fixed, fixed_wrapper, AccountIdOf, EnsureDEXManager, Fixed, LiquiditySourceFilter,
-    LiquiditySourceType, OnPoolCreated, OnPswapBurned, PoolXykPallet, PswapRemintInfo,
+    LiquiditySourceType, OnPoolCreated, OnPswapBurned, PoolXykPallet, PriceToolsPallet,
+    PswapRemintInfo,
 };
 use core::convert::TryInto;
 use frame_support::dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo, Weight};
@@ -42,7 +44,8 @@ use frame_support::traits::Get;
 use frame_support::{ensure, fail};
 use frame_system::ensure_signed;
 use liquidity_proxy::LiquidityProxyTrait;
-use sp_arithmetic::traits::{Saturating, Zero};
+use sp_arithmetic::traits::{Saturating, UniqueSaturatedInto, Zero};
+use sp_std::vec::Vec;
 
 pub mod weights;
 
@@ -56,6 +59,10 @@ mod migration;
 
 pub const TECH_ACCOUNT_PREFIX: &[u8] = b"pswap-distribution";
 pub const TECH_ACCOUNT_MAIN: &[u8] = b"main";
+/// Number of latest distributions per fees account and burn rate changes kept in history.
+pub const HISTORY_DEPTH: usize = 30;
+/// Number of times conversion amount is halved trying to fit max price impact.
+pub const MAX_PRICE_IMPACT_ITERATIONS: u32 = 8;
 
 type DexIdOf<T> = <T as common::Config>::DEXId;
 type AssetIdOf<T> = <T as assets::Config>::AssetId;
@@ -65,6 +72,88 @@ type System<T> = frame_system::Module<T>;
 pub trait WeightInfo {
     fn claim_incentive() -> Weight;
     fn on_initialize(is_distributing: bool) -> Weight;
+    fn exchange_fees(is_checked: bool) -> Weight;
+    fn set_conversion_strategy() -> Weight;
+}
+
+/// Strategy of exchanging collected fees to incentive asset. Default strategy exchanges all
+/// collected fees at once at distribution.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+pub struct ConversionStrategy {
+    /// Max price impact of a single exchange, exchanged amount is reduced to fit it and the rest
+    /// is carried over.
+    pub max_price_impact: Option<Fixed>,
+    /// Number of blocks before and including distribution block to split exchange across.
+    pub split_into: u32,
+    /// Max deviation of exchange price from the average price from price tools, exchange is
+    /// carried over if it's exceeded.
+    pub max_price_deviation: Option<Fixed>,
+}
+
+impl ConversionStrategy {
+    /// Whether exchanges are checked by quotes of the exchanged amount before they are done.
+    pub fn is_checked(&self) -> bool {
+        self.max_price_impact.is_some() || self.max_price_deviation.is_some()
+    }
+}
+
+impl Default for ConversionStrategy {
+    fn default() -> Self {
+        Self {
+            max_price_impact: None,
+            split_into: 1,
+            max_price_deviation: None,
+        }
+    }
+}
+
+/// Outcome of applying `ConversionStrategy` to collected fees.
+enum ConversionDecision {
+    /// Exchange given amount.
+    Exchange(Balance),
+    /// Carry over whole amount. (Exchange Price, Average Price)
+    Postpone(Balance, Balance),
+}
+
+/// Outcome of incentive distribution for a subscribed fees account.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug, Default)]
+pub struct DistributionRecord<BlockNumber> {
+    /// Block at which distribution happened.
+    pub block: BlockNumber,
+    /// Amount of incentive burned from fees account.
+    pub incentive_total: Balance,
+    /// Amount reminted for liquidity providers of the pool.
+    pub liquidity_providers: Balance,
+    /// Amount reminted for vesting.
+    pub vesting: Balance,
+    /// Amount reminted for parliament.
+    pub parliament: Balance,
+    /// Number of liquidity providers who received incentive.
+    pub shareholders_num: u32,
+}
+
+/// Schedule of the burn rate growth.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+pub struct BurnRateTrajectory<BlockNumber> {
+    /// Current burn rate.
+    pub current: Fixed,
+    /// Burn rate increase on each update.
+    pub increase_delta: Fixed,
+    /// Burn rate after which updates stop.
+    pub max: Fixed,
+    /// Block of the next burn rate increase, `None` if it's not going to change anymore.
+    pub next_update: Option<BlockNumber>,
+    /// Block at which burn rate will reach its max, `None` if it's not going to change anymore.
+    pub max_reached_at: Option<BlockNumber>,
+}
+
+/// Push value to the end of history, dropping the oldest entries beyond `HISTORY_DEPTH`.
+fn push_to_history<V>(history: &mut Vec<V>, value: V) {
+    if history.len() >= HISTORY_DEPTH {
+        let excess = history.len() + 1 - HISTORY_DEPTH;
+        history.drain(..excess);
+    }
+    history.push(value);
 }
 
 impl<T: Config> Pallet<T> {
@@ -111,6 +200,7 @@ impl<T: Config> Pallet<T> {
     pub fn unsubscribe(fees_account_id: T::AccountId) -> DispatchResult {
         let value = SubscribedAccounts::<T>::take(&fees_account_id);
         ensure!(value.is_some(), Error::<T>::UnknownSubscription);
+        DistributionHistory::<T>::remove(&fees_account_id);
         frame_system::Pallet::<T>::dec_consumers(&fees_account_id);
         Ok(())
     }
@@ -149,15 +239,19 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    /// Perform exchange of Base Asset to Incentive Asset.
+    /// Perform exchange of Base Asset of the DEX to Incentive Asset according to `ConversionStrategy`.
     ///
     /// - `fees_account_id`: Id of Account which accumulates fees from swaps.
     /// - `dex_id`: Id of DEX to which given account belongs.
+    /// - `parts`: Number of parts collected fees are split into, only one part is exchanged.
     fn exchange_fees_to_incentive(
         fees_account_id: &T::AccountId,
         dex_id: &T::DEXId,
+        parts: u32,
     ) -> DispatchResult {
-        let base_total = Assets::<T>::free_balance(&T::GetBaseAssetId::get(), &fees_account_id)?;
+        // fees are collected in the base asset of the DEX the pool belongs to
+        let base_asset_id = dex_manager::Pallet::<T>::get_dex_info(dex_id)?.base_asset_id;
+        let base_total = Assets::<T>::free_balance(&base_asset_id, &fees_account_id)?;
         if base_total == 0 {
             Self::deposit_event(Event::<T>::NothingToExchange(
                 dex_id.clone(),
@@ -165,23 +259,74 @@ impl<T: Config> Pallet<T> {
             ));
             return Ok(());
         }
+        let strategy = FeesConversionStrategy::<T>::get();
+        let filter = LiquiditySourceFilter::with_allowed(
+            dex_id.clone(),
+            [LiquiditySourceType::XYKPool].into(),
+        );
+
+        let mut amount = base_total / Balance::from(parts.max(1));
+        if amount < base_total {
+            Self::deposit_event(Event::<T>::FeesExchangeSplit(
+                dex_id.clone(),
+                fees_account_id.clone(),
+                amount,
+                base_total.saturating_sub(amount),
+            ));
+        }
+        let checks_result =
+            Self::apply_conversion_strategy(&strategy, &base_asset_id, amount, &filter);
+        match checks_result {
+            Ok(ConversionDecision::Exchange(limited_amount)) => {
+                if limited_amount < amount {
+                    Self::deposit_event(Event::<T>::FeesExchangeLimitedByPriceImpact(
+                        dex_id.clone(),
+                        fees_account_id.clone(),
+                        amount,
+                        limited_amount,
+                    ));
+                    amount = limited_amount;
+                }
+                if amount == 0 {
+                    return Ok(());
+                }
+            }
+            Ok(ConversionDecision::Postpone(price, reference_price)) => {
+                Self::deposit_event(Event::<T>::FeesExchangePostponed(
+                    dex_id.clone(),
+                    fees_account_id.clone(),
+                    amount,
+                    price,
+                    reference_price,
+                ));
+                return Ok(());
+            }
+            Err(_error) => {
+                Self::deposit_event(Event::<T>::FeesExchangeFailed(
+                    dex_id.clone(),
+                    fees_account_id.clone(),
+                    base_asset_id,
+                    amount,
+                    T::GetIncentiveAssetId::get(),
+                ));
+                return Ok(());
+            }
+        }
+
         let outcome = T::LiquidityProxy::exchange(
             fees_account_id,
             fees_account_id,
-            &T::GetBaseAssetId::get(),
+            &base_asset_id,
             &T::GetIncentiveAssetId::get(),
-            SwapAmount::with_desired_input(base_total.clone(), Balance::zero()),
-            LiquiditySourceFilter::with_allowed(
-                dex_id.clone(),
-                [LiquiditySourceType::XYKPool].into(),
-            ),
+            SwapAmount::with_desired_input(amount.clone(), Balance::zero()),
+            filter,
         );
         match outcome {
             Ok(swap_outcome) => Self::deposit_event(Event::<T>::FeesExchanged(
                 dex_id.clone(),
                 fees_account_id.clone(),
-                T::GetBaseAssetId::get(),
-                base_total,
+                base_asset_id,
+                amount,
                 T::GetIncentiveAssetId::get(),
                 swap_outcome.amount,
             )),
@@ -189,14 +334,94 @@ impl<T: Config> Pallet<T> {
             Err(_error) => Self::deposit_event(Event::<T>::FeesExchangeFailed(
                 dex_id.clone(),
                 fees_account_id.clone(),
-                T::GetBaseAssetId::get(),
-                base_total,
+                base_asset_id,
+                amount,
                 T::GetIncentiveAssetId::get(),
             )),
         }
         Ok(())
     }
 
+    /// Decide how much of `amount` of `base_asset_id` should be exchanged now.
+    fn apply_conversion_strategy(
+        strategy: &ConversionStrategy,
+        base_asset_id: &T::AssetId,
+        amount: Balance,
+        filter: &LiquiditySourceFilter<T::DEXId, LiquiditySourceType>,
+    ) -> Result<ConversionDecision, DispatchError> {
+        let amount = match strategy.max_price_impact {
+            Some(max_price_impact) if amount > 0 => {
+                Self::limit_by_price_impact(base_asset_id, amount, max_price_impact, filter)?
+            }
+            _ => amount,
+        };
+        if let (Some(max_price_deviation), true) = (strategy.max_price_deviation, amount > 0) {
+            // average price may be unavailable if incentive asset is not tracked, check is skipped then
+            if let Ok(reference_price) = T::PriceToolsPallet::get_average_price(
+                base_asset_id,
+                &T::GetIncentiveAssetId::get(),
+            ) {
+                let price = Self::quote_price(base_asset_id, amount, filter)?;
+                let reference = FixedWrapper::from(reference_price);
+                let deviation = if price > reference {
+                    (price.clone() - reference.clone()) / reference
+                } else {
+                    (reference.clone() - price.clone()) / reference
+                };
+                if deviation > FixedWrapper::from(max_price_deviation) {
+                    return Ok(ConversionDecision::Postpone(
+                        price
+                            .try_into_balance()
+                            .map_err(|_| Error::<T>::CalculationError)?,
+                        reference_price,
+                    ));
+                }
+            }
+        }
+        Ok(ConversionDecision::Exchange(amount))
+    }
+
+    /// Halve `amount` until price impact of its exchange fits `max_price_impact`. Price of 1% of
+    /// the amount is used as the spot price. Returns zero if impact doesn't fit after
+    /// `MAX_PRICE_IMPACT_ITERATIONS` attempts.
+    fn limit_by_price_impact(
+        base_asset_id: &T::AssetId,
+        amount: Balance,
+        max_price_impact: Fixed,
+        filter: &LiquiditySourceFilter<T::DEXId, LiquiditySourceType>,
+    ) -> Result<Balance, DispatchError> {
+        let spot_price = Self::quote_price(base_asset_id, (amount / 100).max(1), filter)?;
+        let max_price_impact = FixedWrapper::from(max_price_impact);
+        let mut amount = amount;
+        for _ in 0..MAX_PRICE_IMPACT_ITERATIONS {
+            let price = Self::quote_price(base_asset_id, amount, filter)?;
+            let price_impact = fixed_wrapper!(1) - price / spot_price.clone();
+            if price_impact <= max_price_impact {
+                return Ok(amount);
+            }
+            amount /= 2;
+            if amount == 0 {
+                break;
+            }
+        }
+        Ok(0)
+    }
+
+    /// Amount of Incentive Asset received for unit of `base_asset_id` if `amount` of it is exchanged.
+    fn quote_price(
+        base_asset_id: &T::AssetId,
+        amount: Balance,
+        filter: &LiquiditySourceFilter<T::DEXId, LiquiditySourceType>,
+    ) -> Result<FixedWrapper, DispatchError> {
+        let outcome = T::LiquidityProxy::quote(
+            base_asset_id,
+            &T::GetIncentiveAssetId::get(),
+            QuoteAmount::with_desired_input(amount),
+            filter.clone(),
+        )?;
+        Ok(FixedWrapper::from(outcome.amount) / FixedWrapper::from(amount))
+    }
+
     /// Perform distribution of Incentive Asset, i.e. transfer portions of accumulated Incentive Asset
     /// to shareholders according to amount of owned marker token.
     ///
@@ -287,6 +512,20 @@ impl<T: Config> Pallet<T> {
                 distribution.parliament,
             )?;
 
+            DistributionHistory::<T>::mutate(fees_account_id, |history| {
+                push_to_history(
+                    history,
+                    DistributionRecord {
+                        block: System::<T>::block_number(),
+                        incentive_total,
+                        liquidity_providers: distribution.liquidity_providers,
+                        vesting: distribution.vesting,
+                        parliament: distribution.parliament,
+                        shareholders_num: shareholders_num.try_into().unwrap_or(u32::MAX),
+                    },
+                )
+            });
+
             Self::deposit_event(Event::<T>::IncentiveDistributed(
                 dex_id.clone(),
                 fees_account_id.clone(),
@@ -294,6 +533,15 @@ impl<T: Config> Pallet<T> {
                 distribution.liquidity_providers,
                 shareholders_num,
             ));
+            Self::deposit_event(Event::<T>::PoolIncentiveDistributed(
+                dex_id.clone(),
+                fees_account_id.clone(),
+                pool_account.clone(),
+                incentive_total,
+                distribution.liquidity_providers,
+                distribution.vesting,
+                distribution.parliament,
+            ));
             Ok(())
         })
     }
@@ -328,16 +576,23 @@ impl<T: Config> Pallet<T> {
         })
     }
 
-    pub fn incentive_distribution_routine(block_num: T::BlockNumber) -> bool {
+    /// Exchanges collected fees and distributes incentives of the subscribed accounts, returns the weight
+    /// consumed by the exchanges and distributions.
+    pub fn incentive_distribution_routine(block_num: T::BlockNumber) -> Weight {
         let tech_account_id = T::GetTechnicalAccountId::get();
 
-        let mut distributing_count = 0;
+        let strategy = FeesConversionStrategy::<T>::get();
+        let split_into = strategy.split_into;
+
+        let mut distributing_count: u32 = 0;
+        let mut exchanging_count: u32 = 0;
 
         for (fees_account, (dex_id, pool_account, frequency, block_offset)) in
             SubscribedAccounts::<T>::iter()
         {
-            if (block_num.saturating_sub(block_offset) % frequency).is_zero() {
-                let _exchange_result = Self::exchange_fees_to_incentive(&fees_account, &dex_id);
+            let elapsed = block_num.saturating_sub(block_offset) % frequency;
+            if elapsed.is_zero() {
+                let _exchange_result = Self::exchange_fees_to_incentive(&fees_account, &dex_id, 1);
                 let distribute_result = Self::distribute_incentive(
                     &fees_account,
                     &dex_id,
@@ -351,9 +606,33 @@ impl<T: Config> Pallet<T> {
                     ));
                 }
                 distributing_count += 1;
+            } else {
+                let blocks_left: u32 = frequency.saturating_sub(elapsed).unique_saturated_into();
+                if blocks_left < split_into {
+                    // exchange a part of fees, so that exchanges are evenly split till distribution
+                    let _exchange_result = Self::exchange_fees_to_incentive(
+                        &fees_account,
+                        &dex_id,
+                        blocks_left.saturating_add(1),
+                    );
+                    exchanging_count += 1;
+                }
             }
         }
-        distributing_count > 0
+        let is_checked = strategy.is_checked();
+        // distributions are benchmarked with unchecked exchanges, quotes are charged additionally
+        let checks_weight = <T as Config>::WeightInfo::exchange_fees(is_checked)
+            .saturating_sub(<T as Config>::WeightInfo::exchange_fees(false));
+        // each distribution is recorded to the distribution history of the fees account
+        let history_weight =
+            T::DbWeight::get().reads_writes(distributing_count.into(), distributing_count.into());
+        <T as Config>::WeightInfo::on_initialize(distributing_count > 0)
+            .saturating_add(checks_weight.saturating_mul(distributing_count.into()))
+            .saturating_add(history_weight)
+            .saturating_add(
+                <T as Config>::WeightInfo::exchange_fees(is_checked)
+                    .saturating_mul(exchanging_count.into()),
+            )
     }
 
     fn update_burn_rate() {
@@ -362,10 +641,59 @@ impl<T: Config> Pallet<T> {
         if burn_rate < max {
             burn_rate = max.min(burn_rate.cadd(increase_delta).unwrap());
             BurnRate::<T>::mutate(|val| *val = burn_rate.clone());
+            BurnRateHistory::<T>::mutate(|history| {
+                push_to_history(history, (System::<T>::block_number(), burn_rate))
+            });
             Self::deposit_event(Event::<T>::BurnRateChanged(burn_rate))
         }
     }
 
+    /// Query block at which next incentive distribution for given fees account is going to happen.
+    ///
+    /// - `fees_account_id`: Id of Account which accumulates fees from swaps.
+    pub fn next_distribution_block(fees_account_id: &T::AccountId) -> Option<T::BlockNumber> {
+        let (_, _, frequency, block_offset) = SubscribedAccounts::<T>::get(fees_account_id)?;
+        let current_block = System::<T>::block_number();
+        let elapsed = current_block.saturating_sub(block_offset) % frequency;
+        Some(
+            current_block
+                .saturating_add(frequency)
+                .saturating_sub(elapsed),
+        )
+    }
+
+    /// Query current burn rate along with blocks at which it's going to grow.
+    pub fn burn_rate_trajectory() -> BurnRateTrajectory<T::BlockNumber> {
+        let current = BurnRate::<T>::get();
+        let (increase_delta, max) = BurnUpdateInfo::<T>::get();
+        let mut trajectory = BurnRateTrajectory {
+            current,
+            increase_delta,
+            max,
+            next_update: None,
+            max_reached_at: None,
+        };
+        if current >= max || increase_delta <= fixed!(0) {
+            return trajectory;
+        }
+        let frequency = T::GetBurnUpdateFrequency::get();
+        let current_block = System::<T>::block_number();
+        let next_update = current_block
+            .saturating_add(frequency)
+            .saturating_sub(current_block % frequency);
+        // number of updates needed to reach max, rounded up
+        let remaining = max.into_bits().saturating_sub(current.into_bits());
+        let delta = increase_delta.into_bits();
+        let updates: u32 = ((remaining + delta - 1) / delta)
+            .try_into()
+            .unwrap_or(u32::MAX);
+        trajectory.next_update = Some(next_update);
+        trajectory.max_reached_at = Some(
+            next_update.saturating_add(frequency.saturating_mul(updates.saturating_sub(1).into())),
+        );
+        trajectory
+    }
+
     pub fn burn_rate_update_routine(block_num: T::BlockNumber) {
         if (block_num % T::GetBurnUpdateFrequency::get()).is_zero() {
             Self::update_burn_rate();
@@ -398,7 +726,11 @@ pub mod pallet {
 
     #[pallet::config]
     pub trait Config:
-        frame_system::Config + common::Config + assets::Config + technical::Config
+        frame_system::Config
+        + common::Config
+        + assets::Config
+        + technical::Config
+        + dex_manager::Config
     {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         type GetIncentiveAssetId: Get<Self::AssetId>;
@@ -416,6 +748,7 @@ pub mod pallet {
         type WeightInfo: WeightInfo;
         type GetParliamentAccountId: Get<Self::AccountId>;
         type PoolXykPallet: PoolXykPallet<AccountId = Self::AccountId>;
+        type PriceToolsPallet: PriceToolsPallet<Self::AssetId>;
     }
 
     #[pallet::pallet]
@@ -427,10 +760,10 @@ pub mod pallet {
         /// Perform exchange and distribution routines for all substribed accounts
         /// with respect to thir configured frequencies.
         fn on_initialize(block_num: T::BlockNumber) -> Weight {
-            let is_distributing = Self::incentive_distribution_routine(block_num);
+            let weight = Self::incentive_distribution_routine(block_num);
             Self::burn_rate_update_routine(block_num);
 
-            <T as Config>::WeightInfo::on_initialize(is_distributing)
+            weight
         }
 
         fn on_runtime_upgrade() -> Weight {
@@ -446,6 +779,24 @@ pub mod pallet {
             Self::claim_by_account(&who)?;
             Ok(().into())
         }
+
+        /// Set strategy of exchanging collected fees to incentive asset.
+        ///
+        /// Can only be called by root.
+        #[pallet::weight(<T as Config>::WeightInfo::set_conversion_strategy())]
+        pub fn set_conversion_strategy(
+            origin: OriginFor<T>,
+            strategy: ConversionStrategy,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                strategy.split_into > 0,
+                Error::<T>::InvalidConversionStrategy
+            );
+            FeesConversionStrategy::<T>::put(strategy.clone());
+            Self::deposit_event(Event::<T>::ConversionStrategyChanged(strategy));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -491,6 +842,30 @@ pub mod pallet {
         /// [DEX Id, Incentive Asset Id, Total exchanged incentives (Incentives burned after exchange),
         /// Incentives burned (Incentives that is not revived (to burn)]).
         IncentivesBurnedAfterExchange(DexIdOf<T>, AssetIdOf<T>, Balance, Balance),
+        /// Incentive of the pool was burned and reminted to its destinations.
+        /// [DEX Id, Fees Account Id, Pool Account Id, Incentive Burned Amount, Liquidity Providers Amount,
+        /// Vesting Amount, Parliament Amount]
+        PoolIncentiveDistributed(
+            DexIdOf<T>,
+            AccountIdOf<T>,
+            AccountIdOf<T>,
+            Balance,
+            Balance,
+            Balance,
+            Balance,
+        ),
+        /// Strategy of exchanging collected fees to incentive asset was changed.
+        /// [Strategy]
+        ConversionStrategyChanged(ConversionStrategy),
+        /// Only a part of collected fees is exchanged, rest is exchanged in the next blocks.
+        /// [DEX Id, Fees Account Id, Amount To Exchange, Amount Carried Over]
+        FeesExchangeSplit(DexIdOf<T>, AccountIdOf<T>, Balance, Balance),
+        /// Exchanged amount is reduced to fit max price impact, rest is carried over.
+        /// [DEX Id, Fees Account Id, Desired Amount, Reduced Amount]
+        FeesExchangeLimitedByPriceImpact(DexIdOf<T>, AccountIdOf<T>, Balance, Balance),
+        /// Exchange price deviates from the average one more than allowed, exchange is carried over.
+        /// [DEX Id, Fees Account Id, Amount, Exchange Price, Average Price]
+        FeesExchangePostponed(DexIdOf<T>, AccountIdOf<T>, Balance, Balance, Balance),
     }
 
     #[pallet::error]
@@ -507,6 +882,8 @@ pub mod pallet {
         ZeroClaimableIncentives,
         /// Increment account reference error.
         IncRefError,
+        /// Fees exchange can't be split into zero parts.
+        InvalidConversionStrategy,
     }
 
     /// Store for information about accounts containing fees, that participate in incentive distribution mechanism.
@@ -541,6 +918,27 @@ pub mod pallet {
     #[pallet::getter(fn claimable_shares)]
     pub type ClaimableShares<T: Config> = StorageValue<_, Fixed, ValueQuery>;
 
+    /// Strategy of exchanging collected fees to incentive asset.
+    #[pallet::storage]
+    #[pallet::getter(fn conversion_strategy)]
+    pub type FeesConversionStrategy<T: Config> = StorageValue<_, ConversionStrategy, ValueQuery>;
+
+    /// Latest incentive distributions, oldest first. Fees Account Id -> Distributions
+    #[pallet::storage]
+    #[pallet::getter(fn distribution_history)]
+    pub type DistributionHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<DistributionRecord<T::BlockNumber>>,
+        ValueQuery,
+    >;
+
+    /// Latest burn rate changes, oldest first. (Block Number, Burn Rate)
+    #[pallet::storage]
+    #[pallet::getter(fn burn_rate_history)]
+    pub type BurnRateHistory<T: Config> = StorageValue<_, Vec<(T::BlockNumber, Fixed)>, ValueQuery>;
+
     #[pallet::type_value]
     pub(super) fn DefaultForParliamentPswapFraction() -> Fixed {
         fixed!(0.1)
@@ -30,19 +30,25 @@
 
 use crate::{self as pswap_distribution, Config};
 use common::mock::ExistentialDeposits;
-use common::prelude::Balance;
-use common::{balance, fixed, AssetName, AssetSymbol, BalancePrecision, Fixed, FromGenericPair};
+use common::prelude::{Balance, FixedWrapper, QuoteAmount, SwapAmount, SwapOutcome};
+use common::{
+    balance, fixed, AssetName, AssetSymbol, BalancePrecision, DEXInfo, Fixed, FromGenericPair,
+    LiquiditySourceFilter, LiquiditySourceType, PriceToolsPallet,
+};
 use currencies::BasicCurrencyAdapter;
+use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::GenesisBuild;
 use frame_support::weights::Weight;
 use frame_support::{construct_runtime, parameter_types};
 use frame_system;
 use hex_literal::hex;
+use liquidity_proxy::LiquidityProxyTrait;
 use permissions::Scope;
 use sp_core::H256;
 use sp_runtime::testing::Header;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup, Zero};
 use sp_runtime::{AccountId32, Perbill};
+use traits::MultiCurrency;
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
@@ -146,7 +152,7 @@ construct_runtime! {
         Assets: assets::{Module, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Config<T>, Storage, Event<T>},
         Technical: technical::{Module, Call, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Storage},
+        DexManager: dex_manager::{Module, Call, Storage, Event<T>},
         TradingPair: trading_pair::{Module, Call, Config<T>, Storage, Event<T>},
         PoolXYK: pool_xyk::{Module, Call, Storage, Event<T>},
     }
@@ -180,7 +186,7 @@ impl frame_system::Config for Runtime {
 impl Config for Runtime {
     type Event = Event;
     type GetIncentiveAssetId = GetIncentiveAssetId;
-    type LiquidityProxy = ();
+    type LiquidityProxy = MockLiquidityProxy;
     type CompatBalance = Balance;
     type GetDefaultSubscriptionFrequency = GetDefaultSubscriptionFrequency;
     type GetBurnUpdateFrequency = GetBurnUpdateFrequency;
@@ -190,6 +196,73 @@ impl Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = MockPriceTools;
+}
+
+/// Reserves of XOR and PSWAP in the pool used by `MockLiquidityProxy`.
+pub const MOCK_XOR_RESERVE: Balance = balance!(1000);
+pub const MOCK_PSWAP_RESERVE: Balance = balance!(10000);
+/// Average XOR/PSWAP price reported by `MockPriceTools`.
+pub const MOCK_AVERAGE_PRICE: Balance = balance!(10);
+
+/// Exchanges XOR to PSWAP as constant product pool with fixed reserves and no fees.
+pub struct MockLiquidityProxy;
+
+impl MockLiquidityProxy {
+    fn output_amount(amount_in: Balance) -> Balance {
+        (FixedWrapper::from(amount_in) * FixedWrapper::from(MOCK_PSWAP_RESERVE)
+            / (FixedWrapper::from(MOCK_XOR_RESERVE) + FixedWrapper::from(amount_in)))
+        .into_balance()
+    }
+}
+
+impl LiquidityProxyTrait<DEXId, AccountId, AssetId> for MockLiquidityProxy {
+    fn quote(
+        _input_asset_id: &AssetId,
+        _output_asset_id: &AssetId,
+        amount: QuoteAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        match amount {
+            QuoteAmount::WithDesiredInput { desired_amount_in } => {
+                Ok(SwapOutcome::new(Self::output_amount(desired_amount_in), 0))
+            }
+            QuoteAmount::WithDesiredOutput { .. } => unimplemented!(),
+        }
+    }
+
+    fn exchange(
+        sender: &AccountId,
+        receiver: &AccountId,
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+        amount: SwapAmount<Balance>,
+        _filter: LiquiditySourceFilter<DEXId, LiquiditySourceType>,
+    ) -> Result<SwapOutcome<Balance>, DispatchError> {
+        match amount {
+            SwapAmount::WithDesiredInput {
+                desired_amount_in, ..
+            } => {
+                let amount_out = Self::output_amount(desired_amount_in);
+                Currencies::withdraw(*input_asset_id, sender, desired_amount_in)?;
+                Currencies::deposit(*output_asset_id, receiver, amount_out)?;
+                Ok(SwapOutcome::new(amount_out, 0))
+            }
+            SwapAmount::WithDesiredOutput { .. } => unimplemented!(),
+        }
+    }
+}
+
+pub struct MockPriceTools;
+
+impl PriceToolsPallet<AssetId> for MockPriceTools {
+    fn get_average_price(_: &AssetId, _: &AssetId) -> Result<Balance, DispatchError> {
+        Ok(MOCK_AVERAGE_PRICE)
+    }
+
+    fn register_asset(_: &AssetId) -> DispatchResult {
+        Ok(())
+    }
 }
 
 impl tokens::Config for Runtime {
@@ -204,6 +277,7 @@ impl tokens::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl currencies::Config for Runtime {
@@ -249,9 +323,14 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -272,10 +351,12 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = ();
     type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
+    dex_list: Vec<(DEXId, DEXInfo<AssetId>)>,
     endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
     endowed_assets: Vec<(
         AssetId,
@@ -295,6 +376,13 @@ pub struct ExtBuilder {
 impl ExtBuilder {
     pub fn uninitialized() -> Self {
         Self {
+            dex_list: vec![(
+                DEX_A_ID,
+                DEXInfo {
+                    base_asset_id: GetBaseAssetId::get(),
+                    is_public: true,
+                },
+            )],
             endowed_accounts: Vec::new(),
             endowed_assets: vec![(
                 PoolTokenAId::get(),
@@ -317,6 +405,13 @@ impl ExtBuilder {
     pub fn with_accounts(accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
         let permissioned_account_id = GetPswapDistributionAccountId::get();
         Self {
+            dex_list: vec![(
+                DEX_A_ID,
+                DEXInfo {
+                    base_asset_id: GetBaseAssetId::get(),
+                    is_public: true,
+                },
+            )],
             endowed_accounts: accounts,
             endowed_assets: vec![
                 (
@@ -381,6 +476,14 @@ impl Default for ExtBuilder {
 }
 
 impl ExtBuilder {
+    /// Quote the DEX of the subscribed pools in `base_asset_id` instead of XOR.
+    pub fn with_dex_base_asset(mut self, base_asset_id: AssetId) -> Self {
+        for (_, dex_info) in self.dex_list.iter_mut() {
+            dex_info.base_asset_id = base_asset_id;
+        }
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
         let mut t = SystemConfig::default().build_storage::<Runtime>().unwrap();
 
@@ -406,6 +509,12 @@ impl ExtBuilder {
             .assimilate_storage(&mut t)
             .unwrap();
 
+        dex_manager::GenesisConfig::<Runtime> {
+            dex_list: self.dex_list,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
         PermissionsConfig {
             initial_permissions: self.initial_permissions,
             initial_permission_owners: self.initial_permission_owners,
@@ -29,11 +29,18 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::mock::*;
-use crate::{ClaimableShares, Error, Module, ShareholderAccounts, SubscribedAccounts};
+use crate::{
+    BurnRateTrajectory, ClaimableShares, ConversionStrategy, DistributionRecord, Error, Module,
+    ShareholderAccounts, SubscribedAccounts, HISTORY_DEPTH,
+};
 use codec::Encode;
-use common::prelude::Fixed;
-use common::{assert_approx_eq, balance, fixed, DEXId, FromGenericPair, DAI, PSWAP, VAL, XOR};
+use common::prelude::{Balance, Fixed};
+use common::{
+    assert_approx_eq, balance, fixed, AssetName, AssetSymbol, DEXId, FromGenericPair, DAI, PSWAP,
+    VAL, XOR,
+};
 use frame_support::assert_noop;
+use frame_support::sp_runtime::DispatchError;
 use frame_support::traits::PalletVersion;
 use traits::MultiCurrency;
 
@@ -939,3 +946,280 @@ fn migration_v0_2_0_to_v1_1_1() {
         );
     })
 }
+
+#[test]
+fn distribution_history_should_be_recorded() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        pool_xyk::Module::<Runtime>::mint(&pool_account_a(), &liquidity_provider_a(), balance!(3))
+            .unwrap();
+        pool_xyk::Module::<Runtime>::mint(&pool_account_a(), &liquidity_provider_b(), balance!(2))
+            .unwrap();
+        pool_xyk::Module::<Runtime>::mint(&pool_account_a(), &liquidity_provider_c(), balance!(1))
+            .unwrap();
+
+        for i in 0u64..5 {
+            frame_system::Pallet::<Runtime>::set_block_number(i);
+            PswapDistrModule::incentive_distribution_routine(i);
+        }
+
+        assert_eq!(
+            PswapDistrModule::distribution_history(fees_account_a()),
+            vec![DistributionRecord {
+                block: 0,
+                incentive_total: balance!(6),
+                liquidity_providers: balance!(5.4),
+                vesting: balance!(0),
+                parliament: balance!(0.6),
+                shareholders_num: 3,
+            }]
+        );
+        assert_eq!(
+            PswapDistrModule::next_distribution_block(&fees_account_a()),
+            Some(5)
+        );
+        assert_eq!(
+            PswapDistrModule::next_distribution_block(&fees_account_b()),
+            Some(7)
+        );
+        assert_eq!(PswapDistrModule::next_distribution_block(&alice()), None);
+
+        PswapDistrModule::unsubscribe(fees_account_a()).expect("Failed to unsubscribe.");
+        assert!(PswapDistrModule::distribution_history(fees_account_a()).is_empty());
+    })
+}
+
+#[test]
+fn distribution_history_should_be_bounded() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        pool_xyk::Module::<Runtime>::mint(&pool_account_a(), &liquidity_provider_a(), balance!(1))
+            .unwrap();
+
+        let tech_account_id = GetPswapDistributionAccountId::get();
+        for i in 0..HISTORY_DEPTH as u64 + 5 {
+            frame_system::Pallet::<Runtime>::set_block_number(i);
+            assets::Module::<Runtime>::mint_to(
+                &GetIncentiveAssetId::get(),
+                &tech_account_id,
+                &fees_account_a(),
+                balance!(1),
+            )
+            .unwrap();
+            PswapDistrModule::distribute_incentive(
+                &fees_account_a(),
+                &DEX_A_ID,
+                &pool_account_a(),
+                &tech_account_id,
+            )
+            .expect("Error is not expected during distribution");
+        }
+
+        let history = PswapDistrModule::distribution_history(fees_account_a());
+        assert_eq!(history.len(), HISTORY_DEPTH);
+        assert_eq!(history.first().unwrap().block, 5);
+        assert_eq!(history.last().unwrap().block, HISTORY_DEPTH as u64 + 4);
+    })
+}
+
+#[test]
+fn burn_rate_trajectory_should_pass() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(4);
+        assert_eq!(
+            PswapDistrModule::burn_rate_trajectory(),
+            BurnRateTrajectory {
+                current: fixed!(0.1),
+                increase_delta: fixed!(0.1),
+                max: fixed!(0.4),
+                next_update: Some(6),
+                max_reached_at: Some(12),
+            }
+        );
+
+        for i in 6u64..13 {
+            frame_system::Pallet::<Runtime>::set_block_number(i);
+            PswapDistrModule::burn_rate_update_routine(i);
+        }
+        assert_eq!(
+            PswapDistrModule::burn_rate_history(),
+            vec![(6, fixed!(0.2)), (9, fixed!(0.3)), (12, fixed!(0.4))]
+        );
+        assert_eq!(
+            PswapDistrModule::burn_rate_trajectory(),
+            BurnRateTrajectory {
+                current: fixed!(0.4),
+                increase_delta: fixed!(0.1),
+                max: fixed!(0.4),
+                next_update: None,
+                max_reached_at: None,
+            }
+        );
+    })
+}
+
+fn xor_balance(account_id: &AccountId) -> Balance {
+    Currencies::free_balance(GetBaseAssetId::get(), account_id)
+}
+
+#[test]
+fn set_conversion_strategy_should_pass() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let strategy = ConversionStrategy {
+            max_price_impact: Some(fixed!(0.05)),
+            split_into: 3,
+            max_price_deviation: Some(fixed!(0.1)),
+        };
+        assert_noop!(
+            PswapDistrModule::set_conversion_strategy(Origin::signed(alice()), strategy.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            PswapDistrModule::set_conversion_strategy(
+                Origin::root(),
+                ConversionStrategy {
+                    split_into: 0,
+                    ..strategy.clone()
+                }
+            ),
+            Error::<Runtime>::InvalidConversionStrategy
+        );
+        assert_eq!(
+            PswapDistrModule::conversion_strategy(),
+            ConversionStrategy::default()
+        );
+        PswapDistrModule::set_conversion_strategy(Origin::root(), strategy.clone())
+            .expect("Failed to set strategy.");
+        assert_eq!(PswapDistrModule::conversion_strategy(), strategy);
+    })
+}
+
+#[test]
+fn fees_exchange_split_across_blocks_should_pass() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        PswapDistrModule::set_conversion_strategy(
+            Origin::root(),
+            ConversionStrategy {
+                split_into: 5,
+                ..Default::default()
+            },
+        )
+        .expect("Failed to set strategy.");
+        Currencies::deposit(GetBaseAssetId::get(), &fees_account_a(), balance!(100)).unwrap();
+        pool_xyk::Module::<Runtime>::mint(&pool_account_a(), &liquidity_provider_a(), balance!(1))
+            .unwrap();
+
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        PswapDistrModule::incentive_distribution_routine(1);
+        assert_eq!(xor_balance(&fees_account_a()), balance!(80));
+        for i in 2u64..5 {
+            frame_system::Pallet::<Runtime>::set_block_number(i);
+            PswapDistrModule::incentive_distribution_routine(i);
+        }
+        assert_eq!(xor_balance(&fees_account_a()), balance!(20));
+        let incentive_before_distribution =
+            Tokens::free_balance(GetIncentiveAssetId::get(), &fees_account_a());
+        assert!(incentive_before_distribution > balance!(6));
+
+        frame_system::Pallet::<Runtime>::set_block_number(5);
+        PswapDistrModule::incentive_distribution_routine(5);
+        assert_eq!(xor_balance(&fees_account_a()), balance!(0));
+        assert_eq!(
+            Tokens::free_balance(GetIncentiveAssetId::get(), &fees_account_a()),
+            balance!(0)
+        );
+        assert!(
+            PswapDistrModule::distribution_history(fees_account_a())
+                .last()
+                .unwrap()
+                .incentive_total
+                > incentive_before_distribution
+        );
+    })
+}
+
+#[test]
+fn fees_exchange_from_dex_base_asset_should_pass() {
+    let mut ext = ExtBuilder::default()
+        .with_dex_base_asset(VAL.into())
+        .build();
+    ext.execute_with(|| {
+        assets::Module::<Runtime>::register_asset_id(
+            alice(),
+            VAL.into(),
+            AssetSymbol(b"VAL".to_vec()),
+            AssetName(b"SORA Validator Token".to_vec()),
+            18,
+            balance!(0),
+            true,
+        )
+        .expect("Failed to register asset.");
+        Currencies::deposit(VAL.into(), &fees_account_a(), balance!(100)).unwrap();
+
+        frame_system::Pallet::<Runtime>::set_block_number(5);
+        PswapDistrModule::incentive_distribution_routine(5);
+        assert_eq!(
+            Currencies::free_balance(VAL.into(), &fees_account_a()),
+            balance!(0)
+        );
+        // XOR is not the base asset of the DEX, so it's left as is
+        assert_eq!(xor_balance(&fees_account_a()), balance!(1));
+    })
+}
+
+#[test]
+fn fees_exchange_limited_by_price_impact_should_pass() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        PswapDistrModule::set_conversion_strategy(
+            Origin::root(),
+            ConversionStrategy {
+                max_price_impact: Some(fixed!(0.1)),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to set strategy.");
+        Currencies::deposit(GetBaseAssetId::get(), &fees_account_a(), balance!(1000)).unwrap();
+
+        frame_system::Pallet::<Runtime>::set_block_number(5);
+        PswapDistrModule::incentive_distribution_routine(5);
+        // 1000 -> 500 -> 250 -> 125 -> 62.5 XOR until price impact fits 10%
+        assert_eq!(xor_balance(&fees_account_a()), balance!(937.5));
+    })
+}
+
+#[test]
+fn fees_exchange_postponed_by_price_deviation_should_pass() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        PswapDistrModule::set_conversion_strategy(
+            Origin::root(),
+            ConversionStrategy {
+                max_price_deviation: Some(fixed!(0.2)),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to set strategy.");
+        Currencies::deposit(GetBaseAssetId::get(), &fees_account_a(), balance!(1000)).unwrap();
+
+        // exchange price is 5 PSWAP for XOR, while average one is 10
+        frame_system::Pallet::<Runtime>::set_block_number(5);
+        PswapDistrModule::incentive_distribution_routine(5);
+        assert_eq!(xor_balance(&fees_account_a()), balance!(1000));
+
+        PswapDistrModule::set_conversion_strategy(
+            Origin::root(),
+            ConversionStrategy {
+                max_price_deviation: Some(fixed!(0.6)),
+                ..Default::default()
+            },
+        )
+        .expect("Failed to set strategy.");
+        frame_system::Pallet::<Runtime>::set_block_number(10);
+        PswapDistrModule::incentive_distribution_routine(10);
+        assert_eq!(xor_balance(&fees_account_a()), balance!(0));
+    })
+}
@@ -49,6 +49,20 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
             (201_666_000 as Weight).saturating_add(T::DbWeight::get().reads(10 as Weight))
         }
     }
+    fn exchange_fees(is_checked: bool) -> Weight {
+        if is_checked {
+            (1_473_520_000 as Weight)
+                .saturating_add(T::DbWeight::get().reads(24 as Weight))
+                .saturating_add(T::DbWeight::get().writes(6 as Weight))
+        } else {
+            (412_310_000 as Weight)
+                .saturating_add(T::DbWeight::get().reads(15 as Weight))
+                .saturating_add(T::DbWeight::get().writes(6 as Weight))
+        }
+    }
+    fn set_conversion_strategy() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 impl crate::WeightInfo for () {
@@ -58,4 +72,10 @@ impl crate::WeightInfo for () {
     fn on_initialize(_is_distributing: bool) -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+    fn exchange_fees(_is_checked: bool) -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn set_conversion_strategy() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
 }
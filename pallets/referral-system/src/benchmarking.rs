@@ -0,0 +1,109 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Referral system module benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use codec::Decode;
+use common::balance;
+use frame_benchmarking::benchmarks;
+use frame_system::{EventRecord, RawOrigin};
+use sp_std::prelude::*;
+
+// Account which is different for every index
+fn account<T: Config>(index: u32) -> T::AccountId {
+    let mut bytes = [0u8; 32];
+    bytes[28..].copy_from_slice(&(index + 1).to_be_bytes());
+    T::AccountId::decode(&mut &bytes[..]).unwrap_or_default()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Module::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+    set_referrer {
+        // Length of the referrer chain checked for cycles
+        let n in 1 .. MAX_REFERRAL_CHAIN_LENGTH;
+        let caller = account::<T>(0);
+        frame_system::Pallet::<T>::inc_providers(&caller);
+        for i in 1..n {
+            Pallet::<T>::set_referrer_to(&account::<T>(i), account::<T>(i + 1)).unwrap();
+        }
+        let referrer = account::<T>(1);
+        frame_system::Pallet::<T>::inc_providers(&referrer);
+    }: _(RawOrigin::Signed(caller.clone()), referrer.clone())
+    verify {
+        assert_last_event::<T>(Event::ReferrerSet(caller, referrer).into())
+    }
+
+    set_reward_shares {
+        let tier_shares = TierShares {
+            first_tier: Percent::from_percent(60),
+            second_tier: Percent::from_percent(40),
+        };
+    }: _(RawOrigin::Root, tier_shares, Percent::from_percent(10))
+    verify {
+        assert_eq!(Pallet::<T>::reward_tier_shares(), tier_shares);
+    }
+
+    fund_reserves {
+        let caller = account::<T>(0);
+        let amount = balance!(100);
+        T::Currency::make_free_balance_be(&caller, balance!(200));
+    }: _(RawOrigin::Signed(caller.clone()), amount)
+    verify {
+        assert_last_event::<T>(Event::ReservesFunded(caller, amount).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{ExtBuilder, Runtime};
+    use frame_support::assert_ok;
+
+    #[test]
+    fn test_benchmarks() {
+        ExtBuilder::default().build().execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            assert_ok!(test_benchmark_set_referrer::<Runtime>());
+            assert_ok!(test_benchmark_set_reward_shares::<Runtime>());
+            assert_ok!(test_benchmark_fund_reserves::<Runtime>());
+        });
+    }
+}
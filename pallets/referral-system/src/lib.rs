@@ -36,10 +36,95 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod migration;
+pub mod weights;
+
+use codec::{Decode, Encode};
+use common::{Balance, OnSwapFeePaid};
+use frame_support::dispatch::DispatchResult;
 use frame_support::ensure;
-use frame_support::sp_runtime::DispatchError;
+use frame_support::sp_runtime::traits::{One, Saturating, Zero};
+use frame_support::sp_runtime::{DispatchError, Percent};
+use frame_support::traits::{Currency, ExistenceRequirement, Get};
+use frame_support::weights::Weight;
+use sp_std::vec::Vec;
+
+pub const TECH_ACCOUNT_PREFIX: &[u8] = b"referral-system";
+pub const TECH_ACCOUNT_REWARDS: &[u8] = b"rewards";
+
+/// The longest chain of referrers checked for cycles when the referrer is set by the account itself
+pub const MAX_REFERRAL_CHAIN_LENGTH: u32 = 64;
+
+pub trait WeightInfo {
+    fn set_referrer(n: u32) -> Weight;
+    fn set_reward_shares() -> Weight;
+    fn fund_reserves() -> Weight;
+}
+
+/// Statistics of the referrer
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug, Default)]
+pub struct ReferrerStats {
+    /// Number of accounts referred by the referrer
+    pub referrals_count: u32,
+    /// Transaction fees the referrer received from the referrals
+    pub earned_fees: Balance,
+    /// Swap fee kickbacks the referrer received from the referrals
+    pub earned_swap_fees: Balance,
+}
+
+/// Shares of the referrer rewards of the account paid to each tier of its referrers
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct TierShares {
+    /// Share paid to the referrer of the account
+    pub first_tier: Percent,
+    /// Share paid to the referrer of the referrer of the account
+    pub second_tier: Percent,
+}
+
+impl Default for TierShares {
+    fn default() -> Self {
+        Self {
+            first_tier: Percent::one(),
+            second_tier: Percent::zero(),
+        }
+    }
+}
+
+impl<T: Config> OnSwapFeePaid<T::AccountId> for Module<T> {
+    /// Pays the `SwapFeeKickbackShare` of the swap fee to the referrers of the account from the referral
+    /// rewards reserves. The reserves are funded explicitly with `fund_reserves`. Rewards that the reserves
+    /// can't cover are skipped.
+    fn on_swap_fee_paid(account_id: &T::AccountId, xor_fee: Balance) {
+        let kickback = Self::swap_fee_kickback_share().mul_floor(xor_fee);
+        if kickback.is_zero() {
+            return;
+        }
+        let reserves_account_id = T::ReferralRewardsAccountId::get();
+        for (referrer, reward) in Self::referrer_rewards(account_id, kickback) {
+            if T::Currency::transfer(
+                &reserves_account_id,
+                &referrer,
+                reward,
+                ExistenceRequirement::KeepAlive,
+            )
+            .is_ok()
+            {
+                ReferrerStatistics::<T>::mutate(&referrer, |stats| {
+                    stats.earned_swap_fees = stats.earned_swap_fees.saturating_add(reward)
+                });
+            } else {
+                Self::deposit_event(Event::SwapFeeKickbackSkipped(referrer, reward));
+            }
+        }
+    }
+}
 
 impl<T: Config> Module<T> {
+    /// Sets the referrer of the account on behalf of a trusted caller, e.g. a migration, when the accounts may
+    /// not exist yet.
     pub fn set_referrer_to(
         referral: &T::AccountId,
         referrer: T::AccountId,
@@ -52,9 +137,106 @@ impl<T: Config> Module<T> {
                 .map_err(|_| Error::<T>::IncRefError)?;
             frame_system::Pallet::<T>::inc_consumers(&referrer)
                 .map_err(|_| Error::<T>::IncRefError)?;
-            *r = Some(referrer);
-            Ok(())
-        })
+            *r = Some(referrer.clone());
+            Ok::<(), DispatchError>(())
+        })?;
+        Self::add_referral(&referrer, referral);
+        Self::deposit_event(Event::ReferrerSet(referral.clone(), referrer));
+        Ok(())
+    }
+
+    /// Sets the referrer of the account on its own request. Reference counters of the referrer are left intact,
+    /// so that the account can't affect the lifetime of the referrer account.
+    fn set_own_referrer(who: &T::AccountId, referrer: T::AccountId) -> DispatchResult {
+        frame_system::Pallet::<T>::inc_consumers(who).map_err(|_| Error::<T>::IncRefError)?;
+        Referrers::<T>::insert(who, referrer.clone());
+        Self::add_referral(&referrer, who);
+        Self::deposit_event(Event::ReferrerSet(who.clone(), referrer));
+        Ok(())
+    }
+
+    /// Records the block of the first transaction of the account, which starts the period the account can set
+    /// its referrer within.
+    pub fn note_transaction(who: &T::AccountId) {
+        if FirstTransactionBlocks::<T>::contains_key(who) {
+            return;
+        }
+        // The nonce is already incremented for the transaction being processed
+        if frame_system::Module::<T>::account_nonce(who) <= One::one() {
+            FirstTransactionBlocks::<T>::insert(who, frame_system::Module::<T>::block_number());
+        }
+    }
+
+    /// Accounts the transaction fee share paid to the referrer.
+    pub fn note_referrer_reward(referrer: &T::AccountId, amount: Balance) {
+        ReferrerStatistics::<T>::mutate(referrer, |stats| {
+            stats.earned_fees = stats.earned_fees.saturating_add(amount)
+        });
+    }
+
+    /// Splits `amount` rewarded for the transactions of `referral` between its referrers according to
+    /// `RewardTierShares`. The part that isn't returned has no one to be paid to.
+    pub fn referrer_rewards(
+        referral: &T::AccountId,
+        amount: Balance,
+    ) -> Vec<(T::AccountId, Balance)> {
+        let shares = Self::reward_tier_shares();
+        let mut rewards = Vec::new();
+        if let Some(first_tier) = Self::referrer_account(referral) {
+            let second_tier = Self::referrer_account(&first_tier);
+            rewards.push((first_tier, shares.first_tier.mul_floor(amount)));
+            if let Some(second_tier) = second_tier {
+                rewards.push((second_tier, shares.second_tier.mul_floor(amount)));
+            }
+        }
+        rewards.retain(|(_, reward)| !reward.is_zero());
+        rewards
+    }
+
+    fn add_referral(referrer: &T::AccountId, referral: &T::AccountId) {
+        Referrals::<T>::insert(referrer, referral, ());
+        ReferrerStatistics::<T>::mutate(referrer, |stats| {
+            stats.referrals_count = stats.referrals_count.saturating_add(1)
+        });
+    }
+
+    /// Checks that `who` can set its referrer. Returns the length of the referrer chain that has been checked
+    /// for cycles.
+    fn ensure_can_set_referrer(
+        who: &T::AccountId,
+        referrer: &T::AccountId,
+    ) -> Result<u32, DispatchError> {
+        ensure!(who != referrer, Error::<T>::SelfReferral);
+        ensure!(
+            !Referrers::<T>::contains_key(who),
+            Error::<T>::AlreadyHasReferrer
+        );
+        ensure!(
+            frame_system::Pallet::<T>::account_exists(referrer),
+            Error::<T>::ReferrerNotFound
+        );
+
+        let period_is_open = match FirstTransactionBlocks::<T>::get(who) {
+            Some(block) => {
+                frame_system::Module::<T>::block_number()
+                    <= block.saturating_add(T::ReferrerSetGracePeriod::get())
+            }
+            None => frame_system::Module::<T>::account_nonce(who) <= One::one(),
+        };
+        ensure!(period_is_open, Error::<T>::ReferrerSetPeriodExpired);
+
+        // The account has no referrer, so it can only be the root of the referrer chain
+        let mut current = referrer.clone();
+        for length in 1..=MAX_REFERRAL_CHAIN_LENGTH {
+            match Referrers::<T>::get(&current) {
+                Some(next) => {
+                    ensure!(&next != who, Error::<T>::ReferralCycle);
+                    current = next;
+                }
+                None => return Ok(length),
+            }
+        }
+        Err(Error::<T>::ReferralChainTooLong.into())
     }
 }
 
@@ -62,21 +244,106 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
+    use super::*;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
 
     #[pallet::config]
-    pub trait Config: frame_system::Config {}
+    pub trait Config: frame_system::Config {
+        type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+        /// The number of blocks after the first transaction of the account it can set its referrer within
+        type ReferrerSetGracePeriod: Get<Self::BlockNumber>;
+        /// Currency swap fee kickbacks are paid in
+        type Currency: Currency<Self::AccountId, Balance = Balance>;
+        /// Account holding the reserves swap fee kickbacks are paid from
+        type ReferralRewardsAccountId: Get<Self::AccountId>;
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            migration::migrate::<T>()
+        }
+    }
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {}
+    impl<T: Config> Pallet<T> {
+        /// Set the referrer of the signer.
+        ///
+        /// The referrer can be set only once, before the first transaction of the signer or within
+        /// `ReferrerSetGracePeriod` blocks after it. The referrer must exist and must not make a cycle
+        /// of referrers.
+        #[pallet::weight(<T as Config>::WeightInfo::set_referrer(MAX_REFERRAL_CHAIN_LENGTH))]
+        pub fn set_referrer(
+            origin: OriginFor<T>,
+            referrer: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let chain_length = Self::ensure_can_set_referrer(&who, &referrer)?;
+            Self::set_own_referrer(&who, referrer)?;
+            Ok(Some(<T as Config>::WeightInfo::set_referrer(chain_length)).into())
+        }
+
+        /// Set the shares of the referrer rewards paid to each tier of referrers and the share of
+        /// swap fees paid back to the referrers of the swapping account.
+        ///
+        /// Can only be called by root.
+        #[pallet::weight(<T as Config>::WeightInfo::set_reward_shares())]
+        pub fn set_reward_shares(
+            origin: OriginFor<T>,
+            tier_shares: TierShares,
+            swap_fee_kickback_share: Percent,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                tier_shares.first_tier.deconstruct() as u16
+                    + tier_shares.second_tier.deconstruct() as u16
+                    <= Percent::one().deconstruct() as u16,
+                Error::<T>::InvalidTierShares
+            );
+            RewardTierShares::<T>::put(tier_shares);
+            SwapFeeKickbackShare::<T>::put(swap_fee_kickback_share);
+            Self::deposit_event(Event::RewardSharesChanged(
+                tier_shares,
+                swap_fee_kickback_share,
+            ));
+            Ok(().into())
+        }
+
+        /// Transfer `amount` from the signer to the referral rewards reserves swap fee kickbacks are paid from.
+        #[pallet::weight(<T as Config>::WeightInfo::fund_reserves())]
+        pub fn fund_reserves(origin: OriginFor<T>, amount: Balance) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            T::Currency::transfer(
+                &who,
+                &T::ReferralRewardsAccountId::get(),
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            Self::deposit_event(Event::ReservesFunded(who, amount));
+            Ok(().into())
+        }
+    }
+
+    #[pallet::event]
+    #[pallet::metadata(T::AccountId = "AccountId")]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Referrer of the account was set. [Referral, Referrer]
+        ReferrerSet(T::AccountId, T::AccountId),
+        /// Shares of the referrer rewards were changed. [Tier Shares, Swap Fee Kickback Share]
+        RewardSharesChanged(TierShares, Percent),
+        /// Swap fee kickback wasn't paid because the referral rewards reserves can't cover it. [Referrer, Amount]
+        SwapFeeKickbackSkipped(T::AccountId, Balance),
+        /// Referral rewards reserves were funded. [Funder, Amount]
+        ReservesFunded(T::AccountId, Balance),
+    }
 
     #[pallet::error]
     pub enum Error<T> {
@@ -84,12 +351,58 @@ pub mod pallet {
         AlreadyHasReferrer,
         /// Increment account reference error.
         IncRefError,
+        /// Account can't be the referrer of itself.
+        SelfReferral,
+        /// The referrer is referred by the account directly or through other referrers.
+        ReferralCycle,
+        /// The chain of referrers of the referrer is too long to be checked for cycles.
+        ReferralChainTooLong,
+        /// The period the account can set its referrer within has expired.
+        ReferrerSetPeriodExpired,
+        /// The sum of the tier shares exceeds 100%.
+        InvalidTierShares,
+        /// The referrer account doesn't exist.
+        ReferrerNotFound,
     }
 
     #[pallet::storage]
     #[pallet::getter(fn referrer_account)]
     pub type Referrers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+    /// Accounts referred by the referrer. Referrer => Referral => ()
+    #[pallet::storage]
+    pub type Referrals<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        ValueQuery,
+    >;
+
+    /// Statistics of referrers. Referrer => Statistics
+    #[pallet::storage]
+    #[pallet::getter(fn referrer_stats)]
+    pub type ReferrerStatistics<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ReferrerStats, ValueQuery>;
+
+    /// The block of the first transaction of the account. Account => Block
+    #[pallet::storage]
+    #[pallet::getter(fn first_transaction_block)]
+    pub type FirstTransactionBlocks<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber>;
+
+    /// Shares of the referrer rewards paid to each tier of referrers
+    #[pallet::storage]
+    #[pallet::getter(fn reward_tier_shares)]
+    pub type RewardTierShares<T: Config> = StorageValue<_, TierShares, ValueQuery>;
+
+    /// Share of the swap fees paid back to the referrers of the swapping account
+    #[pallet::storage]
+    #[pallet::getter(fn swap_fee_kickback_share)]
+    pub type SwapFeeKickbackShare<T: Config> = StorageValue<_, Percent, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub referrers: Vec<(T::AccountId, T::AccountId)>,
@@ -111,6 +424,7 @@ pub mod pallet {
                 frame_system::Pallet::<T>::inc_consumers(k).unwrap();
                 frame_system::Pallet::<T>::inc_consumers(v).unwrap();
                 Referrers::<T>::insert(k, v);
+                Pallet::<T>::add_referral(v, k);
             });
         }
     }
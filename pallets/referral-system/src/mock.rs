@@ -29,6 +29,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use common::Balance;
+use frame_support::traits::Get;
 use frame_support::{construct_runtime, parameter_types};
 use sp_core::H256;
 use sp_runtime::testing::Header;
@@ -53,9 +54,17 @@ pub fn charlie() -> AccountId {
     AccountId32::from([3; 32])
 }
 
+pub fn dave() -> AccountId {
+    AccountId32::from([4; 32])
+}
+
+pub const REFERRAL_REWARDS: Balance = 1_000;
+
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const ExistentialDeposit: u128 = 0;
+    pub const ReferrerSetGracePeriod: u64 = 10;
+    pub ReferralRewardsAccountId: AccountId = AccountId32::from([10; 32]);
 }
 
 construct_runtime! {
@@ -65,8 +74,8 @@ construct_runtime! {
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
-        Balances: pallet_balances::{Module, Call, Storage, Event<T>},
-        ReferralSystem: referral_system::{Module, Call, Storage},
+        Balances: pallet_balances::{Module, Call, Storage, Config<T>, Event<T>},
+        ReferralSystem: referral_system::{Module, Call, Storage, Event<T>},
     }
 }
 
@@ -105,7 +114,13 @@ impl pallet_balances::Config for Runtime {
     type MaxLocks = ();
 }
 
-impl Config for Runtime {}
+impl Config for Runtime {
+    type Event = Event;
+    type ReferrerSetGracePeriod = ReferrerSetGracePeriod;
+    type Currency = Balances;
+    type ReferralRewardsAccountId = ReferralRewardsAccountId;
+    type WeightInfo = ();
+}
 
 pub struct ExtBuilder;
 
@@ -117,9 +132,22 @@ impl Default for ExtBuilder {
 
 impl ExtBuilder {
     pub fn build(self) -> sp_io::TestExternalities {
-        let t = frame_system::GenesisConfig::default()
+        let mut t = frame_system::GenesisConfig::default()
             .build_storage::<Runtime>()
             .unwrap();
-        t.into()
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: vec![(ReferralRewardsAccountId::get(), REFERRAL_REWARDS)],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        let mut ext: sp_io::TestExternalities = t.into();
+        ext.execute_with(|| {
+            for account in [alice(), bob(), charlie(), dave()].iter() {
+                frame_system::Module::<Runtime>::inc_providers(account);
+            }
+        });
+        ext
     }
 }
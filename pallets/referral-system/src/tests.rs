@@ -28,10 +28,16 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use frame_support::{assert_err, assert_ok};
+use common::OnSwapFeePaid;
+use frame_support::sp_runtime::{DispatchError, Percent};
+use frame_support::traits::{Currency, Get};
+use frame_support::{assert_err, assert_noop, assert_ok};
 
-use crate::mock::{alice, bob, charlie, ExtBuilder, Runtime};
-use crate::{Error, Module};
+use crate::mock::{
+    alice, bob, charlie, dave, AccountId, Event, ExtBuilder, Origin, ReferralRewardsAccountId,
+    Runtime, REFERRAL_REWARDS,
+};
+use crate::{Error, Module, Referrals, ReferrerStats, TierShares};
 
 type E = Error<Runtime>;
 type M = Module<Runtime>;
@@ -53,3 +59,240 @@ fn set_referrer_to_has_referrer() {
         );
     });
 }
+
+#[test]
+fn set_referrer() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(M::set_referrer(Origin::signed(alice()), bob()));
+        assert_ok!(M::set_referrer(Origin::signed(charlie()), bob()));
+        assert_eq!(M::referrer_account(alice()), Some(bob()));
+        assert_eq!(
+            M::referrer_stats(bob()),
+            ReferrerStats {
+                referrals_count: 2,
+                earned_fees: 0,
+                earned_swap_fees: 0,
+            }
+        );
+        let mut referrals: Vec<_> = Referrals::<Runtime>::iter_prefix(bob())
+            .map(|(referral, _)| referral)
+            .collect();
+        referrals.sort();
+        assert_eq!(referrals, vec![alice(), charlie()]);
+
+        assert_noop!(
+            M::set_referrer(Origin::signed(alice()), charlie()),
+            E::AlreadyHasReferrer
+        );
+    });
+}
+
+#[test]
+fn set_referrer_keeps_referrer_refcounts() {
+    ExtBuilder::default().build().execute_with(|| {
+        let unknown = AccountId::from([9; 32]);
+        assert_noop!(
+            M::set_referrer(Origin::signed(alice()), unknown),
+            E::ReferrerNotFound
+        );
+
+        let providers = frame_system::Module::<Runtime>::providers(&bob());
+        let consumers = frame_system::Module::<Runtime>::consumers(&bob());
+        assert_ok!(M::set_referrer(Origin::signed(alice()), bob()));
+        assert_eq!(
+            frame_system::Module::<Runtime>::providers(&bob()),
+            providers
+        );
+        assert_eq!(
+            frame_system::Module::<Runtime>::consumers(&bob()),
+            consumers
+        );
+        assert_eq!(frame_system::Module::<Runtime>::providers(&alice()), 1);
+        assert_eq!(frame_system::Module::<Runtime>::consumers(&alice()), 1);
+    });
+}
+
+#[test]
+fn set_referrer_rejects_self_referral_and_cycles() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            M::set_referrer(Origin::signed(alice()), alice()),
+            E::SelfReferral
+        );
+        assert_ok!(M::set_referrer(Origin::signed(alice()), bob()));
+        assert_ok!(M::set_referrer(Origin::signed(bob()), charlie()));
+        assert_noop!(
+            M::set_referrer(Origin::signed(charlie()), alice()),
+            E::ReferralCycle
+        );
+    });
+}
+
+#[test]
+fn set_referrer_within_grace_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Module::<Runtime>::set_block_number(1);
+        frame_system::Module::<Runtime>::inc_account_nonce(&alice());
+        M::note_transaction(&alice());
+        frame_system::Module::<Runtime>::inc_account_nonce(&alice());
+        M::note_transaction(&alice());
+        assert_eq!(M::first_transaction_block(alice()), Some(1));
+
+        frame_system::Module::<Runtime>::set_block_number(11);
+        assert_ok!(M::set_referrer(Origin::signed(alice()), bob()));
+
+        frame_system::Module::<Runtime>::set_block_number(12);
+        frame_system::Module::<Runtime>::inc_account_nonce(&charlie());
+        M::note_transaction(&charlie());
+        frame_system::Module::<Runtime>::set_block_number(23);
+        assert_noop!(
+            M::set_referrer(Origin::signed(charlie()), bob()),
+            E::ReferrerSetPeriodExpired
+        );
+    });
+}
+
+#[test]
+fn set_referrer_after_transactions_without_record() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Module::<Runtime>::inc_account_nonce(&alice());
+        frame_system::Module::<Runtime>::inc_account_nonce(&alice());
+        assert_noop!(
+            M::set_referrer(Origin::signed(alice()), bob()),
+            E::ReferrerSetPeriodExpired
+        );
+    });
+}
+
+fn tier_shares(first_tier: u8, second_tier: u8) -> TierShares {
+    TierShares {
+        first_tier: Percent::from_percent(first_tier),
+        second_tier: Percent::from_percent(second_tier),
+    }
+}
+
+#[test]
+fn set_reward_shares() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(M::reward_tier_shares(), tier_shares(100, 0));
+        assert_eq!(M::swap_fee_kickback_share(), Percent::zero());
+
+        assert_noop!(
+            M::set_reward_shares(
+                Origin::signed(alice()),
+                tier_shares(80, 20),
+                Percent::from_percent(10)
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            M::set_reward_shares(
+                Origin::root(),
+                tier_shares(90, 20),
+                Percent::from_percent(10)
+            ),
+            E::InvalidTierShares
+        );
+        assert_ok!(M::set_reward_shares(
+            Origin::root(),
+            tier_shares(80, 20),
+            Percent::from_percent(10)
+        ));
+        assert_eq!(M::reward_tier_shares(), tier_shares(80, 20));
+        assert_eq!(M::swap_fee_kickback_share(), Percent::from_percent(10));
+    });
+}
+
+#[test]
+fn referrer_rewards_are_split_between_tiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(M::set_referrer(Origin::signed(alice()), bob()));
+        assert_ok!(M::set_referrer(Origin::signed(bob()), charlie()));
+
+        assert_eq!(M::referrer_rewards(&alice(), 100), vec![(bob(), 100)]);
+        assert_eq!(M::referrer_rewards(&charlie(), 100), vec![]);
+
+        assert_ok!(M::set_reward_shares(
+            Origin::root(),
+            tier_shares(60, 20),
+            Percent::zero()
+        ));
+        assert_eq!(
+            M::referrer_rewards(&alice(), 100),
+            vec![(bob(), 60), (charlie(), 20)]
+        );
+        assert_eq!(M::referrer_rewards(&bob(), 100), vec![(charlie(), 60)]);
+    });
+}
+
+#[test]
+fn swap_fee_kickbacks_are_paid_from_reserves() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(M::set_referrer(Origin::signed(alice()), bob()));
+        assert_ok!(M::set_referrer(Origin::signed(bob()), charlie()));
+
+        // No kickbacks by default
+        M::on_swap_fee_paid(&alice(), 1_000);
+        assert_eq!(pallet_balances::Module::<Runtime>::free_balance(bob()), 0);
+
+        assert_ok!(M::set_reward_shares(
+            Origin::root(),
+            tier_shares(60, 20),
+            Percent::from_percent(10)
+        ));
+        M::on_swap_fee_paid(&alice(), 1_000);
+        M::on_swap_fee_paid(&dave(), 1_000);
+        assert_eq!(pallet_balances::Module::<Runtime>::free_balance(bob()), 60);
+        assert_eq!(
+            pallet_balances::Module::<Runtime>::free_balance(charlie()),
+            20
+        );
+        assert_eq!(
+            pallet_balances::Module::<Runtime>::free_balance(ReferralRewardsAccountId::get()),
+            REFERRAL_REWARDS - 80
+        );
+        assert_eq!(M::referrer_stats(bob()).earned_swap_fees, 60);
+        assert_eq!(M::referrer_stats(charlie()).earned_swap_fees, 20);
+        assert_eq!(M::referrer_stats(bob()).earned_fees, 0);
+
+        // The reserves can't cover the kickbacks
+        frame_system::Module::<Runtime>::set_block_number(1);
+        M::on_swap_fee_paid(&alice(), 100_000);
+        assert_eq!(pallet_balances::Module::<Runtime>::free_balance(bob()), 60);
+        assert_eq!(M::referrer_stats(bob()).earned_swap_fees, 60);
+        assert!(frame_system::Module::<Runtime>::events()
+            .iter()
+            .any(|record| {
+                record.event
+                    == Event::referral_system(crate::Event::SwapFeeKickbackSkipped(bob(), 6_000))
+            }));
+    });
+}
+
+#[test]
+fn reserves_are_funded_explicitly() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Module::<Runtime>::set_block_number(1);
+        let _ = pallet_balances::Module::<Runtime>::deposit_creating(&alice(), 1_000);
+        assert_noop!(
+            M::fund_reserves(Origin::signed(bob()), 100),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+        assert_ok!(M::fund_reserves(Origin::signed(alice()), 100));
+        assert_eq!(
+            pallet_balances::Module::<Runtime>::free_balance(alice()),
+            900
+        );
+        assert_eq!(
+            pallet_balances::Module::<Runtime>::free_balance(ReferralRewardsAccountId::get()),
+            REFERRAL_REWARDS + 100
+        );
+        assert_eq!(
+            frame_system::Module::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            Event::referral_system(crate::Event::ReservesFunded(alice(), 100))
+        );
+    });
+}
@@ -41,16 +41,23 @@ use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
 use std::sync::Arc;
 
 // Runtime API imports.
-pub use rewards_runtime_api::{BalanceInfo, RewardsAPI as RewardsRuntimeAPI};
+pub use rewards_runtime_api::{BalanceInfo, ClaimablesInfo, RewardsAPI as RewardsRuntimeAPI};
 
 #[rpc]
-pub trait RewardsAPI<BlockHash, EthereumAddress, VecBalanceInfo> {
+pub trait RewardsAPI<BlockHash, EthereumAddress, VecBalanceInfo, ClaimablesInfo> {
     #[rpc(name = "rewards_claimables")]
     fn claimables(
         &self,
         eth_address: EthereumAddress,
         at: Option<BlockHash>,
     ) -> Result<VecBalanceInfo>;
+
+    #[rpc(name = "rewards_claimablesInfo")]
+    fn claimables_info(
+        &self,
+        eth_address: EthereumAddress,
+        at: Option<BlockHash>,
+    ) -> Result<ClaimablesInfo>;
 }
 
 pub struct RewardsClient<C, B> {
@@ -69,8 +76,12 @@ impl<C, B> RewardsClient<C, B> {
 }
 
 impl<C, Block, EthereumAddress, Balance>
-    RewardsAPI<<Block as BlockT>::Hash, EthereumAddress, Vec<BalanceInfo<Balance>>>
-    for RewardsClient<C, Block>
+    RewardsAPI<
+        <Block as BlockT>::Hash,
+        EthereumAddress,
+        Vec<BalanceInfo<Balance>>,
+        ClaimablesInfo<Balance>,
+    > for RewardsClient<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static,
@@ -95,4 +106,21 @@ where
             data: Some(format!("{:?}", e).into()),
         })
     }
+
+    fn claimables_info(
+        &self,
+        eth_address: EthereumAddress,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<ClaimablesInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.claimables_info(&at, eth_address).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get claimables info.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
 }
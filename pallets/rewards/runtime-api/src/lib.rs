@@ -36,6 +36,7 @@ use common::utils::string_serialization;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_runtime::Perbill;
 use sp_std::prelude::*;
 
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
@@ -55,11 +56,69 @@ pub struct BalanceInfo<Balance> {
     pub balance: Balance,
 }
 
+#[derive(Eq, PartialEq, Encode, Decode, Default)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ClaimablesInfo<Balance> {
+    /// VAL that is vested and can be claimed now.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub val_vested: Balance,
+    /// VAL left to be claimed, including the amount that isn't vested yet.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub val_total: Balance,
+    /// PSWAP farming rewards.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub pswap_farm: Balance,
+    /// PSWAP NFT waifu rewards.
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            bound(
+                serialize = "Balance: std::fmt::Display",
+                deserialize = "Balance: std::str::FromStr"
+            ),
+            with = "string_serialization"
+        )
+    )]
+    pub pswap_waifu: Balance,
+    /// Share of the burned VAL which is currently vested as rewards.
+    pub vesting_ratio: Perbill,
+}
+
 sp_api::decl_runtime_apis! {
     pub trait RewardsAPI<EthereumAddress, Balance> where
         EthereumAddress: Codec,
         Balance: Codec + MaybeFromStr + MaybeDisplay
     {
         fn claimables(eth_address: EthereumAddress) -> Vec<BalanceInfo<Balance>>;
+
+        fn claimables_info(eth_address: EthereumAddress) -> ClaimablesInfo<Balance>;
     }
 }
@@ -38,7 +38,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::codec::{Decode, Encode};
-use frame_support::dispatch::{DispatchErrorWithPostInfo, Weight};
+use frame_support::dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, Weight};
 use frame_support::storage::StorageMap as StorageMapTrait;
 use frame_support::RuntimeDebug;
 #[cfg(feature = "std")]
@@ -52,7 +52,7 @@ use assets::AssetIdOf;
 use common::prelude::FixedWrapper;
 #[cfg(feature = "include-real-files")]
 use common::vec_push;
-use common::{balance, eth, AccountIdOf, Balance, OnValBurned};
+use common::{balance, eth, AccountIdOf, Balance, OnValBurned, PSWAP, VAL};
 
 #[cfg(feature = "include-real-files")]
 use hex_literal::hex;
@@ -76,8 +76,10 @@ type WeightInfoOf<T> = <T as Config>::WeightInfo;
 #[derive(Encode, Decode, Clone, RuntimeDebug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct RewardInfo {
-    claimable: Balance,
-    total: Balance,
+    /// Amount that can be claimed now
+    pub claimable: Balance,
+    /// Amount left to be claimed, including the amount that isn't vested yet
+    pub total: Balance,
 }
 
 impl RewardInfo {
@@ -115,6 +117,19 @@ impl<T: Config> Pallet<T> {
         ]
     }
 
+    /// Returns the VAL, farming PSWAP and waifu PSWAP rewards of the address along with the
+    /// current VAL vesting ratio.
+    pub fn claimables_info(
+        eth_address: &EthereumAddress,
+    ) -> (RewardInfo, Balance, Balance, Perbill) {
+        (
+            ValOwners::<T>::get(eth_address),
+            PswapFarmOwners::<T>::get(eth_address),
+            PswapWaifuOwners::<T>::get(eth_address),
+            Self::current_vesting_ratio(frame_system::Pallet::<T>::block_number()),
+        )
+    }
+
     fn current_vesting_ratio(elapsed: T::BlockNumber) -> Perbill {
         let max_percentage = T::MAX_VESTING_RATIO.deconstruct() as u32;
         if elapsed >= T::TIME_TO_SATURATION {
@@ -129,6 +144,54 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Pays the rewards of the Ethereum address, which signed the encoded `account_id`, to
+    /// `account_id`.
+    fn claim_rewards_to(
+        account_id: AccountIdOf<T>,
+        signature: Vec<u8>,
+    ) -> DispatchResultWithPostInfo {
+        let eth_address =
+            eth::recover_signer(&account_id.encode(), &signature).map_err(|error| match error {
+                eth::SignatureError::Invalid => Error::<T>::SignatureInvalid,
+                eth::SignatureError::VerificationFailed => Error::<T>::SignatureVerificationFailed,
+            })?;
+        let reserves_acc = ReservesAcc::<T>::get();
+        let mut claimed = false;
+        let mut is_eligible = false;
+        Self::claim_val_reward(
+            &eth_address,
+            &account_id,
+            &VAL.into(),
+            &reserves_acc,
+            &mut claimed,
+            &mut is_eligible,
+        )?;
+        Self::claim_reward::<PswapFarmOwners<T>>(
+            &eth_address,
+            &account_id,
+            &PSWAP.into(),
+            &reserves_acc,
+            &mut claimed,
+            &mut is_eligible,
+        )?;
+        Self::claim_reward::<PswapWaifuOwners<T>>(
+            &eth_address,
+            &account_id,
+            &PSWAP.into(),
+            &reserves_acc,
+            &mut claimed,
+            &mut is_eligible,
+        )?;
+        if claimed {
+            Self::deposit_event(Event::<T>::Claimed(account_id));
+            Ok(().into())
+        } else if is_eligible {
+            Err(Error::<T>::NothingToClaim.into())
+        } else {
+            Err(Error::<T>::AddressNotEligible.into())
+        }
+    }
+
     fn claim_reward<M: StorageMapTrait<EthereumAddress, Balance>>(
         eth_address: &EthereumAddress,
         account_id: &AccountIdOf<T>,
@@ -190,12 +253,8 @@ pub mod pallet {
     use frame_support::traits::PalletVersion;
     use frame_support::transactional;
     use frame_system::pallet_prelude::*;
-    use secp256k1::util::SIGNATURE_SIZE;
-    use secp256k1::{RecoveryId, Signature};
     use sp_std::vec::Vec;
 
-    use common::{PSWAP, VAL};
-
     use super::*;
 
     #[pallet::config]
@@ -294,58 +353,22 @@ pub mod pallet {
         #[transactional]
         pub fn claim(origin: OriginFor<T>, signature: Vec<u8>) -> DispatchResultWithPostInfo {
             let account_id = ensure_signed(origin)?;
-            ensure!(
-                signature.len() == SIGNATURE_SIZE + 1,
-                Error::<T>::SignatureInvalid
-            );
-            let recovery_id = if signature[SIGNATURE_SIZE] >= 27 {
-                signature[SIGNATURE_SIZE] - 27
-            } else {
-                signature[SIGNATURE_SIZE]
-            };
-            let recovery_id = RecoveryId::parse(recovery_id)
-                .map_err(|_| Error::<T>::SignatureVerificationFailed)?;
-            let signature = Signature::parse_slice(&signature[..SIGNATURE_SIZE])
-                .map_err(|_| Error::<T>::SignatureInvalid)?;
-            let message = eth::prepare_message(&account_id.encode());
-            let public_key = secp256k1::recover(&message, &signature, &recovery_id)
-                .map_err(|_| Error::<T>::SignatureVerificationFailed)?;
-            let eth_address = eth::public_key_to_eth_address(&public_key);
-            let reserves_acc = ReservesAcc::<T>::get();
-            let mut claimed = false;
-            let mut is_eligible = false;
-            Self::claim_val_reward(
-                &eth_address,
-                &account_id,
-                &VAL.into(),
-                &reserves_acc,
-                &mut claimed,
-                &mut is_eligible,
-            )?;
-            Self::claim_reward::<PswapFarmOwners<T>>(
-                &eth_address,
-                &account_id,
-                &PSWAP.into(),
-                &reserves_acc,
-                &mut claimed,
-                &mut is_eligible,
-            )?;
-            Self::claim_reward::<PswapWaifuOwners<T>>(
-                &eth_address,
-                &account_id,
-                &PSWAP.into(),
-                &reserves_acc,
-                &mut claimed,
-                &mut is_eligible,
-            )?;
-            if claimed {
-                Self::deposit_event(Event::<T>::Claimed(account_id));
-                Ok(().into())
-            } else if is_eligible {
-                Err(Error::<T>::NothingToClaim.into())
-            } else {
-                Err(Error::<T>::AddressNotEligible.into())
-            }
+            Self::claim_rewards_to(account_id, signature)
+        }
+
+        /// Claims the rewards of the Ethereum address to `destination`.
+        ///
+        /// `signature` is the Ethereum signature of the encoded `destination`, so the call can be
+        /// submitted by any account, e.g. a relayer paying the fee on behalf of the destination.
+        #[pallet::weight(WeightInfoOf::<T>::claim())]
+        #[transactional]
+        pub fn claim_to(
+            origin: OriginFor<T>,
+            destination: AccountIdOf<T>,
+            signature: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+            Self::claim_rewards_to(destination, signature)
         }
 
         /// Finalize the update of unclaimed VAL data in storage
@@ -147,6 +147,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = ();
+    type WeightInfo = ();
 }
 
 impl assets::Config for Runtime {
@@ -169,6 +170,7 @@ impl common::Config for Runtime {
 // Required by assets::Config
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 // Required by assets::Config
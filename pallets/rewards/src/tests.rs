@@ -37,6 +37,7 @@ use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use sp_io::TestExternalities;
+use sp_runtime::Perbill;
 
 use crate::mock::*;
 use crate::{EthereumAddress, PswapFarmOwners, ReservesAcc, RewardInfo};
@@ -136,6 +137,64 @@ fn claim_over_limit() {
     });
 }
 
+#[test]
+fn claim_to_succeeds_when_submitted_by_relayer() {
+    ExtBuilder::with_rewards(true).build().execute_with(|| {
+        // The signature names `account()` as the destination
+        let signature = hex!("eb7009c977888910a96d499f802e4524a939702aa6fc8ed473829bffce9289d850b97a720aa05d4a7e70e15733eeebc4fe862dcb60e018c0bf560b2de013078f1c").into();
+        assert_ok!(Pallet::claim_to(
+            Origin::signed(alice()),
+            account(),
+            signature
+        ));
+        assert_eq!(
+            Assets::free_balance(&VAL, &account()).unwrap(),
+            balance!(111)
+        );
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &account()).unwrap(),
+            balance!(555)
+        );
+        assert_eq!(Assets::free_balance(&VAL, &alice()).unwrap(), 0);
+        assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), 0);
+    });
+}
+
+#[test]
+fn claim_to_fails_for_another_destination() {
+    ExtBuilder::with_rewards(true).build().execute_with(|| {
+        // The signature names `account()`, so another destination recovers another address
+        let signature = hex!("eb7009c977888910a96d499f802e4524a939702aa6fc8ed473829bffce9289d850b97a720aa05d4a7e70e15733eeebc4fe862dcb60e018c0bf560b2de013078f1c").into();
+        assert_noop!(
+            Pallet::claim_to(Origin::signed(account()), alice(), signature),
+            Error::AddressNotEligible
+        );
+    });
+}
+
+#[test]
+fn claimables_info_works() {
+    ExtBuilder::with_rewards(true).build().execute_with(|| {
+        let eth_address = EthereumAddress::from(hex!("21Bc9f4a3d9Dc86f142F802668dB7D908cF0A636"));
+        let (val, pswap_farm, pswap_waifu, vesting_ratio) = Pallet::claimables_info(&eth_address);
+        assert_eq!(val, RewardInfo::new(balance!(111), balance!(1000)));
+        assert_eq!(pswap_farm, balance!(222));
+        assert_eq!(pswap_waifu, balance!(333));
+        assert_eq!(vesting_ratio, Perbill::from_percent(0));
+
+        frame_system::Pallet::<Runtime>::set_block_number(50);
+        let (_, _, _, vesting_ratio) = Pallet::claimables_info(&eth_address);
+        assert_eq!(
+            vesting_ratio,
+            Perbill::from_rational_approximation(55u32, 200u32)
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(100);
+        let (_, _, _, vesting_ratio) = Pallet::claimables_info(&eth_address);
+        assert_eq!(vesting_ratio, Perbill::from_percent(55));
+    });
+}
+
 #[test]
 fn storage_migration_to_v1_2_0_works() {
     ExtBuilder::with_rewards(true).build().execute_with(|| {
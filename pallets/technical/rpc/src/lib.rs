@@ -0,0 +1,156 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+
+use common::InvokeRPCError;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::Block as BlockT;
+
+use std::sync::Arc;
+
+// Runtime API imports.
+use technical_runtime_api::TechAccountInfo;
+pub use technical_runtime_api::TechnicalAPI as TechnicalRuntimeAPI;
+
+#[rpc]
+pub trait TechnicalAPI<BlockHash, AccountId, TechAccountId, TechAccountInfoVec> {
+    #[rpc(name = "technical_lookupTechAccountId")]
+    fn lookup_tech_account_id(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<TechAccountId>>;
+
+    #[rpc(name = "technical_techAccountIdToAccountId")]
+    fn tech_account_id_to_account_id(
+        &self,
+        tech_account_id: TechAccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Option<AccountId>>;
+
+    #[rpc(name = "technical_techAccounts")]
+    fn tech_accounts(
+        &self,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> Result<TechAccountInfoVec>;
+}
+
+pub struct TechnicalClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> TechnicalClient<C, B> {
+    /// Construct default `TechnicalClient`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, TechAccountId>
+    TechnicalAPI<
+        <Block as BlockT>::Hash,
+        AccountId,
+        TechAccountId,
+        Vec<TechAccountInfo<AccountId, TechAccountId>>,
+    > for TechnicalClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: TechnicalRuntimeAPI<Block, AccountId, TechAccountId>,
+    AccountId: Codec,
+    TechAccountId: Codec,
+{
+    fn lookup_tech_account_id(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<TechAccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.lookup_tech_account_id(&at, account_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to lookup technical account id.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn tech_account_id_to_account_id(
+        &self,
+        tech_account_id: TechAccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.tech_account_id_to_account_id(&at, tech_account_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to derive account id from technical account id.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
+    fn tech_accounts(
+        &self,
+        start_after: Option<AccountId>,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<TechAccountInfo<AccountId, TechAccountId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.tech_accounts(&at, start_after, limit)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get technical accounts.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+}
@@ -0,0 +1,130 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Decode;
+use common::{balance, XOR};
+use frame_benchmarking::benchmarks;
+use frame_system::{EventRecord, RawOrigin};
+use hex_literal::hex;
+use sp_core::H256;
+use sp_std::prelude::*;
+
+use crate::htlc::MAX_PREIMAGE_LENGTH;
+use crate::{Config, Event, HashTimeLocks, Pallet};
+
+fn alice<T: Config>() -> T::AccountId {
+    let bytes = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+    T::AccountId::decode(&mut &bytes[..]).unwrap_or_default()
+}
+
+fn bob<T: Config>() -> T::AccountId {
+    let bytes = hex!("8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48");
+    T::AccountId::decode(&mut &bytes[..]).unwrap_or_default()
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Module::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
+fn preimage() -> Vec<u8> {
+    vec![1u8; MAX_PREIMAGE_LENGTH]
+}
+
+fn hash_lock() -> H256 {
+    H256(sp_io::hashing::sha2_256(&preimage()))
+}
+
+// Locks XOR of alice for bob until the block 100
+fn create_htlc<T: Config>() {
+    let caller = alice::<T>();
+    let asset_id: T::AssetId = XOR.into();
+    let owner = assets::Module::<T>::asset_owner(&asset_id).unwrap();
+    assets::Module::<T>::mint_to(&asset_id, &owner, &caller, balance!(100)).unwrap();
+    Pallet::<T>::create_htlc(
+        RawOrigin::Signed(caller).into(),
+        bob::<T>(),
+        asset_id,
+        balance!(100),
+        hash_lock(),
+        100u32.into(),
+    )
+    .unwrap();
+}
+
+benchmarks! {
+    create_htlc {
+        let caller = alice::<T>();
+        let asset_id: T::AssetId = XOR.into();
+        let owner = assets::Module::<T>::asset_owner(&asset_id).unwrap();
+        assets::Module::<T>::mint_to(&asset_id, &owner, &caller, balance!(100)).unwrap();
+    }: {
+        Pallet::<T>::create_htlc(
+            RawOrigin::Signed(caller.clone()).into(),
+            bob::<T>(),
+            asset_id,
+            balance!(100),
+            hash_lock(),
+            100u32.into(),
+        )?;
+    }
+    verify {
+        assert!(HashTimeLocks::<T>::contains_key(&caller, &hash_lock()));
+    }
+
+    claim_htlc {
+        let caller = bob::<T>();
+        create_htlc::<T>();
+    }: {
+        Pallet::<T>::claim_htlc(
+            RawOrigin::Signed(caller.clone()).into(),
+            alice::<T>(),
+            hash_lock(),
+            preimage(),
+        )?;
+    }
+    verify {
+        assert_last_event::<T>(Event::HtlcClaimed(hash_lock(), alice::<T>(), caller, preimage()).into())
+    }
+
+    refund_htlc {
+        let caller = alice::<T>();
+        create_htlc::<T>();
+        frame_system::Module::<T>::set_block_number(1000u32.into());
+    }: {
+        Pallet::<T>::refund_htlc(RawOrigin::Signed(caller.clone()).into(), caller.clone(), hash_lock())?;
+    }
+    verify {
+        assert_last_event::<T>(Event::HtlcRefunded(hash_lock(), caller).into())
+    }
+}
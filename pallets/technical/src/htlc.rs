@@ -0,0 +1,276 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Hash time locked contracts.
+//!
+//! A lock keeps an asset of the source account under the HTLC technical account. The locked
+//! amount can be claimed to the receiver account by anyone who knows the SHA-256 preimage of the
+//! hash lock until the lock expires, and refunded to the source account after that. The same hash
+//! lock can be used on another chain, which makes cross-chain atomic swaps possible.
+//!
+//! Locking, claiming and refunding are done with `HtlcSwapAction`, so they can be performed with
+//! `create_swap` as well as with the extrinsics of the pallet. A lock is identified by its source
+//! account and hash lock, so other accounts can't take the hash lock before the source.
+
+use codec::{Decode, Encode};
+use common::prelude::Balance;
+use common::{FromGenericPair, SwapAction, SwapRulesValidation};
+use frame_support::dispatch::DispatchResult;
+use frame_support::weights::Weight;
+use frame_support::{ensure, RuntimeDebug};
+use sp_core::H256;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::vec::Vec;
+
+use crate::{
+    AccountIdOf, Config, Error, Event, HashTimeLockOf, HashTimeLocks, Module, TechAccountIdOf,
+    WeightInfo,
+};
+
+pub const HTLC_TECH_ACCOUNT_PREFIX: &[u8] = b"htlc";
+pub const HTLC_TECH_ACCOUNT_MAIN: &[u8] = b"main";
+
+/// Max length of the secret unlocking a hash time lock.
+pub const MAX_PREIMAGE_LENGTH: usize = 32;
+
+/// Technical account holding the assets of all pending hash time locks.
+pub fn htlc_tech_account_id<T: Config>() -> T::TechAccountId {
+    T::TechAccountId::from_generic_pair(
+        HTLC_TECH_ACCOUNT_PREFIX.to_vec(),
+        HTLC_TECH_ACCOUNT_MAIN.to_vec(),
+    )
+}
+
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode)]
+pub struct HashTimeLock<AssetId, AccountId, BlockNumber> {
+    /// Account receiving the asset when the lock is claimed.
+    pub receiver_account: AccountId,
+    pub asset: AssetId,
+    pub amount: Balance,
+    /// Last block at which the lock can be claimed, it can only be refunded after that.
+    pub expires_at: BlockNumber,
+}
+
+/// Hash time lock operation. The whole operation is performed when the action is reserved, so
+/// it's claimed instantly and never needs to be cancelled.
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode)]
+pub enum HtlcSwapAction<AssetId, AccountId, BlockNumber> {
+    /// Locks `amount` of `asset` of the swap source for `receiver` under `hash_lock` for `timeout`
+    /// blocks.
+    Lock {
+        receiver: AccountId,
+        asset: AssetId,
+        amount: Balance,
+        hash_lock: H256,
+        timeout: BlockNumber,
+    },
+    /// Releases the asset locked by `source` under `hash_lock` to its receiver.
+    Claim {
+        source: AccountId,
+        hash_lock: H256,
+        preimage: Vec<u8>,
+    },
+    /// Returns the asset locked by `source` under `hash_lock` after the lock has expired.
+    Refund { source: AccountId, hash_lock: H256 },
+}
+
+pub type HtlcSwapActionOf<T> = HtlcSwapAction<
+    <T as assets::Config>::AssetId,
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+impl<T: Config> SwapRulesValidation<AccountIdOf<T>, TechAccountIdOf<T>, T> for HtlcSwapActionOf<T> {
+    fn is_abstract_checking(&self) -> bool {
+        false
+    }
+
+    fn prepare_and_validate(&mut self, source: Option<&AccountIdOf<T>>) -> DispatchResult {
+        let now = frame_system::Pallet::<T>::block_number();
+        match self {
+            HtlcSwapAction::Lock {
+                amount,
+                hash_lock,
+                timeout,
+                ..
+            } => {
+                ensure!(!amount.is_zero(), Error::<T>::ZeroHtlcAmount);
+                ensure!(
+                    now.saturating_add(*timeout) > now,
+                    Error::<T>::InvalidHtlcTimeout
+                );
+                if let Some(source) = source {
+                    ensure!(
+                        !HashTimeLocks::<T>::contains_key(source, &*hash_lock),
+                        Error::<T>::AlreadyExist
+                    );
+                }
+            }
+            HtlcSwapAction::Claim {
+                source,
+                hash_lock,
+                preimage,
+            } => {
+                let lock = HashTimeLocks::<T>::get(&*source, &*hash_lock)
+                    .ok_or(Error::<T>::HtlcNotFound)?;
+                ensure!(
+                    is_valid_preimage(hash_lock, preimage),
+                    Error::<T>::InvalidProof
+                );
+                ensure!(now <= lock.expires_at, Error::<T>::HtlcExpired);
+            }
+            HtlcSwapAction::Refund { source, hash_lock } => {
+                let lock = HashTimeLocks::<T>::get(&*source, &*hash_lock)
+                    .ok_or(Error::<T>::HtlcNotFound)?;
+                ensure!(now > lock.expires_at, Error::<T>::DurationNotPassed);
+            }
+        }
+        Ok(())
+    }
+
+    fn instant_auto_claim_used(&self) -> bool {
+        true
+    }
+
+    fn triggered_auto_claim_used(&self) -> bool {
+        false
+    }
+
+    fn is_able_to_claim(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Config> SwapAction<AccountIdOf<T>, TechAccountIdOf<T>, T> for HtlcSwapActionOf<T> {
+    fn reserve(&self, source: &AccountIdOf<T>) -> DispatchResult {
+        match self {
+            HtlcSwapAction::Lock {
+                receiver,
+                asset,
+                amount,
+                hash_lock,
+                timeout,
+            } => {
+                let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(*timeout);
+                let time_lock = HashTimeLockOf::<T> {
+                    receiver_account: receiver.clone(),
+                    asset: *asset,
+                    amount: *amount,
+                    expires_at,
+                };
+                lock::<T>(&time_lock, source)?;
+                HashTimeLocks::<T>::insert(source, hash_lock, time_lock);
+                Module::<T>::deposit_event(Event::HtlcCreated(
+                    *hash_lock,
+                    source.clone(),
+                    receiver.clone(),
+                    *asset,
+                    *amount,
+                    expires_at,
+                ));
+            }
+            HtlcSwapAction::Claim {
+                source: lock_source,
+                hash_lock,
+                preimage,
+            } => {
+                let time_lock = HashTimeLocks::<T>::take(lock_source, hash_lock)
+                    .ok_or(Error::<T>::HtlcNotFound)?;
+                claim::<T>(&time_lock)?;
+                Module::<T>::deposit_event(Event::HtlcClaimed(
+                    *hash_lock,
+                    lock_source.clone(),
+                    time_lock.receiver_account,
+                    preimage.clone(),
+                ));
+            }
+            HtlcSwapAction::Refund {
+                source: lock_source,
+                hash_lock,
+            } => {
+                let time_lock = HashTimeLocks::<T>::take(lock_source, hash_lock)
+                    .ok_or(Error::<T>::HtlcNotFound)?;
+                refund::<T>(&time_lock, lock_source)?;
+                Module::<T>::deposit_event(Event::HtlcRefunded(*hash_lock, lock_source.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    fn claim(&self, _source: &AccountIdOf<T>) -> bool {
+        true
+    }
+
+    fn weight(&self) -> Weight {
+        match self {
+            HtlcSwapAction::Lock { .. } => <T as Config>::WeightInfo::create_htlc(),
+            HtlcSwapAction::Claim { .. } => <T as Config>::WeightInfo::claim_htlc(),
+            HtlcSwapAction::Refund { .. } => <T as Config>::WeightInfo::refund_htlc(),
+        }
+    }
+
+    fn cancel(&self, _source: &AccountIdOf<T>) {
+        // The action is done when it's reserved, claiming it can't fail.
+    }
+}
+
+/// Checks that `preimage` unlocks `hash_lock`.
+pub fn is_valid_preimage(hash_lock: &H256, preimage: &[u8]) -> bool {
+    preimage.len() <= MAX_PREIMAGE_LENGTH && H256(sp_io::hashing::sha2_256(preimage)) == *hash_lock
+}
+
+/// Transfers the asset of `lock` from the `source` account to the HTLC technical account.
+pub fn lock<T: Config>(lock: &HashTimeLockOf<T>, source: &AccountIdOf<T>) -> DispatchResult {
+    let tech_account_id = htlc_tech_account_id::<T>();
+    if Module::<T>::ensure_tech_account_registered(&tech_account_id).is_err() {
+        Module::<T>::register_tech_account_id(tech_account_id.clone())?;
+    }
+    Module::<T>::transfer_in(&lock.asset, source, &tech_account_id, lock.amount)
+}
+
+/// Releases the asset of `lock` to its receiver. The preimage must be checked by the caller.
+pub fn claim<T: Config>(lock: &HashTimeLockOf<T>) -> DispatchResult {
+    Module::<T>::transfer_out(
+        &lock.asset,
+        &htlc_tech_account_id::<T>(),
+        &lock.receiver_account,
+        lock.amount,
+    )
+}
+
+/// Returns the asset of `lock` to the `source` account.
+pub fn refund<T: Config>(lock: &HashTimeLockOf<T>, source: &AccountIdOf<T>) -> DispatchResult {
+    Module::<T>::transfer_out(
+        &lock.asset,
+        &htlc_tech_account_id::<T>(),
+        source,
+        lock.amount,
+    )
+}
@@ -34,13 +34,22 @@ use codec::{Decode, Encode};
 use common::prelude::Balance;
 use common::{FromGenericPair, SwapAction, SwapRulesValidation};
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::{ensure, Parameter};
+use frame_support::storage::StoragePrefixedMap;
+use frame_support::weights::Weight;
+use frame_support::{ensure, Blake2_128Concat, Parameter, ReversibleStorageHasher};
 use sp_runtime::traits::{MaybeSerializeDeserialize, Member};
 use sp_runtime::RuntimeDebug;
 
 use common::TECH_ACCOUNT_MAGIC_PREFIX;
 use sp_core::H256;
 use sp_std::convert::TryFrom;
+use sp_std::prelude::*;
+
+pub mod htlc;
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[cfg(test)]
 mod mock;
@@ -54,6 +63,21 @@ type AssetIdOf<T> = <T as assets::Config>::AssetId;
 type TechAssetIdOf<T> = <T as Config>::TechAssetId;
 type DEXIdOf<T> = <T as common::Config>::DEXId;
 
+/// Max number of records `tech_accounts` returns at once.
+pub const MAX_TECH_ACCOUNTS_PAGE_SIZE: u32 = 100;
+
+pub type HashTimeLockOf<T> = htlc::HashTimeLock<
+    <T as assets::Config>::AssetId,
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+pub trait WeightInfo {
+    fn create_htlc() -> Weight;
+    fn claim_htlc() -> Weight;
+    fn refund_htlc() -> Weight;
+}
+
 /// Pending atomic swap operation.
 #[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode)]
 pub struct PendingSwap<T: Config> {
@@ -106,6 +130,16 @@ impl<T: Config> Pallet<T> {
         })
     }
 
+    /// Validates and performs the hash time lock action, the same way `create_swap` does for
+    /// `T::SwapAction`.
+    fn perform_htlc_action(
+        source: AccountIdOf<T>,
+        mut action: htlc::HtlcSwapActionOf<T>,
+    ) -> DispatchResult {
+        action.prepare_and_validate(Some(&source))?;
+        action.reserve(&source)
+    }
+
     /// Perform creation of swap, may be used by extrinsic operation or other pallets.
     pub fn create_swap(source: AccountIdOf<T>, action: &mut T::SwapAction) -> DispatchResult {
         ensure!(
@@ -134,6 +168,35 @@ impl<T: Config> Pallet<T> {
         Self::tech_account(account_id).ok_or(Error::<T>::AssociatedAccountIdNotFound.into())
     }
 
+    /// Lists registered technical accounts in storage order, at most `limit` records (capped by
+    /// `MAX_TECH_ACCOUNTS_PAGE_SIZE`) following the `start_after` account, or from the first one
+    /// if it's `None`. The last returned account is the cursor of the next page.
+    pub fn tech_accounts(
+        start_after: Option<T::AccountId>,
+        limit: u32,
+    ) -> Vec<(T::AccountId, T::TechAccountId)> {
+        let prefix = TechAccounts::<T>::final_prefix();
+        let mut key = match start_after {
+            Some(account_id) => TechAccounts::<T>::hashed_key_for(account_id),
+            None => prefix.to_vec(),
+        };
+        let limit = limit.min(MAX_TECH_ACCOUNTS_PAGE_SIZE) as usize;
+        let mut accounts = Vec::new();
+        while accounts.len() < limit {
+            key = match sp_io::storage::next_key(&key) {
+                Some(next_key) if next_key.starts_with(&prefix) => next_key,
+                _ => break,
+            };
+            let account = Blake2_128Concat::reverse(&key[prefix.len()..]);
+            if let Ok(account_id) = T::AccountId::decode(&mut &account[..]) {
+                if let Some(tech_account_id) = Self::tech_account(&account_id) {
+                    accounts.push((account_id, tech_account_id));
+                }
+            }
+        }
+        accounts
+    }
+
     /// Check `TechAccountId` for registration in storage map.
     pub fn ensure_account_registered(
         account_id: &T::AccountId,
@@ -239,6 +302,7 @@ pub use pallet::*;
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
+    use frame_support::transactional;
     use frame_system::pallet_prelude::*;
 
     #[pallet::config]
@@ -278,6 +342,9 @@ pub mod pallet {
         /// Swap action.
         type SwapAction: common::SwapRulesValidation<Self::AccountId, Self::TechAccountId, Self>
             + Parameter;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::pallet]
@@ -288,10 +355,74 @@ pub mod pallet {
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
 
     #[pallet::call]
-    impl<T: Config> Pallet<T> {}
+    impl<T: Config> Pallet<T> {
+        /// Locks `amount` of `asset_id` for `receiver` under the `hash_lock` for `timeout` blocks.
+        ///
+        /// The receiver gets the asset when anyone reveals the SHA-256 preimage of `hash_lock`
+        /// with `claim_htlc`, otherwise the asset can be refunded with `refund_htlc` after the
+        /// timeout. The lock is identified by the origin account and `hash_lock`.
+        #[pallet::weight(<T as Config>::WeightInfo::create_htlc())]
+        #[transactional]
+        pub fn create_htlc(
+            origin: OriginFor<T>,
+            receiver: AccountIdOf<T>,
+            asset_id: AssetIdOf<T>,
+            amount: Balance,
+            hash_lock: H256,
+            timeout: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let source = ensure_signed(origin)?;
+            Self::perform_htlc_action(
+                source,
+                htlc::HtlcSwapAction::Lock {
+                    receiver,
+                    asset: asset_id,
+                    amount,
+                    hash_lock,
+                    timeout,
+                },
+            )?;
+            Ok(().into())
+        }
+
+        /// Releases the asset locked by `source` under `hash_lock` to its receiver, `preimage`
+        /// must hash to `hash_lock` with SHA-256 and be at most `MAX_PREIMAGE_LENGTH` bytes long.
+        #[pallet::weight(<T as Config>::WeightInfo::claim_htlc())]
+        #[transactional]
+        pub fn claim_htlc(
+            origin: OriginFor<T>,
+            source: AccountIdOf<T>,
+            hash_lock: H256,
+            preimage: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::perform_htlc_action(
+                who,
+                htlc::HtlcSwapAction::Claim {
+                    source,
+                    hash_lock,
+                    preimage,
+                },
+            )?;
+            Ok(().into())
+        }
+
+        /// Returns the asset locked by `source` under `hash_lock` after the lock has expired.
+        #[pallet::weight(<T as Config>::WeightInfo::refund_htlc())]
+        #[transactional]
+        pub fn refund_htlc(
+            origin: OriginFor<T>,
+            source: AccountIdOf<T>,
+            hash_lock: H256,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::perform_htlc_action(who, htlc::HtlcSwapAction::Refund { source, hash_lock })?;
+            Ok(().into())
+        }
+    }
 
     #[pallet::event]
-    #[pallet::metadata(AccountIdOf<T> = "AccountId", TechAssetIdOf<T> = "TechAssetId", TechAccountIdOf<T> = "TechAccountId")]
+    #[pallet::metadata(AccountIdOf<T> = "AccountId", TechAssetIdOf<T> = "TechAssetId", TechAccountIdOf<T> = "TechAccountId", AssetIdOf<T> = "AssetId", BlockNumberFor<T> = "BlockNumber")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Some pure technical assets were minted. [asset, owner, minted_amount, total_exist].
@@ -324,6 +455,22 @@ pub mod pallet {
         /// Swap operaction is finalised [initiator, finaliser].
         /// TechAccountId is only pure TechAccountId.
         SwapSuccess(AccountIdOf<T>),
+
+        /// Hash time lock is created [hash_lock, source, receiver, asset, amount, expires_at].
+        HtlcCreated(
+            H256,
+            AccountIdOf<T>,
+            AccountIdOf<T>,
+            AssetIdOf<T>,
+            Balance,
+            BlockNumberFor<T>,
+        ),
+
+        /// Hash time lock is claimed by revealing the secret [hash_lock, source, receiver, preimage].
+        HtlcClaimed(H256, AccountIdOf<T>, AccountIdOf<T>, Vec<u8>),
+
+        /// Hash time lock is refunded after expiration [hash_lock, source].
+        HtlcRefunded(H256, AccountIdOf<T>),
     }
 
     #[pallet::error]
@@ -372,6 +519,14 @@ pub mod pallet {
         AssociatedAccountIdNotFound,
         /// Operation with abstract checking is impossible.
         OperationWithAbstractCheckingIsImposible,
+        /// Hash time lock is not found.
+        HtlcNotFound,
+        /// Hash time lock has expired and can only be refunded.
+        HtlcExpired,
+        /// Amount of hash time lock must be greater than zero.
+        ZeroHtlcAmount,
+        /// Hash time lock must expire in the future.
+        InvalidHtlcTimeout,
     }
 
     /// Registered technical account identifiers. Map from repr `AccountId` into pure `TechAccountId`.
@@ -380,6 +535,12 @@ pub mod pallet {
     pub(super) type TechAccounts<T: Config> =
         StorageMap<_, Blake2_128Concat, AccountIdOf<T>, TechAccountIdOf<T>>;
 
+    /// Pending hash time locks by their source account and hash lock.
+    #[pallet::storage]
+    #[pallet::getter(fn hash_time_lock)]
+    pub(super) type HashTimeLocks<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Identity, H256, HashTimeLockOf<T>>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         /// Registered technical account identifiers. Map from repr `AccountId` into pure `TechAccountId`.
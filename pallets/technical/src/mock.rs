@@ -117,6 +117,7 @@ impl frame_system::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl common::Config for Runtime {
@@ -172,6 +173,7 @@ impl Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = PolySwapActionExample;
+    type WeightInfo = ();
 }
 
 parameter_type_with_key! {
@@ -361,6 +363,7 @@ pub enum PolySwapActionExample {
     GenericPair(GenericPairSwapActionExample),
     Multi(MultiSwapActionExample),
     Crowd(CrowdSwapActionExample),
+    Htlc(crate::htlc::HtlcSwapActionOf<Runtime>),
 }
 
 impl common::SwapAction<AccountId, TechAccountId, Runtime> for PolySwapActionExample {
@@ -369,6 +372,7 @@ impl common::SwapAction<AccountId, TechAccountId, Runtime> for PolySwapActionExa
             GenericPair(a) => a.reserve(source),
             Multi(a) => a.reserve(source),
             Crowd(a) => a.reserve(source),
+            Htlc(a) => a.reserve(source),
         }
     }
     fn claim(&self, source: &AccountId) -> bool {
@@ -376,6 +380,7 @@ impl common::SwapAction<AccountId, TechAccountId, Runtime> for PolySwapActionExa
             GenericPair(a) => a.claim(source),
             Multi(a) => a.claim(source),
             Crowd(a) => a.claim(source),
+            Htlc(a) => a.claim(source),
         }
     }
     fn weight(&self) -> Weight {
@@ -383,6 +388,7 @@ impl common::SwapAction<AccountId, TechAccountId, Runtime> for PolySwapActionExa
             GenericPair(a) => a.weight(),
             Multi(a) => a.weight(),
             Crowd(a) => a.weight(),
+            Htlc(a) => a.weight(),
         }
     }
     fn cancel(&self, source: &AccountId) {
@@ -390,6 +396,7 @@ impl common::SwapAction<AccountId, TechAccountId, Runtime> for PolySwapActionExa
             GenericPair(a) => a.cancel(source),
             Multi(a) => a.cancel(source),
             Crowd(a) => a.cancel(source),
+            Htlc(a) => a.cancel(source),
         }
     }
 }
@@ -400,6 +407,7 @@ impl common::SwapRulesValidation<AccountId, TechAccountId, Runtime> for PolySwap
             GenericPair(a) => a.is_abstract_checking(),
             Multi(a) => a.is_abstract_checking(),
             Crowd(a) => a.is_abstract_checking(),
+            Htlc(a) => a.is_abstract_checking(),
         }
     }
 
@@ -408,6 +416,7 @@ impl common::SwapRulesValidation<AccountId, TechAccountId, Runtime> for PolySwap
             GenericPair(a) => a.prepare_and_validate(source),
             Multi(a) => a.prepare_and_validate(source),
             Crowd(a) => a.prepare_and_validate(source),
+            Htlc(a) => a.prepare_and_validate(source),
         }
     }
 
@@ -416,6 +425,7 @@ impl common::SwapRulesValidation<AccountId, TechAccountId, Runtime> for PolySwap
             GenericPair(a) => a.instant_auto_claim_used(),
             Multi(a) => a.instant_auto_claim_used(),
             Crowd(a) => a.instant_auto_claim_used(),
+            Htlc(a) => a.instant_auto_claim_used(),
         }
     }
     fn triggered_auto_claim_used(&self) -> bool {
@@ -423,6 +433,7 @@ impl common::SwapRulesValidation<AccountId, TechAccountId, Runtime> for PolySwap
             GenericPair(a) => a.triggered_auto_claim_used(),
             Multi(a) => a.triggered_auto_claim_used(),
             Crowd(a) => a.triggered_auto_claim_used(),
+            Htlc(a) => a.triggered_auto_claim_used(),
         }
     }
     fn is_able_to_claim(&self) -> bool {
@@ -430,6 +441,7 @@ impl common::SwapRulesValidation<AccountId, TechAccountId, Runtime> for PolySwap
             GenericPair(a) => a.is_able_to_claim(),
             Multi(a) => a.is_able_to_claim(),
             Crowd(a) => a.is_able_to_claim(),
+            Htlc(a) => a.is_able_to_claim(),
         }
     }
 }
@@ -31,8 +31,9 @@
 use crate::mock::*;
 use common::prelude::Balance;
 use common::{AssetName, AssetSymbol};
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use orml_traits::MultiCurrency;
+use sp_core::H256;
 use PolySwapActionExample::*;
 
 #[test]
@@ -53,6 +54,45 @@ fn should_register_technical_account() {
     });
 }
 
+#[test]
+fn should_list_registered_technical_accounts() {
+    let mut ext = ExtBuilder::default().build();
+    let tech_account_ids = vec![
+        TechAccountId::Generic("Test123".into(), "Some data".into()),
+        TechAccountId::Pure(
+            10,
+            LiquidityKeeper(TradingPair {
+                base_asset_id: common::mock::ComicAssetId::RedPepper.into(),
+                target_asset_id: common::mock::ComicAssetId::BlackPepper.into(),
+            }),
+        ),
+        TechAccountId::Pure(10, FeeCollector),
+    ];
+
+    ext.execute_with(|| {
+        assert!(Technical::tech_accounts(None, 10).is_empty());
+        for tech_account_id in &tech_account_ids {
+            assert_ok!(Technical::register_tech_account_id(tech_account_id.clone()));
+        }
+        let all = Technical::tech_accounts(None, 10);
+        assert_eq!(all.len(), 3);
+        for (account_id, tech_account_id) in &all {
+            assert!(tech_account_ids.contains(tech_account_id));
+            assert_eq!(
+                &Technical::tech_account_id_to_account_id(tech_account_id).unwrap(),
+                account_id
+            );
+        }
+        let mut paged = Technical::tech_accounts(None, 2);
+        assert_eq!(paged.len(), 2);
+        let cursor = paged.last().map(|(account_id, _)| account_id.clone());
+        paged.extend(Technical::tech_accounts(cursor, 2));
+        assert_eq!(paged, all);
+        let cursor = all.last().map(|(account_id, _)| account_id.clone());
+        assert!(Technical::tech_accounts(cursor, 2).is_empty());
+    });
+}
+
 #[test]
 fn generic_pair_swap_simple() {
     let mut ext = ExtBuilder::default().build();
@@ -167,3 +207,274 @@ fn should_have_same_nonce_on_dust_tech_account() {
         assert_eq!(frame_system::Pallet::<Runtime>::account_nonce(&t01), 1);
     });
 }
+
+fn register_red_pepper() {
+    assert_ok!(assets::Module::<Runtime>::register_asset_id(
+        get_alice(),
+        RedPepper(),
+        AssetSymbol(b"RP".to_vec()),
+        AssetName(b"Red Pepper".to_vec()),
+        18,
+        Balance::from(0u32),
+        true,
+    ));
+}
+
+fn hash_lock(secret: &[u8]) -> H256 {
+    H256(sp_io::hashing::sha2_256(secret))
+}
+
+#[test]
+fn htlc_should_be_claimed_with_preimage() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        register_red_pepper();
+        let lock = hash_lock(b"secret");
+        assert_ok!(Technical::create_htlc(
+            Origin::signed(get_alice()),
+            get_bob(),
+            RedPepper(),
+            1000,
+            lock,
+            10
+        ));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&RedPepper(), &get_alice()).unwrap(),
+            98_000
+        );
+        assert_eq!(
+            Technical::hash_time_lock(&get_alice(), &lock)
+                .unwrap()
+                .expires_at,
+            11
+        );
+        assert_noop!(
+            Technical::create_htlc(
+                Origin::signed(get_alice()),
+                get_bob(),
+                RedPepper(),
+                1000,
+                lock,
+                10
+            ),
+            crate::Error::<Runtime>::AlreadyExist
+        );
+        assert_noop!(
+            Technical::claim_htlc(
+                Origin::signed(get_bob()),
+                get_alice(),
+                lock,
+                b"wrong".to_vec()
+            ),
+            crate::Error::<Runtime>::InvalidProof
+        );
+        assert_noop!(
+            Technical::refund_htlc(Origin::signed(get_alice()), get_alice(), lock),
+            crate::Error::<Runtime>::DurationNotPassed
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(11);
+        assert_ok!(Technical::claim_htlc(
+            Origin::signed(get_alice()),
+            get_alice(),
+            lock,
+            b"secret".to_vec()
+        ));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&RedPepper(), &get_bob()).unwrap(),
+            2_001_000
+        );
+        assert!(Technical::hash_time_lock(&get_alice(), &lock).is_none());
+        assert_noop!(
+            Technical::claim_htlc(
+                Origin::signed(get_bob()),
+                get_alice(),
+                lock,
+                b"secret".to_vec()
+            ),
+            crate::Error::<Runtime>::HtlcNotFound
+        );
+    });
+}
+
+#[test]
+fn htlc_should_be_refunded_after_expiration() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        register_red_pepper();
+        let lock = hash_lock(b"secret");
+        assert_ok!(Technical::create_htlc(
+            Origin::signed(get_alice()),
+            get_bob(),
+            RedPepper(),
+            1000,
+            lock,
+            10
+        ));
+
+        frame_system::Pallet::<Runtime>::set_block_number(12);
+        assert_noop!(
+            Technical::claim_htlc(
+                Origin::signed(get_bob()),
+                get_alice(),
+                lock,
+                b"secret".to_vec()
+            ),
+            crate::Error::<Runtime>::HtlcExpired
+        );
+        assert_ok!(Technical::refund_htlc(
+            Origin::signed(get_bob()),
+            get_alice(),
+            lock
+        ));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&RedPepper(), &get_alice()).unwrap(),
+            99_000
+        );
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&RedPepper(), &get_bob()).unwrap(),
+            2_000_000
+        );
+        assert!(Technical::hash_time_lock(&get_alice(), &lock).is_none());
+    });
+}
+
+#[test]
+fn htlc_should_be_performed_as_swap_action() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        register_red_pepper();
+        let lock = hash_lock(b"secret");
+        assert_ok!(Technical::create_swap(
+            get_alice(),
+            &mut Htlc(crate::htlc::HtlcSwapAction::Lock {
+                receiver: get_bob(),
+                asset: RedPepper(),
+                amount: 1000,
+                hash_lock: lock,
+                timeout: 10,
+            })
+        ));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&RedPepper(), &get_alice()).unwrap(),
+            98_000
+        );
+        assert_noop!(
+            Technical::create_swap(
+                get_bob(),
+                &mut Htlc(crate::htlc::HtlcSwapAction::Refund {
+                    source: get_alice(),
+                    hash_lock: lock,
+                })
+            ),
+            crate::Error::<Runtime>::DurationNotPassed
+        );
+        assert_ok!(Technical::create_swap(
+            get_bob(),
+            &mut Htlc(crate::htlc::HtlcSwapAction::Claim {
+                source: get_alice(),
+                hash_lock: lock,
+                preimage: b"secret".to_vec(),
+            })
+        ));
+        assert_eq!(
+            assets::Module::<Runtime>::free_balance(&RedPepper(), &get_bob()).unwrap(),
+            2_001_000
+        );
+        assert!(Technical::hash_time_lock(&get_alice(), &lock).is_none());
+    });
+}
+
+#[test]
+fn htlc_should_not_be_created_with_invalid_parameters() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        register_red_pepper();
+        let lock = hash_lock(b"secret");
+        assert_noop!(
+            Technical::create_htlc(
+                Origin::signed(get_alice()),
+                get_bob(),
+                RedPepper(),
+                0,
+                lock,
+                10
+            ),
+            crate::Error::<Runtime>::ZeroHtlcAmount
+        );
+        assert_noop!(
+            Technical::create_htlc(
+                Origin::signed(get_alice()),
+                get_bob(),
+                RedPepper(),
+                1000,
+                lock,
+                0
+            ),
+            crate::Error::<Runtime>::InvalidHtlcTimeout
+        );
+    });
+}
+
+#[test]
+fn htlc_hash_lock_should_not_be_taken_by_other_account() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        register_red_pepper();
+        let lock = hash_lock(b"secret");
+        assert_ok!(Technical::create_htlc(
+            Origin::signed(get_bob()),
+            get_bob(),
+            RedPepper(),
+            1,
+            lock,
+            10
+        ));
+        assert_ok!(Technical::create_htlc(
+            Origin::signed(get_alice()),
+            get_bob(),
+            RedPepper(),
+            1000,
+            lock,
+            10
+        ));
+        assert_eq!(
+            Technical::hash_time_lock(&get_bob(), &lock).unwrap().amount,
+            1
+        );
+        assert_eq!(
+            Technical::hash_time_lock(&get_alice(), &lock)
+                .unwrap()
+                .amount,
+            1000
+        );
+    });
+}
+
+#[test]
+fn htlc_should_not_be_claimed_with_too_long_preimage() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        register_red_pepper();
+        let preimage = vec![1u8; crate::htlc::MAX_PREIMAGE_LENGTH + 1];
+        let lock = hash_lock(&preimage);
+        assert_ok!(Technical::create_htlc(
+            Origin::signed(get_alice()),
+            get_bob(),
+            RedPepper(),
+            1000,
+            lock,
+            10
+        ));
+        assert_noop!(
+            Technical::claim_htlc(Origin::signed(get_bob()), get_alice(), lock, preimage),
+            crate::Error::<Runtime>::InvalidProof
+        );
+    });
+}
@@ -55,6 +55,15 @@ pub trait TradingPairAPI<BlockHash, DEXId, TradingPair, AssetId, LiquiditySource
         at: Option<BlockHash>,
     ) -> Result<bool>;
 
+    #[rpc(name = "tradingPair_isPairPaused")]
+    fn is_pair_paused(
+        &self,
+        dex_id: DEXId,
+        base_asset_id: AssetId,
+        target_asset_id: AssetId,
+        at: Option<BlockHash>,
+    ) -> Result<bool>;
+
     #[rpc(name = "tradingPair_listEnabledSourcesForPair")]
     fn list_enabled_sources_for_pair(
         &self,
@@ -140,6 +149,26 @@ where
             })
     }
 
+    fn is_pair_paused(
+        &self,
+        dex_id: DEXId,
+        base_asset_id: AssetId,
+        target_asset_id: AssetId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<bool> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.is_pair_paused(&at, dex_id, base_asset_id, target_asset_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to query pair state.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+
     fn list_enabled_sources_for_pair(
         &self,
         dex_id: DEXId,
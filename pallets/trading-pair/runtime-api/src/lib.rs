@@ -46,6 +46,8 @@ sp_api::decl_runtime_apis! {
 
         fn is_pair_enabled(dex_id: DEXId, base_asset_id: AssetId, target_asset_id: AssetId) -> bool;
 
+        fn is_pair_paused(dex_id: DEXId, base_asset_id: AssetId, target_asset_id: AssetId) -> bool;
+
         fn list_enabled_sources_for_pair(
             dex_id: DEXId,
             base_asset_id: AssetId,
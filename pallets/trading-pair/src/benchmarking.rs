@@ -51,6 +51,20 @@ fn alice<T: Config>() -> T::AccountId {
     T::AccountId::decode(&mut &bytes[..]).unwrap_or_default()
 }
 
+fn setup_pair<T: Config>(paused: bool) -> TradingPair<T> {
+    let trading_pair = TradingPair::<T> {
+        base_asset_id: XOR.into(),
+        target_asset_id: DOT.into(),
+    };
+    let mut sources = BTreeSet::new();
+    sources.insert(LiquiditySourceType::XYKPool);
+    EnabledSources::<T>::insert(&T::DEXId::from(DEX), &trading_pair, sources);
+    if paused {
+        PausedPairs::<T>::insert(&T::DEXId::from(DEX), &trading_pair, true);
+    }
+    trading_pair
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
     let events = frame_system::Module::<T>::events();
     let system_event: <T as frame_system::Config>::Event = generic_event.into();
@@ -90,6 +104,94 @@ benchmarks! {
             ).into()
         )
     }
+
+    disable_source {
+        let trading_pair = setup_pair::<T>(false);
+    }: _(
+        RawOrigin::Root,
+        DEX.into(),
+        XOR.into(),
+        DOT.into(),
+        LiquiditySourceType::XYKPool
+    )
+    verify {
+        assert_last_event::<T>(
+            Event::SourceDisabled(
+                DEX.into(),
+                trading_pair,
+                LiquiditySourceType::XYKPool
+            ).into()
+        )
+    }
+
+    enable_source {
+        let trading_pair = setup_pair::<T>(false);
+    }: _(
+        RawOrigin::Root,
+        DEX.into(),
+        XOR.into(),
+        DOT.into(),
+        LiquiditySourceType::MockPool
+    )
+    verify {
+        assert_last_event::<T>(
+            Event::SourceEnabled(
+                DEX.into(),
+                trading_pair,
+                LiquiditySourceType::MockPool
+            ).into()
+        )
+    }
+
+    pause {
+        let trading_pair = setup_pair::<T>(false);
+    }: _(
+        RawOrigin::Root,
+        DEX.into(),
+        XOR.into(),
+        DOT.into()
+    )
+    verify {
+        assert_last_event::<T>(Event::TradingPairPaused(DEX.into(), trading_pair).into())
+    }
+
+    resume {
+        let trading_pair = setup_pair::<T>(true);
+    }: _(
+        RawOrigin::Root,
+        DEX.into(),
+        XOR.into(),
+        DOT.into()
+    )
+    verify {
+        assert_last_event::<T>(Event::TradingPairResumed(DEX.into(), trading_pair).into())
+    }
+
+    delist {
+        let trading_pair = setup_pair::<T>(true);
+    }: _(
+        RawOrigin::Root,
+        DEX.into(),
+        XOR.into(),
+        DOT.into()
+    )
+    verify {
+        assert_last_event::<T>(Event::TradingPairDelisted(DEX.into(), trading_pair).into())
+    }
+
+    relist {
+        let trading_pair = setup_pair::<T>(false);
+        let sources = EnabledSources::<T>::take(&T::DEXId::from(DEX), &trading_pair).unwrap();
+        DelistedPairs::<T>::insert(&T::DEXId::from(DEX), &trading_pair, sources);
+    }: _(
+        RawOrigin::Root,
+        DEX.into(),
+        XOR.into(),
+        DOT.into()
+    )
+    verify {
+        assert_last_event::<T>(Event::TradingPairRelisted(DEX.into(), trading_pair).into())
+    }
 }
 
 #[cfg(test)]
@@ -102,6 +204,12 @@ mod tests {
     fn test_benchmarks() {
         ExtBuilder::default().build().execute_with(|| {
             assert_ok!(test_benchmark_register::<Runtime>());
+            assert_ok!(test_benchmark_disable_source::<Runtime>());
+            assert_ok!(test_benchmark_enable_source::<Runtime>());
+            assert_ok!(test_benchmark_pause::<Runtime>());
+            assert_ok!(test_benchmark_resume::<Runtime>());
+            assert_ok!(test_benchmark_delist::<Runtime>());
+            assert_ok!(test_benchmark_relist::<Runtime>());
         });
     }
 }
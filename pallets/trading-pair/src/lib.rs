@@ -34,11 +34,14 @@
 #[macro_use]
 extern crate alloc;
 
-use common::{EnsureDEXManager, EnsureTradingPairExists, LiquiditySourceType, ManagementMode};
+use common::{
+    EnsureDEXManager, EnsureTradingPairExists, IsSourceEnabled, LiquiditySourceType, ManagementMode,
+};
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::ensure;
 use frame_support::traits::{Get, IsType};
 use frame_support::weights::Weight;
+use frame_system::ensure_root;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 
@@ -58,6 +61,12 @@ type DEXManager<T> = dex_manager::Pallet<T>;
 
 pub trait WeightInfo {
     fn register() -> Weight;
+    fn disable_source() -> Weight;
+    fn enable_source() -> Weight;
+    fn pause() -> Weight;
+    fn resume() -> Weight;
+    fn delist() -> Weight;
+    fn relist() -> Weight;
 }
 
 impl<T: Config> EnsureTradingPairExists<T::DEXId, T::AssetId, DispatchError> for Pallet<T> {
@@ -74,6 +83,17 @@ impl<T: Config> EnsureTradingPairExists<T::DEXId, T::AssetId, DispatchError> for
     }
 }
 
+impl<T: Config> IsSourceEnabled<T::DEXId, T::AssetId> for Pallet<T> {
+    fn is_source_enabled(
+        dex_id: &T::DEXId,
+        input_asset_id: &T::AssetId,
+        output_asset_id: &T::AssetId,
+        source_type: LiquiditySourceType,
+    ) -> bool {
+        Self::is_source_enabled_for_swap(dex_id, input_asset_id, output_asset_id, source_type)
+    }
+}
+
 impl<T: Config> Pallet<T> {
     pub fn list_trading_pairs(dex_id: &T::DEXId) -> Result<Vec<TradingPair<T>>, DispatchError> {
         DEXManager::<T>::ensure_dex_exists(dex_id)?;
@@ -87,25 +107,60 @@ impl<T: Config> Pallet<T> {
         &base_asset_id: &T::AssetId,
         &target_asset_id: &T::AssetId,
     ) -> Result<bool, DispatchError> {
-        DEXManager::<T>::ensure_dex_exists(dex_id)?;
-        let pair = TradingPair::<T> {
-            base_asset_id,
-            target_asset_id,
-        };
+        let pair = Self::stored_pair(dex_id, base_asset_id, target_asset_id)?;
         Ok(Self::enabled_sources(dex_id, &pair).is_some())
     }
 
+    /// Lists sources enabled for the trading pair. Paused trading pairs have no usable sources,
+    /// so an empty set is returned for them.
     pub fn list_enabled_sources_for_trading_pair(
         dex_id: &T::DEXId,
         &base_asset_id: &T::AssetId,
         &target_asset_id: &T::AssetId,
     ) -> Result<BTreeSet<LiquiditySourceType>, DispatchError> {
-        DEXManager::<T>::ensure_dex_exists(dex_id)?;
+        let pair = Self::stored_pair(dex_id, base_asset_id, target_asset_id)?;
+        let sources =
+            Self::enabled_sources(dex_id, &pair).ok_or(Error::<T>::TradingPairDoesntExist)?;
+        if PausedPairs::<T>::get(dex_id, &pair) {
+            Ok(BTreeSet::new())
+        } else {
+            Ok(sources)
+        }
+    }
+
+    pub fn is_trading_pair_paused(
+        dex_id: &T::DEXId,
+        &base_asset_id: &T::AssetId,
+        &target_asset_id: &T::AssetId,
+    ) -> bool {
         let pair = TradingPair::<T> {
             base_asset_id,
             target_asset_id,
         };
-        Ok(Self::enabled_sources(dex_id, &pair).ok_or(Error::<T>::TradingPairDoesntExist)?)
+        PausedPairs::<T>::get(dex_id, &pair)
+    }
+
+    /// Checks if trading between the given assets is paused on the DEX. The order of the assets
+    /// doesn't matter.
+    pub fn is_trading_paused(
+        dex_id: &T::DEXId,
+        asset_a: &T::AssetId,
+        asset_b: &T::AssetId,
+    ) -> bool {
+        Self::is_trading_pair_paused(dex_id, asset_a, asset_b)
+            || Self::is_trading_pair_paused(dex_id, asset_b, asset_a)
+    }
+
+    pub fn ensure_trading_not_paused(
+        dex_id: &T::DEXId,
+        asset_a: &T::AssetId,
+        asset_b: &T::AssetId,
+    ) -> DispatchResult {
+        ensure!(
+            !Self::is_trading_paused(dex_id, asset_a, asset_b),
+            Error::<T>::TradingPairIsPaused
+        );
+        Ok(())
     }
 
     pub fn is_source_enabled_for_trading_pair(
@@ -120,6 +175,30 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// Checks if the source is enabled for trading between the given assets on the DEX. The order of
+    /// the assets doesn't matter, paused trading pairs have no enabled sources.
+    pub fn is_source_enabled_for_swap(
+        dex_id: &T::DEXId,
+        input_asset_id: &T::AssetId,
+        output_asset_id: &T::AssetId,
+        source_type: LiquiditySourceType,
+    ) -> bool {
+        Self::is_source_enabled_for_trading_pair(
+            dex_id,
+            input_asset_id,
+            output_asset_id,
+            source_type,
+        )
+        .unwrap_or(false)
+            || Self::is_source_enabled_for_trading_pair(
+                dex_id,
+                output_asset_id,
+                input_asset_id,
+                source_type,
+            )
+            .unwrap_or(false)
+    }
+
     pub fn enable_source_for_trading_pair(
         dex_id: &T::DEXId,
         &base_asset_id: &T::AssetId,
@@ -127,10 +206,7 @@ impl<T: Config> Pallet<T> {
         source_type: LiquiditySourceType,
     ) -> DispatchResult {
         Self::ensure_trading_pair_exists(dex_id, &base_asset_id, &target_asset_id)?;
-        let pair = TradingPair::<T> {
-            base_asset_id,
-            target_asset_id,
-        };
+        let pair = Self::stored_pair(dex_id, base_asset_id, target_asset_id)?;
         // This logic considers Ok if source is already enabled.
         // unwrap() is safe, check done in `ensure_trading_pair_exists`.
         EnabledSources::<T>::mutate(dex_id, &pair, |opt_set| {
@@ -138,6 +214,49 @@ impl<T: Config> Pallet<T> {
         });
         Ok(())
     }
+
+    fn ensure_root_or_dex_manager(origin: T::Origin, dex_id: &T::DEXId) -> DispatchResult {
+        if ensure_root(origin.clone()).is_err() {
+            T::EnsureDEXManager::ensure_can_manage(dex_id, origin, ManagementMode::Private)?;
+        }
+        Ok(())
+    }
+
+    fn ensure_registered_pair(
+        dex_id: &T::DEXId,
+        base_asset_id: T::AssetId,
+        target_asset_id: T::AssetId,
+    ) -> Result<TradingPair<T>, DispatchError> {
+        let trading_pair = Self::stored_pair(dex_id, base_asset_id, target_asset_id)?;
+        ensure!(
+            EnabledSources::<T>::contains_key(dex_id, &trading_pair),
+            Error::<T>::TradingPairDoesntExist
+        );
+        Ok(trading_pair)
+    }
+
+    /// Returns the key under which the pair of the given assets is stored for the DEX.
+    /// Pairs which don't include the DEX base asset are stored in the canonical order,
+    /// so the order of their assets doesn't matter.
+    fn stored_pair(
+        dex_id: &T::DEXId,
+        base_asset_id: T::AssetId,
+        target_asset_id: T::AssetId,
+    ) -> Result<TradingPair<T>, DispatchError> {
+        let dex_base_asset_id = DEXManager::<T>::get_dex_info(dex_id)?.base_asset_id;
+        if base_asset_id == dex_base_asset_id || target_asset_id == dex_base_asset_id {
+            Ok(TradingPair::<T> {
+                base_asset_id,
+                target_asset_id,
+            })
+        } else {
+            Ok(TradingPair::<T>::canonical(
+                &dex_base_asset_id,
+                base_asset_id,
+                target_asset_id,
+            ))
+        }
+    }
 }
 
 pub use pallet::*;
@@ -171,6 +290,9 @@ pub mod pallet {
         /// Register trading pair on the given DEX.
         /// Can be only called by the DEX owner.
         ///
+        /// The DEX base asset can't be used as the target asset. Pairs of two non-base assets
+        /// are stored in the canonical order, regardless of the order they were given in.
+        ///
         /// - `dex_id`: ID of the exchange.
         /// - `base_asset_id`: base asset ID.
         /// - `target_asset_id`: target asset ID.
@@ -189,18 +311,19 @@ pub mod pallet {
                 base_asset_id != target_asset_id,
                 Error::<T>::IdenticalAssetIds
             );
+            let dex_base_asset_id = DEXManager::<T>::get_dex_info(&dex_id)?.base_asset_id;
             ensure!(
-                base_asset_id == T::GetBaseAssetId::get(),
+                target_asset_id != dex_base_asset_id,
                 Error::<T>::ForbiddenBaseAssetId
             );
-            let trading_pair = TradingPair::<T> {
-                base_asset_id,
-                target_asset_id,
-            };
+            let trading_pair =
+                TradingPair::<T>::canonical(&dex_base_asset_id, base_asset_id, target_asset_id);
             ensure!(
                 Self::enabled_sources(&dex_id, &trading_pair).is_none(),
                 Error::<T>::TradingPairExists
             );
+            // Registering a delisted pair again lists it without its previous sources.
+            DelistedPairs::<T>::remove(&dex_id, &trading_pair);
             EnabledSources::<T>::insert(
                 &dex_id,
                 &trading_pair,
@@ -209,14 +332,188 @@ pub mod pallet {
             Self::deposit_event(Event::TradingPairStored(dex_id, trading_pair));
             Ok(().into())
         }
+
+        /// Disable a liquidity source for the trading pair, e.g. when the source is broken.
+        /// Can be only called by the DEX owner or root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `base_asset_id`: base asset ID.
+        /// - `target_asset_id`: target asset ID.
+        /// - `source_type`: liquidity source to disable.
+        #[pallet::weight(<T as Config>::WeightInfo::disable_source())]
+        pub fn disable_source(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: T::AssetId,
+            target_asset_id: T::AssetId,
+            source_type: LiquiditySourceType,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_root_or_dex_manager(origin, &dex_id)?;
+            let trading_pair =
+                Self::ensure_registered_pair(&dex_id, base_asset_id, target_asset_id)?;
+            EnabledSources::<T>::try_mutate(&dex_id, &trading_pair, |opt_set| {
+                // unwrap() is safe, check done in `ensure_registered_pair`.
+                ensure!(
+                    opt_set.as_mut().unwrap().remove(&source_type),
+                    Error::<T>::SourceIsNotEnabled
+                );
+                Ok::<_, Error<T>>(())
+            })?;
+            Self::deposit_event(Event::SourceDisabled(dex_id, trading_pair, source_type));
+            Ok(().into())
+        }
+
+        /// Enable a liquidity source for the trading pair, e.g. once the disabled source is fixed.
+        /// Can be only called by the DEX owner or root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `base_asset_id`: base asset ID.
+        /// - `target_asset_id`: target asset ID.
+        /// - `source_type`: liquidity source to enable.
+        #[pallet::weight(<T as Config>::WeightInfo::enable_source())]
+        pub fn enable_source(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: T::AssetId,
+            target_asset_id: T::AssetId,
+            source_type: LiquiditySourceType,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_root_or_dex_manager(origin, &dex_id)?;
+            let trading_pair =
+                Self::ensure_registered_pair(&dex_id, base_asset_id, target_asset_id)?;
+            EnabledSources::<T>::try_mutate(&dex_id, &trading_pair, |opt_set| {
+                // unwrap() is safe, check done in `ensure_registered_pair`.
+                ensure!(
+                    opt_set.as_mut().unwrap().insert(source_type),
+                    Error::<T>::SourceIsAlreadyEnabled
+                );
+                Ok::<_, Error<T>>(())
+            })?;
+            Self::deposit_event(Event::SourceEnabled(dex_id, trading_pair, source_type));
+            Ok(().into())
+        }
+
+        /// Pause trading of the pair on all of its liquidity sources.
+        /// Can be only called by the DEX owner or root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `base_asset_id`: base asset ID.
+        /// - `target_asset_id`: target asset ID.
+        #[pallet::weight(<T as Config>::WeightInfo::pause())]
+        pub fn pause(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: T::AssetId,
+            target_asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_root_or_dex_manager(origin, &dex_id)?;
+            let trading_pair =
+                Self::ensure_registered_pair(&dex_id, base_asset_id, target_asset_id)?;
+            ensure!(
+                !PausedPairs::<T>::get(&dex_id, &trading_pair),
+                Error::<T>::TradingPairIsPaused
+            );
+            PausedPairs::<T>::insert(&dex_id, &trading_pair, true);
+            Self::deposit_event(Event::TradingPairPaused(dex_id, trading_pair));
+            Ok(().into())
+        }
+
+        /// Resume trading of the paused pair.
+        /// Can be only called by the DEX owner or root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `base_asset_id`: base asset ID.
+        /// - `target_asset_id`: target asset ID.
+        #[pallet::weight(<T as Config>::WeightInfo::resume())]
+        pub fn resume(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: T::AssetId,
+            target_asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_root_or_dex_manager(origin, &dex_id)?;
+            let trading_pair =
+                Self::ensure_registered_pair(&dex_id, base_asset_id, target_asset_id)?;
+            ensure!(
+                PausedPairs::<T>::get(&dex_id, &trading_pair),
+                Error::<T>::TradingPairIsNotPaused
+            );
+            PausedPairs::<T>::remove(&dex_id, &trading_pair);
+            Self::deposit_event(Event::TradingPairResumed(dex_id, trading_pair));
+            Ok(().into())
+        }
+
+        /// Remove the trading pair from the DEX together with all of its enabled sources.
+        /// Liquidity already provided to the pools of the pair can still be withdrawn.
+        /// The sources are kept aside, so the pair can be brought back with `relist`.
+        /// Can be only called by the DEX owner or root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `base_asset_id`: base asset ID.
+        /// - `target_asset_id`: target asset ID.
+        #[pallet::weight(<T as Config>::WeightInfo::delist())]
+        pub fn delist(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: T::AssetId,
+            target_asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_root_or_dex_manager(origin, &dex_id)?;
+            let trading_pair =
+                Self::ensure_registered_pair(&dex_id, base_asset_id, target_asset_id)?;
+            // unwrap_or_default() is safe, check done in `ensure_registered_pair`.
+            let sources = EnabledSources::<T>::take(&dex_id, &trading_pair).unwrap_or_default();
+            DelistedPairs::<T>::insert(&dex_id, &trading_pair, sources);
+            PausedPairs::<T>::remove(&dex_id, &trading_pair);
+            Self::deposit_event(Event::TradingPairDelisted(dex_id, trading_pair));
+            Ok(().into())
+        }
+
+        /// Bring the delisted trading pair back to the DEX with the sources it had when delisted.
+        /// Can be only called by the DEX owner or root.
+        ///
+        /// - `dex_id`: ID of the exchange.
+        /// - `base_asset_id`: base asset ID.
+        /// - `target_asset_id`: target asset ID.
+        #[pallet::weight(<T as Config>::WeightInfo::relist())]
+        pub fn relist(
+            origin: OriginFor<T>,
+            dex_id: T::DEXId,
+            base_asset_id: T::AssetId,
+            target_asset_id: T::AssetId,
+        ) -> DispatchResultWithPostInfo {
+            Self::ensure_root_or_dex_manager(origin, &dex_id)?;
+            let trading_pair = Self::stored_pair(&dex_id, base_asset_id, target_asset_id)?;
+            let sources = DelistedPairs::<T>::take(&dex_id, &trading_pair)
+                .ok_or(Error::<T>::TradingPairIsNotDelisted)?;
+            EnabledSources::<T>::insert(&dex_id, &trading_pair, sources);
+            Self::deposit_event(Event::TradingPairRelisted(dex_id, trading_pair));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
-    #[pallet::metadata(DexIdOf<T> = "DEXId", TradingPair<T> = "TradingPair")]
+    #[pallet::metadata(
+        DexIdOf<T> = "DEXId",
+        TradingPair<T> = "TradingPair",
+        LiquiditySourceType = "LiquiditySourceType"
+    )]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Trading pair has been redistered on a DEX. [DEX Id, Trading Pair]
         TradingPairStored(DexIdOf<T>, TradingPair<T>),
+        /// Liquidity source has been disabled for a trading pair. [DEX Id, Trading Pair, Source Type]
+        SourceDisabled(DexIdOf<T>, TradingPair<T>, LiquiditySourceType),
+        /// Liquidity source has been enabled for a trading pair. [DEX Id, Trading Pair, Source Type]
+        SourceEnabled(DexIdOf<T>, TradingPair<T>, LiquiditySourceType),
+        /// Trading pair has been paused. [DEX Id, Trading Pair]
+        TradingPairPaused(DexIdOf<T>, TradingPair<T>),
+        /// Trading pair has been resumed. [DEX Id, Trading Pair]
+        TradingPairResumed(DexIdOf<T>, TradingPair<T>),
+        /// Trading pair has been removed from a DEX. [DEX Id, Trading Pair]
+        TradingPairDelisted(DexIdOf<T>, TradingPair<T>),
+        /// Delisted trading pair has been brought back to a DEX. [DEX Id, Trading Pair]
+        TradingPairRelisted(DexIdOf<T>, TradingPair<T>),
     }
 
     #[pallet::error]
@@ -229,6 +526,16 @@ pub mod pallet {
         IdenticalAssetIds,
         /// Trading pair is not registered for given DEXId.
         TradingPairDoesntExist,
+        /// The liquidity source is not enabled for the trading pair.
+        SourceIsNotEnabled,
+        /// The trading pair is paused.
+        TradingPairIsPaused,
+        /// The trading pair is not paused.
+        TradingPairIsNotPaused,
+        /// The liquidity source is already enabled for the trading pair.
+        SourceIsAlreadyEnabled,
+        /// The trading pair is not delisted.
+        TradingPairIsNotDelisted,
     }
 
     #[pallet::storage]
@@ -242,6 +549,29 @@ pub mod pallet {
         BTreeSet<LiquiditySourceType>,
     >;
 
+    /// Trading pairs which have trading temporarily paused on all of their sources.
+    #[pallet::storage]
+    pub(super) type PausedPairs<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::DEXId,
+        Blake2_128Concat,
+        TradingPair<T>,
+        bool,
+        ValueQuery,
+    >;
+
+    /// Sources of the delisted trading pairs, restored when a pair is relisted.
+    #[pallet::storage]
+    pub(super) type DelistedPairs<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::DEXId,
+        Blake2_128Concat,
+        TradingPair<T>,
+        BTreeSet<LiquiditySourceType>,
+    >;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub trading_pairs: Vec<(T::DEXId, TradingPair<T>)>,
@@ -76,6 +76,43 @@ fn should_not_register_trading_pair_with_wrong_base_asset() {
     });
 }
 
+#[test]
+fn should_register_trading_pair_of_non_base_assets() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(TradingPairModule::register(
+            Origin::signed(ALICE),
+            DEX_ID,
+            DOT,
+            KSM
+        ));
+        let pair = TradingPair::canonical(&XOR, DOT, KSM);
+        assert_eq!(
+            TradingPairModule::list_trading_pairs(&DEX_ID).expect("Failed to list trading pairs."),
+            vec![pair]
+        );
+        assert!(TradingPairModule::is_trading_pair_enabled(&DEX_ID, &DOT, &KSM).unwrap());
+        assert!(TradingPairModule::is_trading_pair_enabled(&DEX_ID, &KSM, &DOT).unwrap());
+        assert_noop!(
+            TradingPairModule::register(Origin::signed(ALICE), DEX_ID, KSM, DOT),
+            Error::<Runtime>::TradingPairExists
+        );
+        assert_ok!(TradingPairModule::enable_source_for_trading_pair(
+            &DEX_ID,
+            &KSM,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert!(TradingPairModule::is_source_enabled_for_trading_pair(
+            &DEX_ID,
+            &DOT,
+            &KSM,
+            LiquiditySourceType::XYKPool
+        )
+        .unwrap());
+    });
+}
+
 #[test]
 fn should_not_register_trading_pair_with_same_assets() {
     let mut ext = ExtBuilder::default().build();
@@ -363,3 +400,286 @@ fn should_fail_with_nonexistent_dex() {
         );
     });
 }
+
+#[test]
+fn should_disable_source_for_trading_pair() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(TradingPairModule::register(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert_ok!(TradingPairModule::enable_source_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert_ok!(TradingPairModule::enable_source_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::MockPool
+        ));
+        assert_noop!(
+            TradingPairModule::disable_source(
+                Origin::signed(BOB),
+                DEX_ID,
+                XOR,
+                DOT,
+                LiquiditySourceType::XYKPool
+            ),
+            permissions::Error::<Runtime>::Forbidden
+        );
+        assert_ok!(TradingPairModule::disable_source(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert_eq!(
+            TradingPairModule::list_enabled_sources_for_trading_pair(&DEX_ID, &XOR, &DOT)
+                .expect("Failed to list enabled sources."),
+            vec![LiquiditySourceType::MockPool].into_iter().collect()
+        );
+        assert_noop!(
+            TradingPairModule::disable_source(
+                Origin::root(),
+                DEX_ID,
+                XOR,
+                DOT,
+                LiquiditySourceType::XYKPool
+            ),
+            Error::<Runtime>::SourceIsNotEnabled
+        );
+        assert_noop!(
+            TradingPairModule::disable_source(
+                Origin::root(),
+                DEX_ID,
+                XOR,
+                KSM,
+                LiquiditySourceType::XYKPool
+            ),
+            Error::<Runtime>::TradingPairDoesntExist
+        );
+    });
+}
+
+#[test]
+fn should_enable_disabled_source_for_trading_pair() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(TradingPairModule::register(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert_ok!(TradingPairModule::enable_source_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert_ok!(TradingPairModule::disable_source(
+            Origin::root(),
+            DEX_ID,
+            XOR,
+            DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert_noop!(
+            TradingPairModule::enable_source(
+                Origin::signed(BOB),
+                DEX_ID,
+                XOR,
+                DOT,
+                LiquiditySourceType::XYKPool
+            ),
+            permissions::Error::<Runtime>::Forbidden
+        );
+        assert_ok!(TradingPairModule::enable_source(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert_eq!(
+            TradingPairModule::list_enabled_sources_for_trading_pair(&DEX_ID, &XOR, &DOT)
+                .expect("Failed to list enabled sources."),
+            vec![LiquiditySourceType::XYKPool].into_iter().collect()
+        );
+        assert_noop!(
+            TradingPairModule::enable_source(
+                Origin::root(),
+                DEX_ID,
+                XOR,
+                DOT,
+                LiquiditySourceType::XYKPool
+            ),
+            Error::<Runtime>::SourceIsAlreadyEnabled
+        );
+        assert_noop!(
+            TradingPairModule::enable_source(
+                Origin::root(),
+                DEX_ID,
+                XOR,
+                KSM,
+                LiquiditySourceType::XYKPool
+            ),
+            Error::<Runtime>::TradingPairDoesntExist
+        );
+    });
+}
+
+#[test]
+fn should_pause_and_resume_trading_pair() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(TradingPairModule::register(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert_ok!(TradingPairModule::enable_source_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert_noop!(
+            TradingPairModule::resume(Origin::root(), DEX_ID, XOR, DOT),
+            Error::<Runtime>::TradingPairIsNotPaused
+        );
+        assert_ok!(TradingPairModule::pause(Origin::root(), DEX_ID, XOR, DOT));
+        assert!(TradingPairModule::is_trading_pair_paused(
+            &DEX_ID, &XOR, &DOT
+        ));
+        assert!(TradingPairModule::is_trading_paused(&DEX_ID, &DOT, &XOR));
+        assert!(
+            TradingPairModule::list_enabled_sources_for_trading_pair(&DEX_ID, &XOR, &DOT)
+                .expect("Failed to list enabled sources.")
+                .is_empty()
+        );
+        assert!(!TradingPairModule::is_source_enabled_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        )
+        .expect("Failed to query source state."));
+        assert_noop!(
+            TradingPairModule::pause(Origin::signed(ALICE), DEX_ID, XOR, DOT),
+            Error::<Runtime>::TradingPairIsPaused
+        );
+
+        assert_ok!(TradingPairModule::resume(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert!(!TradingPairModule::is_trading_paused(&DEX_ID, &XOR, &DOT));
+        assert!(TradingPairModule::is_source_enabled_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        )
+        .expect("Failed to query source state."));
+    });
+}
+
+#[test]
+fn should_delist_trading_pair() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(TradingPairModule::register(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert_ok!(TradingPairModule::pause(Origin::root(), DEX_ID, XOR, DOT));
+        assert_noop!(
+            TradingPairModule::delist(Origin::signed(BOB), DEX_ID, XOR, DOT),
+            permissions::Error::<Runtime>::Forbidden
+        );
+        assert_ok!(TradingPairModule::delist(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert!(!TradingPairModule::is_trading_pair_enabled(&DEX_ID, &XOR, &DOT).unwrap());
+        assert!(!TradingPairModule::is_trading_pair_paused(
+            &DEX_ID, &XOR, &DOT
+        ));
+        assert_noop!(
+            TradingPairModule::delist(Origin::root(), DEX_ID, XOR, DOT),
+            Error::<Runtime>::TradingPairDoesntExist
+        );
+        // The pair can be listed again afterwards.
+        assert_ok!(TradingPairModule::register(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert_noop!(
+            TradingPairModule::relist(Origin::root(), DEX_ID, XOR, DOT),
+            Error::<Runtime>::TradingPairIsNotDelisted
+        );
+    });
+}
+
+#[test]
+fn should_relist_trading_pair_with_its_sources() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        assert_ok!(TradingPairModule::register(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert_ok!(TradingPairModule::enable_source_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        ));
+        assert_noop!(
+            TradingPairModule::relist(Origin::root(), DEX_ID, XOR, DOT),
+            Error::<Runtime>::TradingPairIsNotDelisted
+        );
+        assert_ok!(TradingPairModule::delist(Origin::root(), DEX_ID, XOR, DOT));
+        assert_noop!(
+            TradingPairModule::relist(Origin::signed(BOB), DEX_ID, XOR, DOT),
+            permissions::Error::<Runtime>::Forbidden
+        );
+        assert_ok!(TradingPairModule::relist(
+            Origin::signed(ALICE),
+            DEX_ID,
+            XOR,
+            DOT
+        ));
+        assert!(TradingPairModule::is_trading_pair_enabled(&DEX_ID, &XOR, &DOT).unwrap());
+        assert!(TradingPairModule::is_source_enabled_for_trading_pair(
+            &DEX_ID,
+            &XOR,
+            &DOT,
+            LiquiditySourceType::XYKPool
+        )
+        .expect("Failed to query source state."));
+        assert_noop!(
+            TradingPairModule::relist(Origin::root(), DEX_ID, XOR, DOT),
+            Error::<Runtime>::TradingPairIsNotDelisted
+        );
+    });
+}
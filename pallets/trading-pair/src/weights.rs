@@ -38,12 +38,60 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
     fn register() -> Weight {
         (49_338_000 as Weight)
             .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn disable_source() -> Weight {
+        (38_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn enable_source() -> Weight {
+        (38_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn pause() -> Weight {
+        (36_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    fn resume() -> Weight {
+        (36_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(5 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn delist() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn relist() -> Weight {
+        (40_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(4 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
 }
 
 impl crate::WeightInfo for () {
     fn register() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+    fn disable_source() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn enable_source() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn pause() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn resume() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn delist() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+    fn relist() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
 }
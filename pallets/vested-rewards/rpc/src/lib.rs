@@ -0,0 +1,128 @@
+// This file is part of the SORA network and Polkaswap app.
+
+// Copyright (c) 2020, 2021, Polka Biome Ltd. All rights reserved.
+// SPDX-License-Identifier: BSD-4-Clause
+
+// Redistribution and use in source and binary forms, with or without modification,
+// are permitted provided that the following conditions are met:
+
+// Redistributions of source code must retain the above copyright notice, this list
+// of conditions and the following disclaimer.
+// Redistributions in binary form must reproduce the above copyright notice, this
+// list of conditions and the following disclaimer in the documentation and/or other
+// materials provided with the distribution.
+//
+// All advertising materials mentioning features or use of this software must display
+// the following acknowledgement: This product includes software developed by Polka Biome
+// Ltd., SORA, and Polkaswap.
+//
+// Neither the name of the Polka Biome Ltd. nor the names of its contributors may be used
+// to endorse or promote products derived from this software without specific prior written permission.
+
+// THIS SOFTWARE IS PROVIDED BY Polka Biome Ltd. AS IS AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR
+// A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL Polka Biome Ltd. BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING,
+// BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS;
+// OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use codec::Codec;
+
+use common::InvokeRPCError;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay, MaybeFromStr};
+
+use std::sync::Arc;
+
+// Runtime API imports.
+pub use vested_rewards_runtime_api::VestedRewardsAPI as VestedRewardsRuntimeAPI;
+use vested_rewards_runtime_api::{MarketMakerInfo, PendingRewardInfo};
+
+#[rpc]
+pub trait VestedRewardsAPI<BlockHash, AccountId, PendingRewardInfoVec, MarketMakerInfo> {
+    #[rpc(name = "vestedRewards_pendingRewards")]
+    fn pending_rewards(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<PendingRewardInfoVec>;
+
+    #[rpc(name = "vestedRewards_marketMakerInfo")]
+    fn market_maker_info(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<MarketMakerInfo>;
+}
+
+pub struct VestedRewardsClient<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> VestedRewardsClient<C, B> {
+    /// Construct default `Template`.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance>
+    VestedRewardsAPI<
+        <Block as BlockT>::Hash,
+        AccountId,
+        Vec<PendingRewardInfo<Balance>>,
+        MarketMakerInfo<Balance>,
+    > for VestedRewardsClient<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: VestedRewardsRuntimeAPI<Block, AccountId, Balance>,
+    AccountId: Codec,
+    Balance: Codec + MaybeFromStr + MaybeDisplay,
+{
+    fn pending_rewards(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<PendingRewardInfo<Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.pending_rewards(&at, account_id).map_err(|e| RpcError {
+            code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+            message: "Unable to get pending rewards.".into(),
+            data: Some(format!("{:?}", e).into()),
+        })
+    }
+
+    fn market_maker_info(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<MarketMakerInfo<Balance>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(at.unwrap_or(
+            // If the block hash is not supplied assume the best block.
+            self.client.info().best_hash,
+        ));
+        api.market_maker_info(&at, account_id)
+            .map_err(|e| RpcError {
+                code: ErrorCode::ServerError(InvokeRPCError::RuntimeError.into()),
+                message: "Unable to get market maker info.".into(),
+                data: Some(format!("{:?}", e).into()),
+            })
+    }
+}
@@ -36,7 +36,7 @@ use super::*;
 
 use codec::Decode;
 use frame_benchmarking::benchmarks;
-use frame_system::RawOrigin;
+use frame_system::{EventRecord, RawOrigin};
 use hex_literal::hex;
 use sp_std::prelude::*;
 use traits::MultiCurrency;
@@ -52,6 +52,14 @@ fn alice<T: Config>() -> T::AccountId {
     T::AccountId::decode(&mut &bytes[..]).unwrap_or_default()
 }
 
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+    let events = frame_system::Module::<T>::events();
+    let system_event: <T as frame_system::Config>::Event = generic_event.into();
+    // compare to the last event record
+    let EventRecord { event, .. } = &events[events.len() - 1];
+    assert_eq!(event, &system_event);
+}
+
 fn create_account<T: Config>(prefix: Vec<u8>, index: u128) -> T::AccountId {
     let tech_account: T::TechAccountId =
         T::TechAccountId::from_generic_pair(prefix, index.encode());
@@ -118,7 +126,7 @@ benchmarks! {
         let m in 0 .. 10000; // users non-eligible for mm rewards distribution
         prepare_pending_market_makers::<T>(n.into(), m.into());
     }: {
-        let p = Pallet::<T>::market_maker_rewards_distribution_routine();
+        let p = Pallet::<T>::end_market_maker_season(frame_system::Pallet::<T>::block_number());
         assert_eq!(p, n);
     }
     verify {
@@ -135,6 +143,43 @@ benchmarks! {
             );
         }
     }
+
+    set_payee {
+        let caller = alice::<T>();
+        let payee = create_account::<T>(b"payee".to_vec(), 0);
+    }: _(RawOrigin::Signed(caller.clone()), Some(payee.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::PayeeSet(caller, Some(payee)).into())
+    }
+
+    set_claim_delegate {
+        let caller = alice::<T>();
+        let delegate = create_account::<T>(b"delegate".to_vec(), 0);
+    }: _(RawOrigin::Signed(caller.clone()), Some(delegate.clone()))
+    verify {
+        assert_last_event::<T>(Event::<T>::ClaimDelegateSet(caller, Some(delegate)).into())
+    }
+
+    set_vesting_schedule {
+        let schedule = VestingSchedule::Linear(1000u32.into());
+    }: _(RawOrigin::Root, RewardReason::LiquidityProvisionFarming, schedule)
+    verify {
+        assert_last_event::<T>(
+            Event::<T>::VestingScheduleSet(RewardReason::LiquidityProvisionFarming, schedule).into()
+        )
+    }
+
+    set_market_maker_params {
+        let params = MarketMakerRewardsParams {
+            eligibility_tx_count: 1000,
+            min_tx_volume: balance!(1),
+            season_reward: balance!(1000),
+            season_duration: 1000u32.into(),
+        };
+    }: _(RawOrigin::Root, params.clone())
+    verify {
+        assert_last_event::<T>(Event::<T>::MarketMakerParamsChanged.into())
+    }
 }
 
 #[cfg(test)]
@@ -149,6 +194,11 @@ mod tests {
             assert_ok!(test_benchmark_claim_rewards::<Runtime>());
             assert_ok!(test_benchmark_distribute_limits::<Runtime>());
             assert_ok!(test_benchmark_distribute_market_maker_rewards::<Runtime>());
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            assert_ok!(test_benchmark_set_payee::<Runtime>());
+            assert_ok!(test_benchmark_set_claim_delegate::<Runtime>());
+            assert_ok!(test_benchmark_set_vesting_schedule::<Runtime>());
+            assert_ok!(test_benchmark_set_market_maker_params::<Runtime>());
         });
     }
 }
@@ -41,7 +41,8 @@ use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::{Get, IsType};
 use frame_support::weights::Weight;
 use frame_support::{fail, transactional};
-use sp_runtime::traits::Zero;
+use sp_runtime::helpers_128bit::multiply_by_rational;
+use sp_runtime::traits::{AtLeast32BitUnsigned, UniqueSaturatedInto, Zero};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
@@ -59,38 +60,238 @@ mod tests;
 
 pub const TECH_ACCOUNT_PREFIX: &[u8] = b"vested-rewards";
 pub const TECH_ACCOUNT_MARKET_MAKERS: &[u8] = b"market-makers";
+pub const TECH_ACCOUNT_FARMING: &[u8] = b"farming";
+/// Default parameters of the market makers rewards programme, see `MarketMakerRewardsParams`.
 pub const MARKET_MAKER_ELIGIBILITY_TX_COUNT: u32 = 500;
+pub const MARKET_MAKER_MIN_TX_VOLUME: Balance = balance!(1);
 pub const SINGLE_MARKET_MAKER_DISTRIBUTION_AMOUNT: Balance = balance!(20000000);
 pub const MARKET_MAKER_REWARDS_DISTRIBUTION_FREQUENCY: u32 = 432000;
+/// Maximum number of reward amounts of particular type which are vested by own schedules at the same time.
+pub const MAX_LOCKED_REWARDS: usize = 16;
 
 type Assets<T> = assets::Pallet<T>;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 /// Denotes PSWAP rewards amounts of particular types available for user.
 #[derive(Encode, Decode, Eq, PartialEq, Clone, PartialOrd, Ord, Debug, Default)]
-pub struct RewardInfo {
-    /// Reward amount vested, denotes portion of `total_avialable` which can be claimed.
+pub struct RewardInfo<BlockNumber> {
+    /// Reward amount vested by PSWAP burns, denotes portion of `total_avialable` which can be claimed.
     /// Reset to 0 after claim until more is vested over time.
     limit: Balance,
     /// Sum of reward amounts in `rewards`.
     total_available: Balance,
     /// Mapping between reward type represented by `RewardReason` and owned amount by user.
     pub rewards: BTreeMap<RewardReason, Balance>,
+    /// Vesting of the reward types with own vesting schedules, their amounts are vested
+    /// independently of `limit`.
+    pub vesting: BTreeMap<RewardReason, RewardVesting<BlockNumber>>,
+}
+
+impl<BlockNumber> RewardInfo<BlockNumber> {
+    /// Part of the reward amount of `reason` vested by PSWAP burns.
+    fn vested_by_burns(&self, reason: &RewardReason) -> Balance {
+        let amount = self.rewards.get(reason).cloned().unwrap_or_default();
+        match self.vesting.get(reason) {
+            Some(vesting) => amount.saturating_sub(vesting.total()),
+            None => amount,
+        }
+    }
+
+    /// Part of `total_available` vested by PSWAP burns.
+    fn total_vested_by_burns(&self) -> Balance {
+        self.vesting
+            .values()
+            .fold(self.total_available, |total, vesting| {
+                total.saturating_sub(vesting.total())
+            })
+    }
+}
+
+/// Vesting of PSWAP rewards of particular type according to its own schedule.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, PartialOrd, Ord, Debug, Default)]
+pub struct RewardVesting<BlockNumber> {
+    /// Reward amount vested, which can be claimed.
+    pub limit: Balance,
+    /// Reward amounts which are not vested yet, each is vested by the schedule it was added with.
+    pub locked: Vec<LockedReward<BlockNumber>>,
+}
+
+/// Reward amount which is not vested yet.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, PartialOrd, Ord, Debug, Default)]
+pub struct LockedReward<BlockNumber> {
+    /// Reward amount which is not vested yet.
+    pub amount: Balance,
+    /// Block `amount` is vested from.
+    pub start: BlockNumber,
+    /// Block `amount` is fully vested at.
+    pub end: BlockNumber,
+    /// Whether `amount` is vested at once at `end` rather than linearly.
+    pub cliff: bool,
+}
+
+impl<BlockNumber> RewardVesting<BlockNumber> {
+    /// Reward amount both vested and not.
+    pub fn total(&self) -> Balance {
+        self.locked.iter().fold(self.limit, |total, locked| {
+            total.saturating_add(locked.amount)
+        })
+    }
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> RewardVesting<BlockNumber> {
+    /// Moves the amounts vested by the block `now` from `locked` to `limit`.
+    pub fn vest(&mut self, now: BlockNumber) {
+        for locked in self.locked.iter_mut() {
+            self.limit = self.limit.saturating_add(locked.vest(now));
+        }
+        self.locked.retain(|locked| !locked.amount.is_zero());
+    }
+
+    /// Adds `amount` to be vested according to `schedule` starting from the block `now`.
+    /// Amounts which are not vested yet keep their own schedules, the new amount is merged only with
+    /// the one which is fully vested at the same block. When there are already `MAX_LOCKED_REWARDS`
+    /// amounts, the new amount is merged with the one which is fully vested last.
+    pub fn add(
+        &mut self,
+        amount: Balance,
+        schedule: VestingSchedule<BlockNumber>,
+        now: BlockNumber,
+    ) {
+        self.vest(now);
+        let (period, cliff) = match schedule {
+            VestingSchedule::Linear(period) => (period, false),
+            VestingSchedule::Cliff(period) => (period, true),
+            VestingSchedule::Immediate | VestingSchedule::PswapBurn => {
+                self.limit = self.limit.saturating_add(amount);
+                return;
+            }
+        };
+        let end = now.saturating_add(period);
+        if let Some(index) = self
+            .locked
+            .iter()
+            .position(|locked| locked.end == end && locked.cliff == cliff)
+        {
+            let locked = &mut self.locked[index];
+            locked.amount = locked.amount.saturating_add(amount);
+        } else if self.locked.len() < MAX_LOCKED_REWARDS {
+            self.locked.push(LockedReward {
+                amount,
+                start: now,
+                end,
+                cliff,
+            });
+        } else if let Some(locked) = self.locked.iter_mut().max_by_key(|locked| locked.end) {
+            locked.amount = locked.amount.saturating_add(amount);
+        }
+    }
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> LockedReward<BlockNumber> {
+    /// Vests the amount by the block `now` and returns the vested part.
+    pub fn vest(&mut self, now: BlockNumber) -> Balance {
+        let vested = if now >= self.end {
+            self.amount
+        } else if self.cliff || now <= self.start {
+            return 0;
+        } else {
+            let elapsed: u128 = (now - self.start).unique_saturated_into();
+            let duration: u128 = (self.end - self.start).unique_saturated_into();
+            multiply_by_rational(self.amount, elapsed, duration).unwrap_or_default()
+        };
+        self.amount = self.amount.saturating_sub(vested);
+        self.start = now;
+        vested
+    }
+}
+
+/// Vesting schedule of PSWAP rewards of particular type.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum VestingSchedule<BlockNumber> {
+    /// Rewards are vested along with PSWAP burns, proportionally to pending rewards of accounts.
+    PswapBurn,
+    /// Rewards are vested linearly over the number of blocks after they are added.
+    Linear(BlockNumber),
+    /// Rewards are vested at once when the number of blocks pass after they are added.
+    Cliff(BlockNumber),
+    /// Rewards are vested as soon as they are added.
+    Immediate,
+}
+
+impl<BlockNumber> Default for VestingSchedule<BlockNumber> {
+    fn default() -> Self {
+        Self::PswapBurn
+    }
 }
 
 /// Denotes information about users who make transactions counted for market makers strategic rewards
-/// programme. To participate in rewards distribution account needs to get 500+ tx's over 1 XOR in volume each.
+/// programme. To participate in rewards distribution account needs to get `eligibility_tx_count` tx's
+/// over `min_tx_volume` XOR in volume each.
 #[derive(Encode, Decode, Eq, PartialEq, Clone, PartialOrd, Ord, Debug, Default)]
 pub struct MarketMakerInfo {
-    /// Number of eligible transactions - namely those with individual volume over 1 XOR.
-    count: u32,
+    /// Number of eligible transactions - namely those with individual volume over `min_tx_volume` XOR.
+    pub count: u32,
     /// Cumulative volume of eligible transactions.
-    volume: Balance,
+    pub volume: Balance,
+}
+
+/// Parameters of the market makers strategic rewards programme.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+pub struct MarketMakerRewardsParams<BlockNumber> {
+    /// Number of transactions account needs to make within a season to be eligible for rewards.
+    pub eligibility_tx_count: u32,
+    /// Minimal XOR volume of transaction to be counted.
+    pub min_tx_volume: Balance,
+    /// Amount of PSWAP distributed between eligible accounts at the end of a season.
+    pub season_reward: Balance,
+    /// Duration of a season in blocks.
+    pub season_duration: BlockNumber,
+}
+
+impl<BlockNumber: From<u32>> Default for MarketMakerRewardsParams<BlockNumber> {
+    fn default() -> Self {
+        Self {
+            eligibility_tx_count: MARKET_MAKER_ELIGIBILITY_TX_COUNT,
+            min_tx_volume: MARKET_MAKER_MIN_TX_VOLUME,
+            season_reward: SINGLE_MARKET_MAKER_DISTRIBUTION_AMOUNT,
+            season_duration: MARKET_MAKER_REWARDS_DISTRIBUTION_FREQUENCY.into(),
+        }
+    }
+}
+
+/// Season of the market makers strategic rewards programme in progress.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug, Default)]
+pub struct MarketMakerSeason<BlockNumber> {
+    /// Sequential number of the season.
+    pub index: u32,
+    /// Block the season started at.
+    pub start: BlockNumber,
+}
+
+/// Results of the finished season of the market makers strategic rewards programme.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Debug)]
+pub struct MarketMakerSeasonInfo<BlockNumber> {
+    /// Block the season started at.
+    pub start: BlockNumber,
+    /// Block the season ended at.
+    pub end: BlockNumber,
+    /// Parameters of the programme the season ended with.
+    pub params: MarketMakerRewardsParams<BlockNumber>,
+    /// Number of accounts eligible for rewards.
+    pub eligible_accounts: u32,
+    /// Cumulative volume of eligible accounts.
+    pub total_eligible_volume: Balance,
+    /// Amount of PSWAP rewards distributed between eligible accounts.
+    pub distributed: Balance,
 }
 
 pub trait WeightInfo {
     fn claim_incentives() -> Weight;
     fn on_initialize(_n: u32) -> Weight;
+    fn set_payee() -> Weight;
+    fn set_claim_delegate() -> Weight;
+    fn set_vesting_schedule() -> Weight;
+    fn set_market_maker_params() -> Weight;
 }
 
 impl<T: Config> Pallet<T> {
@@ -98,43 +299,142 @@ impl<T: Config> Pallet<T> {
         account_id: &T::AccountId,
         reason: RewardReason,
         amount: Balance,
+    ) -> DispatchResult {
+        Self::add_reward(account_id, reason, amount, false)
+    }
+
+    /// Add reward which is already vested elsewhere, so it's claimable immediately
+    /// regardless of vesting schedule of the reward type.
+    pub fn add_vested_reward(
+        account_id: &T::AccountId,
+        reason: RewardReason,
+        amount: Balance,
+    ) -> DispatchResult {
+        Self::add_reward(account_id, reason, amount, true)
+    }
+
+    fn add_reward(
+        account_id: &T::AccountId,
+        reason: RewardReason,
+        amount: Balance,
+        vested: bool,
     ) -> DispatchResult {
         if !Rewards::<T>::contains_key(account_id) {
             frame_system::Pallet::<T>::inc_consumers(account_id)
                 .map_err(|_| Error::<T>::IncRefError)?;
         }
+        let schedule = VestingSchedules::<T>::get(reason);
         Rewards::<T>::mutate(account_id, |info| {
             info.total_available = info.total_available.saturating_add(amount);
             info.rewards
                 .entry(reason)
                 .and_modify(|e| *e = e.saturating_add(amount))
                 .or_insert(amount);
+            if schedule != VestingSchedule::PswapBurn {
+                let schedule = if vested {
+                    VestingSchedule::Immediate
+                } else {
+                    schedule
+                };
+                info.vesting.entry(reason).or_default().add(
+                    amount,
+                    schedule,
+                    frame_system::Pallet::<T>::block_number(),
+                );
+            } else if vested {
+                info.limit = info.limit.saturating_add(amount);
+            }
         });
-        TotalRewards::<T>::mutate(|balance| *balance = balance.saturating_add(amount));
+        if schedule == VestingSchedule::PswapBurn {
+            TotalRewards::<T>::mutate(|balance| *balance = balance.saturating_add(amount));
+        }
         Ok(())
     }
 
-    /// General claim function, which updates user reward status.
-    pub fn claim_rewards_inner(account_id: &T::AccountId) -> DispatchResult {
+    /// Pending and currently claimable reward amounts of the account for each reward type.
+    pub fn claimable_rewards(account_id: &T::AccountId) -> Vec<(RewardReason, Balance, Balance)> {
+        let mut info = Rewards::<T>::get(account_id);
+        let now = frame_system::Pallet::<T>::block_number();
+        info.vesting
+            .values_mut()
+            .for_each(|vesting| vesting.vest(now));
+        // Amount vested by PSWAP burns is claimed in order of the reward types
+        let mut limit = info.limit;
+        info.rewards
+            .iter()
+            .map(|(reason, &pending)| {
+                let claimable_by_burns = info.vested_by_burns(reason).min(limit);
+                limit = limit.saturating_sub(claimable_by_burns);
+                let claimable_by_schedule = info
+                    .vesting
+                    .get(reason)
+                    .map(|vesting| vesting.limit)
+                    .unwrap_or_default();
+                (
+                    *reason,
+                    pending,
+                    claimable_by_burns.saturating_add(claimable_by_schedule),
+                )
+            })
+            .collect()
+    }
+
+    /// Account the rewards of `account_id` are paid to when it doesn't specify the destination.
+    pub fn payee_of(account_id: &T::AccountId) -> T::AccountId {
+        Payees::<T>::get(account_id).unwrap_or_else(|| account_id.clone())
+    }
+
+    /// General claim function, which updates user reward status and pays claimed rewards to `destination`.
+    pub fn claim_rewards_inner(
+        account_id: &T::AccountId,
+        destination: &T::AccountId,
+    ) -> DispatchResult {
         let mut remove_after_mutate = false;
+        let now = frame_system::Pallet::<T>::block_number();
         let result = Rewards::<T>::mutate(account_id, |info| {
+            info.vesting
+                .values_mut()
+                .for_each(|vesting| vesting.vest(now));
             if info.total_available.is_zero() {
                 fail!(Error::<T>::NothingToClaim);
-            } else if info.limit.is_zero() {
+            } else if info.limit.is_zero()
+                && info.vesting.values().all(|vesting| vesting.limit.is_zero())
+            {
                 fail!(Error::<T>::ClaimLimitExceeded);
             } else {
                 let mut total_actual_claimed: Balance = 0;
-                for (&reward_reason, amount) in info.rewards.iter_mut() {
-                    let claimable = amount.clone().min(info.limit);
+                let mut total_claimed_by_burns: Balance = 0;
+                let reasons: Vec<RewardReason> = info.rewards.keys().cloned().collect();
+                for reward_reason in reasons {
+                    let claimable_by_burns = info.vested_by_burns(&reward_reason).min(info.limit);
+                    let claimable_by_schedule = info
+                        .vesting
+                        .get(&reward_reason)
+                        .map(|vesting| vesting.limit)
+                        .unwrap_or_default();
+                    let claimable = claimable_by_burns.saturating_add(claimable_by_schedule);
+                    if claimable.is_zero() {
+                        continue;
+                    }
                     let actual_claimed =
-                        Self::claim_reward_by_reason(account_id, reward_reason, claimable)
+                        Self::claim_reward_by_reason(destination, reward_reason, claimable)
                             .unwrap_or(balance!(0));
-                    info.limit = info.limit.saturating_sub(actual_claimed);
+                    // amount vested by own schedule is claimed first
+                    let claimed_by_schedule = actual_claimed.min(claimable_by_schedule);
+                    if let Some(vesting) = info.vesting.get_mut(&reward_reason) {
+                        vesting.limit = vesting.limit.saturating_sub(claimed_by_schedule);
+                    }
+                    let claimed_by_burns = actual_claimed.saturating_sub(claimed_by_schedule);
+                    info.limit = info.limit.saturating_sub(claimed_by_burns);
+                    total_claimed_by_burns =
+                        total_claimed_by_burns.saturating_add(claimed_by_burns);
                     total_actual_claimed = total_actual_claimed.saturating_add(actual_claimed);
                     if claimable > actual_claimed {
                         Self::deposit_event(Event::<T>::ActualDoesntMatchAvailable(reward_reason));
                     }
-                    *amount = amount.saturating_sub(actual_claimed);
+                    if let Some(amount) = info.rewards.get_mut(&reward_reason) {
+                        *amount = amount.saturating_sub(actual_claimed);
+                    }
                 }
                 // clear zeroed entries
                 // NOTE: .retain() is an unstable feature yet
@@ -144,12 +444,18 @@ impl<T: Config> Pallet<T> {
                     .into_iter()
                     .filter(|&(_, reward)| reward > balance!(0))
                     .collect();
+                info.vesting = info
+                    .vesting
+                    .clone()
+                    .into_iter()
+                    .filter(|(_, vesting)| !vesting.total().is_zero())
+                    .collect();
                 if total_actual_claimed.is_zero() {
                     fail!(Error::<T>::RewardsSupplyShortage);
                 }
                 info.total_available = info.total_available.saturating_sub(total_actual_claimed);
                 TotalRewards::<T>::mutate(|total| {
-                    *total = total.saturating_sub(total_actual_claimed)
+                    *total = total.saturating_sub(total_claimed_by_burns)
                 });
                 remove_after_mutate = info.total_available == 0;
                 Ok(())
@@ -170,7 +476,7 @@ impl<T: Config> Pallet<T> {
     ) -> Result<Balance, DispatchError> {
         let source_account = match reason {
             RewardReason::BuyOnBondingCurve => T::GetBondingCurveRewardsAccountId::get(),
-            // RewardReason::LiquidityProvisionFarming => T::GetFarmingRewardsAccountId::get(), // TODO: handle with farming rewards
+            RewardReason::LiquidityProvisionFarming => T::GetFarmingRewardsAccountId::get(),
             RewardReason::MarketMakerVolume => T::GetMarketMakerRewardsAccountId::get(),
             _ => fail!(Error::<T>::UnhandledRewardType),
         };
@@ -188,8 +494,10 @@ impl<T: Config> Pallet<T> {
 
         // if there's no accounts to vest, then amount is not utilized nor stored
         if !total_rewards.is_zero() {
-            Rewards::<T>::translate(|_key: T::AccountId, mut info: RewardInfo| {
-                let share_of_the_vested_amount = FixedWrapper::from(info.total_available)
+            Rewards::<T>::translate(|_key: T::AccountId, mut info: RewardInfo<T::BlockNumber>| {
+                // rewards with own vesting schedules don't take part in the distribution
+                let total_vested_by_burns = info.total_vested_by_burns();
+                let share_of_the_vested_amount = FixedWrapper::from(total_vested_by_burns)
                     * FixedWrapper::from(vested_amount)
                     / FixedWrapper::from(total_rewards);
 
@@ -198,19 +506,49 @@ impl<T: Config> Pallet<T> {
                     .unwrap_or(info.limit);
 
                 // don't vest more than available
-                info.limit = new_limit.min(info.total_available);
+                info.limit = new_limit.min(total_vested_by_burns);
                 Some(info)
             })
         };
     }
 
-    /// Returns number of accounts who received rewards.
-    pub fn market_maker_rewards_distribution_routine() -> u32 {
+    /// Ends the current season of the market makers rewards programme at the block `now`,
+    /// distributes its rewards and starts the next season.
+    /// Returns number of eligible accounts.
+    pub fn end_market_maker_season(now: T::BlockNumber) -> u32 {
+        let season = CurrentSeason::<T>::get();
+        let params = MarketMakerParams::<T>::get();
+        let (eligible_accounts, total_eligible_volume, distributed) =
+            Self::distribute_market_maker_rewards(&params);
+        SeasonsHistory::<T>::insert(
+            season.index,
+            MarketMakerSeasonInfo {
+                start: season.start,
+                end: now,
+                params,
+                eligible_accounts,
+                total_eligible_volume,
+                distributed,
+            },
+        );
+        CurrentSeason::<T>::put(MarketMakerSeason {
+            index: season.index.saturating_add(1),
+            start: now,
+        });
+        Self::deposit_event(Event::<T>::MarketMakerSeasonEnded(season.index));
+        eligible_accounts
+    }
+
+    /// Returns number of eligible accounts, their total volume and the amount of distributed rewards.
+    fn distribute_market_maker_rewards(
+        params: &MarketMakerRewardsParams<T::BlockNumber>,
+    ) -> (u32, Balance, Balance) {
         // collect list of accounts with volume info
         let mut eligible_accounts = Vec::new();
         let mut total_eligible_volume = balance!(0);
+        let mut distributed = balance!(0);
         for (account, info) in MarketMakersRegistry::<T>::drain() {
-            if info.count >= MARKET_MAKER_ELIGIBILITY_TX_COUNT {
+            if info.count >= params.eligibility_tx_count {
                 eligible_accounts.push((account, info.volume));
                 total_eligible_volume = total_eligible_volume.saturating_add(info.volume);
             }
@@ -219,7 +557,7 @@ impl<T: Config> Pallet<T> {
         if total_eligible_volume > 0 {
             for (account, volume) in eligible_accounts {
                 let reward = (FixedWrapper::from(volume)
-                    * FixedWrapper::from(SINGLE_MARKET_MAKER_DISTRIBUTION_AMOUNT)
+                    * FixedWrapper::from(params.season_reward)
                     / FixedWrapper::from(total_eligible_volume))
                 .try_into_balance()
                 .unwrap_or(0);
@@ -228,6 +566,8 @@ impl<T: Config> Pallet<T> {
                         Self::add_pending_reward(&account, RewardReason::MarketMakerVolume, reward);
                     if res.is_err() {
                         Self::deposit_event(Event::<T>::FailedToSaveCalculatedReward(account))
+                    } else {
+                        distributed = distributed.saturating_add(reward);
                     }
                 } else {
                     Self::deposit_event(Event::<T>::AddingZeroMarketMakerReward(account));
@@ -236,7 +576,30 @@ impl<T: Config> Pallet<T> {
         } else {
             Self::deposit_event(Event::<T>::NoEligibleMarketMakers);
         }
-        eligible_accounts_count.try_into().unwrap_or(u32::MAX)
+        (
+            eligible_accounts_count.try_into().unwrap_or(u32::MAX),
+            total_eligible_volume,
+            distributed,
+        )
+    }
+
+    /// Market maker reward the account would get if the current season ended now.
+    pub fn projected_market_maker_reward(account_id: &T::AccountId) -> Balance {
+        let params = MarketMakerParams::<T>::get();
+        let info = MarketMakersRegistry::<T>::get(account_id);
+        if info.count < params.eligibility_tx_count {
+            return balance!(0);
+        }
+        let total_eligible_volume = MarketMakersRegistry::<T>::iter_values()
+            .filter(|info| info.count >= params.eligibility_tx_count)
+            .fold(balance!(0), |total, info| total.saturating_add(info.volume));
+        if total_eligible_volume.is_zero() {
+            return balance!(0);
+        }
+        (FixedWrapper::from(info.volume) * FixedWrapper::from(params.season_reward)
+            / FixedWrapper::from(total_eligible_volume))
+        .try_into_balance()
+        .unwrap_or(0)
     }
 }
 
@@ -256,7 +619,7 @@ impl<T: Config> VestedRewardsPallet<T::AccountId> for Module<T> {
         xor_volume: Balance,
         count: u32,
     ) -> DispatchResult {
-        if xor_volume >= balance!(1) {
+        if xor_volume >= MarketMakerParams::<T>::get().min_tx_volume {
             MarketMakersRegistry::<T>::mutate(account_id, |info| {
                 info.count = info.count.saturating_add(count);
                 info.volume = info
@@ -271,6 +634,10 @@ impl<T: Config> VestedRewardsPallet<T::AccountId> for Module<T> {
         Pallet::<T>::add_pending_reward(account_id, RewardReason::BuyOnBondingCurve, pswap_amount)
     }
 
+    fn add_vested_tbc_reward(account_id: &T::AccountId, pswap_amount: Balance) -> DispatchResult {
+        Pallet::<T>::add_vested_reward(account_id, RewardReason::BuyOnBondingCurve, pswap_amount)
+    }
+
     fn add_farming_reward(account_id: &T::AccountId, pswap_amount: Balance) -> DispatchResult {
         Pallet::<T>::add_pending_reward(
             account_id,
@@ -302,7 +669,7 @@ pub mod pallet {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         /// Accounts holding PSWAP dedicated for rewards.
         type GetMarketMakerRewardsAccountId: Get<Self::AccountId>;
-        // type GetFarmingRewardsAccountId: Get<Self::AccountId>; // TODO: implement with farming rewards
+        type GetFarmingRewardsAccountId: Get<Self::AccountId>;
         type GetBondingCurveRewardsAccountId: Get<Self::AccountId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
@@ -319,8 +686,11 @@ pub mod pallet {
         }
 
         fn on_initialize(block_number: T::BlockNumber) -> Weight {
-            if (block_number % MARKET_MAKER_REWARDS_DISTRIBUTION_FREQUENCY.into()).is_zero() {
-                let elems = Module::<T>::market_maker_rewards_distribution_routine();
+            let season_end = CurrentSeason::<T>::get()
+                .start
+                .saturating_add(MarketMakerParams::<T>::get().season_duration);
+            if block_number >= season_end {
+                let elems = Module::<T>::end_market_maker_season(block_number);
                 <T as Config>::WeightInfo::on_initialize(elems)
             } else {
                 <T as Config>::WeightInfo::on_initialize(0)
@@ -331,11 +701,109 @@ pub mod pallet {
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Claim all available PSWAP rewards by account signing this transaction.
+        ///
+        /// Rewards are paid to the payee of the account if it's set.
         #[pallet::weight(<T as Config>::WeightInfo::claim_incentives())]
         #[transactional]
         pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            Self::claim_rewards_inner(&who)?;
+            Self::claim_rewards_inner(&who, &Self::payee_of(&who))?;
+            Ok(().into())
+        }
+
+        /// Claim all available PSWAP rewards by account signing this transaction to `destination`.
+        #[pallet::weight(<T as Config>::WeightInfo::claim_incentives())]
+        #[transactional]
+        pub fn claim_rewards_to(
+            origin: OriginFor<T>,
+            destination: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::claim_rewards_inner(&who, &destination)?;
+            Ok(().into())
+        }
+
+        /// Claim all available PSWAP rewards of `owner` by its claim delegate signing this transaction.
+        ///
+        /// Rewards are paid to the payee of `owner` if it's set, otherwise to `owner` itself.
+        #[pallet::weight(<T as Config>::WeightInfo::claim_incentives())]
+        #[transactional]
+        pub fn claim_rewards_for(
+            origin: OriginFor<T>,
+            owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                ClaimDelegates::<T>::get(&owner).as_ref() == Some(&who),
+                Error::<T>::NotClaimDelegate
+            );
+            Self::claim_rewards_inner(&owner, &Self::payee_of(&owner))?;
+            Ok(().into())
+        }
+
+        /// Set the account the rewards of the account signing this transaction are paid to.
+        /// `None` resets the payee to the account itself.
+        #[pallet::weight(<T as Config>::WeightInfo::set_payee())]
+        pub fn set_payee(
+            origin: OriginFor<T>,
+            payee: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            match payee.as_ref() {
+                Some(payee) if payee != &who => Payees::<T>::insert(&who, payee),
+                _ => Payees::<T>::remove(&who),
+            }
+            Self::deposit_event(Event::<T>::PayeeSet(who, payee));
+            Ok(().into())
+        }
+
+        /// Set the account allowed to claim the rewards of the account signing this transaction.
+        /// `None` removes the delegate.
+        #[pallet::weight(<T as Config>::WeightInfo::set_claim_delegate())]
+        pub fn set_claim_delegate(
+            origin: OriginFor<T>,
+            delegate: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            match delegate.as_ref() {
+                Some(delegate) => ClaimDelegates::<T>::insert(&who, delegate),
+                None => ClaimDelegates::<T>::remove(&who),
+            }
+            Self::deposit_event(Event::<T>::ClaimDelegateSet(who, delegate));
+            Ok(().into())
+        }
+
+        /// Set the vesting schedule of rewards of the type, it's applied to the rewards added afterwards.
+        ///
+        /// Can only be called by root.
+        #[pallet::weight(<T as Config>::WeightInfo::set_vesting_schedule())]
+        pub fn set_vesting_schedule(
+            origin: OriginFor<T>,
+            reason: RewardReason,
+            schedule: VestingSchedule<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            VestingSchedules::<T>::insert(reason, schedule);
+            Self::deposit_event(Event::<T>::VestingScheduleSet(reason, schedule));
+            Ok(().into())
+        }
+
+        /// Set parameters of the market makers strategic rewards programme, they're applied to
+        /// the current season.
+        ///
+        /// Can only be called by root.
+        #[pallet::weight(<T as Config>::WeightInfo::set_market_maker_params())]
+        pub fn set_market_maker_params(
+            origin: OriginFor<T>,
+            params: MarketMakerRewardsParams<T::BlockNumber>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ensure!(
+                params.eligibility_tx_count > 0 && !params.season_duration.is_zero(),
+                Error::<T>::InvalidMarketMakerParams
+            );
+            MarketMakerParams::<T>::put(params);
+            Self::deposit_event(Event::<T>::MarketMakerParamsChanged);
             Ok(().into())
         }
 
@@ -368,10 +836,14 @@ pub mod pallet {
         CantSubtractSnapshot,
         /// Failed to perform reward calculation.
         CantCalculateReward,
+        /// Account is not allowed to claim rewards of the owner.
+        NotClaimDelegate,
+        /// Market makers rewards programme requires non-zero number of transactions and season duration.
+        InvalidMarketMakerParams,
     }
 
     #[pallet::event]
-    #[pallet::metadata(AccountIdOf<T> = "AccountId")]
+    #[pallet::metadata(AccountIdOf<T> = "AccountId", VestingSchedule<BlockNumberFor<T>> = "VestingSchedule<BlockNumber>")]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Rewards vested, limits were raised. [vested amount]
@@ -384,6 +856,16 @@ pub mod pallet {
         AddingZeroMarketMakerReward(AccountIdOf<T>),
         /// Couldn't find any account with enough transactions to count market maker rewards.
         NoEligibleMarketMakers,
+        /// Account the rewards of the owner are paid to was set. [owner, payee]
+        PayeeSet(AccountIdOf<T>, Option<AccountIdOf<T>>),
+        /// Account allowed to claim the rewards of the owner was set. [owner, delegate]
+        ClaimDelegateSet(AccountIdOf<T>, Option<AccountIdOf<T>>),
+        /// Vesting schedule of rewards of the type was set. [reason for reward, schedule]
+        VestingScheduleSet(RewardReason, VestingSchedule<BlockNumberFor<T>>),
+        /// Parameters of the market makers strategic rewards programme were changed.
+        MarketMakerParamsChanged,
+        /// Season of the market makers strategic rewards programme ended and its rewards were distributed. [season index]
+        MarketMakerSeasonEnded(u32),
     }
 
     /// Reserved for future use
@@ -391,10 +873,10 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn rewards)]
     pub type Rewards<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, RewardInfo, ValueQuery>;
+        StorageMap<_, Blake2_128Concat, T::AccountId, RewardInfo<T::BlockNumber>, ValueQuery>;
 
     /// Reserved for future use
-    /// Total amount of PSWAP pending rewards.
+    /// Total amount of PSWAP pending rewards vested by PSWAP burns.
     #[pallet::storage]
     #[pallet::getter(fn total_rewards)]
     pub type TotalRewards<T: Config> = StorageValue<_, Balance, ValueQuery>;
@@ -404,4 +886,39 @@ pub mod pallet {
     #[pallet::getter(fn market_makers_registry)]
     pub type MarketMakersRegistry<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, MarketMakerInfo, ValueQuery>;
+
+    /// Accounts the rewards are paid to instead of their owners. Owner => Payee
+    #[pallet::storage]
+    #[pallet::getter(fn payee)]
+    pub type Payees<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+    /// Accounts allowed to claim the rewards on behalf of their owners. Owner => Delegate
+    #[pallet::storage]
+    #[pallet::getter(fn claim_delegate)]
+    pub type ClaimDelegates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+    /// Vesting schedules of the reward types.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedule)]
+    pub type VestingSchedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, RewardReason, VestingSchedule<T::BlockNumber>, ValueQuery>;
+
+    /// Parameters of the market makers strategic rewards programme.
+    #[pallet::storage]
+    #[pallet::getter(fn market_maker_params)]
+    pub type MarketMakerParams<T: Config> =
+        StorageValue<_, MarketMakerRewardsParams<T::BlockNumber>, ValueQuery>;
+
+    /// Season of the market makers strategic rewards programme in progress.
+    #[pallet::storage]
+    #[pallet::getter(fn current_season)]
+    pub type CurrentSeason<T: Config> =
+        StorageValue<_, MarketMakerSeason<T::BlockNumber>, ValueQuery>;
+
+    /// Results of the finished seasons of the market makers strategic rewards programme. Season index => Results
+    #[pallet::storage]
+    #[pallet::getter(fn season_info)]
+    pub type SeasonsHistory<T: Config> =
+        StorageMap<_, Twox64Concat, u32, MarketMakerSeasonInfo<T::BlockNumber>>;
 }
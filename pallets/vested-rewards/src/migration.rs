@@ -29,8 +29,9 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::{
-    Config, Error, MarketMakersRegistry, Pallet, RewardInfo, Weight,
-    MARKET_MAKER_ELIGIBILITY_TX_COUNT, SINGLE_MARKET_MAKER_DISTRIBUTION_AMOUNT,
+    Config, CurrentSeason, Error, MarketMakerSeason, MarketMakersRegistry, Pallet, RewardInfo,
+    Weight, MARKET_MAKER_ELIGIBILITY_TX_COUNT, MARKET_MAKER_REWARDS_DISTRIBUTION_FREQUENCY,
+    SINGLE_MARKET_MAKER_DISTRIBUTION_AMOUNT,
 };
 use common::prelude::{Balance, FixedWrapper};
 use common::{balance, fixed_wrapper, RewardReason};
@@ -38,6 +39,7 @@ use frame_support::debug;
 use frame_support::traits::{Get, GetPalletVersion, PalletVersion};
 use sp_runtime::traits::Zero;
 use sp_runtime::DispatchError;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec::Vec;
 
 pub fn migrate<T: Config>() -> Weight {
@@ -49,6 +51,13 @@ pub fn migrate<T: Config>() -> Weight {
         Some(version) if version == PalletVersion::new(0, 1, 0) => {
             let migrated_weight = migrate_rewards_from_tbc::<T>().unwrap_or(100_000);
             weight = weight.saturating_add(migrated_weight);
+            weight = weight.saturating_add(init_market_maker_season::<T>());
+        }
+        // Version 1.3.0 adds vesting of reward types with own vesting schedules to rewards,
+        // moves market makers rewards programme parameters to storage and distributes rewards by seasons
+        Some(version) if version == PalletVersion::new(1, 1, 0) => {
+            weight = weight.saturating_add(add_rewards_vesting::<T>());
+            weight = weight.saturating_add(init_market_maker_season::<T>());
         }
         _ => (),
     }
@@ -56,6 +65,31 @@ pub fn migrate<T: Config>() -> Weight {
     weight
 }
 
+/// Aligns the first season with the previously used fixed distribution schedule, so that the
+/// rewards are distributed at the same block as before the upgrade.
+pub fn init_market_maker_season<T: Config>() -> Weight {
+    let now = frame_system::Pallet::<T>::block_number();
+    let start = now - now % MARKET_MAKER_REWARDS_DISTRIBUTION_FREQUENCY.into();
+    CurrentSeason::<T>::put(MarketMakerSeason { index: 0, start });
+    T::DbWeight::get().reads_writes(1, 1)
+}
+
+pub fn add_rewards_vesting<T: Config>() -> Weight {
+    let mut weight: Weight = 0;
+    crate::Rewards::<T>::translate::<(Balance, Balance, BTreeMap<RewardReason, Balance>), _>(
+        |_, (limit, total_available, rewards)| {
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+            Some(RewardInfo {
+                limit,
+                total_available,
+                rewards,
+                vesting: Default::default(),
+            })
+        },
+    );
+    weight
+}
+
 pub fn migrate_rewards_from_tbc<T: Config>() -> Option<Weight> {
     let mut weight: Weight = 0;
     let mut calculated_total_rewards = Balance::zero();
@@ -75,6 +109,7 @@ pub fn migrate_rewards_from_tbc<T: Config>() -> Option<Weight> {
                 .iter()
                 .cloned()
                 .collect(),
+            vesting: Default::default(),
         };
         // Assuming target storage is empty before migration.
         crate::Rewards::<T>::insert(account, reward_info);
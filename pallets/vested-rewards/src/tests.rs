@@ -29,11 +29,15 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::mock::*;
-use crate::{Error, MarketMakerInfo, RewardInfo, MARKET_MAKER_REWARDS_DISTRIBUTION_FREQUENCY};
+use crate::{
+    Error, MarketMakerInfo, MarketMakerRewardsParams, MarketMakerSeason, MarketMakerSeasonInfo,
+    RewardInfo, VestingSchedule, MARKET_MAKER_REWARDS_DISTRIBUTION_FREQUENCY,
+};
 use common::{
     balance, Balance, OnPswapBurned, PswapRemintInfo, RewardReason, VestedRewardsPallet, PSWAP,
 };
 use frame_support::assert_noop;
+use frame_support::sp_runtime::DispatchError;
 use frame_support::traits::OnInitialize;
 use sp_std::collections::btree_map::BTreeMap;
 use traits::currency::MultiCurrency;
@@ -163,7 +167,8 @@ fn migration_v0_1_0_to_v1_1_0_bonding_curve() {
             crate::RewardInfo {
                 limit: 0,
                 total_available: 0,
-                rewards: BTreeMap::new()
+                rewards: BTreeMap::new(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -171,7 +176,8 @@ fn migration_v0_1_0_to_v1_1_0_bonding_curve() {
             crate::RewardInfo {
                 limit: 0,
                 total_available: 0,
-                rewards: BTreeMap::new()
+                rewards: BTreeMap::new(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -179,7 +185,8 @@ fn migration_v0_1_0_to_v1_1_0_bonding_curve() {
             crate::RewardInfo {
                 limit: 0,
                 total_available: 0,
-                rewards: BTreeMap::new()
+                rewards: BTreeMap::new(),
+                vesting: Default::default(),
             }
         );
 
@@ -206,7 +213,8 @@ fn migration_v0_1_0_to_v1_1_0_bonding_curve() {
                 rewards: [(RewardReason::BuyOnBondingCurve, balance!(680))]
                     .iter()
                     .cloned()
-                    .collect()
+                    .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -217,7 +225,8 @@ fn migration_v0_1_0_to_v1_1_0_bonding_curve() {
                 rewards: [(RewardReason::BuyOnBondingCurve, balance!(68))]
                     .iter()
                     .cloned()
-                    .collect()
+                    .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -228,7 +237,8 @@ fn migration_v0_1_0_to_v1_1_0_bonding_curve() {
                 rewards: [(RewardReason::BuyOnBondingCurve, balance!(6.8))]
                     .iter()
                     .cloned()
-                    .collect()
+                    .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -307,7 +317,8 @@ fn migration_v0_1_0_to_v1_1_0_market_makers() {
                 rewards: [(RewardReason::MarketMakerVolume, balance!(8000000))]
                     .iter()
                     .cloned()
-                    .collect()
+                    .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -318,7 +329,8 @@ fn migration_v0_1_0_to_v1_1_0_market_makers() {
                 rewards: [(RewardReason::MarketMakerVolume, balance!(12000000))]
                     .iter()
                     .cloned()
-                    .collect()
+                    .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -326,7 +338,8 @@ fn migration_v0_1_0_to_v1_1_0_market_makers() {
             RewardInfo {
                 limit: 0,
                 total_available: balance!(0),
-                rewards: Default::default()
+                rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
     });
@@ -391,6 +404,7 @@ fn claiming_single_user() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(0));
@@ -404,6 +418,7 @@ fn claiming_single_user() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -413,6 +428,36 @@ fn claiming_single_user() {
     });
 }
 
+#[test]
+fn claiming_vested_tbc_reward_immediately() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        deposit_rewards_to_reserves(balance!(1000));
+        VestedRewards::add_vested_tbc_reward(&alice(), balance!(30))
+            .expect("Failed to add reward.");
+        assert_eq!(
+            VestedRewards::rewards(&alice()),
+            RewardInfo {
+                limit: balance!(30),
+                total_available: balance!(30),
+                rewards: [(RewardReason::BuyOnBondingCurve, balance!(30))]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                vesting: Default::default(),
+            }
+        );
+        assert_eq!(VestedRewards::total_rewards(), balance!(30));
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(VestedRewards::rewards(&alice()), Default::default());
+        assert_eq!(VestedRewards::total_rewards(), balance!(0));
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(30)
+        );
+    });
+}
+
 #[test]
 fn claiming_single_user_multiple_rewards() {
     let mut ext = ExtBuilder::default().build();
@@ -437,6 +482,7 @@ fn claiming_single_user_multiple_rewards() {
                 .iter()
                 .cloned()
                 .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(0));
@@ -450,6 +496,7 @@ fn claiming_single_user_multiple_rewards() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -491,6 +538,7 @@ fn claiming_multiple_users() {
                 .iter()
                 .cloned()
                 .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -505,6 +553,7 @@ fn claiming_multiple_users() {
                 .iter()
                 .cloned()
                 .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -519,6 +568,7 @@ fn claiming_multiple_users() {
                 .iter()
                 .cloned()
                 .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(0));
@@ -533,6 +583,7 @@ fn claiming_multiple_users() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -541,6 +592,7 @@ fn claiming_multiple_users() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -549,6 +601,7 @@ fn claiming_multiple_users() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(3));
@@ -581,6 +634,7 @@ fn sequential_claims_until_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         // no claim yet, another portion of reward is vested
@@ -597,6 +651,7 @@ fn sequential_claims_until_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         // user claims existing reward
@@ -611,6 +666,7 @@ fn sequential_claims_until_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -631,6 +687,7 @@ fn sequential_claims_until_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         // remaining portion is vested
@@ -647,6 +704,7 @@ fn sequential_claims_until_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         // trying to claim remaining amount, amount is limited because reserves are depleted
@@ -660,6 +718,7 @@ fn sequential_claims_until_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -679,6 +738,7 @@ fn sequential_claims_until_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -715,6 +775,7 @@ fn some_rewards_reserves_are_depleted() {
                 .iter()
                 .cloned()
                 .collect(),
+                vesting: Default::default(),
             }
         );
         VestedRewards::claim_rewards(Origin::signed(alice())).unwrap();
@@ -727,6 +788,7 @@ fn some_rewards_reserves_are_depleted() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_noop!(
@@ -736,6 +798,40 @@ fn some_rewards_reserves_are_depleted() {
     });
 }
 
+#[test]
+fn should_claim_farming_rewards() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        Currencies::deposit(PSWAP, &GetFarmingRewardsAccountId::get(), balance!(100)).unwrap();
+        VestedRewards::add_farming_reward(&alice(), balance!(30)).expect("Failed to add reward.");
+        VestedRewards::on_pswap_burned(PswapRemintInfo {
+            vesting: balance!(20),
+            ..Default::default()
+        });
+        VestedRewards::claim_rewards(Origin::signed(alice())).unwrap();
+        assert_eq!(
+            VestedRewards::rewards(&alice()),
+            RewardInfo {
+                limit: balance!(0),
+                total_available: balance!(10),
+                rewards: [(RewardReason::LiquidityProvisionFarming, balance!(10))]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                vesting: Default::default(),
+            }
+        );
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(20)
+        );
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &GetFarmingRewardsAccountId::get()).unwrap(),
+            balance!(80)
+        );
+    });
+}
+
 #[test]
 fn all_rewards_reserves_are_depleted() {
     let mut ext = ExtBuilder::default().build();
@@ -765,6 +861,7 @@ fn all_rewards_reserves_are_depleted() {
                 .iter()
                 .cloned()
                 .collect(),
+                vesting: Default::default(),
             }
         );
     });
@@ -792,6 +889,7 @@ fn claiming_without_rewards() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_noop!(
@@ -844,6 +942,7 @@ fn empty_reward_entries_are_removed() {
                 .iter()
                 .cloned()
                 .collect(),
+                vesting: Default::default(),
             }
         );
         VestedRewards::claim_rewards(Origin::signed(alice())).unwrap();
@@ -857,6 +956,7 @@ fn empty_reward_entries_are_removed() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
     });
@@ -887,6 +987,7 @@ fn accounts_with_no_rewards_are_removed() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         let accounts: Vec<_> = crate::Rewards::<Runtime>::iter().collect();
@@ -900,6 +1001,7 @@ fn accounts_with_no_rewards_are_removed() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
 
@@ -931,6 +1033,7 @@ fn distributing_with_all_eligible_accounts() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -939,6 +1042,7 @@ fn distributing_with_all_eligible_accounts() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -947,6 +1051,7 @@ fn distributing_with_all_eligible_accounts() {
                 limit: balance!(0),
                 total_available: balance!(0),
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -984,6 +1089,7 @@ fn distributing_with_all_eligible_accounts() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -995,6 +1101,7 @@ fn distributing_with_all_eligible_accounts() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -1006,6 +1113,7 @@ fn distributing_with_all_eligible_accounts() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         // close to 20M but with precision mismatch
@@ -1088,6 +1196,7 @@ fn distributing_with_partially_eligible_accounts() {
                 limit: balance!(0),
                 total_available: reward_alice,
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -1096,6 +1205,7 @@ fn distributing_with_partially_eligible_accounts() {
                 limit: balance!(0),
                 total_available: reward_bob,
                 rewards: Default::default(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(
@@ -1107,6 +1217,7 @@ fn distributing_with_partially_eligible_accounts() {
                     .iter()
                     .cloned()
                     .collect(),
+                vesting: Default::default(),
             }
         );
         assert_eq!(reward_alice + reward_bob + reward_eve, balance!(20000000));
@@ -1227,3 +1338,427 @@ fn distributing_with_no_accounts_is_postponed() {
         );
     });
 }
+
+#[test]
+fn claiming_to_destination() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        deposit_rewards_to_reserves(balance!(1000));
+        VestedRewards::add_tbc_reward(&alice(), balance!(100)).expect("Failed to add reward.");
+        VestedRewards::on_pswap_burned(PswapRemintInfo {
+            vesting: balance!(12),
+            ..Default::default()
+        });
+        VestedRewards::claim_rewards_to(Origin::signed(alice()), bob()).expect("Failed to claim");
+        assert_eq!(
+            VestedRewards::rewards(&alice()).total_available,
+            balance!(88)
+        );
+        assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(0));
+        assert_eq!(Assets::free_balance(&PSWAP, &bob()).unwrap(), balance!(12));
+    });
+}
+
+#[test]
+fn claiming_to_payee() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        deposit_rewards_to_reserves(balance!(1000));
+        VestedRewards::add_tbc_reward(&alice(), balance!(100)).expect("Failed to add reward.");
+        VestedRewards::set_payee(Origin::signed(alice()), Some(bob())).unwrap();
+        assert_eq!(VestedRewards::payee(&alice()), Some(bob()));
+        VestedRewards::on_pswap_burned(PswapRemintInfo {
+            vesting: balance!(12),
+            ..Default::default()
+        });
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(Assets::free_balance(&PSWAP, &alice()).unwrap(), balance!(0));
+        assert_eq!(Assets::free_balance(&PSWAP, &bob()).unwrap(), balance!(12));
+
+        VestedRewards::set_payee(Origin::signed(alice()), None).unwrap();
+        assert_eq!(VestedRewards::payee(&alice()), None);
+        VestedRewards::on_pswap_burned(PswapRemintInfo {
+            vesting: balance!(12),
+            ..Default::default()
+        });
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(12)
+        );
+    });
+}
+
+#[test]
+fn claiming_by_delegate() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        deposit_rewards_to_reserves(balance!(1000));
+        VestedRewards::add_tbc_reward(&alice(), balance!(100)).expect("Failed to add reward.");
+        VestedRewards::on_pswap_burned(PswapRemintInfo {
+            vesting: balance!(24),
+            ..Default::default()
+        });
+        assert_noop!(
+            VestedRewards::claim_rewards_for(Origin::signed(bob()), alice()),
+            Error::<Runtime>::NotClaimDelegate
+        );
+
+        VestedRewards::set_claim_delegate(Origin::signed(alice()), Some(bob())).unwrap();
+        VestedRewards::claim_rewards_for(Origin::signed(bob()), alice()).expect("Failed to claim");
+        // delegate can't redirect the rewards
+        assert_eq!(Assets::free_balance(&PSWAP, &bob()).unwrap(), balance!(0));
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(24)
+        );
+
+        VestedRewards::set_claim_delegate(Origin::signed(alice()), None).unwrap();
+        assert_noop!(
+            VestedRewards::claim_rewards_for(Origin::signed(bob()), alice()),
+            Error::<Runtime>::NotClaimDelegate
+        );
+    });
+}
+
+#[test]
+fn linear_vesting_schedule() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        Currencies::deposit(PSWAP, &GetFarmingRewardsAccountId::get(), balance!(1000)).unwrap();
+        assert_noop!(
+            VestedRewards::set_vesting_schedule(
+                Origin::signed(alice()),
+                RewardReason::LiquidityProvisionFarming,
+                VestingSchedule::Linear(100)
+            ),
+            DispatchError::BadOrigin
+        );
+        VestedRewards::set_vesting_schedule(
+            Origin::root(),
+            RewardReason::LiquidityProvisionFarming,
+            VestingSchedule::Linear(100),
+        )
+        .unwrap();
+
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        VestedRewards::add_farming_reward(&alice(), balance!(100)).expect("Failed to add reward.");
+        assert_eq!(VestedRewards::total_rewards(), balance!(0));
+        // rewards with own schedule are not vested by PSWAP burns
+        VestedRewards::on_pswap_burned(PswapRemintInfo {
+            vesting: balance!(100),
+            ..Default::default()
+        });
+        assert_eq!(
+            VestedRewards::claimable_rewards(&alice()),
+            vec![(
+                RewardReason::LiquidityProvisionFarming,
+                balance!(100),
+                balance!(0)
+            )]
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(26);
+        assert_eq!(
+            VestedRewards::claimable_rewards(&alice()),
+            vec![(
+                RewardReason::LiquidityProvisionFarming,
+                balance!(100),
+                balance!(25)
+            )]
+        );
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(25)
+        );
+        assert_noop!(
+            VestedRewards::claim_rewards(Origin::signed(alice())),
+            Error::<Runtime>::ClaimLimitExceeded
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(51);
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(50)
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(200);
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(100)
+        );
+        assert_eq!(VestedRewards::rewards(&alice()), Default::default());
+    });
+}
+
+#[test]
+fn added_rewards_dont_extend_vesting_of_locked_rewards() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        Currencies::deposit(PSWAP, &GetFarmingRewardsAccountId::get(), balance!(1000)).unwrap();
+        VestedRewards::set_vesting_schedule(
+            Origin::root(),
+            RewardReason::LiquidityProvisionFarming,
+            VestingSchedule::Linear(100),
+        )
+        .unwrap();
+
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        VestedRewards::add_farming_reward(&alice(), balance!(100)).expect("Failed to add reward.");
+        frame_system::Pallet::<Runtime>::set_block_number(51);
+        VestedRewards::add_farming_reward(&alice(), balance!(100)).expect("Failed to add reward.");
+        assert_eq!(
+            VestedRewards::claimable_rewards(&alice()),
+            vec![(
+                RewardReason::LiquidityProvisionFarming,
+                balance!(200),
+                balance!(50)
+            )]
+        );
+
+        // the first reward is fully vested by its own schedule
+        frame_system::Pallet::<Runtime>::set_block_number(101);
+        assert_eq!(
+            VestedRewards::claimable_rewards(&alice()),
+            vec![(
+                RewardReason::LiquidityProvisionFarming,
+                balance!(200),
+                balance!(150)
+            )]
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(151);
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(200)
+        );
+    });
+}
+
+#[test]
+fn cliff_vesting_schedule() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        deposit_rewards_to_reserves(balance!(1000));
+        VestedRewards::set_vesting_schedule(
+            Origin::root(),
+            RewardReason::MarketMakerVolume,
+            VestingSchedule::Cliff(10),
+        )
+        .unwrap();
+
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        VestedRewards::add_market_maker_reward(&alice(), balance!(50))
+            .expect("Failed to add reward.");
+
+        frame_system::Pallet::<Runtime>::set_block_number(10);
+        assert_noop!(
+            VestedRewards::claim_rewards(Origin::signed(alice())),
+            Error::<Runtime>::ClaimLimitExceeded
+        );
+
+        frame_system::Pallet::<Runtime>::set_block_number(11);
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(50)
+        );
+    });
+}
+
+#[test]
+fn immediate_vesting_schedule_along_with_vesting_by_burns() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        deposit_rewards_to_reserves(balance!(1000));
+        VestedRewards::set_vesting_schedule(
+            Origin::root(),
+            RewardReason::BuyOnBondingCurve,
+            VestingSchedule::Immediate,
+        )
+        .unwrap();
+
+        VestedRewards::add_tbc_reward(&alice(), balance!(10)).expect("Failed to add reward.");
+        VestedRewards::add_market_maker_reward(&alice(), balance!(100))
+            .expect("Failed to add reward.");
+        assert_eq!(VestedRewards::total_rewards(), balance!(100));
+        VestedRewards::on_pswap_burned(PswapRemintInfo {
+            vesting: balance!(20),
+            ..Default::default()
+        });
+        assert_eq!(
+            VestedRewards::claimable_rewards(&alice()),
+            vec![
+                (RewardReason::BuyOnBondingCurve, balance!(10), balance!(10)),
+                (RewardReason::MarketMakerVolume, balance!(100), balance!(20)),
+            ]
+        );
+
+        VestedRewards::claim_rewards(Origin::signed(alice())).expect("Failed to claim");
+        assert_eq!(
+            Assets::free_balance(&PSWAP, &alice()).unwrap(),
+            balance!(30)
+        );
+        assert_eq!(VestedRewards::total_rewards(), balance!(80));
+        assert_eq!(
+            VestedRewards::rewards(&alice()),
+            RewardInfo {
+                limit: balance!(0),
+                total_available: balance!(80),
+                rewards: [(RewardReason::MarketMakerVolume, balance!(80))]
+                    .iter()
+                    .cloned()
+                    .collect(),
+                vesting: Default::default(),
+            }
+        );
+    });
+}
+
+#[test]
+fn setting_market_maker_params() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        let params = MarketMakerRewardsParams {
+            eligibility_tx_count: 10,
+            min_tx_volume: balance!(5),
+            season_reward: balance!(1000),
+            season_duration: 100,
+        };
+        assert_noop!(
+            VestedRewards::set_market_maker_params(Origin::signed(alice()), params.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            VestedRewards::set_market_maker_params(
+                Origin::root(),
+                MarketMakerRewardsParams {
+                    season_duration: 0,
+                    ..params.clone()
+                }
+            ),
+            Error::<Runtime>::InvalidMarketMakerParams
+        );
+        assert_noop!(
+            VestedRewards::set_market_maker_params(
+                Origin::root(),
+                MarketMakerRewardsParams {
+                    eligibility_tx_count: 0,
+                    ..params.clone()
+                }
+            ),
+            Error::<Runtime>::InvalidMarketMakerParams
+        );
+        assert_eq!(
+            VestedRewards::market_maker_params(),
+            MarketMakerRewardsParams::default()
+        );
+        VestedRewards::set_market_maker_params(Origin::root(), params.clone())
+            .expect("Failed to set params");
+        assert_eq!(VestedRewards::market_maker_params(), params);
+
+        VestedRewards::update_market_maker_records(&alice(), balance!(4), 10).unwrap();
+        VestedRewards::update_market_maker_records(&bob(), balance!(5), 10).unwrap();
+        assert_eq!(
+            VestedRewards::market_makers_registry(&alice()),
+            Default::default()
+        );
+        assert_eq!(
+            VestedRewards::market_makers_registry(&bob()),
+            MarketMakerInfo {
+                count: 10,
+                volume: balance!(50),
+            }
+        );
+    });
+}
+
+#[test]
+fn market_maker_seasons_are_recorded_to_history() {
+    let mut ext = ExtBuilder::default().build();
+    ext.execute_with(|| {
+        Currencies::deposit(
+            PSWAP,
+            &GetMarketMakerRewardsAccountId::get(),
+            balance!(400000000),
+        )
+        .unwrap();
+        let params = MarketMakerRewardsParams {
+            eligibility_tx_count: 10,
+            min_tx_volume: balance!(1),
+            season_reward: balance!(1000),
+            season_duration: 100,
+        };
+        VestedRewards::set_market_maker_params(Origin::root(), params.clone())
+            .expect("Failed to set params");
+        VestedRewards::update_market_maker_records(&alice(), balance!(10), 10).unwrap();
+        VestedRewards::update_market_maker_records(&bob(), balance!(30), 10).unwrap();
+        VestedRewards::update_market_maker_records(&eve(), balance!(10), 9).unwrap();
+
+        assert_eq!(
+            VestedRewards::projected_market_maker_reward(&alice()),
+            balance!(250)
+        );
+        assert_eq!(
+            VestedRewards::projected_market_maker_reward(&bob()),
+            balance!(750)
+        );
+        assert_eq!(
+            VestedRewards::projected_market_maker_reward(&eve()),
+            balance!(0)
+        );
+
+        for block_n in 1..100 {
+            VestedRewards::on_initialize(block_n.into());
+        }
+        assert_eq!(VestedRewards::season_info(0), None);
+        VestedRewards::on_initialize(100);
+        assert_eq!(
+            VestedRewards::season_info(0),
+            Some(MarketMakerSeasonInfo {
+                start: 0,
+                end: 100,
+                params,
+                eligible_accounts: 2,
+                total_eligible_volume: balance!(400),
+                distributed: balance!(1000),
+            })
+        );
+        assert_eq!(
+            VestedRewards::current_season(),
+            MarketMakerSeason {
+                index: 1,
+                start: 100,
+            }
+        );
+        assert_eq!(
+            VestedRewards::rewards(&alice()).rewards,
+            [(RewardReason::MarketMakerVolume, balance!(250))]
+                .iter()
+                .cloned()
+                .collect()
+        );
+        assert_eq!(
+            VestedRewards::rewards(&bob()).rewards,
+            [(RewardReason::MarketMakerVolume, balance!(750))]
+                .iter()
+                .cloned()
+                .collect()
+        );
+        assert_eq!(VestedRewards::rewards(&eve()), Default::default());
+
+        for block_n in 101..200 {
+            VestedRewards::on_initialize(block_n.into());
+        }
+        assert_eq!(VestedRewards::season_info(1), None);
+        VestedRewards::on_initialize(200);
+        assert_eq!(
+            VestedRewards::season_info(1).map(|info| (info.start, info.end, info.distributed)),
+            Some((100, 200, balance!(0)))
+        );
+    });
+}
@@ -73,6 +73,18 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
     fn on_initialize(_n: u32) -> Weight {
         100_000_000 as Weight // TODO: benchmark
     }
+    fn set_payee() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_claim_delegate() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_vesting_schedule() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_market_maker_params() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 impl crate::WeightInfo for () {
@@ -83,4 +95,20 @@ impl crate::WeightInfo for () {
     fn on_initialize(_n: u32) -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+
+    fn set_payee() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn set_claim_delegate() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn set_vesting_schedule() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
+
+    fn set_market_maker_params() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
 }
@@ -222,6 +222,8 @@ where
             }
         };
 
+        referral_system::Pallet::<T>::note_transaction(who);
+
         if let Some(paid) = withdrawn {
             // Calculate the amount to refund to the caller
             // A refund is possible in two cases:
@@ -258,16 +260,27 @@ where
             // Applying VAL buy-back-and-burn logic
             let xor_burned_weight = T::XorBurnedWeight::get();
             let xor_into_val_burned_weight = T::XorIntoValBurnedWeight::get();
-            let (referrer_xor, adjusted_paid) = adjusted_paid.ration(
+            let (mut referrer_xor, adjusted_paid) = adjusted_paid.ration(
                 T::ReferrerWeight::get(),
                 xor_burned_weight + xor_into_val_burned_weight,
             );
-            if let Some(referrer) = referral_system::Pallet::<T>::referrer_account(who) {
-                let _ = T::XorCurrency::resolve_into_existing(&referrer, referrer_xor);
+            let referrer_amount: Balance = referrer_xor.peek().unique_saturated_into();
+            for (referrer, reward) in
+                referral_system::Pallet::<T>::referrer_rewards(who, referrer_amount)
+            {
+                let (reward_xor, rest) = referrer_xor.split(BalanceOf::<T>::saturated_from(reward));
+                referrer_xor = rest;
+                if let Err(reward_xor) =
+                    T::XorCurrency::resolve_into_existing(&referrer, reward_xor)
+                {
+                    referrer_xor.subsume(reward_xor);
+                } else {
+                    referral_system::Pallet::<T>::note_referrer_reward(&referrer, reward);
+                }
             }
+            // The referrer share that has no one to be paid to is burned
+            drop(referrer_xor);
 
-            // TODO: decide what should be done with XOR if there is no referrer.
-            // Burn XOR for now
             let (_xor_burned, xor_to_val) =
                 adjusted_paid.ration(xor_burned_weight, xor_into_val_burned_weight);
             let xor_to_val: Balance = xor_to_val.peek().unique_saturated_into();
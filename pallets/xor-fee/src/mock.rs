@@ -78,6 +78,8 @@ impl AppCrypto<UintAuthorityId, TestSignature> for TestAppCrypto {
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const ReferrerWeight: u32 = 10;
+    pub const ReferrerSetGracePeriod: BlockNumber = 100;
+    pub GetReferralRewardsAccountId: AccountId = 421;
     pub const XorBurnedWeight: u32 = 40;
     pub const XorIntoValBurnedWeight: u32 = 50;
     pub const SoraParliamentShare: Percent = Percent::from_percent(10);
@@ -137,9 +139,9 @@ construct_runtime! {
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Module, Call, Config<T>, Storage},
-        DexManager: dex_manager::{Module, Call, Config<T>, Storage},
+        DexManager: dex_manager::{Module, Call, Config<T>, Storage, Event<T>},
         TradingPair: trading_pair::{Module, Call, Config<T>, Storage, Event<T>},
-        ReferralSystem: referral_system::{Module, Call, Config<T>, Storage},
+        ReferralSystem: referral_system::{Module, Call, Config<T>, Storage, Event<T>},
         Balances: pallet_balances::{Module, Call, Storage, Event<T>},
         TransactionPayment: pallet_transaction_payment::{Module, Storage},
         Technical: technical::{Module, Call, Config<T>, Storage, Event<T>},
@@ -330,7 +332,11 @@ impl mock_liquidity_source::Config<mock_liquidity_source::Instance1> for Runtime
     type EnsureTradingPairExists = trading_pair::Module<Runtime>;
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -338,7 +344,13 @@ impl trading_pair::Config for Runtime {
     type WeightInfo = ();
 }
 
-impl referral_system::Config for Runtime {}
+impl referral_system::Config for Runtime {
+    type Event = Event;
+    type ReferrerSetGracePeriod = ReferrerSetGracePeriod;
+    type Currency = Balances;
+    type ReferralRewardsAccountId = GetReferralRewardsAccountId;
+    type WeightInfo = ();
+}
 
 impl pallet_balances::Config for Runtime {
     type Balance = Balance;
@@ -369,6 +381,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = ();
+    type WeightInfo = ();
 }
 
 impl currencies::Config for Runtime {
@@ -393,6 +406,7 @@ impl assets::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl tokens::Config for Runtime {
@@ -592,6 +606,7 @@ impl liquidity_proxy::LiquidityProxyTrait<DEXId, AccountId, AssetId> for MockLiq
 pub const MOCK_WEIGHT: Weight = 600_000_000;
 
 pub const REFERRER_ACCOUNT: u64 = 3;
+pub const SECOND_TIER_REFERRER_ACCOUNT: u64 = 4;
 pub const FROM_ACCOUNT: u64 = 1;
 pub const TO_ACCOUNT: u64 = 2;
 pub const STASH_ACCOUNT: u64 = 11;
@@ -658,6 +673,7 @@ impl ExtBuilder {
                 (TO_ACCOUNT, initial_balance),
                 (EMPTY_ACCOUNT, 0),
                 (REFERRER_ACCOUNT, initial_balance),
+                (SECOND_TIER_REFERRER_ACCOUNT, initial_balance),
                 (STASH_ACCOUNT, initial_balance),
                 (STASH_ACCOUNT2, initial_balance),
             ],
@@ -666,7 +682,10 @@ impl ExtBuilder {
         .unwrap();
 
         referral_system::GenesisConfig::<Runtime> {
-            referrers: vec![(FROM_ACCOUNT, REFERRER_ACCOUNT)],
+            referrers: vec![
+                (FROM_ACCOUNT, REFERRER_ACCOUNT),
+                (REFERRER_ACCOUNT, SECOND_TIER_REFERRER_ACCOUNT),
+            ],
         }
         .assimilate_storage(&mut t)
         .unwrap();
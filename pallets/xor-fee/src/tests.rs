@@ -30,8 +30,10 @@
 
 use common::prelude::{AssetName, AssetSymbol, FixedWrapper, SwapAmount};
 use common::{balance, fixed_wrapper, FilterMode, VAL, XOR};
+use frame_support::assert_ok;
 use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
 use sp_runtime::traits::SignedExtension;
+use sp_runtime::Percent;
 use traits::MultiCurrency;
 use xor_fee::LiquidityInfo;
 
@@ -88,6 +90,86 @@ fn referrer_gets_bonus_from_tx_fee() {
                 && Balances::free_balance(REFERRER_ACCOUNT)
                     <= (expected_referrer_balance + fixed_wrapper!(1)).into_balance()
         );
+        assert_eq!(
+            referral_system::Pallet::<Runtime>::referrer_stats(REFERRER_ACCOUNT).earned_fees,
+            Balances::free_balance(REFERRER_ACCOUNT) - crate::mock::initial_balance()
+        );
+    });
+}
+
+#[test]
+fn referrer_share_without_referrer_is_burned() {
+    ExtBuilder::build().execute_with(|| {
+        let call: &<Runtime as frame_system::Config>::Call = &Call::Balances(
+            pallet_balances::Call::transfer(FROM_ACCOUNT, balance!(TRANSFER_AMOUNT)),
+        );
+
+        let len = 10;
+        let dispatch_info = info_from_weight(MOCK_WEIGHT);
+        let pre = ChargeTransactionPayment::<Runtime>::from(0u128.into())
+            .pre_dispatch(&TO_ACCOUNT, call, &dispatch_info, len)
+            .unwrap();
+        assert!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+            pre,
+            &dispatch_info,
+            &default_post_info(),
+            len,
+            &Ok(())
+        )
+        .is_ok());
+
+        assert_eq!(
+            Balances::free_balance(GetReferralRewardsAccountId::get()),
+            0
+        );
+        assert_eq!(Balances::free_balance(REFERRER_ACCOUNT), initial_balance());
+    });
+}
+
+#[test]
+fn second_tier_referrer_gets_share_of_bonus_from_tx_fee() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(referral_system::Pallet::<Runtime>::set_reward_shares(
+            Origin::root(),
+            referral_system::TierShares {
+                first_tier: Percent::from_percent(70),
+                second_tier: Percent::from_percent(30),
+            },
+            Percent::zero()
+        ));
+        let call: &<Runtime as frame_system::Config>::Call = &Call::Balances(
+            pallet_balances::Call::transfer(TO_ACCOUNT, balance!(TRANSFER_AMOUNT)),
+        );
+
+        let len = 10;
+        let dispatch_info = info_from_weight(MOCK_WEIGHT);
+        let pre = ChargeTransactionPayment::<Runtime>::from(0u128.into())
+            .pre_dispatch(&FROM_ACCOUNT, call, &dispatch_info, len)
+            .unwrap();
+        assert!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+            pre,
+            &dispatch_info,
+            &default_post_info(),
+            len,
+            &Ok(())
+        )
+        .is_ok());
+
+        let first_tier_reward = Balances::free_balance(REFERRER_ACCOUNT) - initial_balance();
+        let second_tier_reward =
+            Balances::free_balance(SECOND_TIER_REFERRER_ACCOUNT) - initial_balance();
+        assert!(second_tier_reward > 0);
+        assert!(first_tier_reward > 2 * second_tier_reward);
+        assert!(first_tier_reward < 3 * second_tier_reward);
+        assert_eq!(
+            referral_system::Pallet::<Runtime>::referrer_stats(REFERRER_ACCOUNT).earned_fees,
+            first_tier_reward
+        );
+        assert_eq!(
+            referral_system::Pallet::<Runtime>::referrer_stats(SECOND_TIER_REFERRER_ACCOUNT)
+                .earned_fees,
+            second_tier_reward
+        );
     });
 }
 
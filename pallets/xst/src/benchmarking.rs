@@ -76,6 +76,15 @@ benchmarks! {
     verify {
         assert_last_event::<T>(Event::PoolInitialized(common::DEXId::Polkaswap.into(), DAI.into()).into())
     }
+
+    set_price_source {
+    }: _(
+        RawOrigin::Root,
+        PriceSource::Oracle
+    )
+    verify {
+        assert_last_event::<T>(Event::PriceSourceChanged(PriceSource::Oracle).into())
+    }
 }
 
 #[cfg(test)]
@@ -89,6 +98,7 @@ mod tests {
     fn test_benchmarks() {
         ExtBuilder::default().build().execute_with(|| {
             assert_ok!(test_benchmark_initialize_pool::<Runtime>());
+            assert_ok!(test_benchmark_set_price_source::<Runtime>());
         });
     }
 }
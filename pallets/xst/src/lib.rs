@@ -47,12 +47,13 @@ use assets::AssetIdOf;
 use codec::{Decode, Encode};
 use common::fixnum::ops::Zero as _;
 use common::prelude::{
-    Balance, EnsureDEXManager, EnsureTradingPairExists, Fixed, FixedWrapper, PriceToolsPallet,
-    QuoteAmount, SwapAmount, SwapOutcome,
+    Balance, EnsureDEXManager, EnsureTradingPairExists, Fixed, FixedWrapper, PriceOracle,
+    PriceToolsPallet, QuoteAmount, SwapAmount, SwapOutcome,
 };
 use common::{
-    balance, fixed, fixed_wrapper, DEXId, DexIdOf, GetMarketInfo, LiquiditySource,
-    LiquiditySourceFilter, LiquiditySourceType, ManagementMode, RewardReason, DAI, XOR, XSTUSD,
+    balance, fixed, fixed_wrapper, CircuitBreaker, DEXId, DexIdOf, GetMarketInfo, LiquiditySource,
+    LiquiditySourceFilter, LiquiditySourceType, ManagementMode, PriceSource, RewardReason, DAI,
+    XOR, XSTUSD,
 };
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
@@ -70,6 +71,7 @@ pub trait WeightInfo {
     fn initialize_pool() -> Weight;
     fn set_reference_asset() -> Weight;
     fn enable_synthetic_asset() -> Weight;
+    fn set_price_source() -> Weight;
 }
 
 type Assets<T> = assets::Module<T>;
@@ -143,6 +145,10 @@ pub mod pallet {
             DispatchError,
         >;
         type PriceToolsPallet: PriceToolsPallet<Self::AssetId>;
+        /// External price oracle, which can be chosen as a source of reference prices instead of price tools.
+        type PriceOracle: PriceOracle<Self::AssetId>;
+        /// Circuit breaker, which halts trading of synthetic assets when prices deviate too much from average.
+        type CircuitBreaker: CircuitBreaker<Self::AssetId>;
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -154,7 +160,8 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(_block_number: T::BlockNumber) -> Weight {
-            <T as Config>::WeightInfo::on_initialize(0)
+            let elems = Module::<T>::circuit_breaker_routine();
+            <T as Config>::WeightInfo::on_initialize(elems)
         }
 
         fn on_runtime_upgrade() -> Weight {
@@ -200,6 +207,18 @@ pub mod pallet {
             EnabledSynthetics::<T>::mutate(|set| set.insert(synthetic_asset));
             Ok(().into())
         }
+
+        /// Change source of reference prices used to determine assets value.
+        #[pallet::weight(<T as Config>::WeightInfo::set_price_source())]
+        pub fn set_price_source(
+            origin: OriginFor<T>,
+            price_source: PriceSource,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+            ReferencePriceSource::<T>::put(price_source);
+            Self::deposit_event(Event::PriceSourceChanged(price_source));
+            Ok(().into())
+        }
     }
 
     #[pallet::event]
@@ -210,6 +229,8 @@ pub mod pallet {
         PoolInitialized(DexIdOf<T>, AssetIdOf<T>),
         /// Reference Asset has been changed for pool. [New Reference Asset Id]
         ReferenceAssetChanged(AssetIdOf<T>),
+        /// Source of reference prices has been changed. [New Price Source]
+        PriceSourceChanged(PriceSource),
     }
 
     #[pallet::error]
@@ -262,6 +283,11 @@ pub mod pallet {
     #[pallet::getter(fn reference_asset_id)]
     pub type ReferenceAssetId<T: Config> = StorageValue<_, T::AssetId, ValueQuery>;
 
+    /// Source of reference prices, either price tools averages or external price oracle.
+    #[pallet::storage]
+    #[pallet::getter(fn reference_price_source)]
+    pub type ReferencePriceSource<T: Config> = StorageValue<_, PriceSource, ValueQuery>;
+
     /// Current reserves balance for collateral tokens, used for client usability.
     #[pallet::storage]
     pub(super) type CollateralReserves<T: Config> =
@@ -610,15 +636,30 @@ impl<T: Config> Module<T> {
     ///
     /// Example use: understand actual value of two tokens in terms of USD.
     fn reference_price(asset_id: &T::AssetId) -> Result<Balance, DispatchError> {
+        Self::reference_price_with_source(asset_id, ReferencePriceSource::<T>::get())
+    }
+
+    /// Determine particular asset price in terms of a reference asset using given source of prices.
+    fn reference_price_with_source(
+        asset_id: &T::AssetId,
+        price_source: PriceSource,
+    ) -> Result<Balance, DispatchError> {
         let reference_asset_id = ReferenceAssetId::<T>::get();
         // XSTUSD is a special case because it is equal to the reference asset, DAI
         let price = if asset_id == &reference_asset_id || asset_id == &XSTUSD.into() {
             balance!(1)
         } else {
-            <T as pallet::Config>::PriceToolsPallet::get_average_price(
-                asset_id,
-                &reference_asset_id,
-            )
+            match price_source {
+                PriceSource::PriceTools => {
+                    <T as pallet::Config>::PriceToolsPallet::get_average_price(
+                        asset_id,
+                        &reference_asset_id,
+                    )
+                }
+                PriceSource::Oracle => {
+                    <T as pallet::Config>::PriceOracle::get_price(asset_id, &reference_asset_id)
+                }
+            }
             .map(|avg| {
                 // We don't let the price of XOR w.r.t. DAI go under $100, to prevent manipulation attacks
                 if asset_id == &XOR.into() && &reference_asset_id == &DAI.into() {
@@ -630,6 +671,54 @@ impl<T: Config> Module<T> {
         };
         Ok(price)
     }
+
+    /// Compare spot prices of base asset in terms of synthetic asset with its average price, trading of synthetic
+    /// asset is halted by circuit breaker if they deviate too much.
+    ///
+    /// Spot prices are derived from the active reference price source, so they are compared with prices
+    /// of the other, independent source: oracle prices when `price_tools` averages are used and vice versa.
+    fn check_price_deviation(synthetic_asset_id: &T::AssetId) -> DispatchResult {
+        let control_source = match ReferencePriceSource::<T>::get() {
+            PriceSource::PriceTools => PriceSource::Oracle,
+            PriceSource::Oracle => PriceSource::PriceTools,
+        };
+        let base_asset_id = &T::GetBaseAssetId::get();
+        let average_price = Self::reference_price_with_source(base_asset_id, control_source)
+            .and_then(|base_price| {
+                let synthetic_price =
+                    Self::reference_price_with_source(synthetic_asset_id, control_source)?;
+                (FixedWrapper::from(base_price) / FixedWrapper::from(synthetic_price))
+                    .get()
+                    .map_err(|_| Error::<T>::PriceCalculationFailed.into())
+            });
+        // nothing to compare spot prices with if control prices are not available
+        let average_price = match average_price {
+            Ok(price) => price,
+            Err(_) => return Ok(()),
+        };
+        let buy_price =
+            <Self as GetMarketInfo<T::AssetId>>::buy_price(base_asset_id, synthetic_asset_id)?;
+        let sell_price =
+            <Self as GetMarketInfo<T::AssetId>>::sell_price(base_asset_id, synthetic_asset_id)?;
+        T::CircuitBreaker::check_price_deviation(
+            LiquiditySourceType::XSTPool,
+            synthetic_asset_id,
+            buy_price,
+            sell_price,
+            average_price,
+        )
+    }
+
+    /// Check price deviation for all enabled synthetic assets. Returns number of checked assets.
+    pub fn circuit_breaker_routine() -> u32 {
+        let mut count = 0;
+        for synthetic_asset_id in EnabledSynthetics::<T>::get() {
+            // failure means trading is halted, which is tracked by circuit breaker
+            let _ = Self::check_price_deviation(&synthetic_asset_id);
+            count += 1;
+        }
+        count
+    }
 }
 
 impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, DispatchError>
@@ -643,11 +732,16 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
         if *dex_id != DEXId::Polkaswap.into() {
             return false;
         }
-        if input_asset_id == &T::GetBaseAssetId::get() {
-            EnabledSynthetics::<T>::get().contains(&output_asset_id)
+        let synthetic_asset_id = if input_asset_id == &T::GetBaseAssetId::get() {
+            output_asset_id
         } else {
-            EnabledSynthetics::<T>::get().contains(&input_asset_id)
-        }
+            input_asset_id
+        };
+        EnabledSynthetics::<T>::get().contains(synthetic_asset_id)
+            && !T::CircuitBreaker::is_trading_halted(
+                LiquiditySourceType::XSTPool,
+                synthetic_asset_id,
+            )
     }
 
     fn quote(
@@ -682,6 +776,11 @@ impl<T: Config> LiquiditySource<T::DEXId, T::AccountId, T::AssetId, Balance, Dis
         if !Self::can_exchange(dex_id, input_asset_id, output_asset_id) {
             fail!(Error::<T>::CantExchange);
         }
+        if input_asset_id == &T::GetBaseAssetId::get() {
+            Self::check_price_deviation(output_asset_id)?;
+        } else {
+            Self::check_price_deviation(input_asset_id)?;
+        }
 
         let outcome = Self::swap_mint_burn_assets(
             dex_id,
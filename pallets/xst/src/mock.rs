@@ -31,7 +31,7 @@
 use crate::{self as xstpool, Config};
 use common::mock::ExistentialDeposits;
 use common::prelude::{
-    Balance, FixedWrapper, PriceToolsPallet, QuoteAmount, SwapAmount, SwapOutcome,
+    Balance, FixedWrapper, PriceOracle, PriceToolsPallet, QuoteAmount, SwapAmount, SwapOutcome,
 };
 use common::{
     self, balance, fixed, fixed_wrapper, hash, Amount, AssetId32, AssetName, AssetSymbol, DEXInfo,
@@ -48,6 +48,7 @@ use sp_core::H256;
 use sp_runtime::testing::Header;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup, Zero};
 use sp_runtime::{DispatchError, DispatchResult, Perbill};
+use std::cell::RefCell;
 use std::collections::HashMap;
 
 pub type AccountId = AccountId32;
@@ -107,7 +108,7 @@ construct_runtime! {
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Module, Call, Config, Storage, Event<T>},
-        DexManager: dex_manager::{Module, Call, Storage},
+        DexManager: dex_manager::{Module, Call, Storage, Event<T>},
         TradingPair: trading_pair::{Module, Call, Storage, Event<T>},
         MockLiquiditySource: mock_liquidity_source::<Instance1>::{Module, Call, Config<T>, Storage},
         Tokens: tokens::{Module, Call, Config<T>, Storage, Event<T>},
@@ -120,6 +121,7 @@ construct_runtime! {
         XSTPool: xstpool::{Module, Call, Storage, Event<T>},
         PswapDistribution: pswap_distribution::{Module, Call, Storage, Event<T>},
         DEXApi: dex_api::{Module, Storage, Event<T>},
+        CircuitBreaker: circuit_breaker::{Module, Call, Storage, Event<T>},
     }
 }
 
@@ -148,7 +150,11 @@ impl frame_system::Config for Runtime {
     type SS58Prefix = ();
 }
 
-impl dex_manager::Config for Runtime {}
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = ();
+    type WeightInfo = ();
+}
 
 impl trading_pair::Config for Runtime {
     type Event = Event;
@@ -168,6 +174,14 @@ impl Config for Runtime {
     type EnsureTradingPairExists = trading_pair::Module<Runtime>;
     type EnsureDEXManager = dex_manager::Module<Runtime>;
     type PriceToolsPallet = MockDEXApi;
+    type PriceOracle = MockPriceOracle;
+    type CircuitBreaker = CircuitBreaker;
+    type WeightInfo = ();
+}
+
+impl circuit_breaker::Config for Runtime {
+    type Event = Event;
+    type AssetId = AssetId;
     type WeightInfo = ();
 }
 
@@ -215,11 +229,13 @@ impl dex_api::Config for Runtime {
     type XYKPool = MockLiquiditySource;
     type XSTPool = XSTPool;
     type MulticollateralBondingCurvePool = ();
+    type IsSourceEnabled = ();
     type WeightInfo = ();
 }
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = ();
 }
 
 impl technical::Config for Runtime {
@@ -229,6 +245,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = ();
 }
 
 impl pallet_balances::Config for Runtime {
@@ -254,6 +271,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = ();
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = ();
 }
 
 impl pool_xyk::Config for Runtime {
@@ -269,6 +287,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetXykFee;
     type OnPoolCreated = PswapDistribution;
     type OnPoolReservesChanged = ();
+    type OnPoolTokensChanged = ();
     type WeightInfo = ();
 }
 
@@ -571,6 +590,31 @@ impl PriceToolsPallet<AssetId> for MockDEXApi {
     }
 }
 
+thread_local! {
+    /// Multiplier applied by `MockPriceOracle` to average prices, used to simulate price manipulation.
+    pub static ORACLE_PRICE_MULTIPLIER: RefCell<Balance> = RefCell::new(1);
+}
+
+/// Mock of external price oracle, reports average prices multiplied by `ORACLE_PRICE_MULTIPLIER`.
+pub struct MockPriceOracle;
+
+impl MockPriceOracle {
+    pub fn set_price_multiplier(multiplier: Balance) {
+        ORACLE_PRICE_MULTIPLIER.with(|m| *m.borrow_mut() = multiplier);
+    }
+}
+
+impl PriceOracle<AssetId> for MockPriceOracle {
+    fn get_price(
+        input_asset_id: &AssetId,
+        output_asset_id: &AssetId,
+    ) -> Result<Balance, DispatchError> {
+        let price = MockDEXApi::get_average_price(input_asset_id, output_asset_id)?;
+        let multiplier = ORACLE_PRICE_MULTIPLIER.with(|m| *m.borrow());
+        Ok(price.saturating_mul(multiplier))
+    }
+}
+
 impl ExtBuilder {
     pub fn new(
         endowed_accounts: Vec<(AccountId, AssetId, Balance, AssetSymbol, AssetName, u8)>,
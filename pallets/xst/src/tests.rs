@@ -31,8 +31,9 @@
 #[rustfmt::skip]
 mod tests {
     use crate::{Error, Module, migration::get_permissioned_tech_account_id, mock::*};
-    use common::{self, AssetName, AssetSymbol, DEXId, FromGenericPair, LiquiditySource, LiquiditySourceType, USDT, VAL, XOR, XSTUSD, balance, fixed, prelude::{Balance, SwapAmount, QuoteAmount,}};
-    use frame_support::{assert_noop, assert_ok};
+    use common::{self, AssetName, AssetSymbol, DEXId, FromGenericPair, LiquiditySource, LiquiditySourceType, PriceSource, USDT, VAL, XOR, XSTUSD, balance, fixed, prelude::{Balance, SwapAmount, QuoteAmount,}};
+    use frame_support::{assert_err, assert_noop, assert_ok};
+    use frame_support::traits::OnInitialize;
     use permissions::{BURN, MINT};
     use sp_arithmetic::traits::{Zero};
     use sp_runtime::DispatchError;
@@ -511,4 +512,108 @@ mod tests {
             assert_eq!(quote_outcome_d.amount, quote_without_impact_d.amount);
         });
     }
+
+    #[test]
+    fn should_switch_price_source() {
+        let mut ext = ExtBuilder::default().build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            MockPriceOracle::set_price_multiplier(2);
+            let average_price = XSTPool::reference_price(&XOR).unwrap();
+            assert_eq!(XSTPool::reference_price_source(), PriceSource::PriceTools);
+
+            assert_noop!(XSTPool::set_price_source(Origin::signed(alice()), PriceSource::Oracle), DispatchError::BadOrigin);
+            assert_ok!(XSTPool::set_price_source(Origin::root(), PriceSource::Oracle));
+            assert_eq!(XSTPool::reference_price_source(), PriceSource::Oracle);
+            // mock oracle reports prices twice as high as average prices
+            assert_eq!(XSTPool::reference_price(&XOR).unwrap(), average_price * 2);
+            // synthetic asset price doesn't depend on price source
+            assert_eq!(XSTPool::reference_price(&XSTUSD).unwrap(), balance!(1));
+
+            assert_ok!(XSTPool::set_price_source(Origin::root(), PriceSource::PriceTools));
+            assert_eq!(XSTPool::reference_price(&XOR).unwrap(), average_price);
+        });
+    }
+
+    #[test]
+    fn circuit_breaker_should_halt_trading_on_price_manipulation() {
+        let mut ext = ExtBuilder::new(vec![
+            (alice(), DAI, balance!(0), AssetSymbol(b"DAI".to_vec()), AssetName(b"DAI".to_vec()), 18),
+            (alice(), USDT, balance!(0), AssetSymbol(b"USDT".to_vec()), AssetName(b"Tether USD".to_vec()), 18),
+            (alice(), XOR, balance!(0), AssetSymbol(b"XOR".to_vec()), AssetName(b"SORA".to_vec()), 18),
+            (alice(), VAL, balance!(0), AssetSymbol(b"VAL".to_vec()), AssetName(b"SORA Validator Token".to_vec()), 18),
+            (alice(), XSTUSD, balance!(10000), AssetSymbol(b"XSTUSD".to_vec()), AssetName(b"SORA Synthetic USD".to_vec()), 18),
+        ])
+        .build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = xst_pool_init().unwrap();
+            TradingPair::register(Origin::signed(alice()), DEXId::Polkaswap.into(), XOR, XSTUSD).expect("Failed to register trading pair.");
+            XSTPool::initialize_pool_unchecked(XSTUSD, false).expect("Failed to initialize pool.");
+            let swap = || common::with_transaction(|| XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap.into(),
+                &XSTUSD,
+                &XOR,
+                SwapAmount::with_desired_input(balance!(100), Balance::zero()),
+            ));
+            assert_ok!(swap());
+
+            // average prices are manipulated and deviate from prices reported by oracle, default price source is checked too
+            assert_eq!(XSTPool::reference_price_source(), PriceSource::PriceTools);
+            MockPriceOracle::set_price_multiplier(2);
+            assert_err!(swap(), circuit_breaker::Error::<Runtime>::PriceDeviationExceeded);
+            // refused exchange doesn't halt trading, it's done by routine on block initialization
+            assert_eq!(CircuitBreaker::halted_until(LiquiditySourceType::XSTPool, XSTUSD), None);
+            XSTPool::on_initialize(1);
+            let halted_until = CircuitBreaker::halted_until(LiquiditySourceType::XSTPool, XSTUSD).unwrap();
+            assert!(!XSTPool::can_exchange(&DEXId::Polkaswap.into(), &XSTUSD, &XOR));
+            assert_err!(swap(), Error::<Runtime>::CantExchange);
+
+            // prices are restored, but trading is resumed only after cooldown
+            MockPriceOracle::set_price_multiplier(1);
+            XSTPool::on_initialize(2);
+            assert_err!(swap(), Error::<Runtime>::CantExchange);
+            frame_system::Pallet::<Runtime>::set_block_number(halted_until);
+            XSTPool::on_initialize(halted_until);
+            assert!(XSTPool::can_exchange(&DEXId::Polkaswap.into(), &XSTUSD, &XOR));
+            assert_ok!(swap());
+        });
+    }
+
+    #[test]
+    fn circuit_breaker_should_check_oracle_price_source() {
+        let mut ext = ExtBuilder::new(vec![
+            (alice(), DAI, balance!(0), AssetSymbol(b"DAI".to_vec()), AssetName(b"DAI".to_vec()), 18),
+            (alice(), USDT, balance!(0), AssetSymbol(b"USDT".to_vec()), AssetName(b"Tether USD".to_vec()), 18),
+            (alice(), XOR, balance!(0), AssetSymbol(b"XOR".to_vec()), AssetName(b"SORA".to_vec()), 18),
+            (alice(), VAL, balance!(0), AssetSymbol(b"VAL".to_vec()), AssetName(b"SORA Validator Token".to_vec()), 18),
+            (alice(), XSTUSD, balance!(10000), AssetSymbol(b"XSTUSD".to_vec()), AssetName(b"SORA Synthetic USD".to_vec()), 18),
+        ])
+        .build();
+        ext.execute_with(|| {
+            MockDEXApi::init().unwrap();
+            let _ = xst_pool_init().unwrap();
+            TradingPair::register(Origin::signed(alice()), DEXId::Polkaswap.into(), XOR, XSTUSD).expect("Failed to register trading pair.");
+            XSTPool::initialize_pool_unchecked(XSTUSD, false).expect("Failed to initialize pool.");
+            let swap = || common::with_transaction(|| XSTPool::exchange(
+                &alice(),
+                &alice(),
+                &DEXId::Polkaswap.into(),
+                &XSTUSD,
+                &XOR,
+                SwapAmount::with_desired_input(balance!(100), Balance::zero()),
+            ));
+            assert_ok!(XSTPool::set_price_source(Origin::root(), PriceSource::Oracle));
+            assert_ok!(swap());
+
+            // prices reported by oracle are manipulated and deviate from average prices
+            MockPriceOracle::set_price_multiplier(2);
+            assert_err!(swap(), circuit_breaker::Error::<Runtime>::PriceDeviationExceeded);
+            XSTPool::on_initialize(1);
+            assert!(CircuitBreaker::halted_until(LiquiditySourceType::XSTPool, XSTUSD).is_some());
+            assert_err!(swap(), Error::<Runtime>::CantExchange);
+        });
+    }
 }
@@ -58,6 +58,9 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(3 as Weight))
             .saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    fn set_price_source() -> Weight {
+        (18_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 impl crate::WeightInfo for () {
@@ -73,4 +76,7 @@ impl crate::WeightInfo for () {
     fn enable_synthetic_asset() -> Weight {
         EXTRINSIC_FIXED_WEIGHT
     }
+    fn set_price_source() -> Weight {
+        EXTRINSIC_FIXED_WEIGHT
+    }
 }
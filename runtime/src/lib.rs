@@ -115,7 +115,7 @@ use eth_bridge::requests::{AssetKind, OffchainRequest, OutgoingRequestEncoded, R
 use impls::{CollectiveWeightInfo, DemocracyWeightInfo, OnUnbalancedDemocracySlash};
 
 use frame_support::traits::Get;
-pub use {assets, eth_bridge, frame_system, multicollateral_bonding_curve_pool, xst};
+pub use {assets, eth_bridge, frame_system, multicollateral_bonding_curve_pool, permissions, xst};
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -206,7 +206,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("sora-substrate"),
     impl_name: create_runtime_str!("sora-substrate"),
     authoring_version: 1,
-    spec_version: 13,
+    spec_version: 14,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
     transaction_version: 13,
@@ -628,7 +628,15 @@ impl trading_pair::Config for Runtime {
     type WeightInfo = ();
 }
 
-impl dex_manager::Config for Runtime {}
+parameter_types! {
+    pub const DEXRegistrationDeposit: Balance = balance!(10000);
+}
+
+impl dex_manager::Config for Runtime {
+    type Event = Event;
+    type DEXRegistrationDeposit = DEXRegistrationDeposit;
+    type WeightInfo = dex_manager::weights::WeightInfo<Runtime>;
+}
 
 pub type TechAccountId = common::TechAccountId<AccountId, TechAssetId, DEXId>;
 pub type TechAssetId = common::TechAssetId<common::PredefinedAssetId>;
@@ -641,6 +649,7 @@ impl technical::Config for Runtime {
     type Trigger = ();
     type Condition = ();
     type SwapAction = pool_xyk::PolySwapAction<AssetId, AccountId, TechAccountId>;
+    type WeightInfo = technical::weights::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -660,6 +669,7 @@ impl pool_xyk::Config for Runtime {
     type GetFee = GetFee;
     type OnPoolCreated = (PswapDistribution, Farming);
     type OnPoolReservesChanged = PriceTools;
+    type OnPoolTokensChanged = Farming;
     type WeightInfo = pool_xyk::weights::WeightInfo<Runtime>;
 }
 
@@ -697,6 +707,7 @@ impl liquidity_proxy::Config for Runtime {
     type SecondaryMarket = pool_xyk::Module<Runtime>;
     type WeightInfo = liquidity_proxy::weights::WeightInfo<Runtime>;
     type VestedRewardsPallet = VestedRewards;
+    type OnSwapFeePaid = ReferralSystem;
 }
 
 impl mock_liquidity_source::Config<mock_liquidity_source::Instance1> for Runtime {
@@ -736,6 +747,7 @@ impl dex_api::Config for Runtime {
     type MulticollateralBondingCurvePool = multicollateral_bonding_curve_pool::Module<Runtime>;
     type XYKPool = pool_xyk::Module<Runtime>;
     type XSTPool = xst::Module<Runtime>;
+    type IsSourceEnabled = trading_pair::Module<Runtime>;
     type WeightInfo = dex_api::weights::WeightInfo<Runtime>;
 }
 
@@ -839,7 +851,31 @@ where
     type Extrinsic = UncheckedExtrinsic;
 }
 
-impl referral_system::Config for Runtime {}
+parameter_types! {
+    pub const ReferrerSetGracePeriod: BlockNumber = 1 * DAYS;
+    pub GetReferralRewardsTechAccountId: TechAccountId = {
+        let tech_account_id = TechAccountId::from_generic_pair(
+            referral_system::TECH_ACCOUNT_PREFIX.to_vec(),
+            referral_system::TECH_ACCOUNT_REWARDS.to_vec(),
+        );
+        tech_account_id
+    };
+    pub GetReferralRewardsAccountId: AccountId = {
+        let tech_account_id = GetReferralRewardsTechAccountId::get();
+        let account_id =
+            technical::Module::<Runtime>::tech_account_id_to_account_id(&tech_account_id)
+                .expect("Failed to get ordinary account id for technical account id.");
+        account_id
+    };
+}
+
+impl referral_system::Config for Runtime {
+    type Event = Event;
+    type ReferrerSetGracePeriod = ReferrerSetGracePeriod;
+    type Currency = Balances;
+    type ReferralRewardsAccountId = GetReferralRewardsAccountId;
+    type WeightInfo = referral_system::weights::WeightInfo<Runtime>;
+}
 
 impl rewards::Config for Runtime {
     const BLOCKS_PER_DAY: BlockNumber = 1 * DAYS;
@@ -851,6 +887,21 @@ impl rewards::Config for Runtime {
     type WeightInfo = rewards::weights::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+    pub GetAirdropTechAccountId: TechAccountId = {
+        TechAccountId::from_generic_pair(
+            airdrop::TECH_ACCOUNT_PREFIX.to_vec(),
+            airdrop::TECH_ACCOUNT_MAIN.to_vec(),
+        )
+    };
+}
+
+impl airdrop::Config for Runtime {
+    type Event = Event;
+    type GetTechAccountId = GetAirdropTechAccountId;
+    type WeightInfo = airdrop::weights::WeightInfo<Runtime>;
+}
+
 pub struct ExtrinsicsFlatFees;
 
 // Flat fees implementation for the selected extrinsics.
@@ -860,16 +911,22 @@ impl xor_fee::ApplyCustomFees<Call> for ExtrinsicsFlatFees {
     fn compute_fee(call: &Call) -> Option<Balance> {
         match call {
             Call::Assets(assets::Call::register(..))
+            | Call::DEXManager(dex_manager::Call::register_dex(..))
             | Call::EthBridge(eth_bridge::Call::transfer_to_sidechain(..))
             | Call::PoolXYK(pool_xyk::Call::withdraw_liquidity(..))
-            | Call::Rewards(rewards::Call::claim(..)) => Some(balance!(0.007)),
-            Call::Assets(..)
+            | Call::Rewards(rewards::Call::claim(..))
+            | Call::Rewards(rewards::Call::claim_to(..))
+            | Call::Airdrop(airdrop::Call::claim(..)) => Some(balance!(0.007)),
+            Call::Airdrop(..)
+            | Call::Assets(..)
+            | Call::DEXManager(..)
             | Call::EthBridge(..)
             | Call::LiquidityProxy(..)
             | Call::MulticollateralBondingCurvePool(..)
             | Call::PoolXYK(..)
             | Call::Rewards(..)
             | Call::Staking(pallet_staking::Call::payout_stakers(..))
+            | Call::Technical(..)
             | Call::TradingPair(..) => Some(balance!(0.0007)),
             _ => None,
         }
@@ -1008,6 +1065,7 @@ impl pallet_sudo::Config for Runtime {
 
 impl permissions::Config for Runtime {
     type Event = Event;
+    type WeightInfo = permissions::weights::WeightInfo<Runtime>;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -1223,6 +1281,7 @@ impl farming::Config for Runtime {
     type SchedulerOriginCaller = OriginCaller;
     type Scheduler = Scheduler;
     type RewardDoublingAssets = FarmingRewardDoublingAssets;
+    type Event = Event;
     type WeightInfo = ();
 }
 
@@ -1239,6 +1298,7 @@ impl pswap_distribution::Config for Runtime {
     type WeightInfo = pswap_distribution::weights::WeightInfo<Runtime>;
     type GetParliamentAccountId = GetParliamentAccountId;
     type PoolXykPallet = PoolXYK;
+    type PriceToolsPallet = PriceTools;
 }
 
 parameter_types! {
@@ -1298,6 +1358,20 @@ parameter_types! {
                 .expect("Failed to get ordinary account id for technical account id.");
         account_id
     };
+    pub GetFarmingRewardsTechAccountId: TechAccountId = {
+        let tech_account_id = TechAccountId::from_generic_pair(
+            vested_rewards::TECH_ACCOUNT_PREFIX.to_vec(),
+            vested_rewards::TECH_ACCOUNT_FARMING.to_vec(),
+        );
+        tech_account_id
+    };
+    pub GetFarmingRewardsAccountId: AccountId = {
+        let tech_account_id = GetFarmingRewardsTechAccountId::get();
+        let account_id =
+            technical::Module::<Runtime>::tech_account_id_to_account_id(&tech_account_id)
+                .expect("Failed to get ordinary account id for technical account id.");
+        account_id
+    };
 }
 
 impl multicollateral_bonding_curve_pool::Config for Runtime {
@@ -1306,6 +1380,8 @@ impl multicollateral_bonding_curve_pool::Config for Runtime {
     type EnsureDEXManager = DEXManager;
     type EnsureTradingPairExists = TradingPair;
     type PriceToolsPallet = PriceTools;
+    type PriceOracle = PriceOracle;
+    type CircuitBreaker = CircuitBreaker;
     type VestedRewardsPallet = VestedRewards;
     type WeightInfo = multicollateral_bonding_curve_pool::weights::WeightInfo<Runtime>;
 }
@@ -1316,6 +1392,8 @@ impl xst::Config for Runtime {
     type EnsureDEXManager = DEXManager;
     type EnsureTradingPairExists = TradingPair;
     type PriceToolsPallet = PriceTools;
+    type PriceOracle = PriceOracle;
+    type CircuitBreaker = CircuitBreaker;
     type WeightInfo = xst::weights::WeightInfo<Runtime>;
 }
 
@@ -1340,6 +1418,7 @@ impl vested_rewards::Config for Runtime {
     type Event = Event;
     type GetBondingCurveRewardsAccountId = GetMbcPoolRewardsAccountId;
     type GetMarketMakerRewardsAccountId = GetMarketMakerRewardsAccountId;
+    type GetFarmingRewardsAccountId = GetFarmingRewardsAccountId;
     type WeightInfo = vested_rewards::weights::WeightInfo<Runtime>;
 }
 
@@ -1349,6 +1428,25 @@ impl price_tools::Config for Runtime {
     type WeightInfo = price_tools::weights::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+    pub const PriceFeedStalenessPeriod: BlockNumber = 50;
+    pub const MinPriceFeeds: u32 = 3;
+}
+
+impl price_oracle::Config for Runtime {
+    type Event = Event;
+    type AssetId = AssetId;
+    type StalenessPeriod = PriceFeedStalenessPeriod;
+    type MinFeeds = MinPriceFeeds;
+    type WeightInfo = price_oracle::weights::WeightInfo<Runtime>;
+}
+
+impl circuit_breaker::Config for Runtime {
+    type Event = Event;
+    type AssetId = AssetId;
+    type WeightInfo = circuit_breaker::weights::WeightInfo<Runtime>;
+}
+
 /// Payload data to be signed when making signed transaction from off-chain workers,
 ///   inside `create_transaction` function.
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
@@ -1376,7 +1474,7 @@ construct_runtime! {
         RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Module, Call, Storage} = 4,
         TransactionPayment: pallet_transaction_payment::{Module, Storage} = 5,
         Permissions: permissions::{Module, Call, Storage, Config<T>, Event<T>} = 6,
-        ReferralSystem: referral_system::{Module, Call, Storage} = 7,
+        ReferralSystem: referral_system::{Module, Call, Storage, Event<T>} = 7,
         Rewards: rewards::{Module, Call, Config<T>, Storage, Event<T>} = 8,
         XorFee: xor_fee::{Module, Call, Storage, Event<T>} = 9,
         BridgeMultisig: bridge_multisig::{Module, Call, Storage, Config<T>, Event<T>} = 10,
@@ -1396,9 +1494,9 @@ construct_runtime! {
         Currencies: currencies::{Module, Call, Event<T>} = 19,
         TradingPair: trading_pair::{Module, Call, Storage, Config<T>, Event<T>} = 20,
         Assets: assets::{Module, Call, Storage, Config<T>, Event<T>} = 21,
-        DEXManager: dex_manager::{Module, Storage, Config<T>} = 22,
+        DEXManager: dex_manager::{Module, Call, Storage, Config<T>, Event<T>} = 22,
         MulticollateralBondingCurvePool: multicollateral_bonding_curve_pool::{Module, Call, Storage, Config<T>, Event<T>} = 23,
-        Technical: technical::{Module, Call, Config<T>, Event<T>} = 24,
+        Technical: technical::{Module, Call, Config<T>, Storage, Event<T>} = 24,
         PoolXYK: pool_xyk::{Module, Call, Storage, Event<T>} = 25,
         LiquidityProxy: liquidity_proxy::{Module, Call, Event<T>} = 26,
         Council: pallet_collective::<Instance1>::{Module, Call, Storage, Origin<T>, Event<T>, Config<T>} = 27,
@@ -1416,12 +1514,15 @@ construct_runtime! {
         ElectionsPhragmen: pallet_elections_phragmen::{Module, Call, Storage, Event<T>, Config<T>} = 39,
         VestedRewards: vested_rewards::{Module, Call, Storage, Event<T>} = 40,
         Identity: pallet_identity::{Module, Call, Storage, Event<T>} = 41,
-        Farming: farming::{Module, Call, Storage} = 42,
+        Farming: farming::{Module, Call, Storage, Event<T>} = 42,
         XSTPool: xst::{Module, Call, Storage, Config<T>, Event<T>} = 43,
         PriceTools: price_tools::{Module, Storage, Event<T>} = 44,
+        PriceOracle: price_oracle::{Module, Call, Storage, Config<T>, Event<T>} = 45,
+        CircuitBreaker: circuit_breaker::{Module, Call, Storage, Event<T>} = 46,
+        Airdrop: airdrop::{Module, Call, Storage, Event<T>} = 47,
 
         // Available only for test net
-        Faucet: faucet::{Module, Call, Config<T>, Event<T>} = 80,
+        Faucet: faucet::{Module, Call, Config<T>, Event<T>, ValidateUnsigned} = 80,
     }
 }
 
@@ -1439,7 +1540,7 @@ construct_runtime! {
         RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Module, Call, Storage} = 4,
         TransactionPayment: pallet_transaction_payment::{Module, Storage} = 5,
         Permissions: permissions::{Module, Call, Storage, Config<T>, Event<T>} = 6,
-        ReferralSystem: referral_system::{Module, Call, Storage} = 7,
+        ReferralSystem: referral_system::{Module, Call, Storage, Event<T>} = 7,
         Rewards: rewards::{Module, Call, Config<T>, Storage, Event<T>} = 8,
         XorFee: xor_fee::{Module, Call, Storage, Event<T>} = 9,
         BridgeMultisig: bridge_multisig::{Module, Call, Storage, Config<T>, Event<T>} = 10,
@@ -1459,9 +1560,9 @@ construct_runtime! {
         Currencies: currencies::{Module, Call, Event<T>} = 19,
         TradingPair: trading_pair::{Module, Call, Storage, Config<T>, Event<T>} = 20,
         Assets: assets::{Module, Call, Storage, Config<T>, Event<T>} = 21,
-        DEXManager: dex_manager::{Module, Storage, Config<T>} = 22,
+        DEXManager: dex_manager::{Module, Call, Storage, Config<T>, Event<T>} = 22,
         MulticollateralBondingCurvePool: multicollateral_bonding_curve_pool::{Module, Call, Storage, Config<T>, Event<T>} = 23,
-        Technical: technical::{Module, Call, Config<T>, Event<T>} = 24,
+        Technical: technical::{Module, Call, Config<T>, Storage, Event<T>} = 24,
         PoolXYK: pool_xyk::{Module, Call, Storage, Event<T>} = 25,
         LiquidityProxy: liquidity_proxy::{Module, Call, Event<T>} = 26,
         Council: pallet_collective::<Instance1>::{Module, Call, Storage, Origin<T>, Event<T>, Config<T>} = 27,
@@ -1479,9 +1580,12 @@ construct_runtime! {
         ElectionsPhragmen: pallet_elections_phragmen::{Module, Call, Storage, Event<T>, Config<T>} = 39,
         VestedRewards: vested_rewards::{Module, Call, Storage, Event<T>} = 40,
         Identity: pallet_identity::{Module, Call, Storage, Event<T>} = 41,
-        Farming: farming::{Module, Call, Storage} = 42,
+        Farming: farming::{Module, Call, Storage, Event<T>} = 42,
         XSTPool: xst::{Module, Call, Storage, Config<T>, Event<T>} = 43,
         PriceTools: price_tools::{Module, Storage, Event<T>} = 44,
+        PriceOracle: price_oracle::{Module, Call, Storage, Config<T>, Event<T>} = 45,
+        CircuitBreaker: circuit_breaker::{Module, Call, Storage, Event<T>} = 46,
+        Airdrop: airdrop::{Module, Call, Storage, Event<T>} = 47,
     }
 }
 
@@ -1698,6 +1802,10 @@ impl_runtime_apis! {
                 || TradingPair::is_trading_pair_enabled(&dex_id, &asset_id_b, &asset_id_a).unwrap_or(false)
         }
 
+        fn is_pair_paused(dex_id: DEXId, asset_id_a: AssetId, asset_id_b: AssetId) -> bool {
+            TradingPair::is_trading_paused(&dex_id, &asset_id_a, &asset_id_b)
+        }
+
         fn list_enabled_sources_for_pair(
             dex_id: DEXId,
             base_asset_id: AssetId,
@@ -1838,10 +1946,26 @@ impl_runtime_apis! {
         }
     }
 
-    impl iroha_migration_runtime_api::IrohaMigrationAPI<Block> for Runtime {
+    impl iroha_migration_runtime_api::IrohaMigrationAPI<Block, BlockNumber> for Runtime {
         fn needs_migration(iroha_address: String) -> bool {
             IrohaMigration::needs_migration(&iroha_address)
         }
+
+        fn pending_multisig_migrations(
+        ) -> Vec<iroha_migration_runtime_api::PendingMultisigMigrationInfo<BlockNumber>> {
+            IrohaMigration::pending_multisig_migrations()
+                .into_iter()
+                .map(|(iroha_address, approval_count, public_key_count, quorum, migrate_at)| {
+                    iroha_migration_runtime_api::PendingMultisigMigrationInfo {
+                        iroha_address,
+                        approval_count,
+                        public_key_count,
+                        quorum,
+                        migrate_at,
+                    }
+                })
+                .collect()
+        }
     }
 
     impl liquidity_proxy_runtime_api::LiquidityProxyAPI<
@@ -1909,6 +2033,7 @@ impl_runtime_apis! {
     impl pswap_distribution_runtime_api::PswapDistributionAPI<
         Block,
         AccountId,
+        BlockNumber,
         Balance,
     > for Runtime {
         fn claimable_amount(
@@ -1919,12 +2044,326 @@ impl_runtime_apis! {
                 balance: claimable
             }
         }
+
+        fn next_distribution_block(fees_account_id: AccountId) -> Option<BlockNumber> {
+            PswapDistribution::next_distribution_block(&fees_account_id)
+        }
+
+        fn distribution_history(
+            fees_account_id: AccountId,
+        ) -> Vec<pswap_distribution_runtime_api::DistributionInfo<BlockNumber, Balance>> {
+            PswapDistribution::distribution_history(&fees_account_id)
+                .into_iter()
+                .map(|record| pswap_distribution_runtime_api::DistributionInfo {
+                    block: record.block,
+                    incentive_total: record.incentive_total,
+                    liquidity_providers: record.liquidity_providers,
+                    vesting: record.vesting,
+                    parliament: record.parliament,
+                    shareholders_num: record.shareholders_num,
+                })
+                .collect()
+        }
+
+        fn burn_rate_info() -> pswap_distribution_runtime_api::BurnRateInfo<BlockNumber, Balance> {
+            use sp_std::convert::TryInto;
+            let fixed_to_balance = |value: Fixed| value.into_bits().try_into().unwrap_or(0);
+            let trajectory = PswapDistribution::burn_rate_trajectory();
+            pswap_distribution_runtime_api::BurnRateInfo {
+                current: fixed_to_balance(trajectory.current),
+                increase_delta: fixed_to_balance(trajectory.increase_delta),
+                max: fixed_to_balance(trajectory.max),
+                update_frequency: GetBurnUpdateFrequency::get(),
+                next_update: trajectory.next_update,
+                max_reached_at: trajectory.max_reached_at,
+                history: PswapDistribution::burn_rate_history()
+                    .into_iter()
+                    .map(|(block, burn_rate)| pswap_distribution_runtime_api::BurnRateChange {
+                        block,
+                        burn_rate: fixed_to_balance(burn_rate),
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl farming_runtime_api::FarmingAPI<
+        Block,
+        AccountId,
+        AssetId,
+        BlockNumber,
+        Balance,
+    > for Runtime {
+        fn farm_info(
+            farm_id: farming_runtime_api::FarmId,
+        ) -> Option<farming_runtime_api::FarmInfo<AccountId, AssetId, BlockNumber, Balance>> {
+            Farming::farm(farm_id).map(|farm| farming_runtime_api::FarmInfo {
+                owner: farm.owner,
+                pool: farm.pool,
+                reward_asset: farm.reward_asset,
+                reward_per_block: farm.reward_per_block,
+                start_block: farm.start_block,
+                end_block: farm.end_block,
+                last_distribution_block: farm.last_distribution_block,
+                undistributed_reward: farm.undistributed_reward,
+            })
+        }
+
+        fn pool_farms(pool: AccountId) -> Vec<farming_runtime_api::FarmId> {
+            farming::FarmsByPool::<Runtime>::get(&pool)
+        }
+
+        fn farmer_info(
+            farm_id: farming_runtime_api::FarmId,
+            account_id: AccountId,
+        ) -> farming_runtime_api::FarmerInfo<Balance> {
+            farming_runtime_api::FarmerInfo::<Balance> {
+                reward: Farming::farmer_reward(farm_id, &account_id),
+            }
+        }
+
+        fn accrued_reward(
+            account_id: AccountId,
+            pool: AccountId,
+        ) -> farming_runtime_api::FarmerInfo<Balance> {
+            farming_runtime_api::FarmerInfo::<Balance> {
+                reward: Farming::accrued_reward(&account_id, &pool),
+            }
+        }
+
+        fn liquidity_lock(
+            account_id: AccountId,
+            pool: AccountId,
+        ) -> Option<farming_runtime_api::LiquidityLockInfo<BlockNumber, Balance>> {
+            let lock = PoolXYK::active_liquidity_lock(&pool, &account_id)?;
+            let weight_multiplier = Farming::get_lock_multiplier(&lock, System::block_number())
+                .try_into_balance()
+                .ok()?;
+            Some(farming_runtime_api::LiquidityLockInfo {
+                pool_tokens: lock.pool_tokens,
+                unlock_block: lock.unlock_block,
+                weight_multiplier,
+            })
+        }
+    }
+
+    impl referral_system_runtime_api::ReferralSystemAPI<Block, AccountId, Balance> for Runtime {
+        fn referrals(account_id: AccountId) -> Vec<AccountId> {
+            referral_system::Referrals::<Runtime>::iter_prefix(&account_id)
+                .map(|(referral, _)| referral)
+                .collect()
+        }
+
+        fn referrer_info(
+            account_id: AccountId,
+        ) -> referral_system_runtime_api::ReferrerInfo<AccountId, Balance> {
+            let stats = ReferralSystem::referrer_stats(&account_id);
+            referral_system_runtime_api::ReferrerInfo {
+                referrer: ReferralSystem::referrer_account(&account_id),
+                referrals_count: stats.referrals_count,
+                earned_fees: stats.earned_fees,
+                earned_swap_fees: stats.earned_swap_fees,
+            }
+        }
+    }
+
+    impl permissions_runtime_api::PermissionsAPI<Block, AccountId, permissions::Scope, BlockNumber> for Runtime {
+        fn list_permissions(
+            account_id: AccountId,
+        ) -> Vec<permissions_runtime_api::PermissionInfo<permissions::Scope, BlockNumber>> {
+            Permissions::list_permissions(&account_id)
+                .into_iter()
+                .map(|(permission_id, scope, expires_at)| {
+                    permissions_runtime_api::PermissionInfo {
+                        permission_id,
+                        scope,
+                        expires_at,
+                    }
+                })
+                .collect()
+        }
+    }
+
+    impl faucet_runtime_api::FaucetAPI<Block, AccountId, AssetId, Balance> for Runtime {
+        #[allow(unused_variables)]
+        fn remaining_allowance(
+            account_id: AccountId,
+        ) -> Vec<faucet_runtime_api::AllowanceInfo<AssetId, Balance>> {
+            #[cfg(feature = "private-net")]
+            {
+                Faucet::remaining_allowances(&account_id)
+                    .into_iter()
+                    .map(|(asset_id, remaining)| faucet_runtime_api::AllowanceInfo {
+                        asset_id,
+                        remaining,
+                    })
+                    .collect()
+            }
+            #[cfg(not(feature = "private-net"))]
+            {
+                Vec::new()
+            }
+        }
+    }
+
+    impl technical_runtime_api::TechnicalAPI<Block, AccountId, TechAccountId> for Runtime {
+        fn lookup_tech_account_id(account_id: AccountId) -> Option<TechAccountId> {
+            Technical::tech_account(&account_id)
+        }
+
+        fn tech_account_id_to_account_id(tech_account_id: TechAccountId) -> Option<AccountId> {
+            Technical::tech_account_id_to_account_id(&tech_account_id).ok()
+        }
+
+        fn tech_accounts(
+            start_after: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<technical_runtime_api::TechAccountInfo<AccountId, TechAccountId>> {
+            Technical::tech_accounts(start_after, limit)
+                .into_iter()
+                .map(|(account_id, tech_account_id)| technical_runtime_api::TechAccountInfo {
+                    account_id,
+                    tech_account_id,
+                })
+                .collect()
+        }
+    }
+
+    impl vested_rewards_runtime_api::VestedRewardsAPI<Block, AccountId, Balance> for Runtime {
+        fn pending_rewards(
+            account_id: AccountId,
+        ) -> Vec<vested_rewards_runtime_api::PendingRewardInfo<Balance>> {
+            VestedRewards::claimable_rewards(&account_id)
+                .into_iter()
+                .map(|(reason, pending, claimable)| {
+                    vested_rewards_runtime_api::PendingRewardInfo {
+                        reason,
+                        pending,
+                        claimable,
+                    }
+                })
+                .collect()
+        }
+
+        fn market_maker_info(
+            account_id: AccountId,
+        ) -> vested_rewards_runtime_api::MarketMakerInfo<Balance> {
+            let info = VestedRewards::market_makers_registry(&account_id);
+            vested_rewards_runtime_api::MarketMakerInfo {
+                count: info.count,
+                volume: info.volume,
+                eligible: info.count >= VestedRewards::market_maker_params().eligibility_tx_count,
+                projected_reward: VestedRewards::projected_market_maker_reward(&account_id),
+            }
+        }
+    }
+
+    impl multicollateral_bonding_curve_pool_runtime_api::MulticollateralBondingCurvePoolAPI<
+        Block,
+        AccountId,
+        AssetId,
+        Balance,
+        SwapVariant,
+    > for Runtime {
+        fn curve_state() -> Option<multicollateral_bonding_curve_pool_runtime_api::CurveStateInfo<AssetId, Balance>> {
+            let to_balance = |value: Fixed| common::prelude::FixedWrapper::from(value).try_into_balance().ok();
+            let base_asset_id = GetBaseAssetId::get();
+            Some(multicollateral_bonding_curve_pool_runtime_api::CurveStateInfo {
+                initial_price: to_balance(MulticollateralBondingCurvePool::initial_price())?,
+                price_change_step: to_balance(MulticollateralBondingCurvePool::price_change_step())?,
+                price_change_rate: to_balance(MulticollateralBondingCurvePool::price_change_rate())?,
+                sell_price_coefficient: to_balance(MulticollateralBondingCurvePool::sell_price_coefficient())?,
+                base_fee: to_balance(MulticollateralBondingCurvePool::base_fee())?,
+                buy_price: to_balance(MulticollateralBondingCurvePool::buy_function(&base_asset_id, fixed!(0)).ok()?)?,
+                sell_price: to_balance(MulticollateralBondingCurvePool::sell_function(&base_asset_id, fixed!(0)).ok()?)?,
+                enabled_collaterals: MulticollateralBondingCurvePool::enabled_targets().into_iter().collect(),
+                retired_collaterals: MulticollateralBondingCurvePool::retired_targets().into_iter().collect(),
+            })
+        }
+
+        fn rewards(account_id: AccountId) -> multicollateral_bonding_curve_pool_runtime_api::RewardsInfo<Balance> {
+            let (limit, total_available) = MulticollateralBondingCurvePool::rewards(&account_id);
+            multicollateral_bonding_curve_pool_runtime_api::RewardsInfo::<Balance> {
+                limit,
+                total_available,
+            }
+        }
+
+        fn total_rewards() -> multicollateral_bonding_curve_pool_runtime_api::BalanceInfo<Balance> {
+            multicollateral_bonding_curve_pool_runtime_api::BalanceInfo::<Balance> {
+                balance: MulticollateralBondingCurvePool::total_rewards()
+            }
+        }
+
+        fn collateral_reserves(
+            collateral_asset_id: AssetId,
+        ) -> Option<multicollateral_bonding_curve_pool_runtime_api::BalanceInfo<Balance>> {
+            if !MulticollateralBondingCurvePool::enabled_targets().contains(&collateral_asset_id) {
+                return None;
+            }
+            Some(multicollateral_bonding_curve_pool_runtime_api::BalanceInfo::<Balance> {
+                balance: MulticollateralBondingCurvePool::collateral_reserves(&collateral_asset_id)
+            })
+        }
+
+        fn ideal_reserves() -> Option<multicollateral_bonding_curve_pool_runtime_api::BalanceInfo<Balance>> {
+            MulticollateralBondingCurvePool::ideal_reserves_reference_price(fixed!(0))
+                .ok()
+                .map(|balance| multicollateral_bonding_curve_pool_runtime_api::BalanceInfo::<Balance> { balance })
+        }
+
+        fn buy_price(
+            collateral_asset_id: AssetId,
+            amount: BalanceWrapper,
+            swap_variant: SwapVariant,
+        ) -> Option<multicollateral_bonding_curve_pool_runtime_api::BalanceInfo<Balance>> {
+            if !MulticollateralBondingCurvePool::enabled_targets().contains(&collateral_asset_id) {
+                return None;
+            }
+            let price = MulticollateralBondingCurvePool::buy_price(
+                &GetBaseAssetId::get(),
+                &collateral_asset_id,
+                QuoteAmount::with_variant(swap_variant, amount.into()),
+            ).ok()?;
+            Some(multicollateral_bonding_curve_pool_runtime_api::BalanceInfo::<Balance> {
+                balance: common::prelude::FixedWrapper::from(price).try_into_balance().ok()?
+            })
+        }
+
+        fn sell_price(
+            collateral_asset_id: AssetId,
+            amount: BalanceWrapper,
+            swap_variant: SwapVariant,
+        ) -> Option<multicollateral_bonding_curve_pool_runtime_api::BalanceInfo<Balance>> {
+            if !MulticollateralBondingCurvePool::enabled_targets().contains(&collateral_asset_id) {
+                return None;
+            }
+            let price = MulticollateralBondingCurvePool::sell_price(
+                &GetBaseAssetId::get(),
+                &collateral_asset_id,
+                QuoteAmount::with_variant(swap_variant, amount.into()),
+            ).ok()?;
+            Some(multicollateral_bonding_curve_pool_runtime_api::BalanceInfo::<Balance> {
+                balance: common::prelude::FixedWrapper::from(price).try_into_balance().ok()?
+            })
+        }
     }
 
     impl rewards_runtime_api::RewardsAPI<Block, sp_core::H160, Balance> for Runtime {
         fn claimables(eth_address: sp_core::H160) -> Vec<rewards_runtime_api::BalanceInfo<Balance>> {
             Rewards::claimables(&eth_address).into_iter().map(|balance| rewards_runtime_api::BalanceInfo::<Balance> { balance }).collect()
         }
+
+        fn claimables_info(eth_address: sp_core::H160) -> rewards_runtime_api::ClaimablesInfo<Balance> {
+            let (val, pswap_farm, pswap_waifu, vesting_ratio) = Rewards::claimables_info(&eth_address);
+            rewards_runtime_api::ClaimablesInfo {
+                val_vested: val.claimable,
+                val_total: val.total,
+                pswap_farm,
+                pswap_waifu,
+                vesting_ratio,
+            }
+        }
     }
 
     impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -2052,6 +2491,7 @@ impl_runtime_apis! {
             let mut batches = Vec::<BenchmarkBatch>::new();
             let params = (&config, &whitelist);
 
+            add_benchmark!(params, batches, airdrop, Airdrop);
             add_benchmark!(params, batches, assets, Assets);
             add_benchmark!(params, batches, dex_api, DEXAPIBench::<Runtime>);
             #[cfg(feature = "private-net")]
@@ -2060,13 +2500,17 @@ impl_runtime_apis! {
             add_benchmark!(params, batches, iroha_migration, IrohaMigration);
             add_benchmark!(params, batches, liquidity_proxy, LiquidityProxyBench::<Runtime>);
             add_benchmark!(params, batches, multicollateral_bonding_curve_pool, MulticollateralBondingCurvePool);
+            add_benchmark!(params, batches, permissions, Permissions);
             add_benchmark!(params, batches, pswap_distribution, PswapDistributionBench::<Runtime>);
+            add_benchmark!(params, batches, referral_system, ReferralSystem);
             add_benchmark!(params, batches, rewards, Rewards);
+            add_benchmark!(params, batches, technical, Technical);
             add_benchmark!(params, batches, trading_pair, TradingPair);
             add_benchmark!(params, batches, pool_xyk, XYKPoolBench::<Runtime>);
             add_benchmark!(params, batches, eth_bridge, EthBridge);
             add_benchmark!(params, batches, vested_rewards, VestedRewards);
             add_benchmark!(params, batches, price_tools, PriceTools);
+            add_benchmark!(params, batches, price_oracle, PriceOracle);
             add_benchmark!(params, batches, xor_fee, XorFeeBench::<Runtime>);
 
             if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }